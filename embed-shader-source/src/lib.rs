@@ -29,21 +29,20 @@ pub fn embed_shader_source(input: TokenStream) -> TokenStream {
 
     quote! {
         {
-            static mut DATA: [u8; #len] = [#(#source), *];
+            static DATA: [u8; #len] = [#(#source), *];
+            // Decoded once, so that every renderer instance can share it
+            static SOURCE: std::sync::OnceLock<String> = std::sync::OnceLock::new();
 
             // Trigger recompilation when file changes.
             let _ = include_bytes!(#path);
 
-            unsafe {
-                for byte in &mut DATA {
-                    *byte = byte.wrapping_add(128);
-                }
+            let source = SOURCE.get_or_init(|| {
+                let data = DATA.iter().map(|byte| byte.wrapping_add(128)).collect();
+                String::from_utf8(data).unwrap()
+            }).as_str();
+            let filename = #filename.to_string();
 
-                let source = str::from_utf8(&DATA).unwrap();
-                let filename = #filename.to_string();
-
-                crate::engine::graphics::ShaderSource { source, filename }
-            }
+            crate::engine::graphics::ShaderSource { source, filename }
         }
     }.into()
 }
\ No newline at end of file
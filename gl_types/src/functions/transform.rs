@@ -1,5 +1,7 @@
 #![allow(non_snake_case)]
 
+use nalgebra::{Matrix4, Unit};
+
 use crate::{matrices::Mat4, vectors::Vec3};
 
 use super::geometric::{cross, dot, normalize};
@@ -21,6 +23,14 @@ pub fn lookAt(eye: Vec3, center: Vec3, up: Vec3) -> Mat4 {
     )
 }
 
-// pub fn rotate(m: &Mat4, angle: f32, axis: &Vec3) -> Mat4 {
+pub fn translate(m: Mat4, v: Vec3) -> Mat4 {
+    Mat4(m.0 * Matrix4::new_translation(&v.0))
+}
 
-// }
\ No newline at end of file
+pub fn rotate(m: Mat4, angle: f32, axis: Vec3) -> Mat4 {
+    Mat4(m.0 * Matrix4::from_axis_angle(&Unit::new_normalize(axis.0), angle))
+}
+
+pub fn scale(m: Mat4, v: Vec3) -> Mat4 {
+    Mat4(m.0 * Matrix4::new_nonuniform_scaling(&v.0))
+}
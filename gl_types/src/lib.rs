@@ -3,6 +3,7 @@ mod element_wise;
 pub mod vectors;
 pub mod matrices;
 pub mod functions;
+pub mod quaternion;
 
 pub use functions::*;
 pub use element_wise::*;
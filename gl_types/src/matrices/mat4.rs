@@ -26,6 +26,14 @@ impl Mat4 {
 
 matrix_arithmetic!(Mat4);
 
+impl Mul<Vec4> for Mat4 {
+    type Output = Vec4;
+
+    fn mul(self, rhs: Vec4) -> Self::Output {
+        Vec4(self.0 * rhs.0)
+    }
+}
+
 impl Seal for Mat4 {}
 
 pub trait Mat4Constructor<T>: Seal {
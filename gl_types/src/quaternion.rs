@@ -0,0 +1,131 @@
+use std::{fmt::Debug, ops::{Mul, MulAssign}};
+
+use nalgebra::{Quaternion, Rotation3, Unit, UnitQuaternion, Vector3};
+
+use crate::{matrices::{Mat4, Mat4Constructor}, vectors::Vec3};
+
+/// Unit quaternion describing a rotation.
+#[repr(C)]
+#[derive(Clone, Copy, PartialEq)]
+pub struct Quat(pub(in crate) UnitQuaternion<f32>);
+
+impl Debug for Quat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Quat {
+    pub const IDENTITY: Quat = Quat(Unit::new_unchecked(Quaternion::new(1.0, 0.0, 0.0, 0.0)));
+
+    /// Normalizes the input, so any non-zero quaternion is accepted.
+    pub fn new(x: f32, y: f32, z: f32, w: f32) -> Quat {
+        Quat(UnitQuaternion::new_normalize(Quaternion::new(w, x, y, z)))
+    }
+
+    pub fn from_axis_angle(axis: Vec3, angle: f32) -> Quat {
+        Quat(UnitQuaternion::from_axis_angle(&Unit::new_normalize(axis.0), angle))
+    }
+
+    /// Euler angles in radians, x is pitch, y is yaw and z is roll. They are applied z, x then y.
+    pub fn from_euler(angles: Vec3) -> Quat {
+        let x = UnitQuaternion::from_axis_angle(&Vector3::x_axis(), angles.x());
+        let y = UnitQuaternion::from_axis_angle(&Vector3::y_axis(), angles.y());
+        let z = UnitQuaternion::from_axis_angle(&Vector3::z_axis(), angles.z());
+
+        Quat(y * x * z)
+    }
+
+    /// Inverse of `from_euler`. Pitch is kept in [-pi/2, pi/2], at the poles the roll is folded into the yaw.
+    pub fn to_euler(self) -> Vec3 {
+        let m = self.0.to_rotation_matrix().into_inner();
+        let sin_pitch = (-m[(1, 2)]).clamp(-1.0, 1.0);
+
+        if sin_pitch.abs() > 0.9999 {
+            Vec3::_new(sin_pitch.asin(), (-m[(2, 0)]).atan2(m[(0, 0)]), 0.0)
+        } else {
+            Vec3::_new(sin_pitch.asin(), m[(0, 2)].atan2(m[(2, 2)]), m[(1, 0)].atan2(m[(1, 1)]))
+        }
+    }
+
+    /// Rotation that turns +z towards `forward` and +y as close to `up` as possible.
+    pub fn look_rotation(forward: Vec3, up: Vec3) -> Quat {
+        Quat(UnitQuaternion::from_rotation_matrix(&Rotation3::face_towards(&forward.0, &up.0)))
+    }
+
+    /// Spherical interpolation along the shortest path.
+    pub fn slerp(self, other: Quat, t: f32) -> Quat {
+        let other = if self.0.coords.dot(&other.0.coords) < 0.0 { Quat(Unit::new_unchecked(-other.0.into_inner())) } else { other };
+
+        match self.0.try_slerp(&other.0, t, 1e-6) {
+            Some(q) => Quat(q),
+            None => self.nlerp(other, t)
+        }
+    }
+
+    pub fn nlerp(self, other: Quat, t: f32) -> Quat {
+        Quat(self.0.nlerp(&other.0, t))
+    }
+
+    pub fn inverse(self) -> Quat {
+        Quat(self.0.inverse())
+    }
+
+    pub fn angle(self) -> f32 {
+        self.0.angle()
+    }
+
+    pub fn axis(self) -> Option<Vec3> {
+        self.0.axis().map(|axis| Vec3(axis.into_inner()))
+    }
+
+    pub fn x(&self) -> f32 {
+        self.0.i
+    }
+
+    pub fn y(&self) -> f32 {
+        self.0.j
+    }
+
+    pub fn z(&self) -> f32 {
+        self.0.k
+    }
+
+    pub fn w(&self) -> f32 {
+        self.0.w
+    }
+}
+
+impl Default for Quat {
+    fn default() -> Self {
+        Quat::IDENTITY
+    }
+}
+
+impl Mul for Quat {
+    type Output = Quat;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        Quat(self.0 * rhs.0)
+    }
+}
+
+impl MulAssign for Quat {
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 *= rhs.0;
+    }
+}
+
+impl Mul<Vec3> for Quat {
+    type Output = Vec3;
+
+    fn mul(self, rhs: Vec3) -> Self::Output {
+        Vec3(self.0 * rhs.0)
+    }
+}
+
+impl Mat4Constructor<Quat> for Mat4 {
+    fn new(args: Quat) -> Mat4 {
+        Mat4(args.0.to_homogeneous())
+    }
+}
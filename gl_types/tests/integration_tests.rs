@@ -1,4 +1,4 @@
-use gl_types::{functions::geometric::{length, normalize}, mat2, mat3, mat4, matrix::inverse, vec2, vec3, vec4, vectors::{Vec2, Vec3, Vec4, VecN}};
+use gl_types::{functions::geometric::{distance, length, normalize}, mat2, mat3, mat4, matrix::inverse, quaternion::Quat, vec2, vec3, vec4, vectors::{Vec2, Vec3, Vec4, VecN}};
 use rand::Rng;
 
 const TEST_COUNT: usize = 100000;
@@ -315,4 +315,27 @@ fn inverse_test() {
     assert_eq!(inverse, mat3!(vec3!(-11.0 / 12.0, - 1.0 / 6.0, 3.0 / 4.0), vec3!(1.0 / 3.0, 1.0 / 3.0, -1.0 / 3.0), vec3!(1.0 / 12.0, -1.0 / 6.0, 1.0 / 12.0)));
 }
 
+#[test]
+fn quaternion_test() {
+    let near = |a: Vec3, b: Vec3| distance(a, b) < 1e-5;
+    let quarter = std::f32::consts::FRAC_PI_2;
+
+    let yaw = Quat::from_axis_angle(vec3!(0, 1, 0), quarter);
+    assert!(near(yaw * vec3!(0, 0, 1), vec3!(1, 0, 0)));
+    assert!(near(vec3!(mat4!(yaw) * vec4!(0, 0, 1, 0)), vec3!(1, 0, 0)));
+    assert!(near((yaw * yaw.inverse()) * vec3!(1, 2, 3), vec3!(1, 2, 3)));
+
+    let angles = vec3!(0.5, -1.0, 0.25);
+    assert!(near(Quat::from_euler(angles).to_euler(), angles));
+    assert!(near(Quat::from_euler(vec3!(0.0, quarter, 0.0)) * vec3!(0, 0, 1), vec3!(1, 0, 0)));
+
+    let half = Quat::IDENTITY.slerp(yaw, 0.5);
+    assert!((half.angle() - quarter / 2.0).abs() < 1e-5);
+    assert!(near(half.axis().unwrap(), vec3!(0, 1, 0)));
+
+    let look = Quat::look_rotation(vec3!(1, 0, 0), vec3!(0, 1, 0));
+    assert!(near(look * vec3!(0, 0, 1), vec3!(1, 0, 0)));
+    assert!(near(look * vec3!(0, 1, 0), vec3!(0, 1, 0)));
+}
+
 // Need more tests, but i'm lazy
\ No newline at end of file
@@ -0,0 +1,58 @@
+use std::{cell::Cell, rc::Rc, time::Instant};
+
+/// Supplies the current time in seconds to `Engine::run`. Only differences between calls matter.
+pub trait TimeSource {
+    fn now(&mut self) -> f64;
+}
+
+pub struct SystemClock {
+    start: Instant
+}
+
+impl SystemClock {
+    pub fn new() -> SystemClock {
+        SystemClock { start: Instant::now() }
+    }
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeSource for SystemClock {
+    fn now(&mut self) -> f64 {
+        self.start.elapsed().as_secs_f64()
+    }
+}
+
+// Clones share the same time, so one handle can be given to the engine and the other kept to advance it
+#[derive(Clone, Default)]
+pub struct ManualClock {
+    time: Rc<Cell<f64>>
+}
+
+impl ManualClock {
+    pub fn new() -> ManualClock {
+        ManualClock::default()
+    }
+
+    pub fn advance(&self, seconds: f64) {
+        self.time.set(self.time.get() + seconds);
+    }
+
+    pub fn set(&self, seconds: f64) {
+        self.time.set(seconds);
+    }
+
+    pub fn get(&self) -> f64 {
+        self.time.get()
+    }
+}
+
+impl TimeSource for ManualClock {
+    fn now(&mut self) -> f64 {
+        self.time.get()
+    }
+}
@@ -34,7 +34,7 @@ impl<T: Clone> Clone for VecAllocator<T> {
     }
 }
 
-#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
 pub struct AllocationIndex {
     allocator_id: u128,
     index: usize,
@@ -1,20 +1,33 @@
 
+use std::{any::TypeId, collections::HashMap, path::Path};
+
 use gl46::{GL_COLOR_BUFFER_BIT, GL_DEPTH_BUFFER_BIT};
-use glfw::{Action, WindowEvent};
+use glfw::WindowEvent;
+use log::{error, warn};
 
-use crate::engine::{errors::{Error, Result}, graphics::{sprite_renderer::SpriteRenderer, terrain::terrain_renderer::TerrainRenderer}};
+use crate::engine::{errors::{Error, ErrorPolicy, InputError, Result}, input::{is_input_event, InputPlayback, InputRecorder, InputRecording}, graphics::{sprite_renderer::{components::queue_sprites, SpriteRenderer}, terrain::{queue_terrain, terrain_renderer::TerrainRenderer}}};
 
-use super::{game_object::World, graphics::Graphics, input::Input};
+use super::{Events, Schedule, Stage, System, SystemClock, Time, TimeSource, game_object::{component::Component, ComponentID, ComponentRemoved, World}, graphics::Graphics, input::Input};
 
 pub struct Engine {
     pub gfx: Graphics,
     pub world: World,
     pub input: Input,
+    pub time: Time,
+    pub events: Events,
     pub(in crate::engine) sprite_renderer: SpriteRenderer,
     pub(in crate::engine) terrain_renderer: TerrainRenderer,
+    schedule: Schedule,
+    time_source: Box<dyn TimeSource>,
+    fixed_accumulator: f64,
     fixed_tick_duration: f64,
+    max_fixed_steps: u32,
     fixed_input: Input,
-    error_queue: Vec<Error>
+    error_queue: Vec<Error>,
+    error_policy: ErrorPolicy,
+    component_error_policies: HashMap<TypeId, ErrorPolicy>,
+    input_recorder: Option<InputRecorder>,
+    input_playback: Option<InputPlayback>
 }
 
 #[derive(Debug)]
@@ -27,140 +40,319 @@ impl Engine {
     pub fn create_window(window_title: &str, width: u32, height: u32, window_mode: WindowMode) -> Result<Engine> {
         let gfx = Graphics::init(window_title, width, height, window_mode)?;
 
+        Self::new(gfx)
+    }
+
+    /// Creates an engine without a window, rendering into the software gl backend. Drive it with `step`.
+    pub fn create_headless() -> Result<Engine> {
+        let gfx = Graphics::init_headless()?;
+
+        Self::new(gfx)
+    }
+
+    fn new(gfx: Graphics) -> Result<Engine> {
         let world = World::new();
+        let mut input = Input::new();
+        let mut fixed_input = Input::with_input_map(&input);
+
+        let (width, height) = gfx.window_size();
+        input.set_window_size(width, height);
+        fixed_input.set_window_size(width, height);
 
         let sprite_renderer = SpriteRenderer::new(&gfx)?;
         let terrain_renderer = TerrainRenderer::new(&gfx)?;
+
+        let mut schedule = Schedule::new();
+        schedule.add(Stage::PreRender, System::new("queue_sprites", queue_sprites))?;
+        schedule.add(Stage::PreRender, System::new("queue_terrain", queue_terrain))?;
         
-        Ok(Engine { gfx, world, sprite_renderer, terrain_renderer, fixed_tick_duration: 1.0 / 60.0, error_queue: Vec::new(), input: Input::new(), fixed_input: Input::new() })
+        Ok(Engine {
+            gfx,
+            world,
+            sprite_renderer,
+            terrain_renderer,
+            schedule,
+            time_source: Box::new(SystemClock::new()),
+            fixed_accumulator: 0.0,
+            fixed_tick_duration: 1.0 / 60.0,
+            max_fixed_steps: 8,
+            error_queue: Vec::new(),
+            error_policy: ErrorPolicy::default(),
+            component_error_policies: HashMap::new(),
+            input_recorder: None,
+            input_playback: None,
+            input,
+            time: Time::new(),
+            events: Events::new(),
+            fixed_input
+        })
+    }
+
+    pub fn fixed_input(&self) -> &Input {
+        &self.fixed_input
+    }
+
+    pub fn set_time_source<T: TimeSource + 'static>(&mut self, time_source: T) {
+        self.time_source = Box::new(time_source);
+    }
+
+    pub fn fixed_tick_duration(&self) -> f64 {
+        self.fixed_tick_duration
+    }
+
+    pub fn set_fixed_tick_duration(&mut self, seconds: f64) {
+        assert!(seconds > 0.0, "Fixed tick duration must be positive!");
+
+        self.fixed_tick_duration = seconds;
+    }
+
+    /// Maximum amount of fixed ticks run in a single frame, time beyond that is dropped.
+    pub fn set_max_fixed_steps(&mut self, steps: u32) {
+        self.max_fixed_steps = steps;
+    }
+
+    /// How far the current frame is between the last fixed tick and the next one, in `[0, 1)`. Used to interpolate rendering.
+    pub fn fixed_alpha(&self) -> f32 {
+        (self.fixed_accumulator / self.fixed_tick_duration) as f32
+    }
+
+    /// Systems added while their stage is running first run in the next frame.
+    pub fn add_system(&mut self, stage: Stage, system: System) -> Result<()> {
+        Ok(self.schedule.add(stage, system)?)
+    }
+
+    pub fn remove_system(&mut self, stage: Stage, name: &str) -> Result<()> {
+        Ok(self.schedule.remove(stage, name)?)
     }
 
     pub fn run(&mut self) -> Result<()> {
-        let mut last_tick = self.gfx.get_glfw_time();
-        let mut last_fixed_tick = last_tick;
-        let mut fixed_tick_overflow = 0.0;
+        let mut last_tick = self.time_source.now();
 
         self.log_errors();
 
         while !self.gfx.should_close() {
-            self.gfx.poll_events();
-            for msg in self.gfx.flush_messages() {
-                match msg {
-                    (_, WindowEvent::Key(key, _, Action::Press, _)) => {
-                        let key_state = self.input.modify_key_state(key);
-                        key_state.press = true;
-                        key_state.is_down = true;
-
-                        let fixed_key_state = self.fixed_input.modify_key_state(key);
-                        fixed_key_state.press = true;
-                        fixed_key_state.is_down = true;
-                    },
-                    (_, WindowEvent::Key(key, _, Action::Release, _)) => {
-                        let key_state = self.input.modify_key_state(key);
-                        key_state.release = true;
-                        key_state.is_down = false;
-
-                        let fixed_key_state = self.fixed_input.modify_key_state(key);
-                        fixed_key_state.release = true;
-                        fixed_key_state.is_down = false;
-                    },
-                    (_, WindowEvent::MouseButton(button, Action::Press, _)) => {
-                        let key_state = self.input.modify_mouse_button_state(button as u32);
-                        key_state.press = true;
-                        key_state.is_down = true;
-
-                        let fixed_key_state = self.fixed_input.modify_mouse_button_state(button as u32);
-                        fixed_key_state.press = true;
-                        fixed_key_state.is_down = true;
-                    },
-                    (_, WindowEvent::MouseButton(button, Action::Release, _)) => {
-                        let key_state = self.input.modify_mouse_button_state(button as u32);
-                        key_state.release = true;
-                        key_state.is_down = false;
-
-                        let fixed_key_state = self.fixed_input.modify_mouse_button_state(button as u32);
-                        fixed_key_state.release = true;
-                        fixed_key_state.is_down = false;
-                    },
-                    (_, WindowEvent::Scroll(x, y)) => {
-                        self.input.add_scroll_delta(x, y);
-                        self.fixed_input.add_scroll_delta(x, y);
-                    }
-                    // (_, WindowEvent::Key(Key::Escape, _, Action::Press, _)) => gfx.set_should_close(true),
-                    // (_, WindowEvent::Key(Key::Space, _, Action::Press, _)) => gfx.set_fullscreen(Monitor::from_primary()),
-                    _ => ()
-                }
+            let current_time = self.time_source.now();
+            self.step(current_time - last_tick)?;
+            last_tick = current_time;
+        }
+
+        Ok(())
+    }
+
+    /// Runs a single frame as if `delta_time` unscaled seconds passed since the previous one.
+    /// While an input recording is played back its frame times are used instead.
+    pub fn step(&mut self, delta_time: f64) -> Result<()> {
+        let delta_time = self.process_input(delta_time);
+
+        // TODO: move clear call to after game tick
+
+        self.gfx.glClear(GL_COLOR_BUFFER_BIT | GL_DEPTH_BUFFER_BIT);
+        
+        // Game tick
+        let delta_time = self.time.advance(delta_time);
+        self.run_systems(Stage::PreUpdate, delta_time as f32)?;
+        World::update(self, delta_time as f32)?;
+        self.run_systems(Stage::Update, delta_time as f32)?;
+        self.run_systems(Stage::PostUpdate, delta_time as f32)?;
+
+        self.input.clear_frame_state();
+
+        // Fixed ticks follow scaled time, so pausing stops them. They always advance by fixed_tick_duration, catching up with at most max_fixed_steps per frame
+        self.fixed_accumulator += delta_time;
+        let mut steps = 0;
+
+        while self.fixed_accumulator >= self.fixed_tick_duration && steps < self.max_fixed_steps {
+            self.time.advance_fixed();
+            World::fixed_update(self, self.fixed_tick_duration as f32)?;
+            self.run_systems(Stage::FixedUpdate, self.fixed_tick_duration as f32)?;
+            self.fixed_accumulator -= self.fixed_tick_duration;
+            steps += 1;
+
+            self.fixed_input.clear_frame_state();
+        }
+
+        // Drop whatever couldn't be caught up on instead of falling further behind every frame
+        if self.fixed_accumulator >= self.fixed_tick_duration {
+            self.fixed_accumulator %= self.fixed_tick_duration;
+        }
+
+        self.run_systems(Stage::PreRender, delta_time as f32)?;
+
+        self.log_errors();
+        match self.world.get_main_camera() {
+            Some(camera) => {
+                let mut camera = camera.borrow_mut();
+                self.sprite_renderer.render(&self.gfx, &camera.view_matrix(), &camera.projection_matrix());
+                self.terrain_renderer.render(&self.gfx, camera.view_matrix(), camera.projection_matrix(), camera.position());
+            },
+            _ => ()
+        }
+
+        for (id, mut component) in self.world.get_removed_components() {
+            self.events.send(ComponentRemoved { object: self.world.get_owner(id), component: id });
+
+            if let Err(error) = component.on_remove(self, self.world.get_owner(id)) {
+                self.handle_component_error(id, "on_remove", error)?;
             }
+        }
 
-            // TODO: move clear call to after game tick
+        // Render
+        // gfx.render();
 
-            self.gfx.glClear(GL_COLOR_BUFFER_BIT | GL_DEPTH_BUFFER_BIT);
-            
-            // Game tick
-            let current_time = self.gfx.get_glfw_time();
-            World::update(self, (current_time - last_tick) as f32)?; // TODO: This is not supposed to crash, catch and log errors
-            last_tick = current_time;
+        // Swap front and back buffers
+        self.gfx.swap_buffers();
+
+        self.events.advance_frame();
+
+        Ok(())
+    }
 
-            self.input.modify_all_key_states(|key| {
-                key.press = false;
-                key.release = false;
-            });
-            self.input.modify_all_mouse_button_states(|button| {
-                button.press = false;
-                button.release = false;
-            });
-            self.input.set_scroll_delta(0.0, 0.0);
-
-            let fixed_diff = current_time - last_fixed_tick - self.fixed_tick_duration;
-
-            // Add overflow to adjust for errors in timing
-            if fixed_diff + fixed_tick_overflow >= 0.0 {
-                fixed_tick_overflow = f64::max(0.0, fixed_diff * 2.0);
-                World::fixed_update(self, (current_time - last_fixed_tick) as f32)?; // TODO: This is not supposed to crash, catch and log errors
-                last_fixed_tick = current_time;
-
-                self.fixed_input.modify_all_key_states(|key| {
-                    key.press = false;
-                    key.release = false;
-                });
-                self.fixed_input.modify_all_mouse_button_states(|button| {
-                    button.press = false;
-                    button.release = false;
-                });
-                self.fixed_input.set_scroll_delta(0.0, 0.0);
+    fn run_systems(&mut self, stage: Stage, delta_time: f32) -> Result<()> {
+        for name in self.schedule.order(stage) {
+            // Systems removed by an earlier one are skipped
+            let Some(mut run) = self.schedule.take(stage, name) else { continue; };
+
+            let result = run(self, delta_time);
+            self.schedule.put_back(stage, name, run);
+
+            if let Err(error) = result {
+                self.handle_system_error(stage, name, error)?;
             }
+        }
+
+        Ok(())
+    }
+
+    // Feeds this frame's events into both inputs, either from glfw or from a recording, and returns the frame time to use
+    fn process_input(&mut self, delta_time: f64) -> f64 {
+        self.gfx.poll_events();
+        let mut events: Vec<WindowEvent> = self.gfx.flush_messages().map(|(_, event)| event).collect();
+        let mut gamepads = self.gfx.poll_gamepads();
+        let mut delta_time = delta_time;
+
+        if let Some(playback) = &mut self.input_playback {
+            match playback.next_frame() {
+                Some(frame) => {
+                    if frame.frame != self.time.frame_count() || frame.fixed_tick != self.time.fixed_tick_count() {
+                        warn!("Input playback out of sync, recorded frame {} tick {} but at frame {} tick {}", frame.frame, frame.fixed_tick, self.time.frame_count(), self.time.fixed_tick_count());
+                    }
 
-            self.log_errors();
-            match self.world.get_main_camera() {
-                Some(camera) => {
-                    let mut camera = camera.borrow_mut();
-                    self.sprite_renderer.render(&self.gfx, &camera.view_matrix(), &camera.projection_matrix());
-                    self.terrain_renderer.render(&self.gfx, camera.view_matrix(), camera.projection_matrix(), camera.position());
+                    // Live input is dropped, but the real window can still be resized. Recorded resizes are played back on top
+                    events.retain(|event| matches!(event, WindowEvent::Size(..)) || !is_input_event(event));
+                    events.extend(frame.events);
+                    gamepads = frame.gamepads;
+                    delta_time = frame.delta_time;
                 },
-                _ => ()
+                None => self.input_playback = None
             }
+        }
 
-            for (owner, mut component) in self.world.get_removed_components() {
-                component.on_remove(self, owner)?; // TODO: This is not supposed to crash, catch and log errors
+        if let Some(recorder) = &mut self.input_recorder {
+            if let Err(error) = recorder.record(self.time.frame_count(), self.time.fixed_tick_count(), delta_time, &events, &gamepads) {
+                error!("Stopped recording input - {}", error);
+                self.input_recorder = None;
             }
+        }
 
-            // Render
-            // gfx.render();
+        for event in &events {
+            self.input.handle_event(event);
+            self.fixed_input.handle_event(event);
+            self.events.send_window_event(event);
+        }
 
-            // Swap front and back buffers
-            self.gfx.swap_buffers();
+        for (id, state) in gamepads {
+            self.input.update_gamepad(id, state);
+            self.fixed_input.update_gamepad(id, state);
         }
 
+        delta_time
+    }
+
+    pub fn start_input_recording<P: AsRef<Path>>(&mut self, path: P) -> Result<()> {
+        if self.input_recorder.is_some() {
+            Err(InputError::RecordingActiveError)?
+        }
+
+        self.input_recorder = Some(InputRecorder::create(path)?);
+
         Ok(())
     }
 
+    pub fn stop_input_recording(&mut self) -> Result<()> {
+        match self.input_recorder.take() {
+            Some(recorder) => recorder.finish(),
+            None => Ok(())
+        }
+    }
+
+    /// Replaces input from glfw with the recording, starting with the next frame. Live input resumes once it runs out.
+    pub fn play_input_recording(&mut self, recording: InputRecording) {
+        self.input_playback = Some(InputPlayback::new(recording));
+    }
+
+    pub fn is_playing_input(&self) -> bool {
+        self.input_playback.is_some()
+    }
+
     fn log_errors(&mut self) {
         // Take erorr queue from error_queue, turn it into a Box and log them
         let mut errors = Vec::new();
         std::mem::swap(&mut errors, &mut self.error_queue);
         let errors = errors.into_boxed_slice();
 
-        errors.iter().for_each(|error| eprintln!("{}", error))
+        errors.iter().for_each(|error| eprintln!("{}\n{:?}", error, error.backtrace()))
+    }
+
+    /// Sets the policy used for component errors that have no per-type policy.
+    pub fn set_error_policy(&mut self, policy: ErrorPolicy) {
+        self.error_policy = policy;
+    }
+
+    pub fn set_component_error_policy<C: Component>(&mut self, policy: ErrorPolicy) {
+        self.component_error_policies.insert(TypeId::of::<C>(), policy);
+    }
+
+    pub fn error_policy<C: Component>(&self) -> ErrorPolicy {
+        self.component_error_policies.get(&TypeId::of::<C>()).copied().unwrap_or(self.error_policy)
+    }
+
+    // Only returns an error if the policy says to abort
+    pub(in crate::engine) fn handle_component_error(&mut self, component: ComponentID, stage: &'static str, error: Error) -> Result<()> {
+        let object = self.world.get_owner(component);
+        let error = Error::ComponentError {
+            object,
+            object_name: self.world.get_name(object).unwrap_or("<destroyed>").to_owned(),
+            type_name: component.type_name(),
+            stage,
+            source: Box::new(error)
+        };
+
+        let policy = self.component_error_policies.get(&component.type_id()).copied().unwrap_or(self.error_policy);
+
+        match policy {
+            ErrorPolicy::LogAndContinue => self.error_queue.push(error),
+            ErrorPolicy::DisableComponent => {
+                // Removed components can't be disabled anymore, the error still gets logged
+                let _ = self.world.set_component_enabled(component, false);
+                self.error_queue.push(error);
+            },
+            ErrorPolicy::Abort => return Err(error)
+        }
+
+        Ok(())
+    }
+
+    // Systems have nothing to disable, so only abort differs from logging
+    fn handle_system_error(&mut self, stage: Stage, system: &'static str, error: Error) -> Result<()> {
+        let error = Error::SystemError { system, stage, source: Box::new(error) };
+
+        match self.error_policy {
+            ErrorPolicy::Abort => Err(error),
+            _ => {
+                self.error_queue.push(error);
+                Ok(())
+            }
+        }
     }
 
     // fn init(&mut self) {
@@ -186,4 +378,347 @@ impl Engine {
     //         obj.fixed_update(&self, delta_time).unwrap_or_else(|err| self.error_queue.push(err));
     //     }
     // }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use glfw::{Action, JoystickId, Key, Modifiers, MouseButton, WindowEvent};
+
+    use crate::engine::{errors::{Error, ErrorPolicy, ObjectError, Result}, game_object::{component::Component, ObjectID, World}, input::{GamepadSnapshot, InputFrame, InputRecording}, Engine, EventReader, ManualClock, Stage, System, WindowResized};
+
+    struct Failing {
+        updates: u32
+    }
+
+    impl Component for Failing {
+        fn update(&mut self, _engine: &mut Engine, _owner: ObjectID, _delta_time: f32) -> Result<()> {
+            self.updates += 1;
+
+            Err("Failing component")?
+        }
+    }
+
+    #[test]
+    pub fn component_error_policies() {
+        let mut engine = Engine::create_headless().unwrap();
+        let obj = engine.world.create_game_object("Failing", engine.world.get_root()).unwrap();
+        engine.world.add_component(obj, Failing { updates: 0 }).unwrap();
+        let component = engine.world.get_component::<Failing>(obj).unwrap();
+
+        // Log and continue keeps updating the component
+        World::update(&mut engine, 0.1).unwrap();
+        World::update(&mut engine, 0.1).unwrap();
+        assert_eq!(engine.error_queue.len(), 2);
+        assert_eq!(engine.world.borrow_component::<Failing>(component).unwrap().updates, 2);
+        match &engine.error_queue[0] {
+            Error::ComponentError { object, object_name, type_name, stage, .. } => {
+                assert!(*object == obj);
+                assert_eq!(object_name, "Failing");
+                assert!(type_name.ends_with("Failing"));
+                assert_eq!(*stage, "update");
+            },
+            other => panic!("Unexpected error {other}")
+        }
+        engine.log_errors();
+        assert!(engine.error_queue.is_empty());
+
+        // Per type policy overrides the global one
+        engine.set_error_policy(ErrorPolicy::Abort);
+        engine.set_component_error_policy::<Failing>(ErrorPolicy::DisableComponent);
+        World::update(&mut engine, 0.1).unwrap();
+        World::update(&mut engine, 0.1).unwrap();
+        assert_eq!(engine.error_queue.len(), 1);
+        assert!(!engine.world.is_component_enabled(component));
+        assert_eq!(engine.world.borrow_component::<Failing>(component).unwrap().updates, 3);
+
+        engine.world.set_component_enabled(component, true).unwrap();
+        engine.set_component_error_policy::<Failing>(ErrorPolicy::Abort);
+        assert!(World::update(&mut engine, 0.1).is_err());
+    }
+
+    struct Recorder {
+        clock: Option<ManualClock>,
+        deltas: Vec<f32>,
+        fixed_deltas: Vec<f32>
+    }
+
+    impl Component for Recorder {
+        fn update(&mut self, engine: &mut Engine, _owner: ObjectID, delta_time: f32) -> Result<()> {
+            self.deltas.push(delta_time);
+
+            if let Some(clock) = &self.clock {
+                clock.advance(0.25);
+
+                if self.deltas.len() == 4 {
+                    engine.gfx.set_should_close(true);
+                }
+            }
+
+            Ok(())
+        }
+
+        fn fixed_update(&mut self, _engine: &mut Engine, _owner: ObjectID, delta_time: f32) -> Result<()> {
+            self.fixed_deltas.push(delta_time);
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    pub fn headless_step() {
+        let mut engine = Engine::create_headless().unwrap();
+        let obj = engine.world.create_game_object("Recorder", engine.world.get_root()).unwrap();
+        engine.world.add_component(obj, Recorder { clock: None, deltas: Vec::new(), fixed_deltas: Vec::new() }).unwrap();
+        let component = engine.world.get_component::<Recorder>(obj).unwrap();
+
+        engine.set_fixed_tick_duration(0.25);
+        engine.set_max_fixed_steps(3);
+
+        let mut fixed_steps = Vec::new();
+        let mut alphas = Vec::new();
+        for delta in [0.5, 0.125, 0.125, 2.0, 0.375] {
+            let before = engine.world.borrow_component::<Recorder>(component).unwrap().fixed_deltas.len();
+            engine.step(delta).unwrap();
+            fixed_steps.push(engine.world.borrow_component::<Recorder>(component).unwrap().fixed_deltas.len() - before);
+            alphas.push(engine.fixed_alpha());
+        }
+
+        // The 2 second frame is capped at 3 ticks and the rest is dropped
+        assert_eq!(fixed_steps, [2, 0, 1, 3, 1]);
+        assert_eq!(alphas, [0.0, 0.5, 0.0, 0.0, 0.5]);
+
+        let recorder = engine.world.borrow_component::<Recorder>(component).unwrap();
+        assert_eq!(recorder.deltas, [0.5, 0.125, 0.125, 2.0, 0.375]);
+        assert!(recorder.fixed_deltas.iter().all(|delta| *delta == 0.25));
+        assert!(engine.error_queue.is_empty());
+    }
+
+    #[test]
+    pub fn run_with_manual_clock() {
+        let mut engine = Engine::create_headless().unwrap();
+        let clock = ManualClock::new();
+        engine.set_time_source(clock.clone());
+
+        let obj = engine.world.create_game_object("Recorder", engine.world.get_root()).unwrap();
+        engine.world.add_component(obj, Recorder { clock: Some(clock.clone()), deltas: Vec::new(), fixed_deltas: Vec::new() }).unwrap();
+        let component = engine.world.get_component::<Recorder>(obj).unwrap();
+
+        engine.run().unwrap();
+
+        let recorder = engine.world.borrow_component::<Recorder>(component).unwrap();
+        assert_eq!(recorder.deltas, [0.0, 0.25, 0.25, 0.25]);
+        assert_eq!(clock.get(), 1.0);
+    }
+
+    #[test]
+    pub fn scaled_and_paused_time() {
+        let mut engine = Engine::create_headless().unwrap();
+        let obj = engine.world.create_game_object("Recorder", engine.world.get_root()).unwrap();
+        engine.world.add_component(obj, Recorder { clock: None, deltas: Vec::new(), fixed_deltas: Vec::new() }).unwrap();
+        let component = engine.world.get_component::<Recorder>(obj).unwrap();
+
+        engine.set_fixed_tick_duration(0.25);
+        engine.time.set_scale(0.5);
+        engine.step(1.0).unwrap();
+
+        engine.time.pause();
+        engine.step(1.0).unwrap();
+
+        let recorder = engine.world.borrow_component::<Recorder>(component).unwrap();
+        assert_eq!(recorder.deltas, [0.5, 0.0]);
+        assert_eq!(recorder.fixed_deltas, [0.25, 0.25]);
+        assert_eq!(engine.time.fixed_tick_count(), 2);
+        assert_eq!(engine.time.elapsed(), 0.5);
+        assert_eq!(engine.time.unscaled_elapsed(), 2.0);
+    }
+
+    struct KeyLogger {
+        downs: Vec<bool>,
+        fixed_presses: u32,
+        text: String
+    }
+
+    impl Component for KeyLogger {
+        fn update(&mut self, engine: &mut Engine, _owner: ObjectID, _delta_time: f32) -> Result<()> {
+            self.downs.push(engine.input.get_key_state(Key::W).is_down);
+            self.text += &engine.input.text();
+
+            Ok(())
+        }
+
+        fn fixed_update(&mut self, engine: &mut Engine, _owner: ObjectID, _delta_time: f32) -> Result<()> {
+            if engine.fixed_input().get_key_state(Key::W).press {
+                self.fixed_presses += 1;
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    pub fn input_record_and_playback() {
+        let frame = |frame: u64, fixed_tick: u64, events: Vec<WindowEvent>| InputFrame { frame, fixed_tick, delta_time: 0.125, events, gamepads: Vec::new() };
+        let mut snapshot = GamepadSnapshot::default();
+        snapshot.buttons[3] = true;
+        snapshot.axes[1] = -0.5;
+
+        let mut recording = InputRecording { frames: vec![
+            frame(0, 0, vec![WindowEvent::Key(Key::W, 17, Action::Press, Modifiers::Shift), WindowEvent::Char('w')]),
+            frame(1, 0, vec![WindowEvent::CursorPos(10.5, 20.25)]),
+            frame(2, 1, vec![WindowEvent::Key(Key::W, 17, Action::Release, Modifiers::empty())]),
+            frame(3, 1, vec![WindowEvent::MouseButton(MouseButton::Button7, Action::Press, Modifiers::empty())])
+        ]};
+        recording.frames[1].gamepads.push((JoystickId::Joystick1, Some(snapshot)));
+        recording.frames[3].gamepads.push((JoystickId::Joystick1, None));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.rec");
+
+        let mut engine = Engine::create_headless().unwrap();
+        engine.set_fixed_tick_duration(0.25);
+        let obj = engine.world.create_game_object("Logger", engine.world.get_root()).unwrap();
+        engine.world.add_component(obj, KeyLogger { downs: Vec::new(), fixed_presses: 0, text: String::new() }).unwrap();
+        let component = engine.world.get_component::<KeyLogger>(obj).unwrap();
+
+        engine.play_input_recording(recording.clone());
+        engine.start_input_recording(&path).unwrap();
+        assert!(engine.start_input_recording(&path).is_err());
+
+        for _ in 0..4 {
+            assert!(engine.is_playing_input());
+            engine.step(1.0).unwrap();
+        }
+        engine.stop_input_recording().unwrap();
+        engine.step(1.0).unwrap();
+        assert!(!engine.is_playing_input());
+
+        // Recorded frame times are used instead of the stepped ones
+        assert_eq!(engine.time.unscaled_elapsed(), 1.5);
+
+        let logger = engine.world.borrow_component::<KeyLogger>(component).unwrap();
+        assert_eq!(logger.downs, [true, true, false, false, false]);
+        assert_eq!(logger.fixed_presses, 1);
+        assert_eq!(logger.text, "w");
+        drop(logger);
+        assert!(engine.input.get_mouse_button_state(MouseButton::Button7 as u32).is_down);
+
+        // Re-recording the playback gives back the same stream
+        assert_eq!(InputRecording::load(&path).unwrap(), recording);
+    }
+
+    // Logs every update and removal, optionally destroying its own object during update
+    struct Lifetime {
+        name: &'static str,
+        log: Rc<RefCell<Vec<String>>>,
+        destroy_owner: bool
+    }
+
+    impl Component for Lifetime {
+        fn update(&mut self, engine: &mut Engine, owner: ObjectID, _delta_time: f32) -> Result<()> {
+            self.log.borrow_mut().push(format!("update {}", self.name));
+
+            if self.destroy_owner {
+                engine.world.destroy(owner)?;
+            }
+
+            Ok(())
+        }
+
+        fn on_remove(&mut self, _engine: &mut Engine, _owner: ObjectID) -> Result<()> {
+            self.log.borrow_mut().push(format!("remove {}", self.name));
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    pub fn recursive_destroy() {
+        let mut engine = Engine::create_headless().unwrap();
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let lifetime = |name, destroy_owner| Lifetime { name, log: log.clone(), destroy_owner };
+
+        let parent = engine.world.create_game_object("parent", engine.world.get_root()).unwrap();
+        let child = engine.world.create_game_object("child", parent).unwrap();
+        let grandchild = engine.world.create_game_object("grandchild", child).unwrap();
+        let other = engine.world.create_game_object("other", engine.world.get_root()).unwrap();
+        engine.world.add_component(parent, lifetime("parent", false)).unwrap();
+        engine.world.add_component(grandchild, lifetime("grandchild", false)).unwrap();
+        engine.world.add_component(other, lifetime("other", true)).unwrap();
+
+        assert!(matches!(engine.world.destroy(engine.world.get_root()), Err(Error::ObjectError { source: ObjectError::RootObjectDeleteError, .. })));
+
+        engine.world.destroy(parent).unwrap();
+        assert!(engine.world.get_name(child).is_err());
+        assert!(engine.world.get_name(grandchild).is_err());
+        assert!(engine.world.get_children(engine.world.get_root()).unwrap().iter().all(|obj| *obj != parent));
+
+        // Destroyed components don't update, the one destroying itself finishes its update first
+        engine.step(0.1).unwrap();
+        engine.step(0.1).unwrap();
+        assert!(engine.error_queue.is_empty());
+        assert_eq!(*log.borrow(), ["update other", "remove parent", "remove grandchild", "remove other"]);
+        assert!(engine.world.get_name(other).is_err());
+    }
+
+    struct Spawned(ObjectID);
+
+    struct ResizeListener {
+        resized: Option<EventReader<WindowResized>>,
+        spawned: Option<EventReader<Spawned>>,
+        log: Vec<String>
+    }
+
+    impl Component for ResizeListener {
+        fn init(&mut self, engine: &mut Engine, _owner: ObjectID) -> Result<()> {
+            self.resized = Some(engine.events.reader());
+            self.spawned = Some(engine.events.reader());
+
+            Ok(())
+        }
+
+        fn update(&mut self, engine: &mut Engine, _owner: ObjectID, _delta_time: f32) -> Result<()> {
+            for event in self.resized.as_mut().unwrap().read(&engine.events) {
+                self.log.push(format!("resized {}x{}", event.width, event.height));
+            }
+
+            for Spawned(object) in self.spawned.as_mut().unwrap().read(&engine.events) {
+                self.log.push(format!("spawned {}", engine.world.get_name(*object)?));
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    pub fn window_and_custom_events() {
+        let frame = |frame: u64, events: Vec<WindowEvent>| InputFrame { frame, fixed_tick: 0, delta_time: 0.0, events, gamepads: Vec::new() };
+
+        let mut engine = Engine::create_headless().unwrap();
+        let obj = engine.world.create_game_object("Listener", engine.world.get_root()).unwrap();
+        engine.world.add_component(obj, ResizeListener { resized: None, spawned: None, log: Vec::new() }).unwrap();
+        let component = engine.world.get_component::<ResizeListener>(obj).unwrap();
+
+        // Sent after the listener ran, so it only sees it next frame
+        engine.add_system(Stage::PostUpdate, System::new("spawner", |engine, _| {
+            if engine.time.frame_count() == 1 {
+                let object = engine.world.create_game_object("Goblin", engine.world.get_root())?;
+                engine.events.send(Spawned(object));
+            }
+
+            Ok(())
+        })).unwrap();
+
+        engine.play_input_recording(InputRecording { frames: vec![
+            frame(0, vec![WindowEvent::Size(800, 600)]),
+            frame(1, vec![WindowEvent::Size(640, 480)]),
+            frame(2, vec![])
+        ]});
+
+        for _ in 0..4 {
+            engine.step(0.0).unwrap();
+        }
+
+        assert_eq!(engine.world.borrow_component::<ResizeListener>(component).unwrap().log, ["resized 640x480", "spawned Goblin"]);
+    }
+}
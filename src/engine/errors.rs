@@ -1,6 +1,8 @@
 use image::ImageError;
 use thiserror::Error;
 
+use crate::engine::{game_object::ObjectID, Stage};
+
 type BT = backtrace::Backtrace;
 
 #[derive(Error, Debug)]
@@ -9,6 +11,12 @@ pub enum ObjectError {
     DeadComponentError,
     #[error("Root object cannot be deleted!")]
     RootObjectDeleteError,
+    #[error("Root object cannot be moved!")]
+    RootObjectMoveError,
+    #[error("Object cannot become its own descendant!")]
+    ParentCycleError,
+    #[error("Child index {index} is out of bounds for {len} children!")]
+    ChildIndexError { index: usize, len: usize },
     #[error("Component does not belong to object!")]
     ComponentMismatchError,
     #[error("Object is dead!")]
@@ -18,7 +26,17 @@ pub enum ObjectError {
     #[error("Component is not of type {type_name}")]
     ComponentDowncastError { type_name: String },
     #[error("Component not found!")]
-    ComponentNotFoundError
+    ComponentNotFoundError,
+    #[error("Components of type {type_name} are already borrowed!")]
+    ComponentBorrowError { type_name: &'static str },
+    #[error("Invalid scene at line {line} - {msg}")]
+    SceneParseError { line: usize, msg: String },
+    #[error("Failed to load \"{object}\" from scene - {msg}")]
+    SceneLoadError { object: String, msg: String },
+    #[error("No object at \"{path}\" in prefab!")]
+    PrefabPathError { path: String },
+    #[error("{type_name} isn't registered as a prefab component!")]
+    PrefabComponentError { type_name: &'static str }
 }
 
 #[derive(Error, Debug)]
@@ -34,7 +52,37 @@ pub enum GraphicsError {
     #[error("{msg}")]
     GLLoadError{msg: &'static str},
     #[error(transparent)]
-    GLInitError(#[from] glfw::InitError)
+    GLInitError(#[from] glfw::InitError),
+    #[error("A gl trace is already being recorded!")]
+    TraceActiveError,
+    #[error("Invalid gl trace at line {line} - {msg}")]
+    TraceParseError { line: usize, msg: String },
+    #[error("Failed to replay gl trace call {call} - {msg}")]
+    TraceReplayError { call: usize, msg: String }
+}
+
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("Invalid input binding at line {line} - {msg}")]
+    BindingParseError { line: usize, msg: String },
+    #[error("No input binding named \"{name}\"")]
+    UnknownBindingError { name: String },
+    #[error("Input binding \"{name}\" is not {expected}")]
+    BindingTypeError { name: String, expected: &'static str },
+    #[error("Invalid input recording at line {line} - {msg}")]
+    RecordingParseError { line: usize, msg: String },
+    #[error("Input is already being recorded!")]
+    RecordingActiveError
+}
+
+#[derive(Error, Debug)]
+pub enum ScheduleError {
+    #[error("Systems {systems:?} in {stage:?} form an ordering cycle!")]
+    CycleError { stage: Stage, systems: Vec<&'static str> },
+    #[error("System \"{name}\" already exists!")]
+    DuplicateSystemError { name: &'static str },
+    #[error("No system named \"{name}\"")]
+    UnknownSystemError { name: String }
 }
 
 #[derive(Error, Debug)]
@@ -63,6 +111,16 @@ pub enum Error {
         backtrace: BT
     },
     #[error("{source}")]
+    InputError {
+        source: InputError,
+        backtrace: BT
+    },
+    #[error("{source}")]
+    ScheduleError {
+        source: ScheduleError,
+        backtrace: BT
+    },
+    #[error("{source}")]
     BasicError {
         source: BasicError,
         backtrace: BT
@@ -85,9 +143,32 @@ pub enum Error {
     StaticStringError {
         msg: &'static str,
         backtrace: BT
+    },
+    #[error("{type_name} on \"{object_name}\" ({object:?}) failed in {stage} - {source}")]
+    ComponentError {
+        object: ObjectID,
+        object_name: String,
+        type_name: &'static str,
+        stage: &'static str,
+        source: Box<Error>
+    },
+    #[error("System \"{system}\" failed in {stage:?} - {source}")]
+    SystemError {
+        system: &'static str,
+        stage: Stage,
+        source: Box<Error>
     }
 }
 
+/// Decides what the engine does when a component returns an error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum ErrorPolicy {
+    #[default]
+    LogAndContinue,
+    DisableComponent,
+    Abort
+}
+
 impl From<ObjectError> for Error {
     fn from(value: ObjectError) -> Self {
         Error::ObjectError { source: value, backtrace: BT::new() }
@@ -118,6 +199,18 @@ impl From<glfw::InitError> for Error {
     }
 }
 
+impl From<InputError> for Error {
+    fn from(value: InputError) -> Self {
+        Error::InputError { source: value, backtrace: BT::new() }
+    }
+}
+
+impl From<ScheduleError> for Error {
+    fn from(value: ScheduleError) -> Self {
+        Error::ScheduleError { source: value, backtrace: BT::new() }
+    }
+}
+
 impl From<BasicError> for Error {
     fn from(value: BasicError) -> Self {
         Error::BasicError { source: value, backtrace: BT::new() }
@@ -146,7 +239,11 @@ impl Error {
             Error::IoError { backtrace, .. } => backtrace,
             Error::StaticStringError { backtrace, .. } => backtrace,
             Error::OptionError { backtrace } => backtrace,
-            Error::BasicError { backtrace, .. } => backtrace
+            Error::BasicError { backtrace, .. } => backtrace,
+            Error::InputError { backtrace, .. } => backtrace,
+            Error::ScheduleError { backtrace, .. } => backtrace,
+            Error::ComponentError { source, .. } => source.backtrace(),
+            Error::SystemError { source, .. } => source.backtrace()
         }
     }
 }
@@ -0,0 +1,172 @@
+use std::{any::TypeId, collections::{HashMap, VecDeque}, marker::PhantomData};
+
+use downcast_rs::{impl_downcast, Downcast};
+use glfw::WindowEvent;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WindowResized {
+    pub width: u32,
+    pub height: u32
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WindowFocused {
+    pub focused: bool
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WindowCloseRequested;
+
+struct Channel<E> {
+    // Oldest first, with the frame each event was sent in
+    events: VecDeque<(u64, E)>,
+    // Id of the oldest event still stored, ids are consecutive from there
+    first_id: u64
+}
+
+trait AnyChannel: Downcast {
+    fn expire(&mut self, oldest_frame: u64);
+}
+
+impl_downcast!(AnyChannel);
+
+impl<E: 'static> AnyChannel for Channel<E> {
+    fn expire(&mut self, oldest_frame: u64) {
+        while self.events.front().is_some_and(|(frame, _)| *frame < oldest_frame) {
+            self.events.pop_front();
+            self.first_id += 1;
+        }
+    }
+}
+
+/// Typed events shared by everything running in the engine. Events stay readable for a number of frames,
+/// so events sent late in a frame still reach readers that already ran.
+pub struct Events {
+    channels: HashMap<TypeId, Box<dyn AnyChannel>>,
+    frame: u64,
+    retention: u64
+}
+
+/// Where a subscriber is in one event type's stream. Each subscriber keeps its own, so every one of them sees every event.
+pub struct EventReader<E> {
+    next_id: u64,
+    _marker: PhantomData<fn() -> E>
+}
+
+impl Events {
+    pub(in crate::engine) fn new() -> Events {
+        Events { channels: HashMap::new(), frame: 0, retention: 2 }
+    }
+
+    pub fn send<E: 'static>(&mut self, event: E) {
+        let frame = self.frame;
+
+        self.channel_mut::<E>().events.push_back((frame, event));
+    }
+
+    /// A reader that only sees events sent from now on.
+    pub fn reader<E: 'static>(&self) -> EventReader<E> {
+        let next_id = self.channel::<E>().map_or(0, |channel| channel.first_id + channel.events.len() as u64);
+
+        EventReader { next_id, _marker: PhantomData }
+    }
+
+    /// How many frames events stay readable, counting the one they were sent in.
+    pub fn retention(&self) -> u64 {
+        self.retention
+    }
+
+    /// Events are always readable in the frame they were sent in, so anything below 1 is treated as 1.
+    pub fn set_retention(&mut self, frames: u64) {
+        self.retention = frames.max(1);
+    }
+
+    // Called at the end of every frame
+    pub(in crate::engine) fn advance_frame(&mut self) {
+        self.frame += 1;
+
+        let oldest_frame = (self.frame + 1).saturating_sub(self.retention);
+        for channel in self.channels.values_mut() {
+            channel.expire(oldest_frame);
+        }
+    }
+
+    pub(in crate::engine) fn send_window_event(&mut self, event: &WindowEvent) {
+        match *event {
+            WindowEvent::Size(width, height) => self.send(WindowResized { width: width.max(0) as u32, height: height.max(0) as u32 }),
+            WindowEvent::Focus(focused) => self.send(WindowFocused { focused }),
+            WindowEvent::Close => self.send(WindowCloseRequested),
+            _ => ()
+        }
+    }
+
+    fn channel<E: 'static>(&self) -> Option<&Channel<E>> {
+        self.channels.get(&TypeId::of::<E>()).map(|channel| channel.downcast_ref().unwrap())
+    }
+
+    fn channel_mut<E: 'static>(&mut self) -> &mut Channel<E> {
+        self.channels.entry(TypeId::of::<E>())
+            .or_insert_with(|| Box::new(Channel::<E> { events: VecDeque::new(), first_id: 0 }))
+            .downcast_mut()
+            .unwrap()
+    }
+}
+
+impl<E: 'static> EventReader<E> {
+    /// Every event this reader hasn't seen yet, oldest first. Events that expired before being read are skipped.
+    pub fn read<'a>(&mut self, events: &'a Events) -> impl Iterator<Item = &'a E> + use<'a, E> {
+        let channel = events.channel::<E>();
+        let unread = channel.map_or(0..0, |channel| {
+            let start = self.next_id.saturating_sub(channel.first_id) as usize;
+            start.min(channel.events.len())..channel.events.len()
+        });
+
+        if let Some(channel) = channel {
+            self.next_id = channel.first_id + channel.events.len() as u64;
+        }
+
+        channel.into_iter().flat_map(move |channel| channel.events.range(unread.clone()).map(|(_, event)| event))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Events;
+
+    #[derive(Debug, PartialEq)]
+    struct Hit(u32);
+
+    #[test]
+    pub fn event_readers() {
+        let mut events = Events::new();
+        let mut early = events.reader::<Hit>();
+
+        events.send(Hit(1));
+        let mut late = events.reader::<Hit>();
+        events.send(Hit(2));
+
+        assert_eq!(early.read(&events).collect::<Vec<_>>(), [&Hit(1), &Hit(2)]);
+        assert_eq!(late.read(&events).collect::<Vec<_>>(), [&Hit(2)]);
+        assert_eq!(early.read(&events).count(), 0);
+
+        // Sent this frame and kept through the next one
+        events.advance_frame();
+        events.send(Hit(3));
+        assert_eq!(late.read(&events).collect::<Vec<_>>(), [&Hit(3)]);
+
+        events.advance_frame();
+        assert_eq!(early.read(&events).collect::<Vec<_>>(), [&Hit(3)]);
+        events.advance_frame();
+        assert_eq!(events.reader::<Hit>().next_id, 3);
+
+        let mut fresh = Events::new();
+        fresh.set_retention(0);
+        assert_eq!(fresh.retention(), 1);
+        let mut reader = fresh.reader::<Hit>();
+        fresh.send(Hit(4));
+        fresh.advance_frame();
+        assert_eq!(reader.read(&fresh).count(), 0);
+        fresh.send(Hit(5));
+        assert_eq!(reader.read(&fresh).collect::<Vec<_>>(), [&Hit(5)]);
+    }
+}
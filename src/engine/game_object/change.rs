@@ -0,0 +1,165 @@
+use std::{cell::Cell, marker::PhantomData};
+
+use super::{component::Component, ComponentID, ObjectID, World};
+
+/// A point in time for change detection, see `World::change_tick`. The default tick is older than every change.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tick(u64);
+
+pub(in crate::engine::game_object) struct ComponentTicks {
+    added: u64,
+    changed: Cell<u64>
+}
+
+impl ComponentTicks {
+    pub(in crate::engine::game_object) fn new(tick: u64) -> ComponentTicks {
+        ComponentTicks { added: tick, changed: Cell::new(tick) }
+    }
+}
+
+/// Sent on the engine's event bus at the end of the frame a component was removed in, including the components of destroyed objects.
+#[derive(Clone, Copy)]
+pub struct ComponentRemoved {
+    pub object: ObjectID,
+    pub component: ComponentID
+}
+
+/// Narrows a query down using change ticks. Filters on the query's own type look at the component itself,
+/// filters on any other type at the owner's first component of that type.
+pub trait QueryFilter {
+    fn matches(world: &World, component: ComponentID, since: Tick) -> bool;
+}
+
+/// Added after the tick.
+pub struct Added<C>(PhantomData<C>);
+
+/// Added or mutably borrowed after the tick.
+pub struct Changed<C>(PhantomData<C>);
+
+impl<C: Component> QueryFilter for Added<C> {
+    fn matches(world: &World, component: ComponentID, since: Tick) -> bool {
+        filtered::<C>(world, component).is_some_and(|component| world.is_added(component, since))
+    }
+}
+
+impl<C: Component> QueryFilter for Changed<C> {
+    fn matches(world: &World, component: ComponentID, since: Tick) -> bool {
+        filtered::<C>(world, component).is_some_and(|component| world.is_changed(component, since))
+    }
+}
+
+fn filtered<C: Component>(world: &World, component: ComponentID) -> Option<ComponentID> {
+    if component.is::<C>() {
+        Some(component)
+    } else {
+        world.get_component::<C>(component.owner).ok()
+    }
+}
+
+impl World {
+    /// Marks the current point in time. Components added or mutably borrowed after this call are newer than the returned tick,
+    /// so keeping it around and passing it to the next filter sees every change exactly once.
+    pub fn change_tick(&self) -> Tick {
+        let tick = self.change_tick.get();
+        self.change_tick.set(tick + 1);
+
+        Tick(tick)
+    }
+
+    pub fn is_added(&self, component: ComponentID, since: Tick) -> bool {
+        self.component_ticks.get(&component).is_some_and(|ticks| ticks.added > since.0)
+    }
+
+    pub fn is_changed(&self, component: ComponentID, since: Tick) -> bool {
+        self.component_ticks.get(&component).is_some_and(|ticks| ticks.changed.get() > since.0)
+    }
+
+    /// Components changing themselves in one of their callbacks aren't borrowed through the world, so they have to say so.
+    pub fn set_changed(&self, component: ComponentID) {
+        if let Some(ticks) = self.component_ticks.get(&component) {
+            ticks.changed.set(self.change_tick.get());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::{game_object::{component::components::Transform, test_components::Health, FilterQuery, ObjectID, World}, Engine};
+
+    use super::{Added, Changed, ComponentRemoved, Tick};
+
+    #[test]
+    pub fn change_detection() {
+        let mut world = World::new();
+        let a = world.create_game_object("a", world.get_root()).unwrap();
+        let b = world.create_game_object("b", world.get_root()).unwrap();
+        world.add_component(a, Health(1)).unwrap();
+        world.add_component(b, Health(2)).unwrap();
+
+        let changed = |world: &World, since| world.query::<Health>().unwrap().filter::<Changed<Health>>(since).iter().map(|(object, _)| object).collect::<Vec<ObjectID>>();
+        let added = |world: &World, since| world.query::<Health>().unwrap().filter::<Added<Health>>(since).iter().map(|(object, _)| object).collect::<Vec<ObjectID>>();
+
+        assert_eq!(changed(&world, Tick::default()), [a, b]);
+        let since = world.change_tick();
+        assert!(changed(&world, since).is_empty());
+
+        // Shared borrows don't count, mutable ones do even if nothing is written
+        let health = world.get_component::<Health>(b).unwrap();
+        world.borrow_component::<Health>(health).unwrap();
+        assert!(changed(&world, since).is_empty());
+        world.borrow_component_mut::<Health>(health).unwrap();
+        assert_eq!(changed(&world, since), [b]);
+        assert!(added(&world, since).is_empty());
+
+        let since = world.change_tick();
+        let c = world.create_game_object("c", world.get_root()).unwrap();
+        world.add_component(c, Health(3)).unwrap();
+        assert_eq!(added(&world, since), [c]);
+        assert_eq!(changed(&world, since), [c]);
+
+        // Mutable queries only mark what they hand out
+        let before_c = since;
+        let since = world.change_tick();
+        for (_, health) in world.query_mut::<Health>().unwrap().filter::<Added<Health>>(before_c).iter_mut() {
+            health.0 *= 10;
+        }
+        assert_eq!(changed(&world, since), [c]);
+
+        // Filters on other types go by the owner
+        let since = world.change_tick();
+        world.borrow_component_mut::<Transform>(world.get_component::<Transform>(c).unwrap()).unwrap().set_position(gl_types::vec3!(1.0, 0.0, 0.0));
+        let moved: Vec<_> = world.query::<Health>().unwrap().filter::<Changed<Transform>>(since).iter().map(|(object, health)| (object, health.0)).collect();
+        assert_eq!(moved, [(c, 30)]);
+        assert_eq!(world.query2::<Transform, Health>().unwrap().filter::<Changed<Transform>>(since).iter().count(), 1);
+        assert_eq!(world.query2::<Transform, Health>().unwrap().filter::<Changed<Transform>>(since).filter::<Changed<Health>>(since).iter().count(), 0);
+
+        world.set_changed(health);
+        assert_eq!(changed(&world, since), [b]);
+
+        // Reparenting moves the object in the world, so its transform counts as changed
+        let since = world.change_tick();
+        world.set_parent(a, c).unwrap();
+        assert_eq!(world.query::<Health>().unwrap().filter::<Changed<Transform>>(since).iter().map(|(object, _)| object).collect::<Vec<_>>(), [a]);
+    }
+
+    #[test]
+    pub fn observed_removals() {
+        let mut engine = Engine::create_headless().unwrap();
+        let mut removed = engine.events.reader::<ComponentRemoved>();
+        let a = engine.world.create_game_object("a", engine.world.get_root()).unwrap();
+        let b = engine.world.create_game_object("b", engine.world.get_root()).unwrap();
+        engine.world.add_component(a, Health(1)).unwrap();
+        engine.world.add_component(b, Health(2)).unwrap();
+        engine.step(0.0).unwrap();
+
+        let health = engine.world.get_component::<Health>(a).unwrap();
+        engine.world.remove_component(health).unwrap();
+        engine.world.destroy(b).unwrap();
+        engine.step(0.0).unwrap();
+
+        let healths: Vec<ObjectID> = removed.read(&engine.events).filter(|removed| removed.component.is::<Health>()).map(|removed| removed.object).collect();
+        assert_eq!(healths, [a, b]);
+        assert_eq!(removed.read(&engine.events).count(), 0);
+        assert!(!engine.world.is_changed(health, Tick::default()));
+    }
+}
@@ -1,7 +1,7 @@
 use crate::engine::{Engine, errors::Result};
 use downcast_rs::{Downcast, impl_downcast};
 
-use crate::engine::game_object::ObjectID;
+use crate::engine::game_object::{ObjectID, World};
 
 #[allow(unused)]
 pub trait Component: Downcast {
@@ -9,28 +9,17 @@ pub trait Component: Downcast {
     fn update(&mut self, engine: &mut Engine, owner: ObjectID, delta_time: f32) -> Result<()> {Ok(())}
     fn fixed_update(&mut self, engine: &mut Engine, owner: ObjectID, delta_time: f32) -> Result<()> {Ok(())}
     fn on_remove(&mut self, engine: &mut Engine, owner: ObjectID) -> Result<()> {Ok(())}
+    /// Called once the component becomes active again, either by being enabled or by its object becoming active.
+    fn on_enable(&mut self, engine: &mut Engine, owner: ObjectID) -> Result<()> {Ok(())}
+    fn on_disable(&mut self, engine: &mut Engine, owner: ObjectID) -> Result<()> {Ok(())}
     
+    /// Adds the component to a freshly created object, which already has a `Transform`. Used when instantiating scenes and prefabs.
+    fn attach(self, world: &mut World, object: ObjectID) -> Result<()> where Self: Sized {
+        world.add_component(object, self)
+    }
+
     /// Priority determines execution order. The return value of this function should not change.
     fn priority(&self) -> &'static i32 { &0 }
 }
 
 impl_downcast!(Component);
-
-// pub trait CloneRequirement {
-//     fn clone_box(&self) -> Box<dyn Component>;
-// }
-
-// impl<T> CloneRequirement for T
-// where
-//     T: Component + Clone
-// {   
-//     fn clone_box(&self) -> Box<dyn Component> {
-//         Box::new(self.clone())
-//     }
-// }
-
-// impl Clone for Box<dyn Component> {
-//     fn clone(&self) -> Box<dyn Component> {
-//         self.clone_box()
-//     }
-// }
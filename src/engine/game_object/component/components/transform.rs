@@ -1,16 +1,146 @@
-use gl_types::vectors::Vec3;
+use std::cell::Cell;
 
-use crate::engine::game_object::component::Component;
+use gl_types::{mat4, matrices::Mat4, quaternion::Quat, transform::{scale, translate}, vec3, vectors::Vec3};
 
-#[derive(Clone, Copy)]
+use crate::engine::{errors::Result, game_object::{component::Component, ObjectID, SceneComponent, SceneFields, World}};
+
+/// Position, rotation and scale relative to the parent object.
 pub struct Transform {
-    pub position: Vec3,
-    pub rotation: Vec3,
-    pub scale: Vec3
+    position: Vec3,
+    rotation: Quat,
+    scale: Vec3,
+    world: Cell<WorldCache>
+}
+
+// The world matrix is only recomputed when this transform changed or the parent's world matrix got a new version
+#[derive(Clone, Copy)]
+struct WorldCache {
+    matrix: Mat4,
+    version: u64,
+    parent_version: u64,
+    dirty: bool
+}
+
+impl Component for Transform {
+    // Every object already has one
+    fn attach(self, world: &mut World, object: ObjectID) -> Result<()> {
+        world.replace_transform(object, &self)
+    }
+}
+
+impl SceneComponent for Transform {
+    const NAME: &'static str = "Transform";
+
+    fn save(&self, fields: &mut Vec<String>) -> Result<()> {
+        let (p, r, s) = (self.position, self.rotation, self.scale);
+
+        fields.extend([p.x(), p.y(), p.z(), r.x(), r.y(), r.z(), r.w(), s.x(), s.y(), s.z()].map(|value| format!("{:?}", value)));
+
+        Ok(())
+    }
+
+    fn load(fields: &mut SceneFields) -> std::result::Result<Self, String> {
+        let position = vec3!(fields.next::<f32>()?, fields.next::<f32>()?, fields.next::<f32>()?);
+        let rotation = Quat::new(fields.next()?, fields.next()?, fields.next()?, fields.next()?);
+        let scale = vec3!(fields.next::<f32>()?, fields.next::<f32>()?, fields.next::<f32>()?);
+
+        Ok(Transform::new(position, rotation, scale))
+    }
 }
 
-impl Component for Transform {}
+// A copy can end up under a different parent, so it starts without a cached world matrix
+impl Clone for Transform {
+    fn clone(&self) -> Self {
+        Transform::new(self.position, self.rotation, self.scale)
+    }
+}
 
 impl Transform {
-    pub const ZERO: Transform = Transform { position: Vec3::ZERO, rotation: Vec3::ZERO, scale: Vec3::ZERO };
-}
\ No newline at end of file
+    // Every use is a fresh copy with its own cache, which is what we want
+    #[allow(clippy::declare_interior_mutable_const)]
+    pub const IDENTITY: Transform = Transform::new(Vec3::ZERO, Quat::IDENTITY, Vec3::ONE);
+
+    pub const fn new(position: Vec3, rotation: Quat, scale: Vec3) -> Transform {
+        Transform { position, rotation, scale, world: Cell::new(WorldCache { matrix: Mat4::IDENTITY, version: 0, parent_version: 0, dirty: true }) }
+    }
+
+    pub fn position(&self) -> Vec3 {
+        self.position
+    }
+
+    pub fn set_position(&mut self, position: Vec3) {
+        self.position = position;
+        self.mark_dirty();
+    }
+
+    pub fn rotation(&self) -> Quat {
+        self.rotation
+    }
+
+    pub fn set_rotation(&mut self, rotation: Quat) {
+        self.rotation = rotation;
+        self.mark_dirty();
+    }
+
+    /// See `Quat::from_euler` for the convention.
+    pub fn euler_angles(&self) -> Vec3 {
+        self.rotation.to_euler()
+    }
+
+    pub fn set_euler_angles(&mut self, angles: Vec3) {
+        self.set_rotation(Quat::from_euler(angles));
+    }
+
+    pub fn rotate(&mut self, rotation: Quat) {
+        self.set_rotation(rotation * self.rotation);
+    }
+
+    // Directions are relative to the parent, like the rest of the transform
+    pub fn forward(&self) -> Vec3 {
+        self.rotation * vec3!(0.0, 0.0, 1.0)
+    }
+
+    pub fn right(&self) -> Vec3 {
+        self.rotation * vec3!(1.0, 0.0, 0.0)
+    }
+
+    pub fn up(&self) -> Vec3 {
+        self.rotation * vec3!(0.0, 1.0, 0.0)
+    }
+
+    pub fn scale(&self) -> Vec3 {
+        self.scale
+    }
+
+    pub fn set_scale(&mut self, scale: Vec3) {
+        self.scale = scale;
+        self.mark_dirty();
+    }
+
+    pub fn local_matrix(&self) -> Mat4 {
+        let matrix = translate(Mat4::IDENTITY, self.position) * mat4!(self.rotation);
+
+        scale(matrix, self.scale)
+    }
+
+    pub(in crate::engine) fn mark_dirty(&self) {
+        let mut cache = self.world.get();
+        cache.dirty = true;
+        self.world.set(cache);
+    }
+
+    // Returns the world matrix and its version, children compare the version against the one they were built from
+    pub(in crate::engine) fn world_matrix(&self, parent: Mat4, parent_version: u64) -> (Mat4, u64) {
+        let mut cache = self.world.get();
+
+        if cache.dirty || cache.parent_version != parent_version {
+            cache.matrix = parent * self.local_matrix();
+            cache.version += 1;
+            cache.parent_version = parent_version;
+            cache.dirty = false;
+            self.world.set(cache);
+        }
+
+        (cache.matrix, cache.version)
+    }
+}
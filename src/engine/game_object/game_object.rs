@@ -8,5 +8,10 @@ pub(in crate::engine::game_object) struct GameObject {
     pub name: String,
     pub parent: ObjectID,
     pub components: Vec<ComponentID>,
-    pub children: HashSet<ObjectID>
+    pub children: Vec<ObjectID>,
+    pub tags: HashSet<String>,
+    pub layer: u32,
+    pub active: bool,
+    // Active and so is every ancestor, kept up to date so running components doesn't walk the hierarchy
+    pub active_in_hierarchy: bool
 }
\ No newline at end of file
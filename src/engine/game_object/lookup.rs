@@ -0,0 +1,200 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::engine::errors::Result;
+
+use super::{ObjectID, World};
+
+// Objects by name, tag and layer. Each list is in the order objects got that name, tag or layer.
+#[derive(Default)]
+pub(in crate::engine::game_object) struct Lookup {
+    pub(in crate::engine::game_object) names: HashMap<String, Vec<ObjectID>>,
+    pub(in crate::engine::game_object) tags: HashMap<String, Vec<ObjectID>>,
+    pub(in crate::engine::game_object) layers: HashMap<u32, Vec<ObjectID>>
+}
+
+pub(in crate::engine::game_object) fn index<K: Eq + Hash>(index: &mut HashMap<K, Vec<ObjectID>>, key: K, object: ObjectID) {
+    index.entry(key).or_default().push(object);
+}
+
+pub(in crate::engine::game_object) fn unindex<K: Eq + Hash>(index: &mut HashMap<K, Vec<ObjectID>>, key: &K, object: ObjectID) {
+    if let Some(objects) = index.get_mut(key) {
+        objects.retain(|other| *other != object);
+
+        if objects.is_empty() {
+            index.remove(key);
+        }
+    }
+}
+
+impl World {
+    /// Where several children share the name, the first in sibling order is returned.
+    pub fn find_child(&self, object: ObjectID, name: &str) -> Result<Option<ObjectID>> {
+        let children = &self.object(object)?.children;
+
+        let Some(candidates) = self.lookup.names.get(name) else { return Ok(None); };
+        let mut matching = candidates.iter().copied().filter(|candidate| self.object(*candidate).is_ok_and(|candidate| candidate.parent == object));
+
+        // Names are rarely shared between siblings, so sibling order is only looked at when they are
+        match (matching.next(), matching.next()) {
+            (None, _) => Ok(None),
+            (Some(child), None) => Ok(Some(child)),
+            _ => Ok(children.iter().copied().find(|child| candidates.contains(child)))
+        }
+    }
+
+    /// Follows child names separated by '/', like "Level/Enemies/Goblin 3". An empty path is `object` itself.
+    /// Where siblings share a name, the first in sibling order is followed.
+    pub fn find_path(&self, object: ObjectID, path: &str) -> Result<Option<ObjectID>> {
+        self.get_name(object)?;
+
+        let mut current = object;
+
+        for name in path.split('/').filter(|name| !name.is_empty()) {
+            match self.find_child(current, name)? {
+                Some(child) => current = child,
+                None => return Ok(None)
+            }
+        }
+
+        Ok(Some(current))
+    }
+
+    /// Any object below `object` with the given name, at any depth. If there are several, the one that had the name first wins.
+    pub fn find_descendant(&self, object: ObjectID, name: &str) -> Result<Option<ObjectID>> {
+        self.get_name(object)?;
+
+        let Some(candidates) = self.lookup.names.get(name) else { return Ok(None); };
+
+        Ok(candidates.iter().copied().find(|candidate| self.is_descendant(*candidate, object)))
+    }
+
+    pub fn is_descendant(&self, object: ObjectID, ancestor: ObjectID) -> bool {
+        let mut current = object;
+
+        while current != self.root {
+            let Ok(parent) = self.get_parent(current) else { return false; };
+
+            if parent == ancestor {
+                return true;
+            }
+
+            current = parent;
+        }
+
+        false
+    }
+
+    pub fn add_tag<S: Into<String>>(&mut self, object: ObjectID, tag: S) -> Result<()> {
+        let tag = tag.into();
+
+        if self.object_mut(object)?.tags.insert(tag.clone()) {
+            index(&mut self.lookup.tags, tag, object);
+        }
+
+        Ok(())
+    }
+
+    pub fn remove_tag(&mut self, object: ObjectID, tag: &str) -> Result<()> {
+        if self.object_mut(object)?.tags.remove(tag) {
+            unindex(&mut self.lookup.tags, &tag.to_owned(), object);
+        }
+
+        Ok(())
+    }
+
+    pub fn has_tag(&self, object: ObjectID, tag: &str) -> Result<bool> {
+        Ok(self.object(object)?.tags.contains(tag))
+    }
+
+    /// Sorted, so the result doesn't change between runs.
+    pub fn get_tags(&self, object: ObjectID) -> Result<Box<[&str]>> {
+        let mut tags: Vec<&str> = self.object(object)?.tags.iter().map(String::as_str).collect();
+        tags.sort();
+
+        Ok(tags.into_boxed_slice())
+    }
+
+    /// Every object with the tag, in the order they were tagged.
+    pub fn find_by_tag(&self, tag: &str) -> Box<[ObjectID]> {
+        self.lookup.tags.get(tag).map(|objects| objects.as_slice().into()).unwrap_or_default()
+    }
+
+    pub fn get_layer(&self, object: ObjectID) -> Result<u32> {
+        Ok(self.object(object)?.layer)
+    }
+
+    /// Objects start on layer 0.
+    pub fn set_layer(&mut self, object: ObjectID, layer: u32) -> Result<()> {
+        let previous = std::mem::replace(&mut self.object_mut(object)?.layer, layer);
+
+        unindex(&mut self.lookup.layers, &previous, object);
+        index(&mut self.lookup.layers, layer, object);
+
+        Ok(())
+    }
+
+    pub fn find_by_layer(&self, layer: u32) -> Box<[ObjectID]> {
+        self.lookup.layers.get(&layer).map(|objects| objects.as_slice().into()).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::game_object::World;
+
+    #[test]
+    pub fn object_lookup() {
+        let mut world = World::new();
+        let root = world.get_root();
+        let level = world.create_game_object("Level", root).unwrap();
+        let enemies = world.create_game_object("Enemies", level).unwrap();
+        let goblin = world.create_game_object("Goblin 3", enemies).unwrap();
+        let other = world.create_game_object("Goblin 3", root).unwrap();
+
+        assert_eq!(world.find_path(root, "Level/Enemies/Goblin 3").unwrap(), Some(goblin));
+        assert_eq!(world.find_path(level, "Enemies/Goblin 3").unwrap(), Some(goblin));
+        assert_eq!(world.find_path(root, "Goblin 3").unwrap(), Some(other));
+        assert_eq!(world.find_path(root, "").unwrap(), Some(root));
+        assert_eq!(world.find_path(root, "Level/Goblin 3").unwrap(), None);
+        assert_eq!(world.find_descendant(level, "Goblin 3").unwrap(), Some(goblin));
+        assert_eq!(world.find_descendant(root, "Goblin 3").unwrap(), Some(goblin));
+        assert_eq!(world.find_descendant(enemies, "Level").unwrap(), None);
+
+        // Renaming and reparenting keep the index up to date
+        world.set_name(goblin, "Goblin 4".to_owned()).unwrap();
+        assert_eq!(world.find_path(root, "Level/Enemies/Goblin 4").unwrap(), Some(goblin));
+        assert_eq!(world.find_descendant(level, "Goblin 3").unwrap(), None);
+        world.set_parent(other, enemies).unwrap();
+        assert_eq!(world.find_path(root, "Level/Enemies/Goblin 3").unwrap(), Some(other));
+
+        // Siblings sharing a name are found in sibling order
+        let twin = world.create_game_object("Goblin 3", enemies).unwrap();
+        assert_eq!(world.find_child(enemies, "Goblin 3").unwrap(), Some(other));
+        world.set_sibling_index(twin, 0).unwrap();
+        assert_eq!(world.find_child(enemies, "Goblin 3").unwrap(), Some(twin));
+        world.destroy(twin).unwrap();
+
+        world.add_tag(goblin, "enemy").unwrap();
+        world.add_tag(other, "enemy").unwrap();
+        world.add_tag(other, "boss").unwrap();
+        world.add_tag(other, "boss").unwrap();
+        assert_eq!(&*world.find_by_tag("enemy"), [goblin, other]);
+        assert_eq!(&*world.find_by_tag("boss"), [other]);
+        assert_eq!(&*world.get_tags(other).unwrap(), ["boss", "enemy"]);
+        world.remove_tag(other, "boss").unwrap();
+        assert!(world.find_by_tag("boss").is_empty());
+        assert!(!world.has_tag(other, "boss").unwrap());
+
+        world.set_layer(goblin, 2).unwrap();
+        assert_eq!(world.get_layer(goblin).unwrap(), 2);
+        assert_eq!(&*world.find_by_layer(2), [goblin]);
+        assert!(!world.find_by_layer(0).contains(&goblin));
+
+        // Destroyed objects disappear from every lookup
+        world.destroy(level).unwrap();
+        assert!(world.find_by_tag("enemy").is_empty());
+        assert!(world.find_by_layer(2).is_empty());
+        assert_eq!(world.find_descendant(root, "Goblin 3").unwrap(), None);
+        assert_eq!(&*world.find_by_layer(0), [root]);
+    }
+}
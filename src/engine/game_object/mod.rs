@@ -1,6 +1,19 @@
 mod game_object;
 mod world;
+mod storage;
+mod query;
+mod scene;
+mod prefab;
+mod lookup;
+mod change;
+mod registry;
+#[cfg(test)]
+mod test_components;
 
 pub mod component;
 
-pub use world::*;
\ No newline at end of file
+pub use world::*;
+pub use query::*;
+pub use scene::*;
+pub use prefab::*;
+pub use change::*;
\ No newline at end of file
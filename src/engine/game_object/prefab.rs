@@ -0,0 +1,278 @@
+use std::any::TypeId;
+
+use crate::engine::errors::{ObjectError, Result};
+
+use super::{component::Component, ComponentID, ObjectID, World};
+
+// A component owned by a prefab, every instance gets its own clone
+pub(in crate::engine::game_object) trait PrefabComponent {
+    fn component_type(&self) -> TypeId;
+    fn attach(&self, world: &mut World, object: ObjectID) -> Result<()>;
+}
+
+struct Captured<C>(C);
+
+impl<C: Component + Clone> PrefabComponent for Captured<C> {
+    fn component_type(&self) -> TypeId {
+        TypeId::of::<C>()
+    }
+
+    fn attach(&self, world: &mut World, object: ObjectID) -> Result<()> {
+        self.0.clone().attach(world, object)
+    }
+}
+
+pub(in crate::engine::game_object) type Capture = fn(&World, ComponentID) -> Result<Box<dyn PrefabComponent>>;
+
+struct PrefabObject {
+    parent: Option<usize>,
+    name: String,
+    // Child names from the prefab's root separated by '/', the root itself is ""
+    path: String,
+    tags: Vec<String>,
+    layer: u32,
+    active: bool,
+    components: Vec<Box<dyn PrefabComponent>>
+}
+
+/// A captured object subtree that can be instantiated any number of times. Objects are stored parents first, the root being the first one.
+pub struct Prefab {
+    objects: Vec<PrefabObject>
+}
+
+impl Prefab {
+    pub fn instantiate(&self, world: &mut World, parent: ObjectID) -> Result<ObjectID> {
+        self.instance().spawn(world, parent)
+    }
+
+    /// Starts an instance that can be changed before it's spawned.
+    pub fn instance(&self) -> PrefabInstance<'_> {
+        PrefabInstance { prefab: self, name: None, overrides: Vec::new() }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.objects[0].name
+    }
+}
+
+/// Per instance changes to a prefab.
+pub struct PrefabInstance<'a> {
+    prefab: &'a Prefab,
+    name: Option<String>,
+    overrides: Vec<(String, Box<dyn PrefabComponent>)>
+}
+
+impl PrefabInstance<'_> {
+    /// Renames the instance's root object.
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Replaces the component of type `C` on every object at `path`, or adds it to the ones without one.
+    /// Paths are child names separated by '/' starting below the root, which is "". Setting a type at a path again replaces the earlier one.
+    pub fn set<C: Component + Clone>(mut self, path: &str, component: C) -> Self {
+        self.overrides.retain(|(other, existing)| !(other == path && existing.component_type() == TypeId::of::<C>()));
+        self.overrides.push((path.to_owned(), Box::new(Captured(component))));
+        self
+    }
+
+    pub fn spawn(self, world: &mut World, parent: ObjectID) -> Result<ObjectID> {
+        world.get_name(parent)?;
+
+        // Checked first so a typo doesn't leave half an instance behind
+        if let Some((path, _)) = self.overrides.iter().find(|(path, _)| !self.prefab.objects.iter().any(|object| object.path == *path)) {
+            Err(ObjectError::PrefabPathError { path: path.clone() })?
+        }
+
+        let mut created: Vec<ObjectID> = Vec::with_capacity(self.prefab.objects.len());
+        let spawned = self.spawn_objects(world, parent, &mut created);
+
+        // Whatever got created before the error goes away again
+        if let Err(error) = spawned {
+            if let Some(&root) = created.first() {
+                world.destroy(root)?;
+            }
+
+            return Err(error);
+        }
+
+        Ok(created[0])
+    }
+
+    fn spawn_objects(&self, world: &mut World, parent: ObjectID, created: &mut Vec<ObjectID>) -> Result<()> {
+        for (i, object) in self.prefab.objects.iter().enumerate() {
+            let name = match (i, &self.name) {
+                (0, Some(name)) => name.clone(),
+                _ => object.name.clone()
+            };
+            let id = world.create_game_object(name, object.parent.map_or(parent, |parent| created[parent]))?;
+            created.push(id);
+            world.set_layer(id, object.layer)?;
+            world.set_active(id, object.active)?;
+            for tag in &object.tags {
+                world.add_tag(id, tag.clone())?;
+            }
+
+            let overrides: Vec<&Box<dyn PrefabComponent>> = self.overrides.iter().filter(|(path, _)| *path == object.path).map(|(_, component)| component).collect();
+
+            for component in &object.components {
+                let component = overrides.iter().find(|o| o.component_type() == component.component_type()).copied().unwrap_or(component);
+                component.attach(world, id)?;
+            }
+
+            for component in overrides.iter().filter(|o| !object.components.iter().any(|component| component.component_type() == o.component_type())) {
+                component.attach(world, id)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl World {
+    pub fn register_prefab_component<C: Component + Clone>(&mut self) {
+        self.component_types.entry::<C>().capture = Some(|world, component| {
+            Ok(Box::new(Captured(world.borrow_component::<C>(component)?.clone())))
+        });
+    }
+
+    /// Captures `object` and everything below it. Every component has to be registered with `register_prefab_component`,
+    /// and none of them can be borrowed, so a component can't capture its own object.
+    pub fn create_prefab(&self, object: ObjectID) -> Result<Prefab> {
+        let mut prefab = Prefab { objects: Vec::new() };
+        self.capture(&mut prefab, object, None)?;
+
+        Ok(prefab)
+    }
+
+    fn capture(&self, prefab: &mut Prefab, object: ObjectID, parent: Option<usize>) -> Result<()> {
+        let name = self.get_name(object)?.to_owned();
+        let path = match parent {
+            None => String::new(),
+            Some(parent) if prefab.objects[parent].path.is_empty() => name.clone(),
+            Some(parent) => format!("{}/{}", prefab.objects[parent].path, name)
+        };
+
+        let mut components = Vec::new();
+
+        for component in self.component_ids(object)? {
+            let capture = self.component_types.get(component.type_id()).and_then(|registered| registered.capture);
+            let capture = capture.ok_or(ObjectError::PrefabComponentError { type_name: component.type_name() })?;
+
+            components.push(capture(self, *component)?);
+        }
+
+        let tags = self.get_tags(object)?.iter().map(|tag| tag.to_string()).collect();
+        let layer = self.get_layer(object)?;
+        let active = self.is_active_self(object)?;
+
+        prefab.objects.push(PrefabObject { parent, name, path, tags, layer, active, components });
+        let index = prefab.objects.len() - 1;
+
+        for child in self.get_children(object)? {
+            self.capture(prefab, child, Some(index))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gl_types::vec3;
+
+    use crate::engine::{errors::{Error, ObjectError, Result}, game_object::{component::{components::Transform, Component}, test_components::Health, ObjectID, World}};
+
+    #[derive(Clone)]
+    struct Damage(u32);
+
+    impl Component for Damage {}
+
+    struct Armor;
+
+    impl Component for Armor {}
+
+    #[derive(Clone)]
+    struct Cursed;
+
+    impl Component for Cursed {
+        fn attach(self, _world: &mut World, _object: ObjectID) -> Result<()> {
+            Err(ObjectError::PrefabComponentError { type_name: "Cursed" })?
+        }
+    }
+
+    #[test]
+    pub fn prefab_instances() {
+        let mut world = World::new();
+        world.register_prefab_component::<Health>();
+        world.register_prefab_component::<Damage>();
+
+        let goblin = world.create_game_object("Goblin", world.get_root()).unwrap();
+        let hand = world.create_game_object("Hand", goblin).unwrap();
+        let sword = world.create_game_object("Sword", hand).unwrap();
+        world.add_component(goblin, Health(10)).unwrap();
+        world.add_tag(goblin, "enemy").unwrap();
+        world.set_layer(sword, 3).unwrap();
+        world.add_component(sword, Damage(3)).unwrap();
+        let transform = world.get_component::<Transform>(hand).unwrap();
+        world.borrow_component_mut::<Transform>(transform).unwrap().set_position(vec3!(1.0, 0.0, 0.0));
+
+        let prefab = world.create_prefab(goblin).unwrap();
+        assert_eq!(prefab.name(), "Goblin");
+
+        let level = world.create_game_object("Level", world.get_root()).unwrap();
+        let first = prefab.instantiate(&mut world, level).unwrap();
+        let second = prefab.instance()
+            .name("Goblin Chief")
+            .set("", Health(99))
+            .set("", Health(25))
+            .set("", Transform::new(vec3!(0.0, 5.0, 0.0), Default::default(), vec3!(1.0)))
+            .set("Hand/Sword", Damage(8))
+            .set("Hand", Health(1))
+            .spawn(&mut world, level).unwrap();
+
+        let health = |world: &World, object| world.borrow_component::<Health>(world.get_component::<Health>(object).unwrap()).unwrap().0;
+        let damage = |world: &World, object| world.borrow_component::<Damage>(world.get_component::<Damage>(object).unwrap()).unwrap().0;
+        let sword_of = |world: &World, object| world.find_child(world.find_child(object, "Hand").unwrap().unwrap(), "Sword").unwrap().unwrap();
+
+        assert_eq!(world.get_name(first).unwrap(), "Goblin");
+        assert_eq!(world.get_name(second).unwrap(), "Goblin Chief");
+        assert_eq!(health(&world, first), 10);
+        assert_eq!(health(&world, second), 25);
+        assert_eq!(damage(&world, sword_of(&world, first)), 3);
+        assert_eq!(damage(&world, sword_of(&world, second)), 8);
+        assert_eq!(health(&world, world.find_child(second, "Hand").unwrap().unwrap()), 1);
+        assert_eq!(world.get_components::<Health>(second).unwrap().len(), 1);
+        assert_eq!(world.get_components::<Transform>(second).unwrap().len(), 1);
+        assert_eq!(&*world.find_by_tag("enemy"), [goblin, first, second]);
+        assert_eq!(world.get_layer(sword_of(&world, second)).unwrap(), 3);
+
+        // Each instance owns its components and keeps the captured transforms
+        let component = world.get_component::<Damage>(sword_of(&world, first)).unwrap();
+        world.borrow_component_mut::<Damage>(component).unwrap().0 = 100;
+        assert_eq!(damage(&world, sword), 3);
+        assert_eq!(damage(&world, sword_of(&world, second)), 8);
+        assert_eq!(world.world_position(sword_of(&world, first)).unwrap(), vec3!(1.0, 0.0, 0.0));
+        assert_eq!(world.world_position(sword_of(&world, second)).unwrap(), vec3!(1.0, 5.0, 0.0));
+
+        // Unknown paths are rejected before anything gets created
+        let children = world.get_children(level).unwrap().len();
+        let result = prefab.instance().set("Hand/Shield", Damage(1)).spawn(&mut world, level);
+        assert!(matches!(result, Err(Error::ObjectError { source: ObjectError::PrefabPathError { .. }, .. })));
+        assert_eq!(world.get_children(level).unwrap().len(), children);
+
+        // As is everything already created when a component fails to attach
+        let result = prefab.instance().set("Hand/Sword", Cursed).spawn(&mut world, level);
+        assert!(matches!(result, Err(Error::ObjectError { source: ObjectError::PrefabComponentError { type_name: "Cursed" }, .. })));
+        assert_eq!(world.get_children(level).unwrap().len(), children);
+        assert_eq!(&*world.find_by_tag("enemy"), [goblin, first, second]);
+
+        // Components that can't be cloned aren't dropped silently
+        world.add_component(hand, Armor).unwrap();
+        match world.create_prefab(goblin) {
+            Err(Error::ObjectError { source: ObjectError::PrefabComponentError { type_name }, .. }) => assert!(type_name.ends_with("Armor")),
+            _ => panic!("Expected a prefab component error")
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use std::cell::{Ref, RefMut};
+
+use super::{component::Component, storage::Storage, ComponentID, ObjectID, QueryFilter, Tick, World};
+
+// Change filters with the tick each one compares against
+type Filters = Vec<(fn(&World, ComponentID, Tick) -> bool, Tick)>;
+
+/// Shared access to every active component of type `C`. The whole type stays borrowed until the query is dropped.
+pub struct Query<'w, C: Component> {
+    world: &'w World,
+    storage: Option<Ref<'w, Storage<C>>>,
+    filters: Filters
+}
+
+/// Every component handed out counts as changed.
+pub struct QueryMut<'w, C: Component> {
+    world: &'w World,
+    storage: Option<RefMut<'w, Storage<C>>>,
+    filters: Filters
+}
+
+/// Objects that have both an `A` and a `B`, paired with the first component of each type. Pairs where either one is inactive are left out.
+pub struct Query2<'w, A: Component, B: Component> {
+    world: &'w World,
+    a: Option<Ref<'w, Storage<A>>>,
+    b: Option<Ref<'w, Storage<B>>>,
+    filters: Filters
+}
+
+pub struct Query2Mut<'w, A: Component, B: Component> {
+    world: &'w World,
+    a: Option<RefMut<'w, Storage<A>>>,
+    b: Option<RefMut<'w, Storage<B>>>,
+    filters: Filters
+}
+
+mod sealed {
+    pub trait HasFilters {
+        fn filters(&mut self) -> &mut super::Filters;
+    }
+}
+
+/// Implemented by every query type.
+pub trait FilterQuery: sealed::HasFilters + Sized {
+    /// Only keeps components matching `F`, like `Changed<C>` or `Added<C>`, compared against `since`.
+    fn filter<F: QueryFilter>(mut self, since: Tick) -> Self {
+        self.filters().push((F::matches, since));
+        self
+    }
+}
+
+impl<T: sealed::HasFilters> FilterQuery for T {}
+
+impl<C: Component> sealed::HasFilters for Query<'_, C> {
+    fn filters(&mut self) -> &mut Filters {
+        &mut self.filters
+    }
+}
+
+impl<C: Component> sealed::HasFilters for QueryMut<'_, C> {
+    fn filters(&mut self) -> &mut Filters {
+        &mut self.filters
+    }
+}
+
+impl<A: Component, B: Component> sealed::HasFilters for Query2<'_, A, B> {
+    fn filters(&mut self) -> &mut Filters {
+        &mut self.filters
+    }
+}
+
+impl<A: Component, B: Component> sealed::HasFilters for Query2Mut<'_, A, B> {
+    fn filters(&mut self) -> &mut Filters {
+        &mut self.filters
+    }
+}
+
+impl<'w, C: Component> Query<'w, C> {
+    pub(in crate::engine::game_object) fn new(world: &'w World, storage: Option<Ref<'w, Storage<C>>>) -> Self {
+        Query { world, storage, filters: Vec::new() }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (ObjectID, &C)> {
+        let world = self.world;
+        let filters = &self.filters;
+
+        self.storage.iter().flat_map(|storage| storage.iter()).filter(move |(id, _)| keep(world, filters, *id)).map(|(id, component)| (id.owner, component))
+    }
+}
+
+impl<'w, C: Component> QueryMut<'w, C> {
+    pub(in crate::engine::game_object) fn new(world: &'w World, storage: Option<RefMut<'w, Storage<C>>>) -> Self {
+        QueryMut { world, storage, filters: Vec::new() }
+    }
+
+    /// Doesn't count as a change.
+    pub fn iter(&self) -> impl Iterator<Item = (ObjectID, &C)> {
+        let world = self.world;
+        let filters = &self.filters;
+
+        self.storage.iter().flat_map(|storage| storage.iter()).filter(move |(id, _)| keep(world, filters, *id)).map(|(id, component)| (id.owner, component))
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (ObjectID, &mut C)> + use<'_, 'w, C> {
+        let world = self.world;
+        let filters = &self.filters;
+
+        self.storage.iter_mut().flat_map(|storage| storage.iter_mut()).filter(move |(id, _)| keep(world, filters, *id)).map(move |(id, component)| {
+            world.set_changed(id);
+            (id.owner, component)
+        })
+    }
+}
+
+impl<'w, A: Component, B: Component> Query2<'w, A, B> {
+    pub(in crate::engine::game_object) fn new(world: &'w World, a: Option<Ref<'w, Storage<A>>>, b: Option<Ref<'w, Storage<B>>>) -> Self {
+        Query2 { world, a, b, filters: Vec::new() }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (ObjectID, &A, &B)> {
+        let world = self.world;
+        let filters = &self.filters;
+
+        self.a.iter().zip(self.b.iter()).flat_map(move |(a, b)| {
+            let b: &Storage<B> = b;
+
+            a.iter().filter_map(move |(a_id, a)| {
+                let b = b.get(pair::<A, B>(world, filters, a_id)?.index).ok()??;
+
+                Some((a_id.owner, a, b))
+            })
+        })
+    }
+}
+
+impl<'w, A: Component, B: Component> Query2Mut<'w, A, B> {
+    pub(in crate::engine::game_object) fn new(world: &'w World, a: Option<RefMut<'w, Storage<A>>>, b: Option<RefMut<'w, Storage<B>>>) -> Self {
+        Query2Mut { world, a, b, filters: Vec::new() }
+    }
+
+    /// Both components of every pair handed out count as changed.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (ObjectID, &mut A, &mut B)> + use<'_, 'w, A, B> {
+        let world = self.world;
+        let filters = &self.filters;
+
+        self.a.iter_mut().zip(self.b.iter_mut()).flat_map(move |(a, b)| {
+            let a: &mut Storage<A> = a;
+            let b: &mut Storage<B> = b;
+
+            // B is looked up by owner, so split it into separate references up front
+            let b_ids: Vec<_> = a.iter().map(|(a_id, _)| pair::<A, B>(world, filters, a_id).and_then(|b_id| Some((b_id, b.dense_index(b_id.index).ok()?)))).collect();
+            let mut b_slots: Vec<_> = b.slots_mut().collect();
+
+            a.iter_mut().zip(b_ids).filter_map(move |((a_id, a), b_id)| {
+                let (b_id, b_dense) = b_id?;
+                let b = b_slots[b_dense].take()?;
+
+                world.set_changed(a_id);
+                world.set_changed(b_id);
+
+                Some((a_id.owner, a, b))
+            })
+        })
+    }
+}
+
+fn keep(world: &World, filters: &Filters, component: ComponentID) -> bool {
+    world.is_component_active(component) && filters.iter().all(|(matches, since)| matches(world, component, *since))
+}
+
+// First component of type B on the owner, but only for the owner's first A and only if both are active. Filters go by A.
+fn pair<A: Component, B: Component>(world: &World, filters: &Filters, a: ComponentID) -> Option<ComponentID> {
+    if world.get_component::<A>(a.owner).ok()? != a || !keep(world, filters, a) {
+        return None;
+    }
+
+    world.get_component::<B>(a.owner).ok().filter(|b| world.is_component_active(*b))
+}
+
+impl<'a, 'w, C: Component> IntoIterator for &'a Query<'w, C> {
+    type Item = (ObjectID, &'a C);
+    type IntoIter = Box<dyn Iterator<Item = Self::Item> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter())
+    }
+}
+
+impl<'a, 'w, C: Component> IntoIterator for &'a mut QueryMut<'w, C> {
+    type Item = (ObjectID, &'a mut C);
+    type IntoIter = Box<dyn Iterator<Item = Self::Item> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter_mut())
+    }
+}
+
+impl<'a, 'w, A: Component, B: Component> IntoIterator for &'a Query2<'w, A, B> {
+    type Item = (ObjectID, &'a A, &'a B);
+    type IntoIter = Box<dyn Iterator<Item = Self::Item> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter())
+    }
+}
+
+impl<'a, 'w, A: Component, B: Component> IntoIterator for &'a mut Query2Mut<'w, A, B> {
+    type Item = (ObjectID, &'a mut A, &'a mut B);
+    type IntoIter = Box<dyn Iterator<Item = Self::Item> + 'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Box::new(self.iter_mut())
+    }
+}
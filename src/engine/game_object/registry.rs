@@ -0,0 +1,35 @@
+use std::{any::TypeId, collections::HashMap};
+
+use super::{component::Component, Capture, SceneType};
+
+// What the world can do with a component type beyond storing it. Scenes and prefabs each fill in their part.
+#[derive(Default)]
+pub(in crate::engine::game_object) struct RegisteredType {
+    pub(in crate::engine::game_object) scene: Option<SceneType>,
+    pub(in crate::engine::game_object) capture: Option<Capture>
+}
+
+#[derive(Default)]
+pub(in crate::engine::game_object) struct ComponentRegistry {
+    types: HashMap<TypeId, RegisteredType>,
+    // Scene names, which have to be unique across types
+    names: HashMap<&'static str, TypeId>
+}
+
+impl ComponentRegistry {
+    pub(in crate::engine::game_object) fn entry<C: Component>(&mut self) -> &mut RegisteredType {
+        self.types.entry(TypeId::of::<C>()).or_default()
+    }
+
+    pub(in crate::engine::game_object) fn get(&self, type_id: TypeId) -> Option<&RegisteredType> {
+        self.types.get(&type_id)
+    }
+
+    pub(in crate::engine::game_object) fn name<C: Component>(&mut self, name: &'static str) {
+        self.names.insert(name, TypeId::of::<C>());
+    }
+
+    pub(in crate::engine::game_object) fn by_name(&self, name: &str) -> Option<&RegisteredType> {
+        self.get(*self.names.get(name)?)
+    }
+}
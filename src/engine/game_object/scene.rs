@@ -0,0 +1,395 @@
+use std::{fs::File, io::{BufRead, BufReader, BufWriter, Write}, path::Path, str::FromStr};
+
+use crate::engine::{errors::{ObjectError, Result}, text_format::{escape, read_lines, unescape}};
+
+use super::{component::Component, ComponentID, ObjectID, World};
+
+const SCENE_HEADER: &str = "# scene v1";
+
+/// Components that can be saved to and loaded from scenes. They have to be registered with `World::register_scene_component`.
+pub trait SceneComponent: Component + Sized {
+    /// Identifies the component in scene files, so it shouldn't change once scenes were saved with it.
+    const NAME: &'static str;
+
+    fn save(&self, fields: &mut Vec<String>) -> Result<()>;
+    fn load(fields: &mut SceneFields) -> std::result::Result<Self, String>;
+}
+
+/// The fields a component was saved with, read front to back.
+pub struct SceneFields<'a> {
+    fields: std::slice::Iter<'a, String>
+}
+
+impl<'a> SceneFields<'a> {
+    pub fn next_str(&mut self) -> std::result::Result<&'a str, String> {
+        self.fields.next().map(String::as_str).ok_or_else(|| "Missing value".to_owned())
+    }
+
+    pub fn next<T: FromStr>(&mut self) -> std::result::Result<T, String> {
+        let s = self.next_str()?;
+        s.parse().map_err(|_| format!("Invalid value \"{s}\""))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.len() == 0
+    }
+}
+
+type Attach = Box<dyn FnOnce(&mut World, ObjectID) -> Result<()>>;
+
+pub(in crate::engine::game_object) struct SceneType {
+    name: &'static str,
+    save: fn(&World, ComponentID, &mut Vec<String>) -> Result<()>,
+    load: fn(&mut SceneFields) -> std::result::Result<Attach, String>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SceneObject {
+    /// Index of the parent in `Scene::objects`, `None` for objects placed directly under the object the scene is instantiated under.
+    pub parent: Option<usize>,
+    pub name: String,
+    pub tags: Vec<String>,
+    pub layer: u32,
+    pub active: bool,
+    /// Component names and their saved fields.
+    pub components: Vec<(String, Vec<String>)>
+}
+
+/// A saved object hierarchy. Parents always come before their children.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Scene {
+    pub objects: Vec<SceneObject>
+}
+
+impl Scene {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Scene> {
+        Self::parse(BufReader::new(File::open(path)?))
+    }
+
+    pub fn parse<R: BufRead>(reader: R) -> Result<Scene> {
+        let mut objects: Vec<SceneObject> = Vec::new();
+
+        read_lines(reader, SCENE_HEADER, |line, msg| ObjectError::SceneParseError { line, msg }, |mut fields| {
+            let kind = fields.next_string("entry")?;
+
+            match kind.as_str() {
+                "object" => {
+                    let parent: usize = fields.next("parent")?;
+                    let name = fields.next_string("name")?;
+                    fields.finish()?;
+
+                    // Objects are numbered from 1 in the order they appear, 0 is whatever the scene gets instantiated under
+                    if parent > objects.len() {
+                        Err(format!("Parent {parent} comes after its child"))?
+                    }
+
+                    objects.push(SceneObject { parent: parent.checked_sub(1), name, tags: Vec::new(), layer: 0, active: true, components: Vec::new() });
+                },
+                "tag" => {
+                    let tag = fields.next_string("tag")?;
+                    fields.finish()?;
+
+                    objects.last_mut().ok_or("Tag before the first object")?.tags.push(tag);
+                },
+                "layer" => {
+                    let layer = fields.next("layer")?;
+                    fields.finish()?;
+
+                    objects.last_mut().ok_or("Layer before the first object")?.layer = layer;
+                },
+                "active" => {
+                    let active = match fields.next_raw("active flag")? {
+                        "0" => false,
+                        "1" => true,
+                        other => Err(format!("Invalid active flag \"{other}\""))?
+                    };
+                    fields.finish()?;
+
+                    objects.last_mut().ok_or("Active flag before the first object")?.active = active;
+                },
+                "component" => {
+                    let name = fields.next_string("component name")?;
+                    let fields = fields.rest().map(unescape).collect::<std::result::Result<_, _>>()?;
+
+                    objects.last_mut().ok_or("Component before the first object")?.components.push((name, fields));
+                },
+                kind => Err(format!("Unknown entry \"{kind}\""))?
+            }
+
+            Ok(())
+        })?;
+
+        Ok(Scene { objects })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write(&mut writer)?;
+        writer.flush()?;
+
+        Ok(())
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<()> {
+        writeln!(writer, "{}", SCENE_HEADER)?;
+
+        for object in &self.objects {
+            writeln!(writer, "object\t{}\t{}", object.parent.map_or(0, |parent| parent + 1), escape(&object.name))?;
+
+            for tag in &object.tags {
+                writeln!(writer, "tag\t{}", escape(tag))?;
+            }
+
+            if object.layer != 0 {
+                writeln!(writer, "layer\t{}", object.layer)?;
+            }
+
+            if !object.active {
+                writeln!(writer, "active\t0")?;
+            }
+
+            for (name, fields) in &object.components {
+                write!(writer, "component\t{}", escape(name))?;
+
+                for field in fields {
+                    write!(writer, "\t{}", escape(field))?;
+                }
+
+                writeln!(writer)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl World {
+    pub fn register_scene_component<C: SceneComponent>(&mut self) {
+        self.component_types.name::<C>(C::NAME);
+        self.component_types.entry::<C>().scene = Some(SceneType {
+            name: C::NAME,
+            save: |world, component, fields| world.borrow_component::<C>(component)?.save(fields),
+            load: |fields| {
+                let component = C::load(fields)?;
+                Ok(Box::new(move |world, object| component.attach(world, object)))
+            }
+        });
+    }
+
+    /// Captures everything below `object`, without the object itself. Components that aren't registered are left out.
+    /// Registered components are borrowed while saving, so this fails when called from one of their own callbacks.
+    pub fn save_scene(&self, object: ObjectID) -> Result<Scene> {
+        let mut scene = Scene::default();
+        self.save_children(&mut scene, object, None)?;
+
+        Ok(scene)
+    }
+
+    fn save_children(&self, scene: &mut Scene, object: ObjectID, parent: Option<usize>) -> Result<()> {
+        for child in self.get_children(object)? {
+            let mut components = Vec::new();
+
+            for component in self.component_ids(child)? {
+                let Some(scene_type) = self.component_types.get(component.type_id()).and_then(|registered| registered.scene.as_ref()) else { continue; };
+                let mut fields = Vec::new();
+
+                (scene_type.save)(self, *component, &mut fields)?;
+                components.push((scene_type.name.to_owned(), fields));
+            }
+
+            let tags = self.get_tags(child)?.iter().map(|tag| tag.to_string()).collect();
+
+            scene.objects.push(SceneObject { parent, name: self.get_name(child)?.to_owned(), tags, layer: self.get_layer(child)?, active: self.is_active_self(child)?, components });
+            self.save_children(scene, child, Some(scene.objects.len() - 1))?;
+        }
+
+        Ok(())
+    }
+
+    /// Creates the scene's objects below `parent` and returns the top level ones.
+    /// If anything fails, the objects created up to that point are destroyed again.
+    pub fn instantiate_scene(&mut self, scene: &Scene, parent: ObjectID) -> Result<Vec<ObjectID>> {
+        self.get_name(parent)?;
+
+        let mut loaded = Vec::with_capacity(scene.objects.len());
+
+        for (i, object) in scene.objects.iter().enumerate() {
+            if object.parent.is_some_and(|parent| parent >= i) {
+                Err(ObjectError::SceneLoadError { object: object.name.clone(), msg: "Parent comes after its child".to_owned() })?
+            }
+
+            let components = object.components.iter().map(|(name, fields)| {
+                let invalid = |msg| ObjectError::SceneLoadError { object: object.name.clone(), msg };
+                let scene_type = self.component_types.by_name(name).and_then(|registered| registered.scene.as_ref());
+                let scene_type = scene_type.ok_or_else(|| invalid(format!("Unknown component \"{name}\"")))?;
+
+                let mut fields = SceneFields { fields: fields.iter() };
+                let attach = (scene_type.load)(&mut fields).map_err(|msg| invalid(format!("{name} - {msg}")))?;
+
+                match fields.fields.next() {
+                    Some(field) => Err(invalid(format!("{name} - Unexpected field \"{field}\""))),
+                    None => Ok(attach)
+                }
+            }).collect::<std::result::Result<Vec<_>, _>>()?;
+
+            loaded.push(components);
+        }
+
+        let mut top_level = Vec::new();
+        let created = self.create_scene_objects(scene, loaded, parent, &mut top_level);
+
+        // Whatever got created before the error goes away again
+        if let Err(error) = created {
+            for object in top_level {
+                self.destroy(object)?;
+            }
+
+            return Err(error);
+        }
+
+        Ok(top_level)
+    }
+
+    fn create_scene_objects(&mut self, scene: &Scene, loaded: Vec<Vec<Attach>>, parent: ObjectID, top_level: &mut Vec<ObjectID>) -> Result<()> {
+        let mut created: Vec<ObjectID> = Vec::with_capacity(scene.objects.len());
+
+        for (object, components) in scene.objects.iter().zip(loaded) {
+            let id = match object.parent {
+                Some(index) => self.create_game_object(object.name.clone(), created[index])?,
+                None => {
+                    let id = self.create_game_object(object.name.clone(), parent)?;
+                    top_level.push(id);
+                    id
+                }
+            };
+
+            self.set_layer(id, object.layer)?;
+            self.set_active(id, object.active)?;
+            for tag in &object.tags {
+                self.add_tag(id, tag.clone())?;
+            }
+
+            for attach in components {
+                attach(self, id)?;
+            }
+
+            created.push(id);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gl_types::{vec3, quaternion::Quat};
+
+    use crate::engine::{errors::{Error, ObjectError, Result}, game_object::{component::{components::Transform, Component}, test_components::Health, ObjectID, World}, graphics::sprite_renderer::components::{Sprite, SpriteSheet}};
+
+    use super::{Scene, SceneComponent, SceneFields};
+
+    // Loads fine but can never be attached
+    struct Cursed;
+
+    impl Component for Cursed {
+        fn attach(self, _world: &mut World, _object: ObjectID) -> Result<()> {
+            Err(ObjectError::SceneLoadError { object: "Cursed".to_owned(), msg: "Can't be attached".to_owned() })?
+        }
+    }
+
+    impl SceneComponent for Cursed {
+        const NAME: &'static str = "Cursed";
+
+        fn save(&self, _fields: &mut Vec<String>) -> Result<()> {
+            Ok(())
+        }
+
+        fn load(_fields: &mut SceneFields) -> std::result::Result<Self, String> {
+            Ok(Cursed)
+        }
+    }
+
+    #[test]
+    pub fn scene_round_trip() {
+        let mut world = World::new();
+        world.register_scene_component::<Health>();
+
+        let level = world.create_game_object("Level", world.get_root()).unwrap();
+        let goblin = world.create_game_object("Goblin\t1", level).unwrap();
+        let sword = world.create_game_object("Sword", goblin).unwrap();
+        world.set_active(sword, false).unwrap();
+        world.add_component(goblin, Health(12)).unwrap();
+        world.add_tag(goblin, "enemy").unwrap();
+        world.set_layer(goblin, 2).unwrap();
+        world.add_component(goblin, Sprite::new("goblin.png", 3)).unwrap();
+
+        let mut sheet = SpriteSheet::new("goblin.png");
+        sheet.add_sprite(0, 0, 16, 16);
+        sheet.add_sprite(16, 0, 16, 32);
+        world.add_component(level, sheet).unwrap();
+
+        let transform = world.get_component::<Transform>(goblin).unwrap();
+        world.borrow_component_mut::<Transform>(transform).unwrap().set_position(vec3!(1.5, 2.0, -3.25));
+        world.borrow_component_mut::<Transform>(transform).unwrap().set_rotation(Quat::from_euler(vec3!(0.0, 0.5, 0.0)));
+
+        let mut saved = Vec::new();
+        world.save_scene(world.get_root()).unwrap().write(&mut saved).unwrap();
+        let text = String::from_utf8(saved.clone()).unwrap();
+        assert!(text.starts_with("# scene v1\nobject\t0\tLevel\n"));
+        assert!(text.contains("object\t1\tGoblin\\t1\ntag\tenemy\nlayer\t2\n"));
+        assert!(text.contains("object\t2\tSword\nactive\t0\n"));
+        assert!(text.contains("component\tSpriteSheet\tgoblin.png\t0,0,16,16\t16,0,16,32\n"));
+
+        let scene = Scene::parse(&saved[..]).unwrap();
+        let mut loaded = World::new();
+        loaded.register_scene_component::<Health>();
+        let top_level = loaded.instantiate_scene(&scene, loaded.get_root()).unwrap();
+        assert_eq!(top_level.len(), 1);
+
+        let goblin = loaded.find_child(top_level[0], "Goblin\t1").unwrap().unwrap();
+        assert_eq!(loaded.borrow_component::<Health>(loaded.get_component::<Health>(goblin).unwrap()).unwrap().0, 12);
+        assert_eq!(loaded.borrow_component::<Sprite>(loaded.get_component::<Sprite>(goblin).unwrap()).unwrap().sprite_index, 3);
+        assert!(!loaded.is_active_self(loaded.find_child(goblin, "Sword").unwrap().unwrap()).unwrap());
+        assert!(loaded.is_active(goblin).unwrap());
+        assert_eq!(&*loaded.find_by_tag("enemy"), [goblin]);
+        assert_eq!(loaded.get_layer(goblin).unwrap(), 2);
+        assert_eq!(loaded.get_components::<Transform>(goblin).unwrap().len(), 1);
+        assert_eq!(loaded.world_position(goblin).unwrap(), vec3!(1.5, 2.0, -3.25));
+
+        // Saving what was loaded gives the same file back
+        let mut resaved = Vec::new();
+        loaded.save_scene(loaded.get_root()).unwrap().write(&mut resaved).unwrap();
+        assert_eq!(String::from_utf8(resaved).unwrap(), text);
+    }
+
+    #[test]
+    pub fn broken_scenes() {
+        let not_a_scene = Scene::parse(&b"object\t0\tLevel\n"[..]);
+        assert!(matches!(not_a_scene, Err(Error::ObjectError { source: ObjectError::SceneParseError { line: 1, .. }, .. })));
+
+        let bad_parent = Scene::parse(&b"# scene v1\nobject\t0\tLevel\nobject\t3\tGoblin\n"[..]);
+        assert!(matches!(bad_parent, Err(Error::ObjectError { source: ObjectError::SceneParseError { line: 3, .. }, .. })));
+
+        // Health isn't registered, so nothing gets created
+        let scene = Scene::parse(&b"# scene v1\nobject\t0\tLevel\nobject\t1\tGoblin\ncomponent\tHealth\t12\n"[..]).unwrap();
+        let mut world = World::new();
+        assert!(matches!(world.instantiate_scene(&scene, world.get_root()), Err(Error::ObjectError { source: ObjectError::SceneLoadError { .. }, .. })));
+        assert!(world.get_children(world.get_root()).unwrap().is_empty());
+
+        let scene = Scene::parse(&b"# scene v1\nobject\t0\tLevel\ncomponent\tTransform\t1\t2\n"[..]).unwrap();
+        assert!(world.instantiate_scene(&scene, world.get_root()).is_err());
+
+        let extra_field = Scene::parse(&b"# scene v1\nobject\t0\tLevel\nlayer\t2\t3\n"[..]);
+        assert!(matches!(extra_field, Err(Error::ObjectError { source: ObjectError::SceneParseError { line: 3, .. }, .. })));
+
+        world.register_scene_component::<Health>();
+        let scene = Scene::parse(&b"# scene v1\nobject\t0\tLevel\ncomponent\tHealth\t12\t13\n"[..]).unwrap();
+        assert!(matches!(world.instantiate_scene(&scene, world.get_root()), Err(Error::ObjectError { source: ObjectError::SceneLoadError { .. }, .. })));
+
+        // Objects created before a component failed to attach are destroyed again
+        world.register_scene_component::<Cursed>();
+        let scene = Scene::parse(&b"# scene v1\nobject\t0\tLevel\nobject\t1\tGoblin\nobject\t0\tSky\ncomponent\tCursed\n"[..]).unwrap();
+        assert!(world.instantiate_scene(&scene, world.get_root()).is_err());
+        assert!(world.get_children(world.get_root()).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,127 @@
+use std::cell::RefCell;
+
+use downcast_rs::{impl_downcast, Downcast};
+
+use crate::engine::{data_structures::{error::Error, AllocationIndex, VecAllocator}, errors::Result, Engine};
+
+use super::{component::Component, ComponentID, ObjectID, World};
+
+pub(in crate::engine::game_object) type StageFn<'a> = dyn FnMut(&mut dyn Component, &mut Engine, ObjectID) -> Result<()> + 'a;
+
+// Dense storage for one component type. Handles are generational and point at the component's current position,
+// which moves whenever another component of the same type is removed.
+pub(in crate::engine::game_object) struct Storage<C> {
+    handles: VecAllocator<usize>,
+    // None while the component is taken out to run one of its callbacks
+    components: Vec<Option<C>>,
+    ids: Vec<ComponentID>
+}
+
+impl<C: Component> Storage<C> {
+    pub(in crate::engine::game_object) fn new() -> Storage<C> {
+        Storage { handles: VecAllocator::new(), components: Vec::new(), ids: Vec::new() }
+    }
+
+    pub(in crate::engine::game_object) fn insert<F: FnOnce(AllocationIndex) -> ComponentID>(&mut self, component: C, make_id: F) -> ComponentID {
+        let index = self.handles.insert(self.components.len());
+        let id = make_id(index);
+
+        self.components.push(Some(component));
+        self.ids.push(id);
+
+        id
+    }
+
+    pub(in crate::engine::game_object) fn dense_index(&self, index: AllocationIndex) -> std::result::Result<usize, Error> {
+        self.handles.get(index).copied()
+    }
+
+    /// `Ok(None)` if the component is currently taken out.
+    pub(in crate::engine::game_object) fn get(&self, index: AllocationIndex) -> std::result::Result<Option<&C>, Error> {
+        Ok(self.components[self.dense_index(index)?].as_ref())
+    }
+
+    pub(in crate::engine::game_object) fn get_mut(&mut self, index: AllocationIndex) -> std::result::Result<Option<&mut C>, Error> {
+        let dense = self.dense_index(index)?;
+
+        Ok(self.components[dense].as_mut())
+    }
+
+    pub(in crate::engine::game_object) fn remove(&mut self, index: AllocationIndex) -> std::result::Result<Option<C>, Error> {
+        let dense = self.handles.remove(index)?;
+
+        let component = self.components.swap_remove(dense);
+        self.ids.swap_remove(dense);
+
+        if let Some(moved) = self.ids.get(dense) {
+            *self.handles.get_mut(moved.index).unwrap() = dense;
+        }
+
+        Ok(component)
+    }
+
+    pub(in crate::engine::game_object) fn take(&mut self, index: AllocationIndex) -> Option<C> {
+        let dense = self.dense_index(index).ok()?;
+
+        self.components[dense].take()
+    }
+
+    /// Hands the component back if it was removed while it was taken out.
+    pub(in crate::engine::game_object) fn put_back(&mut self, index: AllocationIndex, component: C) -> Option<C> {
+        match self.dense_index(index) {
+            Ok(dense) => {
+                self.components[dense] = Some(component);
+                None
+            },
+            Err(_) => Some(component)
+        }
+    }
+
+    pub(in crate::engine::game_object) fn iter(&self) -> impl Iterator<Item = (ComponentID, &C)> {
+        self.ids.iter().zip(self.components.iter()).filter_map(|(id, component)| Some((*id, component.as_ref()?)))
+    }
+
+    pub(in crate::engine::game_object) fn iter_mut(&mut self) -> impl Iterator<Item = (ComponentID, &mut C)> {
+        self.ids.iter().zip(self.components.iter_mut()).filter_map(|(id, component)| Some((*id, component.as_mut()?)))
+    }
+
+    pub(in crate::engine::game_object) fn slots_mut(&mut self) -> impl Iterator<Item = Option<&mut C>> {
+        self.components.iter_mut().map(Option::as_mut)
+    }
+}
+
+// Type erased access for code that only has a ComponentID
+pub(in crate::engine::game_object) trait AnyStorage: Downcast {
+    fn contains(&mut self, index: AllocationIndex) -> bool;
+    fn remove_boxed(&mut self, index: AllocationIndex) -> std::result::Result<Option<Box<dyn Component>>, Error>;
+    fn runner(&self) -> fn(&mut Engine, ComponentID, &mut StageFn) -> Result<()>;
+}
+
+impl_downcast!(AnyStorage);
+
+impl<C: Component> AnyStorage for RefCell<Storage<C>> {
+    fn contains(&mut self, index: AllocationIndex) -> bool {
+        self.get_mut().dense_index(index).is_ok()
+    }
+
+    fn remove_boxed(&mut self, index: AllocationIndex) -> std::result::Result<Option<Box<dyn Component>>, Error> {
+        Ok(self.get_mut().remove(index)?.map(|component| Box::new(component) as Box<dyn Component>))
+    }
+
+    fn runner(&self) -> fn(&mut Engine, ComponentID, &mut StageFn) -> Result<()> {
+        run_component::<C>
+    }
+}
+
+// The component is moved out of its storage while it runs, so it can freely use the world it lives in
+fn run_component<C: Component>(engine: &mut Engine, component: ComponentID, f: &mut StageFn) -> Result<()> {
+    let Some(mut c) = engine.world.storage_mut::<C>().take(component.index) else { return Ok(()); };
+
+    let result = f(&mut c, engine, component.owner);
+
+    if let Some(c) = engine.world.storage_mut::<C>().put_back(component.index, c) {
+        World::queue_removed(&mut engine.world, component, Box::new(c));
+    }
+
+    result
+}
@@ -0,0 +1,22 @@
+// Components shared by the tests in this module
+use crate::engine::errors::Result;
+
+use super::{component::Component, SceneComponent, SceneFields};
+
+#[derive(Clone)]
+pub(in crate::engine::game_object) struct Health(pub u32);
+
+impl Component for Health {}
+
+impl SceneComponent for Health {
+    const NAME: &'static str = "Health";
+
+    fn save(&self, fields: &mut Vec<String>) -> Result<()> {
+        fields.push(self.0.to_string());
+        Ok(())
+    }
+
+    fn load(fields: &mut SceneFields) -> std::result::Result<Self, String> {
+        Ok(Health(fields.next()?))
+    }
+}
@@ -1,94 +1,137 @@
-use std::{any::TypeId, cell::{Ref, RefCell, RefMut}, collections::{BTreeMap, HashSet}, rc::Rc};
+use std::{any::TypeId, cell::{Cell, Ref, RefCell, RefMut}, collections::{BTreeMap, HashMap, HashSet, VecDeque}, rc::Rc};
 
-use crate::engine::{Engine, data_structures::{AllocationIndex, VecAllocator}, errors::{ObjectError, Result}, graphics::Camera};
+use gl_types::{geometric::length, matrices::{Mat4, MatN}, matrix::inverse, quaternion::Quat, vec3, vec4, vectors::Vec3};
 
-use super::{component::{components::Transform, Component}, game_object::GameObject};
+use crate::engine::{Engine, data_structures::{AllocationIndex, VecAllocator}, errors::{ObjectError, Result}, graphics::{sprite_renderer::components::{Sprite, SpriteSheet}, terrain::Terrain, Camera}};
+
+use super::{component::{components::Transform, Component}, game_object::GameObject, lookup::{index, unindex, Lookup}, storage::{AnyStorage, Storage}, ComponentTicks, Query, Query2, Query2Mut, QueryMut, registry::ComponentRegistry};
 
 pub struct World {
     pub(in crate::engine::game_object) root: ObjectID,
     pub(in crate::engine::game_object) objects: VecAllocator<GameObject>,
-    pub(in crate::engine::game_object) components: VecAllocator<Rc<RefCell<Box<dyn Component>>>>, // TODO: rethink component storage
+    storages: HashMap<TypeId, Box<dyn AnyStorage>>,
     ordered_components: BTreeMap<i32, HashSet<ComponentID>>,
     uninitialized_components: BTreeMap<i32, HashSet<ComponentID>>,
-    removed_comonents: Vec<(ObjectID, Box<dyn Component>)>,
+    removed_comonents: Vec<(ComponentID, Box<dyn Component>)>,
+    disabled_components: HashSet<ComponentID>,
+    // Components last told they're inactive through on_disable, or that were inactive when they got initialized
+    inactive_components: HashSet<ComponentID>,
+    // Components that may have become active or inactive since the hooks last ran
+    activity_changes: Vec<ComponentID>,
+    pub(in crate::engine::game_object) change_tick: Cell<u64>,
+    pub(in crate::engine::game_object) component_ticks: HashMap<ComponentID, ComponentTicks>,
+    pub(in crate::engine::game_object) component_types: ComponentRegistry,
+    pub(in crate::engine::game_object) lookup: Lookup,
     main_camera: Option<Rc<RefCell<Camera>>> // yikes
 }
 
-#[derive(Clone, Copy, Hash, PartialEq, Eq)]
+#[derive(Clone, Copy, Hash, PartialEq, Eq, Debug)]
 pub struct ObjectID {
     idx: AllocationIndex
 }
 
 #[derive(Clone, Copy, Hash, PartialEq, Eq)]
 pub struct ComponentID {
-    index: AllocationIndex,
-    owner: ObjectID,
-    type_: TypeId
+    pub(in crate::engine::game_object) index: AllocationIndex,
+    pub(in crate::engine::game_object) owner: ObjectID,
+    type_: TypeId,
+    type_name: &'static str
+}
+
+impl ComponentID {
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    pub(in crate::engine) fn type_id(&self) -> TypeId {
+        self.type_
+    }
+
+    pub fn is<C: Component>(&self) -> bool {
+        self.type_ == TypeId::of::<C>()
+    }
 }
 
 impl World {
     pub(in crate::engine) fn new() -> World {
         let mut objects = VecAllocator::new();
-        let root = objects.insert(GameObject { name: "root".to_owned(), parent: ObjectID { idx: AllocationIndex::null() }, components: Vec::new(), children: HashSet::new() });
+        let root = objects.insert(GameObject { name: "root".to_owned(), parent: ObjectID { idx: AllocationIndex::null() }, components: Vec::new(), children: Vec::new(), tags: HashSet::new(), layer: 0, active: true, active_in_hierarchy: true });
         let root = ObjectID { idx: root };
 
         let mut world = World {
             root,
             objects,
-            components: VecAllocator::new(),
+            storages: HashMap::new(),
             ordered_components: BTreeMap::new(),
             uninitialized_components: BTreeMap::new(),
             removed_comonents: Vec::new(),
+            disabled_components: HashSet::new(),
+            inactive_components: HashSet::new(),
+            activity_changes: Vec::new(),
+            // Tick 0 is left for Tick::default, so it's older than everything
+            change_tick: Cell::new(1),
+            component_ticks: HashMap::new(),
+            component_types: ComponentRegistry::default(),
+            lookup: Lookup::default(),
             main_camera: None
         };
 
-        world.add_component(world.root, Transform::ZERO).expect("This also shouldn't happen!");
+        world.register_scene_component::<Transform>();
+        world.register_scene_component::<Sprite>();
+        world.register_scene_component::<SpriteSheet>();
+        world.register_scene_component::<Terrain>();
+        world.register_prefab_component::<Transform>();
+        world.register_prefab_component::<Sprite>();
+
+        world.add_component(world.root, Transform::IDENTITY).expect("This also shouldn't happen!");
+        index(&mut world.lookup.names, "root".to_owned(), root);
+        index(&mut world.lookup.layers, 0, root);
 
         world
     }
 
+    // Inactive components wait with their init until they become active
     fn init(engine: &mut Engine) -> Result<()> {
+        let world = &mut engine.world;
         // I really hope the compiler can optimize this nonsense
-        let components: Vec<ComponentID> = engine.world.uninitialized_components.iter().flat_map(|(_, set)| {
+        let components: Vec<ComponentID> = world.uninitialized_components.iter().flat_map(|(_, set)| {
             set.iter().cloned()
-        }).collect();
-        engine.world.uninitialized_components.clear();
+        }).filter(|component| world.is_component_active(*component)).collect();
 
-        let components: Vec<(ObjectID, Rc<RefCell<Box<dyn Component>>>)> = components.into_iter().map(|component| {
-            let owner = component.owner;
-            let rc = engine.world.components.get(component.index).map_err(comp_error)?;
+        let started: HashSet<ComponentID> = components.iter().copied().collect();
+        for set in world.uninitialized_components.values_mut() {
+            set.retain(|component| !started.contains(component));
+        }
 
-            Ok::<(ObjectID, Rc<RefCell<Box<dyn Component>>>), ObjectError>((owner, rc.clone()))
-        }).collect::<std::result::Result<Vec<_>, ObjectError>>()?;
+        // Started even if an earlier init disabled them, they were active when the frame started
+        Self::run_components(engine, components.clone(), "init", false, |component, engine, owner| {
+            component.init(engine, owner)
+        })?;
 
-        components.into_iter().try_for_each(|(owner, rc)| {
-            rc.borrow_mut().init(engine, owner)?; 
+        for component in components {
+            if !engine.world.is_component_active(component) {
+                engine.world.inactive_components.insert(component);
+            }
+        }
 
-            Ok(())
-        })
+        Ok(())
     }
 
     pub(in crate::engine) fn update(engine: &mut Engine, delta_time: f32) -> Result<()> {
         // I really hope the compiler can optimize this nonsense
         Self::init(engine)?;
+        Self::notify_activity(engine)?;
 
         // I really hope the compiler can optimize this nonsense
         let components: Vec<ComponentID> = engine.world.ordered_components.iter().flat_map(|(_, set)| {
             set.iter().cloned()
         }).collect();
 
-        let components: Vec<(ObjectID, Rc<RefCell<Box<dyn Component>>>)> = components.into_iter().map(|component| {
-            let owner = component.owner;
-            let rc = engine.world.components.get(component.index).map_err(comp_error)?;
+        Self::run_components(engine, components, "update", true, |component, engine, owner| {
+            component.update(engine, owner, delta_time)
+        })?;
 
-            Ok::<(ObjectID, Rc<RefCell<Box<dyn Component>>>), ObjectError>((owner, rc.clone()))
-        }).collect::<std::result::Result<Vec<_>, ObjectError>>()?;
-
-        components.into_iter().try_for_each(|(owner, rc)| {
-            rc.borrow_mut().update(engine, owner, delta_time)?; 
-
-            Ok(())
-        })
+        Self::notify_activity(engine)
     }
 
     pub(in crate::engine) fn fixed_update(engine: &mut Engine, delta_time: f32) -> Result<()> {
@@ -97,20 +140,132 @@ impl World {
             set.iter().cloned()
         }).collect();
 
-        let components: Vec<(ObjectID, Rc<RefCell<Box<dyn Component>>>)> = components.into_iter().map(|component| {
-            let owner = component.owner;
-            let rc = engine.world.components.get(component.index).map_err(comp_error)?;
+        Self::run_components(engine, components, "fixed_update", true, |component, engine, owner| {
+            component.fixed_update(engine, owner, delta_time)
+        })?;
+
+        Self::notify_activity(engine)
+    }
 
-            Ok::<(ObjectID, Rc<RefCell<Box<dyn Component>>>), ObjectError>((owner, rc.clone()))
-        }).collect::<std::result::Result<Vec<_>, ObjectError>>()?;
+    // Calls on_disable and on_enable for components whose activity differs from what they were last told.
+    // Something switched off and back on again before this runs gets neither.
+    fn notify_activity(engine: &mut Engine) -> Result<()> {
+        let world = &mut engine.world;
+        let mut enabled = Vec::new();
+        let mut disabled = Vec::new();
+
+        for component in std::mem::take(&mut world.activity_changes) {
+            // Uninitialized components start from whatever they are after init
+            let alive = world.storages.get_mut(&component.type_).is_some_and(|storage| storage.contains(component.index));
+            if !alive || world.uninitialized_components.values().any(|set| set.contains(&component)) {
+                continue;
+            }
 
-        components.into_iter().try_for_each(|(owner, rc)| {
-            rc.borrow_mut().fixed_update(engine, owner, delta_time)?; 
+            let active = world.is_component_active(component);
 
-            Ok(())
+            if active && world.inactive_components.remove(&component) {
+                enabled.push(component);
+            } else if !active && world.inactive_components.insert(component) {
+                disabled.push(component);
+            }
+        }
+
+        Self::run_components(engine, disabled, "on_disable", false, |component, engine, owner| {
+            component.on_disable(engine, owner)
+        })?;
+
+        Self::run_components(engine, enabled, "on_enable", false, |component, engine, owner| {
+            component.on_enable(engine, owner)
         })
     }
 
+    // Errors returned by components are handed to the engine's error policy, only an abort stops the loop
+    fn run_components<F>(engine: &mut Engine, components: Vec<ComponentID>, stage: &'static str, only_active: bool, mut f: F) -> Result<()>
+    where
+        F: FnMut(&mut dyn Component, &mut Engine, ObjectID) -> Result<()>
+    {
+        for component in components {
+            // Earlier components may have disabled this one, destroyed ones are skipped by the runner
+            if only_active && !engine.world.is_component_active(component) {
+                continue;
+            }
+
+            let Some(run) = engine.world.storages.get(&component.type_).map(|storage| storage.runner()) else { continue; };
+
+            if let Err(error) = run(engine, component, &mut f) {
+                engine.handle_component_error(component, stage, error)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn is_component_enabled(&self, component: ComponentID) -> bool {
+        !self.disabled_components.contains(&component)
+    }
+
+    pub fn set_component_enabled(&mut self, component: ComponentID, enabled: bool) -> Result<()> {
+        if !self.storages.get_mut(&component.type_).is_some_and(|storage| storage.contains(component.index)) {
+            Err(ObjectError::DeadComponentError)?
+        }
+
+        let changed = if enabled {
+            self.disabled_components.remove(&component)
+        } else {
+            self.disabled_components.insert(component)
+        };
+
+        if changed {
+            self.activity_changes.push(component);
+        }
+
+        Ok(())
+    }
+
+    /// Enabled and owned by an active object. Only active components are updated and show up in queries.
+    pub fn is_component_active(&self, component: ComponentID) -> bool {
+        self.is_component_enabled(component) && self.objects.get(component.owner.idx).is_ok_and(|obj| obj.active_in_hierarchy)
+    }
+
+    /// Whether the object itself is switched on, regardless of its ancestors.
+    pub fn is_active_self(&self, object: ObjectID) -> Result<bool> {
+        Ok(self.object(object)?.active)
+    }
+
+    /// Active and so is every ancestor.
+    pub fn is_active(&self, object: ObjectID) -> Result<bool> {
+        Ok(self.object(object)?.active_in_hierarchy)
+    }
+
+    /// Deactivating an object deactivates everything below it, their components get `on_disable` called during the next update.
+    pub fn set_active(&mut self, object: ObjectID, active: bool) -> Result<()> {
+        let obj = self.object_mut(object)?;
+        obj.active = active;
+
+        let parent = obj.parent;
+        let parent_active = object == self.root || self.objects.get(parent.idx).unwrap().active_in_hierarchy;
+        self.refresh_activity(object, parent_active);
+
+        Ok(())
+    }
+
+    // Stops at objects whose activity doesn't change, nothing below them changes either
+    fn refresh_activity(&mut self, object: ObjectID, parent_active: bool) {
+        let obj = self.objects.get_mut(object.idx).unwrap();
+        let active = obj.active && parent_active;
+
+        if obj.active_in_hierarchy == active {
+            return;
+        }
+
+        obj.active_in_hierarchy = active;
+        self.activity_changes.extend(obj.components.iter().copied());
+
+        for child in obj.children.clone() {
+            self.refresh_activity(child, active);
+        }
+    }
+
     pub fn get_main_camera(&self) -> Option<Rc<RefCell<Camera>>> {
         self.main_camera.clone()
     }
@@ -127,24 +282,36 @@ impl World {
 
     pub fn set_name(&mut self, object: ObjectID, name: String) -> Result<()> {
         let obj = self.objects.get_mut(object.idx).map_err(obj_error)?;
+        let previous = std::mem::replace(&mut obj.name, name.clone());
 
-        obj.name = name;
+        unindex(&mut self.lookup.names, &previous, object);
+        index(&mut self.lookup.names, name, object);
 
         Ok(())
     }
 
+    pub(in crate::engine::game_object) fn object(&self, object: ObjectID) -> Result<&GameObject> {
+        Ok(self.objects.get(object.idx).map_err(obj_error)?)
+    }
+
+    pub(in crate::engine::game_object) fn object_mut(&mut self, object: ObjectID) -> Result<&mut GameObject> {
+        Ok(self.objects.get_mut(object.idx).map_err(obj_error)?)
+    }
+
     pub fn get_root(&self) -> ObjectID {
         self.root
     }
 
     pub fn add_component<C: Component>(&mut self, object: ObjectID, component: C) -> Result<()> {
         let priority = *component.priority();
-        let index = self.components.insert(Rc::new(RefCell::new(Box::new(component))));
         let owner = object;
-        let object = self.objects.get_mut(object.idx).map_err(obj_error)?;
+        self.objects.get(owner.idx).map_err(obj_error)?;
 
-        let id = ComponentID { index, type_: TypeId::of::<C>(), owner };
-        object.components.push(id);
+        let id = self.storage_mut::<C>().insert(component, |index| {
+            ComponentID { index, type_: TypeId::of::<C>(), type_name: std::any::type_name::<C>(), owner }
+        });
+        self.objects.get_mut(owner.idx).unwrap().components.push(id);
+        self.component_ticks.insert(id, ComponentTicks::new(self.change_tick.get()));
 
         let set = self.ordered_components.entry(priority).or_insert(HashSet::new());
         set.insert(id);
@@ -155,48 +322,90 @@ impl World {
         Ok(())
     }
 
+    // on_remove is deferred to the end of the frame, the component may be the one currently running
     pub fn remove_component(&mut self, component: ComponentID) -> Result<()> {
-        let c = self.components.remove(component.index).map_err(comp_error)?;
-        let c = Rc::into_inner(c).ok_or("Component still owned somewhere.")?.into_inner();
+        let storage = self.storages.get_mut(&component.type_).ok_or(ObjectError::DeadComponentError)?;
+        let c = storage.remove_boxed(component.index).map_err(comp_error)?;
 
-        match self.ordered_components.get_mut(c.priority()) {
-            Some(list) => list.remove(&component),
-            None => unreachable!(),
-        };
+        // Can't ask for the priority, the component might be taken out to run
+        for list in self.ordered_components.values_mut().chain(self.uninitialized_components.values_mut()) {
+            list.remove(&component);
+        }
+        self.disabled_components.remove(&component);
+        self.inactive_components.remove(&component);
+        self.component_ticks.remove(&component);
+
+        // The owner is already gone when its whole object is destroyed
+        if let Ok(owner) = self.objects.get_mut(component.owner.idx) {
+            owner.components.retain(|c| *c != component);
+        }
 
-        self.removed_comonents.push((component.owner, c));
+        // Components removed while running are queued once they're handed back
+        if let Some(c) = c {
+            self.queue_removed(component, c);
+        }
 
         Ok(())
     }
 
+    pub(in crate::engine::game_object) fn queue_removed(&mut self, component: ComponentID, c: Box<dyn Component>) {
+        self.removed_comonents.push((component, c));
+    }
+
+    pub(in crate::engine::game_object) fn storage_mut<C: Component>(&mut self) -> &mut Storage<C> {
+        self.storages.entry(TypeId::of::<C>())
+            .or_insert_with(|| Box::new(RefCell::new(Storage::<C>::new())))
+            .downcast_mut::<RefCell<Storage<C>>>()
+            .unwrap()
+            .get_mut()
+    }
+
+    fn storage<C: Component>(&self) -> Option<&RefCell<Storage<C>>> {
+        self.storages.get(&TypeId::of::<C>()).map(|storage| storage.downcast_ref().unwrap())
+    }
+
+    fn borrow_storage<C: Component>(&self) -> Result<Option<Ref<'_, Storage<C>>>> {
+        Ok(self.storage::<C>().map(|storage| storage.try_borrow().map_err(|_| borrow_error::<C>())).transpose()?)
+    }
+
+    fn borrow_storage_mut<C: Component>(&self) -> Result<Option<RefMut<'_, Storage<C>>>> {
+        Ok(self.storage::<C>().map(|storage| storage.try_borrow_mut().map_err(|_| borrow_error::<C>())).transpose()?)
+    }
+
+    /// Borrows the whole storage of `C`, not just this component. Until the guard is dropped, shared borrows of other `C`s still work,
+    /// but mutable borrows and mutable queries of `C` fail with `ComponentBorrowError`, even for other objects.
     pub fn borrow_component<'a, C: Component>(&'a self, component: ComponentID) -> Result<Ref<'a, C>> {
-        let ref_ = self.components.get(component.index).map_err(obj_error)?.borrow();
+        downcast_check::<C>(component)?;
+        let storage = self.borrow_storage::<C>()?.ok_or(ObjectError::DeadComponentError)?;
 
-        let downcast = Ref::filter_map(ref_, |t| {
-            t.downcast_ref()
-        }).map_err(|_| ObjectError::ComponentDowncastError { type_name: std::any::type_name::<C>().to_owned() })?;
+        // Taken out means the component is running right now
+        storage.get(component.index).map_err(comp_error)?.ok_or_else(borrow_error::<C>)?;
 
-        Ok(downcast)
+        Ok(Ref::map(storage, |storage| storage.get(component.index).unwrap().unwrap()))
     }
 
+    /// Like `borrow_component`, every other borrow of a `C` fails until the guard is dropped, even one on a different object.
+    /// Counts as a change for `Changed` filters, whether or not anything is written.
     pub fn borrow_component_mut<'a, C: Component>(&'a self, component: ComponentID) -> Result<RefMut<'a, C>> {
-        let ref_ = self.components.get(component.index).map_err(obj_error)?.borrow_mut();
+        downcast_check::<C>(component)?;
+        let mut storage = self.borrow_storage_mut::<C>()?.ok_or(ObjectError::DeadComponentError)?;
 
-        let downcast = RefMut::filter_map(ref_, |t| {
-            t.downcast_mut()
-        }).map_err(|_| ObjectError::ComponentDowncastError { type_name: std::any::type_name::<C>().to_owned() })?;
+        storage.get_mut(component.index).map_err(comp_error)?.ok_or_else(borrow_error::<C>)?;
+        self.set_changed(component);
 
-        Ok(downcast)
+        Ok(RefMut::map(storage, |storage| storage.get_mut(component.index).unwrap().unwrap()))
     }
 
     pub fn create_game_object<S: Into<String>>(&mut self, name: S, parent: ObjectID) -> Result<ObjectID> {
         self.objects.get(parent.idx).map_err(obj_error)?;
 
         let name = name.into();
-        let new_obj = GameObject { name, parent: self.root, components: Vec::new(), children: HashSet::new() };
+        let new_obj = GameObject { name: name.clone(), parent: self.root, components: Vec::new(), children: Vec::new(), tags: HashSet::new(), layer: 0, active: true, active_in_hierarchy: true };
         let new_obj = ObjectID { idx: self.objects.insert(new_obj) };
+        index(&mut self.lookup.names, name, new_obj);
+        index(&mut self.lookup.layers, 0, new_obj);
 
-        self.add_component(new_obj, Transform::ZERO).expect("This shouldn't happen!");
+        self.add_component(new_obj, Transform::IDENTITY).expect("This shouldn't happen!");
         self.set_parent(new_obj, parent).unwrap();
 
         Ok(new_obj)
@@ -226,24 +435,31 @@ impl World {
         }).collect())
     }
 
-    pub fn get_children(&self, object: ObjectID) -> Result<Box<[ObjectID]>> {
-        let obj = self.objects.get(object.idx).map_err(obj_error)?;
+    /// Every active component of type `C` in the world. The component running the query, if it's a `C`, is left out.
+    pub fn query<C: Component>(&self) -> Result<Query<'_, C>> {
+        Ok(Query::new(self, self.borrow_storage()?))
+    }
 
-        Ok(obj.children.iter().map(|child| child.to_owned()).collect())
+    pub fn query_mut<C: Component>(&self) -> Result<QueryMut<'_, C>> {
+        Ok(QueryMut::new(self, self.borrow_storage_mut()?))
     }
 
-    pub fn find_child(&self, object: ObjectID, name: &str) -> Result<Option<ObjectID>> {
-        let obj = self.objects.get(object.idx).map_err(obj_error)?;
+    pub fn query2<A: Component, B: Component>(&self) -> Result<Query2<'_, A, B>> {
+        Ok(Query2::new(self, self.borrow_storage()?, self.borrow_storage()?))
+    }
 
-        for child in &obj.children {
-            let child_name = self.get_name(*child).unwrap();
+    pub fn query2_mut<A: Component, B: Component>(&self) -> Result<Query2Mut<'_, A, B>> {
+        Ok(Query2Mut::new(self, self.borrow_storage_mut()?, self.borrow_storage_mut()?))
+    }
 
-            if name == child_name {
-                return Ok(Some(*child));
-            }
-        }
+    pub(in crate::engine::game_object) fn component_ids(&self, object: ObjectID) -> Result<&[ComponentID]> {
+        Ok(&self.objects.get(object.idx).map_err(obj_error)?.components)
+    }
 
-        Ok(None)
+    pub fn get_children(&self, object: ObjectID) -> Result<Box<[ObjectID]>> {
+        let obj = self.objects.get(object.idx).map_err(obj_error)?;
+
+        Ok(obj.children.iter().map(|child| child.to_owned()).collect())
     }
 
     pub fn get_parent(&self, object: ObjectID) -> Result<ObjectID> {
@@ -252,19 +468,146 @@ impl World {
         Ok(obj.parent)
     }
 
+    /// Moves the object to the end of the parent's children.
     pub fn set_parent(&mut self, object: ObjectID, parent: ObjectID) -> Result<()> {
+        // Counted without the object, the same way set_parent_at counts, so it also works if it's already a child
+        let index = self.objects.get(parent.idx).map_err(obj_error)?.children.iter().filter(|child| **child != object).count();
+
+        self.set_parent_at(object, parent, index)
+    }
+
+    /// Moves the object to `index` among the parent's children, counted without the object itself.
+    pub fn set_parent_at(&mut self, object: ObjectID, parent: ObjectID, index: usize) -> Result<()> {
         self.objects.get(parent.idx).map_err(obj_error)?; // Make sure parent is valid first
-        let obj = self.objects.get_mut(object.idx).map_err(obj_error)?;
-        let prev_parent = obj.parent;
+        let prev_parent = self.objects.get(object.idx).map_err(obj_error)?.parent;
+
+        if parent == object || self.is_descendant(parent, object) {
+            Err(ObjectError::ParentCycleError)?
+        }
+
+        // Checked against the children without the object, so moving within the same parent works the same way
+        let siblings = self.objects.get(parent.idx).unwrap().children.iter().filter(|child| **child != object).count();
+        if index > siblings {
+            Err(ObjectError::ChildIndexError { index, len: siblings })?
+        }
+
+        // The local transform stays the same, but the world transform doesn't. Done before anything moves, so failing leaves the hierarchy as it was
+        let transform = self.get_component::<Transform>(object)?;
+        self.borrow_component::<Transform>(transform)?.mark_dirty();
+        self.set_changed(transform);
 
         // update child parent -> update previous parent's children -> update new parent's children
-        obj.parent = parent;
+        self.objects.get_mut(object.idx).unwrap().parent = parent;
 
         let prev_parent = self.objects.get_mut(prev_parent.idx).unwrap(); // This should already be valid so unwrap
-        prev_parent.children.remove(&object);
+        prev_parent.children.retain(|child| *child != object);
 
         let new_parent = self.objects.get_mut(parent.idx).unwrap();
-        new_parent.children.insert(object);
+        new_parent.children.insert(index, object);
+
+        let parent_active = new_parent.active_in_hierarchy;
+        self.refresh_activity(object, parent_active);
+
+        Ok(())
+    }
+
+    pub fn get_sibling_index(&self, object: ObjectID) -> Result<usize> {
+        let parent = self.get_parent(object)?;
+
+        Ok(self.objects.get(parent.idx).map_err(obj_error)?.children.iter().position(|child| *child == object).unwrap())
+    }
+
+    /// Moves the object among its siblings, 0 being the first.
+    pub fn set_sibling_index(&mut self, object: ObjectID, index: usize) -> Result<()> {
+        if object == self.root {
+            Err(ObjectError::RootObjectMoveError)?
+        }
+
+        self.set_parent_at(object, self.get_parent(object)?, index)
+    }
+
+    /// Everything below `object` in pre-order, children in sibling order.
+    pub fn descendants_depth_first(&self, object: ObjectID) -> Result<impl Iterator<Item = ObjectID> + '_> {
+        let mut stack: Vec<ObjectID> = self.objects.get(object.idx).map_err(obj_error)?.children.iter().rev().copied().collect();
+
+        Ok(std::iter::from_fn(move || {
+            let next = stack.pop()?;
+            stack.extend(self.objects.get(next.idx).unwrap().children.iter().rev());
+
+            Some(next)
+        }))
+    }
+
+    /// Everything below `object` level by level, each level in sibling order.
+    pub fn descendants_breadth_first(&self, object: ObjectID) -> Result<impl Iterator<Item = ObjectID> + '_> {
+        let mut queue: VecDeque<ObjectID> = self.objects.get(object.idx).map_err(obj_error)?.children.iter().copied().collect();
+
+        Ok(std::iter::from_fn(move || {
+            let next = queue.pop_front()?;
+            queue.extend(self.objects.get(next.idx).unwrap().children.iter());
+
+            Some(next)
+        }))
+    }
+
+    // Every object is created with a transform, loaded and cloned ones are copied into it
+    pub(in crate::engine) fn replace_transform(&self, object: ObjectID, transform: &Transform) -> Result<()> {
+        let component = self.get_component::<Transform>(object)?;
+        let mut existing = self.borrow_component_mut::<Transform>(component)?;
+
+        existing.set_position(transform.position());
+        existing.set_rotation(transform.rotation());
+        existing.set_scale(transform.scale());
+
+        Ok(())
+    }
+
+    pub fn world_matrix(&self, object: ObjectID) -> Result<Mat4> {
+        Ok(self.versioned_world_matrix(object)?.0)
+    }
+
+    fn versioned_world_matrix(&self, object: ObjectID) -> Result<(Mat4, u64)> {
+        let (parent_matrix, parent_version) = if object == self.root {
+            (Mat4::IDENTITY, 0)
+        } else {
+            self.versioned_world_matrix(self.get_parent(object)?)?
+        };
+
+        let transform = self.borrow_component::<Transform>(self.get_component::<Transform>(object)?)?;
+
+        Ok(transform.world_matrix(parent_matrix, parent_version))
+    }
+
+    pub fn world_position(&self, object: ObjectID) -> Result<Vec3> {
+        let translation = self.world_matrix(object)?.as_slice()[3];
+
+        Ok(vec3!(translation[0], translation[1], translation[2]))
+    }
+
+    // Lengths of the world matrix axes, any shear from non-uniform parent scales is lost
+    pub fn world_scale(&self, object: ObjectID) -> Result<Vec3> {
+        let columns = self.world_matrix(object)?.as_slice().map(|column| length(vec3!(column[0], column[1], column[2])));
+
+        Ok(vec3!(columns[0], columns[1], columns[2]))
+    }
+
+    /// Rotates the object so its forward axis (+z) points at `target`, both `target` and `up` are in world space.
+    pub fn look_at(&self, object: ObjectID, target: Vec3, up: Vec3) -> Result<()> {
+        let parent_inverse: Mat4 = if object == self.root {
+            Mat4::IDENTITY
+        } else {
+            inverse(self.world_matrix(self.get_parent(object)?)?)
+        };
+
+        let mut transform = self.borrow_component_mut::<Transform>(self.get_component::<Transform>(object)?)?;
+
+        // Everything is worked out in the parent's space since that is what the rotation is relative to
+        let target = vec3!(parent_inverse * vec4!(target, 1.0));
+        let up = vec3!(parent_inverse * vec4!(up, 0.0));
+
+        let forward = target - transform.position();
+
+        transform.set_rotation(Quat::look_rotation(forward, up));
 
         Ok(())
     }
@@ -273,24 +616,59 @@ impl World {
         component.owner
     }
 
+    /// Destroys the object and all of its descendants, their components get `on_remove` called at the end of the frame.
     pub fn destroy(&mut self, object: ObjectID) -> Result<()> {
+        if object == self.root {
+            Err(ObjectError::RootObjectDeleteError)?
+        }
+
         let obj = self.objects.get(object.idx).map_err(obj_error)?;
 
         let parent = self.objects.get_mut(obj.parent.idx).unwrap(); // This should already be valid so unwrap
-        parent.children.remove(&object);
+        parent.children.retain(|child| *child != object);
+
+        self.destroy_recursive(object)
+    }
+
+    fn destroy_recursive(&mut self, object: ObjectID) -> Result<()> {
+        let obj = self.objects.remove(object.idx).map_err(obj_error)?;
 
-        self.objects.remove(object.idx).map_err(obj_error)?;
+        unindex(&mut self.lookup.names, &obj.name, object);
+        unindex(&mut self.lookup.layers, &obj.layer, object);
+        for tag in &obj.tags {
+            unindex(&mut self.lookup.tags, tag, object);
+        }
+
+        for component in obj.components {
+            self.remove_component(component)?;
+        }
+
+        for child in obj.children {
+            self.destroy_recursive(child)?;
+        }
 
         Ok(())
     }
 
-    pub(in crate::engine) fn get_removed_components(&mut self) -> Vec<(ObjectID, Box<dyn Component>)> {
+    pub(in crate::engine) fn get_removed_components(&mut self) -> Vec<(ComponentID, Box<dyn Component>)> {
         let removed = std::mem::replace(&mut self.removed_comonents, Vec::new());
 
         removed
     }
 }
 
+fn downcast_check<C: Component>(component: ComponentID) -> Result<()> {
+    if component.type_ != TypeId::of::<C>() {
+        Err(ObjectError::ComponentDowncastError { type_name: std::any::type_name::<C>().to_owned() })?
+    }
+
+    Ok(())
+}
+
+fn borrow_error<C: Component>() -> ObjectError {
+    ObjectError::ComponentBorrowError { type_name: std::any::type_name::<C>() }
+}
+
 fn obj_error(error: crate::engine::data_structures::error::Error) -> ObjectError {
     match error {
         crate::engine::data_structures::error::Error::ElementRemovedError => ObjectError::DeadObjectError,
@@ -303,4 +681,232 @@ fn comp_error(error: crate::engine::data_structures::error::Error) -> ObjectErro
         crate::engine::data_structures::error::Error::ElementRemovedError => ObjectError::DeadComponentError,
         crate::engine::data_structures::error::Error::IndexPointerMismatchError => ObjectError::WorldMismatchError { other: "" },
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use gl_types::{geometric::distance, vec3, vec4, vectors::Vec3};
+
+    use crate::engine::{errors::{Error, ObjectError, Result}, Engine};
+
+    use super::{super::{component::{components::Transform, Component}, test_components::Health}, ObjectID, World};
+
+    fn assert_near(a: Vec3, b: Vec3) {
+        assert!(distance(a, b) < 1e-5, "{:?} != {:?}", a, b);
+    }
+
+    #[test]
+    pub fn world_transforms() {
+        let mut world = World::new();
+        let parent = world.create_game_object("parent", world.get_root()).unwrap();
+        let child = world.create_game_object("child", parent).unwrap();
+
+        let parent_transform = world.get_component::<Transform>(parent).unwrap();
+        let child_transform = world.get_component::<Transform>(child).unwrap();
+
+        world.borrow_component_mut::<Transform>(parent_transform).unwrap().set_position(vec3!(1.0, 0.0, 0.0));
+        world.borrow_component_mut::<Transform>(child_transform).unwrap().set_position(vec3!(0.0, 2.0, 0.0));
+        assert_near(world.world_position(child).unwrap(), vec3!(1.0, 2.0, 0.0));
+
+        // Cached matrices have to notice changes further up the chain
+        world.borrow_component_mut::<Transform>(parent_transform).unwrap().set_position(vec3!(3.0, 0.0, 0.0));
+        assert_near(world.world_position(child).unwrap(), vec3!(3.0, 2.0, 0.0));
+
+        world.borrow_component_mut::<Transform>(parent_transform).unwrap().set_scale(vec3!(2.0, 2.0, 2.0));
+        assert_near(world.world_position(child).unwrap(), vec3!(3.0, 4.0, 0.0));
+        assert_near(world.world_scale(child).unwrap(), vec3!(2.0, 2.0, 2.0));
+
+        world.set_parent(child, world.get_root()).unwrap();
+        assert_near(world.world_position(child).unwrap(), vec3!(0.0, 2.0, 0.0));
+
+        world.set_parent(child, parent).unwrap();
+
+        // Borrows are per type, so one mutable transform blocks every other one
+        let held = world.borrow_component_mut::<Transform>(parent_transform).unwrap();
+        assert!(matches!(world.borrow_component::<Transform>(child_transform), Err(Error::ObjectError { source: ObjectError::ComponentBorrowError { .. }, .. })));
+        drop(held);
+        let held = world.borrow_component::<Transform>(parent_transform).unwrap();
+        assert!(world.borrow_component::<Transform>(child_transform).is_ok());
+        assert!(world.borrow_component_mut::<Transform>(child_transform).is_err());
+        drop(held);
+
+        world.look_at(child, vec3!(3.0, 4.0, 5.0), vec3!(0.0, 1.0, 0.0)).unwrap();
+        let matrix = world.world_matrix(child).unwrap();
+        assert_near(vec3!(matrix * vec4!(0.0, 0.0, 1.0, 0.0)) / 2.0, vec3!(0.0, 0.0, 1.0));
+        assert_near(vec3!(matrix * vec4!(0.0, 1.0, 0.0, 0.0)) / 2.0, vec3!(0.0, 1.0, 0.0));
+
+        world.look_at(child, vec3!(5.0, 4.0, 0.0), vec3!(0.0, 1.0, 0.0)).unwrap();
+        let matrix = world.world_matrix(child).unwrap();
+        assert_near(vec3!(matrix * vec4!(0.0, 0.0, 1.0, 0.0)) / 2.0, vec3!(1.0, 0.0, 0.0));
+        assert_near(world.borrow_component::<Transform>(child_transform).unwrap().forward(), vec3!(1.0, 0.0, 0.0));
+
+        world.look_at(child, vec3!(3.0, 4.0, 5.0), vec3!(1.0, 1.0, 0.0)).unwrap();
+        let matrix = world.world_matrix(child).unwrap();
+        assert_near(vec3!(matrix * vec4!(0.0, 1.0, 0.0, 0.0)) / 2.0, vec3!(1.0, 1.0, 0.0) / 2f32.sqrt());
+    }
+
+    #[test]
+    pub fn typed_queries() {
+        let mut world = World::new();
+        let a = world.create_game_object("a", world.get_root()).unwrap();
+        let b = world.create_game_object("b", world.get_root()).unwrap();
+        let c = world.create_game_object("c", a).unwrap();
+        assert_eq!(world.query::<Health>().unwrap().iter().count(), 0);
+
+        world.add_component(a, Health(1)).unwrap();
+        world.add_component(c, Health(3)).unwrap();
+
+        let healths: Vec<_> = world.query::<Health>().unwrap().iter().map(|(object, health)| (object, health.0)).collect();
+        assert_eq!(healths, [(a, 1), (c, 3)]);
+        assert_eq!(world.query::<Transform>().unwrap().iter().count(), 4);
+
+        for (_, health) in &mut world.query_mut::<Health>().unwrap() {
+            health.0 *= 2;
+        }
+
+        for (object, transform, _) in &mut world.query2_mut::<Transform, Health>().unwrap() {
+            if object == c {
+                transform.set_position(vec3!(1.0, 2.0, 3.0));
+            }
+        }
+        let pairs: Vec<_> = world.query2::<Transform, Health>().unwrap().iter().map(|(object, transform, health)| (object, transform.position(), health.0)).collect();
+        assert_eq!(pairs, [(a, vec3!(0.0, 0.0, 0.0), 2), (c, vec3!(1.0, 2.0, 3.0), 6)]);
+
+        // Borrows are checked per type, shared ones can overlap
+        let query = world.query::<Health>().unwrap();
+        assert!(world.borrow_component::<Health>(world.get_component::<Health>(a).unwrap()).is_ok());
+        assert!(world.query_mut::<Health>().is_err());
+        assert!(world.query2_mut::<Transform, Health>().is_err());
+        assert!(world.query2_mut::<Transform, Transform>().is_err());
+        drop(query);
+
+        world.remove_component(world.get_component::<Health>(c).unwrap()).unwrap();
+        world.destroy(a).unwrap();
+        assert_eq!(world.query::<Health>().unwrap().iter().count(), 0);
+        assert_eq!(world.query::<Transform>().unwrap().iter().map(|(object, _)| object).collect::<Vec<_>>(), [world.get_root(), b]);
+
+        // Handles of removed components stay dead after their slot is reused
+        let health = world.get_component::<Health>(b);
+        assert!(health.is_err());
+        world.add_component(b, Health(5)).unwrap();
+        assert!(world.borrow_component::<Health>(world.get_component::<Health>(b).unwrap()).is_ok());
+    }
+
+    #[test]
+    pub fn child_order() {
+        let mut world = World::new();
+        let root = world.get_root();
+        let names = |world: &World, objects: &[ObjectID]| objects.iter().map(|object| world.get_name(*object).unwrap().to_owned()).collect::<Vec<_>>();
+
+        let a = world.create_game_object("a", root).unwrap();
+        let b = world.create_game_object("b", root).unwrap();
+        let c = world.create_game_object("c", root).unwrap();
+        let a1 = world.create_game_object("a1", a).unwrap();
+        world.create_game_object("a2", a).unwrap();
+        world.create_game_object("b1", b).unwrap();
+        world.create_game_object("a1x", a1).unwrap();
+
+        assert_eq!(names(&world, &world.get_children(root).unwrap()), ["a", "b", "c"]);
+        assert_eq!(names(&world, &world.descendants_depth_first(root).unwrap().collect::<Vec<_>>()), ["a", "a1", "a1x", "a2", "b", "b1", "c"]);
+        assert_eq!(names(&world, &world.descendants_breadth_first(root).unwrap().collect::<Vec<_>>()), ["a", "b", "c", "a1", "a2", "b1", "a1x"]);
+
+        world.set_sibling_index(c, 0).unwrap();
+        world.set_sibling_index(a, 2).unwrap();
+        assert_eq!(names(&world, &world.get_children(root).unwrap()), ["c", "b", "a"]);
+        assert_eq!(world.get_sibling_index(b).unwrap(), 1);
+        assert!(matches!(world.set_sibling_index(a, 3), Err(Error::ObjectError { source: ObjectError::ChildIndexError { index: 3, len: 2 }, .. })));
+
+        world.set_parent_at(a1, root, 1).unwrap();
+        assert_eq!(names(&world, &world.get_children(root).unwrap()), ["c", "a1", "b", "a"]);
+        assert_eq!(names(&world, &world.get_children(a).unwrap()), ["a2"]);
+
+        assert!(matches!(world.set_parent(a1, a1), Err(Error::ObjectError { source: ObjectError::ParentCycleError, .. })));
+        let a1x = world.find_child(a1, "a1x").unwrap().unwrap();
+        assert!(matches!(world.set_parent(a1, a1x), Err(Error::ObjectError { source: ObjectError::ParentCycleError, .. })));
+
+        world.destroy(b).unwrap();
+        assert_eq!(names(&world, &world.get_children(root).unwrap()), ["c", "a1", "a"]);
+
+        // Setting the parent an object already has moves it to the end
+        world.set_parent(c, root).unwrap();
+        assert_eq!(names(&world, &world.get_children(root).unwrap()), ["a1", "a", "c"]);
+        let a2 = world.find_child(a, "a2").unwrap().unwrap();
+        world.set_parent(a2, a).unwrap();
+        assert_eq!(names(&world, &world.get_children(a).unwrap()), ["a2"]);
+    }
+
+    struct Logger(Vec<&'static str>);
+
+    impl Component for Logger {
+        fn init(&mut self, _engine: &mut Engine, _owner: ObjectID) -> Result<()> {
+            self.0.push("init");
+            Ok(())
+        }
+
+        fn update(&mut self, _engine: &mut Engine, _owner: ObjectID, _delta_time: f32) -> Result<()> {
+            self.0.push("update");
+            Ok(())
+        }
+
+        fn on_enable(&mut self, _engine: &mut Engine, _owner: ObjectID) -> Result<()> {
+            self.0.push("enable");
+            Ok(())
+        }
+
+        fn on_disable(&mut self, _engine: &mut Engine, _owner: ObjectID) -> Result<()> {
+            self.0.push("disable");
+            Ok(())
+        }
+    }
+
+    #[test]
+    pub fn object_activity() {
+        let mut engine = Engine::create_headless().unwrap();
+        let root = engine.world.get_root();
+        let parent = engine.world.create_game_object("parent", root).unwrap();
+        let child = engine.world.create_game_object("child", parent).unwrap();
+        let other = engine.world.create_game_object("other", root).unwrap();
+        engine.world.add_component(child, Logger(Vec::new())).unwrap();
+        let logger = engine.world.get_component::<Logger>(child).unwrap();
+        let log = |engine: &Engine| engine.world.borrow_component::<Logger>(logger).unwrap().0.join(" ");
+
+        World::update(&mut engine, 0.1).unwrap();
+        assert_eq!(log(&engine), "init update");
+
+        // Deactivating the parent switches off everything below it
+        engine.world.set_active(parent, false).unwrap();
+        assert!(engine.world.is_active_self(child).unwrap());
+        assert!(!engine.world.is_active(child).unwrap());
+        assert!(!engine.world.is_component_active(logger));
+        assert_eq!(engine.world.query::<Logger>().unwrap().iter().count(), 0);
+        assert_eq!(engine.world.query2::<Transform, Logger>().unwrap().iter().count(), 0);
+        World::update(&mut engine, 0.1).unwrap();
+        assert_eq!(log(&engine), "init update disable");
+
+        // Moving it somewhere active brings it back, disabling the component itself doesn't fire twice
+        engine.world.set_parent(child, other).unwrap();
+        engine.world.set_component_enabled(logger, false).unwrap();
+        World::update(&mut engine, 0.1).unwrap();
+        assert_eq!(log(&engine), "init update disable");
+        engine.world.set_component_enabled(logger, true).unwrap();
+        World::update(&mut engine, 0.1).unwrap();
+        assert_eq!(log(&engine), "init update disable enable update");
+
+        // Switched off and on again before the next update is no change at all
+        engine.world.set_active(other, false).unwrap();
+        engine.world.set_active(other, true).unwrap();
+        World::update(&mut engine, 0.1).unwrap();
+        assert_eq!(log(&engine), "init update disable enable update update");
+
+        // Components of inactive objects wait with init until they're active
+        engine.world.set_active(parent, true).unwrap();
+        engine.world.set_active(other, false).unwrap();
+        engine.world.add_component(other, Logger(Vec::new())).unwrap();
+        let late = engine.world.get_component::<Logger>(other).unwrap();
+        World::update(&mut engine, 0.1).unwrap();
+        assert!(engine.world.borrow_component::<Logger>(late).unwrap().0.is_empty());
+        engine.world.set_active(other, true).unwrap();
+        World::update(&mut engine, 0.1).unwrap();
+        assert_eq!(engine.world.borrow_component::<Logger>(late).unwrap().0, ["init", "update"]);
+        assert_eq!(engine.world.query::<Logger>().unwrap().iter().count(), 2);
+    }
+}
@@ -0,0 +1,875 @@
+use std::{collections::{BTreeMap, HashMap}, ffi::CString, fs::File, io::{BufRead, BufReader, BufWriter, Write}, path::Path};
+
+use gl46::{GLbitfield, GLenum, GLsync, GLDEBUGPROC, GL_UNSIGNED_INT};
+use gl_types::matrices::{Mat4, MatN};
+use log::error;
+
+use crate::engine::{errors::{GraphicsError, Result}, graphics::{gl_enums::GLEnum, GLWrapper, GlUniformLocation, VAO}, text_format::{escape, read_lines, unescape, Fields}};
+
+// Trace files are plain text so they can be diffed and read without any tooling.
+//
+//   # gl trace v2
+//   <frame> <tab> <function> <tab> <arg> <tab> <arg> ... [<tab> -> <tab> <output> ...]
+//
+// Every value is prefixed with its kind:
+//   e:GL_ARRAY_BUFFER=0x8892   u:3   i:-1   f:0.5   b:true   p:0x7ffd...   x:<hex bytes>
+//   s:<string>, with \\ \t \n and \r escaped
+//   l:<value>,<value>,..., with \\ and \c (comma) escaped once more in every value so lists can be nested
+const TRACE_HEADER: &str = "# gl trace v2";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TraceValue {
+    Enum { name: String, value: u32 },
+    UInt(u64),
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Ptr(usize),
+    Str(String),
+    Bytes(Vec<u8>),
+    List(Vec<TraceValue>)
+}
+
+impl TraceValue {
+    // Raw memory for buffer and texture uploads, where the element type isn't known
+    pub fn bytes<T>(data: &[T]) -> TraceValue {
+        let bytes = unsafe { std::slice::from_raw_parts(data.as_ptr() as *const u8, std::mem::size_of_val(data)) };
+        TraceValue::Bytes(bytes.to_vec())
+    }
+
+    pub fn as_u32(&self) -> Option<u32> {
+        match self {
+            TraceValue::Enum { value, .. } => Some(*value),
+            TraceValue::UInt(v) => u32::try_from(*v).ok(),
+            TraceValue::Int(v) => u32::try_from(*v).ok(),
+            TraceValue::Bool(b) => Some(*b as u32),
+            _ => None
+        }
+    }
+
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            TraceValue::UInt(v) => i64::try_from(*v).ok(),
+            TraceValue::Int(v) => Some(*v),
+            _ => None
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            TraceValue::Float(v) => Some(*v),
+            _ => None
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            TraceValue::Str(s) => Some(s),
+            _ => None
+        }
+    }
+
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            TraceValue::Bytes(b) => Some(b),
+            _ => None
+        }
+    }
+
+    pub fn as_list(&self) -> Option<&[TraceValue]> {
+        match self {
+            TraceValue::List(l) => Some(l),
+            _ => None
+        }
+    }
+
+    fn encode(&self, out: &mut String) {
+        match self {
+            TraceValue::Enum { name, value } => *out += &format!("e:{}=0x{:X}", name, value),
+            TraceValue::UInt(v) => *out += &format!("u:{}", v),
+            TraceValue::Int(v) => *out += &format!("i:{}", v),
+            TraceValue::Float(v) => *out += &format!("f:{:?}", v),
+            TraceValue::Bool(v) => *out += &format!("b:{}", v),
+            TraceValue::Ptr(v) => *out += &format!("p:0x{:X}", v),
+            TraceValue::Str(s) => *out += &format!("s:{}", escape(s)),
+            TraceValue::Bytes(bytes) => {
+                *out += "x:";
+                for byte in bytes {
+                    *out += &format!("{:02x}", byte);
+                }
+            },
+            TraceValue::List(values) => {
+                *out += "l:";
+                for (i, value) in values.iter().enumerate() {
+                    if i != 0 {
+                        out.push(',');
+                    }
+
+                    let mut item = String::new();
+                    value.encode(&mut item);
+                    *out += &item.replace('\\', "\\\\").replace(',', "\\c");
+                }
+            }
+        }
+    }
+
+    fn decode(s: &str) -> std::result::Result<TraceValue, String> {
+        let Some((kind, value)) = s.split_once(':') else { return Err(format!("Missing value kind in '{}'", s)) };
+        let invalid = || format!("Invalid value '{}'", s);
+
+        let value = match kind {
+            "e" => {
+                let Some((name, value)) = value.split_once("=0x") else { return Err(format!("Invalid enum '{}'", s)) };
+                TraceValue::Enum { name: name.to_owned(), value: u32::from_str_radix(value, 16).map_err(|_| invalid())? }
+            },
+            "u" => TraceValue::UInt(value.parse().map_err(|_| invalid())?),
+            "i" => TraceValue::Int(value.parse().map_err(|_| invalid())?),
+            "f" => TraceValue::Float(value.parse().map_err(|_| invalid())?),
+            "b" => TraceValue::Bool(value.parse().map_err(|_| invalid())?),
+            "p" => TraceValue::Ptr(usize::from_str_radix(value.trim_start_matches("0x"), 16).map_err(|_| invalid())?),
+            "s" => TraceValue::Str(unescape(value)?),
+            "x" => {
+                if value.len() % 2 != 0 {
+                    return Err(format!("Odd number of hex digits in '{}'", s));
+                }
+
+                let bytes = (0..value.len()).step_by(2)
+                    .map(|i| u8::from_str_radix(&value[i..i + 2], 16))
+                    .collect::<std::result::Result<Vec<u8>, _>>()
+                    .map_err(|_| invalid())?;
+
+                TraceValue::Bytes(bytes)
+            },
+            "l" if value.is_empty() => TraceValue::List(Vec::new()),
+            "l" => TraceValue::List(split_list(value)?.iter().map(|item| TraceValue::decode(item)).collect::<std::result::Result<_, _>>()?),
+            _ => return Err(format!("Unknown value kind '{}'", kind))
+        };
+
+        Ok(value)
+    }
+}
+
+// Splits on the commas between items and undoes the escaping added when the list was encoded
+fn split_list(s: &str) -> std::result::Result<Vec<String>, String> {
+    let mut items = vec![String::new()];
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            ',' => items.push(String::new()),
+            '\\' => match chars.next() {
+                Some('\\') => items.last_mut().unwrap().push('\\'),
+                Some('c') => items.last_mut().unwrap().push(','),
+                _ => return Err(format!("Invalid escape in '{}'", s))
+            },
+            c => items.last_mut().unwrap().push(c)
+        }
+    }
+
+    Ok(items)
+}
+
+pub trait TraceArg {
+    fn trace_value(&self) -> TraceValue;
+}
+
+impl TraceArg for TraceValue {
+    fn trace_value(&self) -> TraceValue {
+        self.clone()
+    }
+}
+
+macro_rules! impl_trace_arg {
+    ($variant:ident as $as:ty: $($t:ty),*) => {
+        $(impl TraceArg for $t {
+            fn trace_value(&self) -> TraceValue {
+                TraceValue::$variant(*self as $as)
+            }
+        })*
+    };
+}
+
+impl_trace_arg!(UInt as u64: u8, u16, u32, u64, usize);
+impl_trace_arg!(Int as i64: i8, i16, i32, i64, isize);
+impl_trace_arg!(Float as f64: f32, f64);
+
+impl TraceArg for bool {
+    fn trace_value(&self) -> TraceValue {
+        TraceValue::Bool(*self)
+    }
+}
+
+impl TraceArg for str {
+    fn trace_value(&self) -> TraceValue {
+        TraceValue::Str(self.to_owned())
+    }
+}
+
+impl TraceArg for &str {
+    fn trace_value(&self) -> TraceValue {
+        TraceValue::Str((*self).to_owned())
+    }
+}
+
+impl<T: TraceArg> TraceArg for [T] {
+    fn trace_value(&self) -> TraceValue {
+        TraceValue::List(self.iter().map(T::trace_value).collect())
+    }
+}
+
+impl<T> TraceArg for *const T {
+    fn trace_value(&self) -> TraceValue {
+        TraceValue::Ptr(*self as usize)
+    }
+}
+
+impl<T> TraceArg for *mut T {
+    fn trace_value(&self) -> TraceValue {
+        TraceValue::Ptr(*self as usize)
+    }
+}
+
+impl<E: GLEnum> TraceArg for E {
+    fn trace_value(&self) -> TraceValue {
+        TraceValue::Enum { name: format!("{:?}", self), value: self.value() }
+    }
+}
+
+impl TraceArg for GLenum {
+    fn trace_value(&self) -> TraceValue {
+        TraceValue::UInt(self.0 as u64)
+    }
+}
+
+impl TraceArg for GLbitfield {
+    fn trace_value(&self) -> TraceValue {
+        TraceValue::UInt(self.0 as u64)
+    }
+}
+
+impl TraceArg for GLsync {
+    fn trace_value(&self) -> TraceValue {
+        TraceValue::Ptr(self.0 as usize)
+    }
+}
+
+impl TraceArg for GLDEBUGPROC {
+    fn trace_value(&self) -> TraceValue {
+        TraceValue::Ptr(self.map_or(0, |f| f as usize))
+    }
+}
+
+impl TraceArg for GlUniformLocation {
+    fn trace_value(&self) -> TraceValue {
+        TraceValue::Int(self.0 as i64)
+    }
+}
+
+impl TraceArg for VAO {
+    fn trace_value(&self) -> TraceValue {
+        TraceValue::UInt(self.vao() as u64)
+    }
+}
+
+impl TraceArg for Mat4 {
+    fn trace_value(&self) -> TraceValue {
+        // Column major, same as what gets sent to GL
+        TraceValue::List(self.as_slice().iter().flatten().map(|v| TraceValue::Float(*v as f64)).collect())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceCall {
+    pub frame: u64,
+    pub name: String,
+    pub args: Vec<TraceValue>,
+    pub outputs: Vec<TraceValue>
+}
+
+impl TraceCall {
+    fn encode(&self) -> String {
+        let mut line = format!("{}\t{}", self.frame, self.name);
+
+        for arg in &self.args {
+            line.push('\t');
+            arg.encode(&mut line);
+        }
+
+        if !self.outputs.is_empty() {
+            line += "\t->";
+            for output in &self.outputs {
+                line.push('\t');
+                output.encode(&mut line);
+            }
+        }
+
+        line
+    }
+
+    fn decode(mut fields: Fields) -> std::result::Result<TraceCall, String> {
+        let frame = fields.next("frame number")?;
+        let name = fields.next_raw("function name")?.to_owned();
+
+        let mut args = Vec::new();
+        let mut outputs = Vec::new();
+        let mut target = &mut args;
+
+        for part in fields.rest() {
+            if part == "->" {
+                target = &mut outputs;
+                continue;
+            }
+
+            target.push(TraceValue::decode(part)?);
+        }
+
+        Ok(TraceCall { frame, name, args, outputs })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GLTrace {
+    pub calls: Vec<TraceCall>
+}
+
+impl GLTrace {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<GLTrace> {
+        Self::parse(BufReader::new(File::open(path)?))
+    }
+
+    pub fn parse<R: BufRead>(reader: R) -> Result<GLTrace> {
+        let mut calls = Vec::new();
+
+        read_lines(reader, TRACE_HEADER, |line, msg| GraphicsError::TraceParseError { line, msg }, |fields| {
+            calls.push(TraceCall::decode(fields)?);
+            Ok(())
+        })?;
+
+        Ok(GLTrace { calls })
+    }
+
+    pub fn frame_count(&self) -> u64 {
+        self.calls.last().map_or(0, |call| call.frame + 1)
+    }
+}
+
+pub(in crate::engine::graphics) struct GLTracer {
+    writer: BufWriter<File>,
+    frame: u64,
+    // Calls are held until the next one starts, so outputs can be attached to them
+    pending: Option<TraceCall>
+}
+
+impl GLTracer {
+    pub(in crate::engine::graphics) fn create<P: AsRef<Path>>(path: P) -> Result<GLTracer> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "{}", TRACE_HEADER)?;
+
+        Ok(GLTracer { writer, frame: 0, pending: None })
+    }
+
+    pub(in crate::engine::graphics) fn call(&mut self, name: &'static str, args: Vec<TraceValue>) -> Result<()> {
+        self.write_pending()?;
+        self.pending = Some(TraceCall { frame: self.frame, name: name.to_owned(), args, outputs: Vec::new() });
+
+        Ok(())
+    }
+
+    pub(in crate::engine::graphics) fn output(&mut self, value: TraceValue) {
+        if let Some(call) = &mut self.pending {
+            call.outputs.push(value);
+        }
+    }
+
+    pub(in crate::engine::graphics) fn end_frame(&mut self) -> Result<()> {
+        self.write_pending()?;
+        self.writer.flush()?;
+        self.frame += 1;
+
+        Ok(())
+    }
+
+    pub(in crate::engine::graphics) fn finish(mut self) -> Result<()> {
+        self.write_pending()?;
+        self.writer.flush()?;
+
+        Ok(())
+    }
+
+    fn write_pending(&mut self) -> Result<()> {
+        if let Some(call) = self.pending.take() {
+            writeln!(self.writer, "{}", call.encode())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum ObjectKind {
+    Buffer,
+    Texture,
+    VertexArray,
+    Shader,
+    Program
+}
+
+// Replays a trace through the raw function table of a GLWrapper. Object names and uniform
+// locations are remapped, since the context being replayed on won't hand out the same ones.
+pub struct GLReplayer {
+    trace: GLTrace,
+    position: usize,
+    names: HashMap<(ObjectKind, u32), u32>,
+    uniform_locations: HashMap<(u32, i32), i32>,
+    current_program: u32,
+    skipped: BTreeMap<String, usize>
+}
+
+struct Args<'a> {
+    call: &'a TraceCall,
+    index: usize
+}
+
+impl<'a> Args<'a> {
+    fn error(&self, msg: &str) -> GraphicsError {
+        GraphicsError::TraceReplayError { call: self.index, msg: format!("{}: {}", self.call.name, msg) }
+    }
+
+    fn get(&self, i: usize) -> Result<&'a TraceValue> {
+        Ok(self.call.args.get(i).ok_or_else(|| self.error(&format!("missing argument {}", i)))?)
+    }
+
+    fn u32(&self, i: usize) -> Result<u32> {
+        Ok(self.get(i)?.as_u32().ok_or_else(|| self.error(&format!("argument {} is not an unsigned int", i)))?)
+    }
+
+    fn i32(&self, i: usize) -> Result<i32> {
+        let value = self.get(i)?.as_i64().and_then(|v| i32::try_from(v).ok());
+        Ok(value.ok_or_else(|| self.error(&format!("argument {} is not an int", i)))?)
+    }
+
+    fn isize(&self, i: usize) -> Result<isize> {
+        let value = self.get(i)?.as_i64().and_then(|v| isize::try_from(v).ok());
+        Ok(value.ok_or_else(|| self.error(&format!("argument {} is not an int", i)))?)
+    }
+
+    fn f32(&self, i: usize) -> Result<f32> {
+        Ok(self.f64(i)? as f32)
+    }
+
+    fn f64(&self, i: usize) -> Result<f64> {
+        Ok(self.get(i)?.as_f64().ok_or_else(|| self.error(&format!("argument {} is not a float", i)))?)
+    }
+
+    fn enum_(&self, i: usize) -> Result<GLenum> {
+        Ok(GLenum(self.u32(i)?))
+    }
+
+    fn str(&self, i: usize) -> Result<&'a str> {
+        Ok(self.get(i)?.as_str().ok_or_else(|| self.error(&format!("argument {} is not a string", i)))?)
+    }
+
+    fn bytes(&self, i: usize) -> Result<&'a [u8]> {
+        Ok(self.get(i)?.as_bytes().ok_or_else(|| self.error(&format!("argument {} is not a byte array", i)))?)
+    }
+
+    fn list(&self, i: usize) -> Result<&'a [TraceValue]> {
+        Ok(self.get(i)?.as_list().ok_or_else(|| self.error(&format!("argument {} is not a list", i)))?)
+    }
+
+    fn u32_list(&self, i: usize) -> Result<Vec<u32>> {
+        let values = self.list(i)?.iter().map(TraceValue::as_u32).collect::<Option<Vec<u32>>>();
+        Ok(values.ok_or_else(|| self.error(&format!("argument {} is not a list of unsigned ints", i)))?)
+    }
+
+    fn f32_list(&self, i: usize) -> Result<Vec<f32>> {
+        let values = self.list(i)?.iter().map(|v| v.as_f64().map(|v| v as f32)).collect::<Option<Vec<f32>>>();
+        Ok(values.ok_or_else(|| self.error(&format!("argument {} is not a list of floats", i)))?)
+    }
+
+    fn output_names(&self) -> Result<Vec<u32>> {
+        let names = match self.call.outputs.first() {
+            Some(TraceValue::List(list)) => list.iter().map(TraceValue::as_u32).collect::<Option<Vec<u32>>>(),
+            Some(value) => value.as_u32().map(|v| vec![v]),
+            None => None
+        };
+
+        Ok(names.ok_or_else(|| self.error("missing output"))?)
+    }
+}
+
+impl GLReplayer {
+    pub fn new(trace: GLTrace) -> GLReplayer {
+        GLReplayer {
+            trace,
+            position: 0,
+            names: HashMap::new(),
+            uniform_locations: HashMap::new(),
+            current_program: 0,
+            skipped: BTreeMap::new()
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.position >= self.trace.calls.len()
+    }
+
+    // Functions that couldn't be replayed, and how many times they were skipped
+    pub fn skipped(&self) -> &BTreeMap<String, usize> {
+        &self.skipped
+    }
+
+    // Replays every call of the next frame. Returns false once the trace has run out.
+    pub fn replay_frame(&mut self, gl: &GLWrapper) -> Result<bool> {
+        let Some(frame) = self.trace.calls.get(self.position).map(|call| call.frame) else { return Ok(false) };
+
+        while self.trace.calls.get(self.position).is_some_and(|call| call.frame == frame) {
+            let index = self.position;
+            self.position += 1;
+
+            // The trace is moved out temporarily so calls can be borrowed while remapping
+            let trace = std::mem::take(&mut self.trace);
+            let result = self.replay_call(gl, Args { call: &trace.calls[index], index });
+            self.trace = trace;
+
+            result?;
+        }
+
+        Ok(true)
+    }
+
+    pub fn replay_all(&mut self, gl: &GLWrapper) -> Result<()> {
+        while self.replay_frame(gl)? {}
+
+        Ok(())
+    }
+
+    fn name(&self, kind: ObjectKind, recorded: u32) -> u32 {
+        // Objects created before the trace started are passed through untouched
+        self.names.get(&(kind, recorded)).copied().unwrap_or(recorded)
+    }
+
+    fn names(&self, kind: ObjectKind, recorded: &[u32]) -> Vec<u32> {
+        recorded.iter().map(|name| self.name(kind, *name)).collect()
+    }
+
+    fn location(&self, recorded: i32) -> i32 {
+        self.uniform_locations.get(&(self.current_program, recorded)).copied().unwrap_or(recorded)
+    }
+
+    fn generate(&mut self, kind: ObjectKind, args: &Args, gen: impl FnOnce(&mut [u32])) -> Result<()> {
+        let recorded = args.output_names()?;
+        let mut names = vec![0; recorded.len()];
+        gen(&mut names);
+
+        for (recorded, name) in recorded.into_iter().zip(names) {
+            self.names.insert((kind, recorded), name);
+        }
+
+        Ok(())
+    }
+
+    fn create(&mut self, kind: ObjectKind, args: &Args, name: u32) -> Result<()> {
+        let recorded = args.output_names()?;
+        let Some(recorded) = recorded.first() else { return Err(args.error("missing output").into()) };
+        self.names.insert((kind, *recorded), name);
+
+        Ok(())
+    }
+
+    fn replay_call(&mut self, gl: &GLWrapper, a: Args) -> Result<()> {
+        use ObjectKind::*;
+
+        let fns = gl.fns();
+
+        match a.call.name.as_str() {
+            "glActiveTexture" => unsafe { fns.ActiveTexture(a.enum_(0)?) },
+            "glAttachShader" => fns.AttachShader(self.name(Program, a.u32(0)?), self.name(Shader, a.u32(1)?)),
+            "glBindBuffer" => unsafe { fns.BindBuffer(a.enum_(0)?, self.name(Buffer, a.u32(1)?)) },
+            "glBindBufferBase" => unsafe { fns.BindBufferBase(a.enum_(0)?, a.u32(1)?, self.name(Buffer, a.u32(2)?)) },
+            "glBindTexture" => unsafe { fns.BindTexture(a.enum_(0)?, self.name(Texture, a.u32(1)?)) },
+            "glBindVertexArray" => fns.BindVertexArray(self.name(VertexArray, a.u32(0)?)),
+            "glBufferData" => {
+                let data = a.bytes(1)?;
+                unsafe { fns.BufferData(a.enum_(0)?, data.len() as _, data.as_ptr() as _, a.enum_(2)?) }
+            },
+            "glBufferNull" => unsafe { fns.BufferData(a.enum_(0)?, a.isize(1)?, std::ptr::null(), a.enum_(2)?) },
+            "glBufferSubData" => {
+                let data = a.bytes(2)?;
+                unsafe { fns.BufferSubData(a.enum_(0)?, a.isize(1)?, data.len() as _, data.as_ptr() as _) }
+            },
+            "glClear" => unsafe { fns.Clear(GLbitfield(a.u32(0)?)) },
+            "glClearColor" => unsafe { fns.ClearColor(a.f32(0)?, a.f32(1)?, a.f32(2)?, a.f32(3)?) },
+            "glClearDepth" => unsafe { fns.ClearDepth(a.f64(0)?) },
+            "glCompileShader" => fns.CompileShader(self.name(Shader, a.u32(0)?)),
+            "glCreateProgram" => {
+                let program = fns.CreateProgram();
+                self.create(Program, &a, program)?;
+            },
+            "glCreateShader" => {
+                let shader = fns.CreateShader(a.enum_(0)?);
+                self.create(Shader, &a, shader)?;
+            },
+            "glCullFace" => unsafe { fns.CullFace(a.enum_(0)?) },
+            "glDeleteBuffers" => {
+                let names = self.names(Buffer, &a.u32_list(0)?);
+                unsafe { fns.DeleteBuffers(names.len() as _, names.as_ptr()) }
+            },
+            "glDeleteProgram" => fns.DeleteProgram(self.name(Program, a.u32(0)?)),
+            "glDeleteShader" => fns.DeleteShader(self.name(Shader, a.u32(0)?)),
+            "glDeleteTextures" => {
+                let names = self.names(Texture, &a.u32_list(0)?);
+                unsafe { fns.DeleteTextures(names.len() as _, names.as_ptr()) }
+            },
+            "glDeleteVertexArrays" => {
+                let names = self.names(VertexArray, &a.u32_list(0)?);
+                unsafe { fns.DeleteVertexArrays(names.len() as _, names.as_ptr()) }
+            },
+            "glDepthFunc" => unsafe { fns.DepthFunc(a.enum_(0)?) },
+            "glDisable" => unsafe { fns.Disable(a.enum_(0)?) },
+            "glDisableVertexAttribArray" => unsafe { fns.DisableVertexAttribArray(a.u32(0)?) },
+            "glDrawArrays" => unsafe { fns.DrawArrays(a.enum_(0)?, a.i32(1)?, a.i32(2)?) },
+            "glDrawArraysInstanced" => unsafe { fns.DrawArraysInstanced(a.enum_(0)?, a.i32(1)?, a.i32(2)?, a.i32(3)?) },
+            "glDrawElements" => {
+                let indices = a.u32_list(1)?;
+                unsafe { fns.DrawElements(a.enum_(0)?, indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _) }
+            },
+            "glDrawElementsInstanced" => {
+                let indices = a.u32_list(1)?;
+                unsafe { fns.DrawElementsInstanced(a.enum_(0)?, indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _, a.i32(2)?) }
+            },
+            "glEnable" => unsafe { fns.Enable(a.enum_(0)?) },
+            "glEnableVertexAttribArray" => unsafe { fns.EnableVertexAttribArray(a.u32(0)?) },
+            "glGenBuffer" | "glGenBuffers" => self.generate(Buffer, &a, |names| unsafe { fns.GenBuffers(names.len() as _, names.as_mut_ptr()) })?,
+            "glGenTexture" | "glGenTextures" => self.generate(Texture, &a, |names| unsafe { fns.GenTextures(names.len() as _, names.as_mut_ptr()) })?,
+            "glGenVertexArray" | "glGenVertexArrays" => self.generate(VertexArray, &a, |names| unsafe { fns.GenVertexArrays(names.len() as _, names.as_mut_ptr()) })?,
+            "glGenerateMipmap" => unsafe { fns.GenerateMipmap(a.enum_(0)?) },
+            "glGetUniformLocation" => {
+                let recorded_program = a.u32(0)?;
+                let name = CString::new(a.str(1)?).map_err(|_| a.error("uniform name contains a null byte"))?;
+                let location = unsafe { fns.GetUniformLocation(self.name(Program, recorded_program), name.as_ptr() as _) };
+
+                let Some(recorded) = a.call.outputs.first().and_then(TraceValue::as_i64) else { return Err(a.error("missing output").into()) };
+                self.uniform_locations.insert((recorded_program, recorded as i32), location);
+            },
+            "glLinkProgram" => fns.LinkProgram(self.name(Program, a.u32(0)?)),
+            "glPixelStorei" => unsafe { fns.PixelStorei(a.enum_(0)?, a.i32(1)?) },
+            "glShaderSource" => {
+                let source = a.str(1)?;
+                let (pointer, len) = (source.as_ptr(), source.len() as i32);
+                unsafe { fns.ShaderSource(self.name(Shader, a.u32(0)?), 1, &pointer, &len) }
+            },
+            "glTexImage2D" => {
+                let pixels = a.bytes(8)?;
+                unsafe { fns.TexImage2D(a.enum_(0)?, a.i32(1)?, a.u32(2)? as i32, a.i32(3)?, a.i32(4)?, a.i32(5)?, a.enum_(6)?, a.enum_(7)?, pixels.as_ptr() as _) }
+            },
+            "glTexParameteri" => unsafe { fns.TexParameteri(a.enum_(0)?, a.enum_(1)?, a.u32(2)? as i32) },
+            "glTextureSubImage2D" => {
+                let pixels = a.bytes(8)?;
+                unsafe { fns.TextureSubImage2D(self.name(Texture, a.u32(0)?), a.i32(1)?, a.i32(2)?, a.i32(3)?, a.i32(4)?, a.i32(5)?, a.enum_(6)?, a.enum_(7)?, pixels.as_ptr() as _) }
+            },
+            "glUniform1f" => unsafe { fns.Uniform1f(self.location(a.i32(0)?), a.f32(1)?) },
+            "glUniform2f" => unsafe { fns.Uniform2f(self.location(a.i32(0)?), a.f32(1)?, a.f32(2)?) },
+            "glUniform3f" => unsafe { fns.Uniform3f(self.location(a.i32(0)?), a.f32(1)?, a.f32(2)?, a.f32(3)?) },
+            "glUniform4f" => unsafe { fns.Uniform4f(self.location(a.i32(0)?), a.f32(1)?, a.f32(2)?, a.f32(3)?, a.f32(4)?) },
+            "glUniform1i" => unsafe { fns.Uniform1i(self.location(a.i32(0)?), a.i32(1)?) },
+            "glUniform2i" => unsafe { fns.Uniform2i(self.location(a.i32(0)?), a.i32(1)?, a.i32(2)?) },
+            "glUniform3i" => unsafe { fns.Uniform3i(self.location(a.i32(0)?), a.i32(1)?, a.i32(2)?, a.i32(3)?) },
+            "glUniform4i" => unsafe { fns.Uniform4i(self.location(a.i32(0)?), a.i32(1)?, a.i32(2)?, a.i32(3)?, a.i32(4)?) },
+            "glUniform1ui" => unsafe { fns.Uniform1ui(self.location(a.i32(0)?), a.u32(1)?) },
+            "glUniform2ui" => unsafe { fns.Uniform2ui(self.location(a.i32(0)?), a.u32(1)?, a.u32(2)?) },
+            "glUniform3ui" => unsafe { fns.Uniform3ui(self.location(a.i32(0)?), a.u32(1)?, a.u32(2)?, a.u32(3)?) },
+            "glUniform4ui" => unsafe { fns.Uniform4ui(self.location(a.i32(0)?), a.u32(1)?, a.u32(2)?, a.u32(3)?, a.u32(4)?) },
+            "glUniformMatrix4f" => {
+                let values = a.f32_list(2)?;
+                if values.len() != 16 {
+                    return Err(a.error("expected 16 matrix values").into());
+                }
+
+                let transpose = matches!(a.get(1)?, TraceValue::Bool(true));
+                unsafe { fns.UniformMatrix4fv(self.location(a.i32(0)?), 1, transpose as u8, values.as_ptr()) }
+            },
+            "glUniformMatrix4fv" => {
+                let mut values = Vec::new();
+                for matrix in a.list(2)? {
+                    let matrix = matrix.as_list().map(|matrix| matrix.iter().map(|v| v.as_f64().map(|v| v as f32)).collect::<Option<Vec<f32>>>());
+                    match matrix.flatten() {
+                        Some(matrix) if matrix.len() == 16 => values.extend(matrix),
+                        _ => return Err(a.error("expected lists of 16 matrix values").into())
+                    }
+                }
+
+                let transpose = matches!(a.get(1)?, TraceValue::Bool(true));
+                unsafe { fns.UniformMatrix4fv(self.location(a.i32(0)?), (values.len() / 16) as _, transpose as u8, values.as_ptr()) }
+            },
+            "glUseProgram" => {
+                self.current_program = a.u32(0)?;
+                fns.UseProgram(self.name(Program, self.current_program))
+            },
+            "glVertexAttribDivisor" => unsafe { fns.VertexAttribDivisor(a.u32(0)?, a.u32(1)?) },
+            "glVertexAttribPointer" => {
+                let normalized = matches!(a.get(3)?, TraceValue::Bool(true));
+                unsafe { fns.VertexAttribPointer(a.u32(0)?, a.i32(1)?, a.enum_(2)?, normalized as u8, a.i32(4)?, a.u32(5)? as usize as _) }
+            },
+            "glViewport" => unsafe { fns.Viewport(a.i32(0)?, a.i32(1)?, a.i32(2)?, a.i32(3)?) },
+            // Queries have no side effects, so there is nothing to replay
+            name if name.starts_with("glGet") || name.starts_with("glIs") => (),
+            name => *self.skipped.entry(name.to_owned()).or_default() += 1
+        }
+
+        Ok(())
+    }
+}
+
+impl GLWrapper {
+    pub fn start_trace<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut tracer = self.tracer.borrow_mut();
+        if tracer.is_some() {
+            return Err(GraphicsError::TraceActiveError.into());
+        }
+
+        *tracer = Some(GLTracer::create(path)?);
+
+        Ok(())
+    }
+
+    pub fn stop_trace(&self) -> Result<()> {
+        match self.tracer.borrow_mut().take() {
+            Some(tracer) => tracer.finish(),
+            None => Ok(())
+        }
+    }
+
+    pub fn is_tracing(&self) -> bool {
+        self.tracer.borrow().is_some()
+    }
+
+    pub(in crate::engine::graphics) fn trace_call(&self, name: &'static str, args: Vec<TraceValue>) {
+        let mut tracer = self.tracer.borrow_mut();
+        let Some(t) = tracer.as_mut() else { return };
+
+        // A trace that failed to write is useless, so stop rather than erroring on every call
+        if let Err(e) = t.call(name, args) {
+            error!("Failed to write gl trace, stopping: {}", e);
+            *tracer = None;
+        }
+    }
+
+    pub(in crate::engine::graphics) fn trace_output(&self, value: TraceValue) {
+        if let Some(tracer) = self.tracer.borrow_mut().as_mut() {
+            tracer.output(value);
+        }
+    }
+
+    pub(in crate::engine::graphics) fn trace_frame(&self) {
+        let mut tracer = self.tracer.borrow_mut();
+        let Some(t) = tracer.as_mut() else { return };
+
+        if let Err(e) = t.end_frame() {
+            error!("Failed to write gl trace, stopping: {}", e);
+            *tracer = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::graphics::{gl_enums::ErrorCode, Graphics, GLReplayer, GLTrace, TraceValue, UniformValue, sprite_renderer::{SpriteData, SpriteRenderer}, image::Image};
+    use gl_types::{matrices::{Mat4, MatN}, vec2, vec3};
+
+    use super::TraceCall;
+
+    #[test]
+    fn trace_value_round_trip() {
+        let call = TraceCall {
+            frame: 3,
+            name: "glTest".to_owned(),
+            args: vec![
+                TraceValue::Enum { name: "GL_ARRAY_BUFFER".to_owned(), value: 0x8892 },
+                TraceValue::UInt(7),
+                TraceValue::Int(-1),
+                TraceValue::Float(0.1),
+                TraceValue::Bool(true),
+                TraceValue::Ptr(0xDEAD),
+                TraceValue::Str("uniform\tvec2 a, b;\n\\".to_owned()),
+                TraceValue::Bytes(vec![0, 1, 254, 255]),
+                TraceValue::List(vec![TraceValue::Str("a,b".to_owned()), TraceValue::Float(2.5)]),
+                TraceValue::List(Vec::new()),
+                TraceValue::List(vec![
+                    TraceValue::List(vec![TraceValue::Str("c,\\d\t".to_owned()), TraceValue::List(vec![TraceValue::UInt(1), TraceValue::UInt(2)])]),
+                    TraceValue::List(Vec::new()),
+                    TraceValue::Str(",".to_owned())
+                ])
+            ],
+            outputs: vec![TraceValue::UInt(4)]
+        };
+
+        let text = format!("# gl trace v2\n{}\n", call.encode());
+        assert_eq!(GLTrace::parse(text.as_bytes()).unwrap().calls, [call]);
+    }
+
+    #[test]
+    fn trace_and_replay_sprites() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("sprites.gltrace");
+
+        let view = Mat4::from_array([[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [1.0, 2.0, 3.0, 1.0]]);
+        let moved = Mat4::from_array([[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [4.0, 5.0, 6.0, 1.0]]);
+
+        {
+            let mut gfx = Graphics::init_headless().unwrap();
+            gfx.start_trace(&path).unwrap();
+
+            let mut renderer = SpriteRenderer::new(&gfx).unwrap();
+            let sheet = renderer.add_sprite_sheet("sheet", &gfx, 0, Image::empty(2, 2)).unwrap();
+
+            for frame in 0..2 {
+                renderer.queue_sprite_instance(SpriteData { position: vec3!(frame, 0, 0), anchor: vec2!(0), dimensions: vec2!(1), sprite_id: 0 }, sheet);
+                renderer.render(&gfx, &view, &view);
+
+                // Arrays of matrices are traced as nested lists
+                if frame == 1 {
+                    let program = gfx.software_context().unwrap().draw_calls().last().unwrap().program;
+                    let location = gfx.glGetUniformLocation(program, "view");
+                    gfx.glUniformMatrix4fv(location, false, &[moved]);
+
+                    // Uniform arrays aren't emulated, so a longer array is rejected instead of cut down to its first matrix
+                    gfx.glUniformMatrix4fv(location, false, &[view, view]);
+                    assert!(matches!(unsafe { gfx.glGetError() }, ErrorCode::GL_INVALID_OPERATION));
+                }
+
+                gfx.swap_buffers();
+            }
+
+            gfx.stop_trace().unwrap();
+            renderer.remove_sprite_sheet(&gfx, sheet);
+        }
+
+        let trace = GLTrace::load(&path).unwrap();
+        assert_eq!(trace.frame_count(), 2);
+
+        let bind = trace.calls.iter().find(|call| call.name == "glBindBufferBase").unwrap();
+        assert_eq!(bind.args[0], TraceValue::Enum { name: "GL_SHADER_STORAGE_BUFFER".to_owned(), value: 0x90D2 });
+
+        // Replay on a fresh context, with a buffer created up front so object names don't line up
+        let gfx = Graphics::init_headless().unwrap();
+        let mut unrelated = 0;
+        gfx.glGenBuffer(&mut unrelated);
+
+        let mut replayer = GLReplayer::new(trace);
+        assert!(replayer.replay_frame(&gfx).unwrap());
+        assert_eq!(gfx.software_context().unwrap().draw_calls().len(), 1);
+
+        replayer.replay_all(&gfx).unwrap();
+        assert!(replayer.is_finished());
+        assert!(replayer.skipped().is_empty(), "{:?}", replayer.skipped());
+
+        let gl = gfx.software_context().unwrap();
+        let draws = gl.draw_calls();
+        assert_eq!(draws.len(), 2);
+        assert_eq!(draws[1].instance_count, 1);
+
+        let program = gl.program(draws[1].program).unwrap();
+        assert_eq!(program.uniform("view"), Some(&UniformValue::Mat4(moved)));
+    }
+}
@@ -1,8 +1,8 @@
 #![allow(non_snake_case)]
 
-use std::{ffi::{c_void, CString}, fmt::Debug, ops::{Deref, DerefMut}};
+use std::{cell::{RefCell, RefMut}, ffi::{c_char, c_void, CStr, CString}, fmt::Debug, ops::{Deref, DerefMut}, rc::Rc};
 
-use crate::engine::{errors::{GraphicsError, Result}, graphics::{VAO, gl_enums::{AtomicCounterBufferPName, AttributeType, BindTransformFeedbackTarget, BlendEquationModeEXT, BlendingFactor, BlitFramebufferFilter, Buffer, BufferAccessARB, BufferPNameARB, BufferPointerNameARB, BufferStorageTarget, BufferTargetARB, BufferUsageARB, ClampColorModeARB, ClampColorTargetARB, ClipControlDepth, ClipControlOrigin, ColorBuffer, ConditionalRenderMode, CopyBufferSubDataTarget, CopyImageSubDataTarget, DebugSeverity, DebugSource, DebugType, DepthFunction, DrawBufferMode, DrawElementsType, EnableCap, ErrorCode, FramebufferAttachment, FramebufferAttachmentParameterName, FramebufferParameterName, FramebufferStatus, FramebufferTarget, FrontFaceDirection, GetFramebufferParameter, GetMultisamplePNameNV, GetPName, GetPointervPName, GetTextureParameter, GraphicsResetStatus, HintMode, HintTarget, InternalFormat, InternalFormatPName, InvalidateFramebufferAttachment, LogicOp, ObjectIdentifier, PatchParameterName, PipelineParameterName, PixelFormat, PixelStoreParameter, PixelType, PointParameterNameARB, PolygonMode, PrecisionType, PrimitiveType, ProgramInterface, ProgramInterfacePName, ProgramParameterPName, ProgramPropertyARB, ProgramResourceProperty, ProgramStagePName, QueryCounterTarget, QueryObjectParameterName, QueryParameterName, QueryTarget, ReadBufferMode, RenderbufferParameterName, RenderbufferTarget, SamplerParameterF, SamplerParameterI, ShaderBinaryFormat, ShaderParameterName, ShaderType, StencilFunction, StencilOp, StringName, SubroutineParameterName, SyncCondition, SyncParameterName, SyncStatus, TextureParameterName, TextureTarget, TextureUnit, TransformFeedbackBufferMode, TransformFeedbackPName, UniformBlockPName, UniformPName, UniformType, VertexArrayPName, VertexAttribEnum, VertexAttribIType, VertexAttribLType, VertexAttribPointerPropertyARB, VertexAttribPointerType, VertexAttribPropertyARB, VertexAttribType, VertexProvokingMode}}};
+use crate::engine::{errors::{GraphicsError, Result}, graphics::{VAO, GLTracer, SoftwareContext, SoftwareContextRef, TraceArg, TraceValue, software_gl, gl_enums::{AtomicCounterBufferPName, AttributeType, BindTransformFeedbackTarget, BlendEquationModeEXT, BlendingFactor, BlitFramebufferFilter, Buffer, BufferAccessARB, BufferPNameARB, BufferPointerNameARB, BufferStorageTarget, BufferTargetARB, BufferUsageARB, ClampColorModeARB, ClampColorTargetARB, ClipControlDepth, ClipControlOrigin, ColorBuffer, ConditionalRenderMode, CopyBufferSubDataTarget, CopyImageSubDataTarget, DebugSeverity, DebugSource, DebugType, DepthFunction, DrawBufferMode, DrawElementsType, EnableCap, ErrorCode, FramebufferAttachment, FramebufferAttachmentParameterName, FramebufferParameterName, FramebufferStatus, FramebufferTarget, FrontFaceDirection, GetFramebufferParameter, GetMultisamplePNameNV, GetPName, GetPointervPName, GetTextureParameter, GraphicsResetStatus, HintMode, HintTarget, InternalFormat, InternalFormatPName, InvalidateFramebufferAttachment, LogicOp, ObjectIdentifier, PatchParameterName, PipelineParameterName, PixelFormat, PixelStoreParameter, PixelType, PointParameterNameARB, PolygonMode, PrecisionType, PrimitiveType, ProgramInterface, ProgramInterfacePName, ProgramParameterPName, ProgramPropertyARB, ProgramResourceProperty, ProgramStagePName, QueryCounterTarget, QueryObjectParameterName, QueryParameterName, QueryTarget, ReadBufferMode, RenderbufferParameterName, RenderbufferTarget, SamplerParameterF, SamplerParameterI, ShaderBinaryFormat, ShaderParameterName, ShaderType, StencilFunction, StencilOp, StringName, SubroutineParameterName, SyncCondition, SyncParameterName, SyncStatus, TextureParameterName, TextureTarget, TextureUnit, TransformFeedbackBufferMode, TransformFeedbackPName, UniformBlockPName, UniformPName, UniformType, VertexArrayPName, VertexAttribEnum, VertexAttribIType, VertexAttribLType, VertexAttribPointerPropertyARB, VertexAttribPointerType, VertexAttribPropertyARB, VertexAttribType, VertexProvokingMode}}};
 
 use gl_types::matrices::{Mat4, MatN};
 use gl46::{CullFaceMode, GL_INT, GL_UNSIGNED_INT, GLDEBUGPROC, GLbitfield, GLenum, GLsync, GlFns, MaterialFace, StencilFaceDirection, VertexBufferObjectUsage};
@@ -10,6 +10,24 @@ use glfw::GLProc;
 
 use self::private::Sealed;
 
+// Arguments are only evaluated while a trace is being recorded
+macro_rules! trace {
+    ($self:ident, $name:literal $(, $arg:expr)*) => {
+        $self.calling($name);
+        if $self.is_tracing() {
+            $self.trace_call($name, vec![$($arg.trace_value()),*]);
+        }
+    };
+}
+
+macro_rules! trace_output {
+    ($self:ident, $value:expr) => {
+        if $self.is_tracing() {
+            $self.trace_output($value.trace_value());
+        }
+    };
+}
+
 mod private {
     pub trait Sealed {}
 }
@@ -77,7 +95,7 @@ impl GLType for f64 {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct GlUniformLocation(i32);
+pub struct GlUniformLocation(pub(in crate::engine::graphics) i32);
 
 pub struct Attrib {
     pub name: String,
@@ -151,138 +169,206 @@ impl DerefMut for CStringArray {
 // }
 
 pub struct GLWrapper {
-    fns: GlFns
+    fns: GlFns,
+    pub(in crate::engine::graphics) tracer: RefCell<Option<GLTracer>>,
+    software: Option<Rc<RefCell<SoftwareContext>>>
 }
 
 impl GLWrapper {
     pub(in crate::engine::graphics) fn init_gl<F: Fn(*const u8) -> GLProc>(f: F) -> Result<GLWrapper> {
         let fns = unsafe { GlFns::load_from(&f).map_err(|e| GraphicsError::GLLoadError { msg: e })? };
 
-        Ok(GLWrapper { fns })
+        Ok(GLWrapper { fns, tracer: RefCell::new(None), software: None })
+    }
+
+    // Loads the software implementation instead of a driver, with a context of its own
+    pub(in crate::engine::graphics) fn init_software() -> Result<GLWrapper> {
+        let mut gl = Self::init_gl(|t| {
+            let name = unsafe { CStr::from_ptr(t as *const c_char) }.to_string_lossy();
+            software_gl::get_proc_address(&name)
+        })?;
+
+        gl.software = Some(Rc::new(RefCell::new(SoftwareContext::new())));
+
+        Ok(gl)
+    }
+
+    // Every GL call goes through here, so the software context is current exactly while its own wrapper calls into it
+    pub(in crate::engine::graphics) fn fns(&self) -> Fns<'_> {
+        if let Some(context) = &self.software {
+            SoftwareContext::make_current(context);
+        }
+
+        Fns { gl: self }
+    }
+
+    fn calling(&self, name: &'static str) {
+        if self.software.is_some() {
+            software_gl::set_calling(name);
+        }
+    }
+
+    // Only available when running on the software backend
+    pub fn software_context(&self) -> Option<SoftwareContextRef<'_>> {
+        self.software.as_ref().map(|context| context.borrow())
+    }
+
+    pub fn software_context_mut(&self) -> Option<RefMut<'_, SoftwareContext>> {
+        self.software.as_ref().map(|context| context.borrow_mut())
     }
 
     pub fn glActiveTexture(&self, texture: TextureUnit) {
-        unsafe { self.fns.ActiveTexture(GLenum(texture as u32)) }
+        trace!(self, "glActiveTexture", texture);
+        unsafe { self.fns().ActiveTexture(GLenum(texture as u32)) }
     }
     
     pub fn glAttachShader(&self, program: u32, shader: u32) {
-        self.fns.AttachShader(program, shader)
+        trace!(self, "glAttachShader", program, shader);
+        self.fns().AttachShader(program, shader)
     }
     
     pub fn glBeginConditionalRender(&self, id: u32, mode: ConditionalRenderMode) {
-        unsafe { self.fns.BeginConditionalRender(id, GLenum(mode as u32)) }
+        trace!(self, "glBeginConditionalRender", id, mode);
+        unsafe { self.fns().BeginConditionalRender(id, GLenum(mode as u32)) }
     }
     
     pub fn glBeginQuery(&self, target: QueryTarget, id: u32) {
-        unsafe { self.fns.BeginQuery(GLenum(target as u32), id) }
+        trace!(self, "glBeginQuery", target, id);
+        unsafe { self.fns().BeginQuery(GLenum(target as u32), id) }
     }
     
     pub fn glBeginTransformFeedback(&self, primitiveMode: PrimitiveType) {
-        unsafe { self.fns.BeginTransformFeedback(GLenum(primitiveMode as u32)) }
+        trace!(self, "glBeginTransformFeedback", primitiveMode);
+        unsafe { self.fns().BeginTransformFeedback(GLenum(primitiveMode as u32)) }
     }
     
     pub fn glBindAttribLocation(&self, program: u32, index: u32, name: &str) {
+        trace!(self, "glBindAttribLocation", program, index, name);
         let null_str = CString::new(name).unwrap();
-        unsafe { self.fns.BindAttribLocation(program, index, null_str.as_ptr() as _) }
+        unsafe { self.fns().BindAttribLocation(program, index, null_str.as_ptr() as _) }
     }
     
     pub fn glBindBuffer(&self, target: BufferTargetARB, buffer: u32) {
-        unsafe { self.fns.BindBuffer(GLenum(target as u32), buffer) }
+        trace!(self, "glBindBuffer", target, buffer);
+        unsafe { self.fns().BindBuffer(GLenum(target as u32), buffer) }
     }
     
     pub fn glBindBufferBase(&self, target: BufferTargetARB, index: u32, buffer: u32) {
-        unsafe { self.fns.BindBufferBase(GLenum(target as u32), index, buffer) }
+        trace!(self, "glBindBufferBase", target, index, buffer);
+        unsafe { self.fns().BindBufferBase(GLenum(target as u32), index, buffer) }
     }
     
     pub fn glBindBufferRange(&self, target: BufferTargetARB, index: u32, buffer: u32, offset: isize, size: isize) {
-        unsafe { self.fns.BindBufferRange(GLenum(target as u32), index, buffer, offset, size) }
+        trace!(self, "glBindBufferRange", target, index, buffer, offset, size);
+        unsafe { self.fns().BindBufferRange(GLenum(target as u32), index, buffer, offset, size) }
     }
     
     pub fn glBindFragDataLocation(&self, program: u32, color: u32, name: &str) {
+        trace!(self, "glBindFragDataLocation", program, color, name);
         let null_str = CString::new(name).unwrap();
-        unsafe { self.fns.BindFragDataLocation(program, color, null_str.as_ptr() as _) }
+        unsafe { self.fns().BindFragDataLocation(program, color, null_str.as_ptr() as _) }
     }
     
     pub fn glBindFragDataLocationIndexed(&self, program: u32, colorNumber: u32, index: u32, name: &str) {
+        trace!(self, "glBindFragDataLocationIndexed", program, colorNumber, index, name);
         let null_str = CString::new(name).unwrap();
-        unsafe { self.fns.BindFragDataLocationIndexed(program, colorNumber, index, null_str.as_ptr() as _) }
+        unsafe { self.fns().BindFragDataLocationIndexed(program, colorNumber, index, null_str.as_ptr() as _) }
     }
     
     pub fn glBindFramebuffer(&self, target: FramebufferTarget, framebuffer: u32) {
-        unsafe { self.fns.BindFramebuffer(GLenum(target as u32), framebuffer) }
+        trace!(self, "glBindFramebuffer", target, framebuffer);
+        unsafe { self.fns().BindFramebuffer(GLenum(target as u32), framebuffer) }
     }
     
     pub fn glBindRenderbuffer(&self, target: RenderbufferTarget, renderbuffer: u32) {
-        unsafe { self.fns.BindRenderbuffer(GLenum(target as u32), renderbuffer) }
+        trace!(self, "glBindRenderbuffer", target, renderbuffer);
+        unsafe { self.fns().BindRenderbuffer(GLenum(target as u32), renderbuffer) }
     }
     
     pub fn glBindSampler(&self, unit: u32, sampler: u32) {
-        unsafe { self.fns.BindSampler(unit, sampler) }
+        trace!(self, "glBindSampler", unit, sampler);
+        unsafe { self.fns().BindSampler(unit, sampler) }
     }
     
     pub fn glBindTexture(&self, target: TextureTarget, texture: u32) {
-        unsafe { self.fns.BindTexture(GLenum(target as u32), texture) }
+        trace!(self, "glBindTexture", target, texture);
+        unsafe { self.fns().BindTexture(GLenum(target as u32), texture) }
     }
     
     pub fn glBindVertexArray(&self, array: VAO) {
-        self.fns.BindVertexArray(array.vao())
+        trace!(self, "glBindVertexArray", array);
+        self.fns().BindVertexArray(array.vao())
     }
     
     pub fn glBlendColor(&self, red: f32, green: f32, blue: f32, alpha: f32) {
-        unsafe { self.fns.BlendColor(red, green, blue, alpha) }
+        trace!(self, "glBlendColor", red, green, blue, alpha);
+        unsafe { self.fns().BlendColor(red, green, blue, alpha) }
     }
     
     pub fn glBlendEquation(&self, mode: BlendEquationModeEXT) {
-        unsafe { self.fns.BlendEquation(GLenum(mode as u32)) }
+        trace!(self, "glBlendEquation", mode);
+        unsafe { self.fns().BlendEquation(GLenum(mode as u32)) }
     }
     
     pub fn glBlendEquationSeparate(&self, modeRGB: BlendEquationModeEXT, modeAlpha: BlendEquationModeEXT) {
-        unsafe { self.fns.BlendEquationSeparate(GLenum(modeRGB as u32), GLenum(modeAlpha as u32)) }
+        trace!(self, "glBlendEquationSeparate", modeRGB, modeAlpha);
+        unsafe { self.fns().BlendEquationSeparate(GLenum(modeRGB as u32), GLenum(modeAlpha as u32)) }
     }
     
     pub fn glBlendFunc(&self, sfactor: BlendingFactor, dfactor: BlendingFactor) {
-        unsafe { self.fns.BlendFunc(GLenum(sfactor as u32), GLenum(dfactor as u32)) }
+        trace!(self, "glBlendFunc", sfactor, dfactor);
+        unsafe { self.fns().BlendFunc(GLenum(sfactor as u32), GLenum(dfactor as u32)) }
     }
     
     pub fn glBlendFuncSeparate(&self, sfactorRGB: BlendingFactor, dfactorRGB: BlendingFactor, sfactorAlpha: BlendingFactor, dfactorAlpha: BlendingFactor) {
-        unsafe { self.fns.BlendFuncSeparate(GLenum(sfactorRGB as u32), GLenum(dfactorRGB as u32), GLenum(sfactorAlpha as u32), GLenum(dfactorAlpha as u32)) }
+        trace!(self, "glBlendFuncSeparate", sfactorRGB, dfactorRGB, sfactorAlpha, dfactorAlpha);
+        unsafe { self.fns().BlendFuncSeparate(GLenum(sfactorRGB as u32), GLenum(dfactorRGB as u32), GLenum(sfactorAlpha as u32), GLenum(dfactorAlpha as u32)) }
     }
     
     pub fn glBlitFramebuffer(&self, srcX0: i32, srcY0: i32, srcX1: i32, srcY1: i32, dstX0: i32, dstY0: i32, dstX1: i32, dstY1: i32, mask: GLbitfield, filter: BlitFramebufferFilter) {
-        unsafe { self.fns.BlitFramebuffer(srcX0, srcY0, srcX1, srcY1, dstX0, dstY0, dstX1, dstY1, mask, GLenum(filter as u32)) }
+        trace!(self, "glBlitFramebuffer", srcX0, srcY0, srcX1, srcY1, dstX0, dstY0, dstX1, dstY1, mask, filter);
+        unsafe { self.fns().BlitFramebuffer(srcX0, srcY0, srcX1, srcY1, dstX0, dstY0, dstX1, dstY1, mask, GLenum(filter as u32)) }
     }
     
     pub fn glBufferData<T>(&self, target: BufferTargetARB, data: &[T], usage: BufferUsageARB) {
-        unsafe { self.fns.BufferData(GLenum(target as u32), (data.len() * std::mem::size_of::<T>()) as _, data.as_ptr() as _, GLenum(usage as u32)) }
+        trace!(self, "glBufferData", target, TraceValue::bytes(data), usage);
+        unsafe { self.fns().BufferData(GLenum(target as u32), (data.len() * std::mem::size_of::<T>()) as _, data.as_ptr() as _, GLenum(usage as u32)) }
     }
     
     pub fn glBufferSubData<T>(&self, target: BufferTargetARB, offset: isize, data: &[T]) {
-        unsafe { self.fns.BufferSubData(GLenum(target as u32), offset, (data.len() * std::mem::size_of::<T>()) as _, data.as_ptr() as _) }
+        trace!(self, "glBufferSubData", target, offset, TraceValue::bytes(data));
+        unsafe { self.fns().BufferSubData(GLenum(target as u32), offset, (data.len() * std::mem::size_of::<T>()) as _, data.as_ptr() as _) }
     }
 
     pub fn glBufferNull(&self, target: BufferTargetARB, size: usize, usage: BufferUsageARB) {
-        unsafe { self.fns.BufferData(GLenum(target as u32), size as _, std::ptr::null(), GLenum(usage as u32) )}
+        trace!(self, "glBufferNull", target, size, usage);
+        unsafe { self.fns().BufferData(GLenum(target as u32), size as _, std::ptr::null(), GLenum(usage as u32) )}
     }
     
     pub fn glCheckFramebufferStatus(&self, target: FramebufferTarget) -> FramebufferStatus {
-        unsafe { self.fns.CheckFramebufferStatus(GLenum(target as u32)) }.0.try_into().unwrap()
+        trace!(self, "glCheckFramebufferStatus", target);
+        unsafe { self.fns().CheckFramebufferStatus(GLenum(target as u32)) }.0.try_into().unwrap()
     }
     
     pub fn glClampColor(&self, target: ClampColorTargetARB, clamp: ClampColorModeARB) {
-        unsafe { self.fns.ClampColor(GLenum(target as u32), GLenum(clamp as u32)) }
+        trace!(self, "glClampColor", target, clamp);
+        unsafe { self.fns().ClampColor(GLenum(target as u32), GLenum(clamp as u32)) }
     }
     
     pub fn glClear(&self, mask: GLbitfield) {
-        unsafe { self.fns.Clear(mask) }
+        trace!(self, "glClear", mask);
+        unsafe { self.fns().Clear(mask) }
     }
     
     pub fn glClearBufferfi(&self, buffer: Buffer, drawbuffer: i32, depth: f32, stencil: i32) {
-        unsafe { self.fns.ClearBufferfi(GLenum(buffer as u32), drawbuffer, depth as _, stencil) }
+        trace!(self, "glClearBufferfi", buffer, drawbuffer, depth, stencil);
+        unsafe { self.fns().ClearBufferfi(GLenum(buffer as u32), drawbuffer, depth as _, stencil) }
     }
     
 
     //TODO: Replace buffer and value with a single enum, rather than doing a runtime check
     pub fn glClearBufferfv(&self, buffer: Buffer, drawbuffer: i32, value: &[f32]) {
+        trace!(self, "glClearBufferfv", buffer, drawbuffer, value);
         let expected_size = match buffer {
             Buffer::GL_COLOR => 4,
             Buffer::GL_DEPTH => 1,
@@ -293,11 +379,12 @@ impl GLWrapper {
             panic!("Incorrect value size!")
         }
 
-        unsafe { self.fns.ClearBufferfv(GLenum(buffer as u32), drawbuffer, value.as_ptr()) }
+        unsafe { self.fns().ClearBufferfv(GLenum(buffer as u32), drawbuffer, value.as_ptr()) }
     }
     
     //TODO: Replace buffer and value with a single enum, rather than doing a runtime check
     pub fn glClearBufferiv(&self, buffer: Buffer, drawbuffer: i32, value: &[i32]) {
+        trace!(self, "glClearBufferiv", buffer, drawbuffer, value);
         let expected_size = match buffer {
             Buffer::GL_COLOR => 4,
             Buffer::GL_DEPTH => 1,
@@ -308,11 +395,12 @@ impl GLWrapper {
             panic!("Incorrect value size!")
         }
 
-        unsafe { self.fns.ClearBufferiv(GLenum(buffer as u32), drawbuffer, value.as_ptr()) }
+        unsafe { self.fns().ClearBufferiv(GLenum(buffer as u32), drawbuffer, value.as_ptr()) }
     }
     
     //TODO: Replace buffer and value with a single enum, rather than doing a runtime check
     pub fn glClearBufferuiv(&self, buffer: Buffer, drawbuffer: i32, value: &[u32]) {
+        trace!(self, "glClearBufferuiv", buffer, drawbuffer, value);
         let expected_size = match buffer {
             Buffer::GL_COLOR => 4,
             Buffer::GL_DEPTH => 1,
@@ -323,341 +411,441 @@ impl GLWrapper {
             panic!("Incorrect value size!")
         }
 
-        unsafe { self.fns.ClearBufferuiv(GLenum(buffer as u32), drawbuffer, value.as_ptr()) }
+        unsafe { self.fns().ClearBufferuiv(GLenum(buffer as u32), drawbuffer, value.as_ptr()) }
     }
     
     pub fn glClearColor(&self, red: f32, green: f32, blue: f32, alpha: f32) {
-        unsafe { self.fns.ClearColor(red, green, blue, alpha) }
+        trace!(self, "glClearColor", red, green, blue, alpha);
+        unsafe { self.fns().ClearColor(red, green, blue, alpha) }
     }
     
     pub fn glClearDepth(&self, depth: f64) {
-        unsafe { self.fns.ClearDepth(depth) }
+        trace!(self, "glClearDepth", depth);
+        unsafe { self.fns().ClearDepth(depth) }
     }
     
     pub fn glClearStencil(&self, s: i32) {
-        unsafe { self.fns.ClearStencil(s) }
+        trace!(self, "glClearStencil", s);
+        unsafe { self.fns().ClearStencil(s) }
     }
     
     pub fn glClientWaitSync(&self, sync: GLsync, flags: GLbitfield, timeout: u64) -> SyncStatus {
-        unsafe { self.fns.ClientWaitSync(sync, flags, timeout) }.0.try_into().unwrap()
+        trace!(self, "glClientWaitSync", sync, flags, timeout);
+        unsafe { self.fns().ClientWaitSync(sync, flags, timeout) }.0.try_into().unwrap()
     }
     
     pub fn glColorMask(&self, red: u8, green: u8, blue: u8, alpha: u8) {
-        unsafe { self.fns.ColorMask(red, green, blue, alpha) }
+        trace!(self, "glColorMask", red, green, blue, alpha);
+        unsafe { self.fns().ColorMask(red, green, blue, alpha) }
     }
     
     pub fn glColorMaski(&self, index: u32, r: u8, g: u8, b: u8, a: u8) {
-        unsafe { self.fns.ColorMaski(index, r, g, b, a) }
+        trace!(self, "glColorMaski", index, r, g, b, a);
+        unsafe { self.fns().ColorMaski(index, r, g, b, a) }
     }
     
     pub fn glCompileShader(&self, shader: u32) {
-        self.fns.CompileShader(shader)
+        trace!(self, "glCompileShader", shader);
+        self.fns().CompileShader(shader)
     }
     
     pub fn glCompressedTexImage1D(&self, target: TextureTarget, level: i32, internalformat: InternalFormat, width: u32, border: i32, data: &[u8]) {
-        unsafe { self.fns.CompressedTexImage1D(GLenum(target as u32), level, GLenum(internalformat as u32), width as _, border, data.len() as _, data.as_ptr() as _) }
+        trace!(self, "glCompressedTexImage1D", target, level, internalformat, width, border, TraceValue::bytes(data));
+        unsafe { self.fns().CompressedTexImage1D(GLenum(target as u32), level, GLenum(internalformat as u32), width as _, border, data.len() as _, data.as_ptr() as _) }
     }
     
     pub fn glCompressedTexImage2D(&self, target: TextureTarget, level: i32, internalformat: InternalFormat, width: u32, height: u32, border: i32, data: &[u8]) {
-        unsafe { self.fns.CompressedTexImage2D(GLenum(target as u32), level, GLenum(internalformat as u32), width as _, height as _, border, data.len() as _, data.as_ptr() as _) }
+        trace!(self, "glCompressedTexImage2D", target, level, internalformat, width, height, border, TraceValue::bytes(data));
+        unsafe { self.fns().CompressedTexImage2D(GLenum(target as u32), level, GLenum(internalformat as u32), width as _, height as _, border, data.len() as _, data.as_ptr() as _) }
     }
     
     pub fn glCompressedTexImage3D(&self, target: TextureTarget, level: i32, internalformat: InternalFormat, width: u32, height: u32, depth: u32, border: i32, data: &[u8]) {
-        unsafe { self.fns.CompressedTexImage3D(GLenum(target as u32), level, GLenum(internalformat as u32), width as _, height as _, depth as _, border, data.len() as _, data.as_ptr() as _) }
+        trace!(self, "glCompressedTexImage3D", target, level, internalformat, width, height, depth, border, TraceValue::bytes(data));
+        unsafe { self.fns().CompressedTexImage3D(GLenum(target as u32), level, GLenum(internalformat as u32), width as _, height as _, depth as _, border, data.len() as _, data.as_ptr() as _) }
     }
     
     pub fn glCompressedTexSubImage1D(&self, target: TextureTarget, level: i32, xoffset: i32, width: u32, format: PixelFormat, data: &[u8]) {
-        unsafe { self.fns.CompressedTexSubImage1D(GLenum(target as u32), level, xoffset, width as _, GLenum(format as u32), data.len() as _, data.as_ptr() as _) }
+        trace!(self, "glCompressedTexSubImage1D", target, level, xoffset, width, format, TraceValue::bytes(data));
+        unsafe { self.fns().CompressedTexSubImage1D(GLenum(target as u32), level, xoffset, width as _, GLenum(format as u32), data.len() as _, data.as_ptr() as _) }
     }
     
     pub fn glCompressedTexSubImage2D(&self, target: TextureTarget, level: i32, xoffset: i32, yoffset: i32, width: u32, height: u32, format: PixelFormat, data: &[u8]) {
-        unsafe { self.fns.CompressedTexSubImage2D(GLenum(target as u32), level, xoffset, yoffset, width as _, height as _, GLenum(format as u32), data.len() as _, data.as_ptr() as _) }
+        trace!(self, "glCompressedTexSubImage2D", target, level, xoffset, yoffset, width, height, format, TraceValue::bytes(data));
+        unsafe { self.fns().CompressedTexSubImage2D(GLenum(target as u32), level, xoffset, yoffset, width as _, height as _, GLenum(format as u32), data.len() as _, data.as_ptr() as _) }
     }
     
     pub fn glCompressedTexSubImage3D(&self, target: TextureTarget, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, width: u32, height: u32, depth: u32, format: PixelFormat, data: &[u8]) {
-        unsafe { self.fns.CompressedTexSubImage3D(GLenum(target as u32), level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, GLenum(format as u32), data.len() as _, data.as_ptr() as _) }
+        trace!(self, "glCompressedTexSubImage3D", target, level, xoffset, yoffset, zoffset, width, height, depth, format, TraceValue::bytes(data));
+        unsafe { self.fns().CompressedTexSubImage3D(GLenum(target as u32), level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, GLenum(format as u32), data.len() as _, data.as_ptr() as _) }
     }
     
     pub fn glCopyBufferSubData(&self, readTarget: CopyBufferSubDataTarget, writeTarget: CopyBufferSubDataTarget, readOffset: isize, writeOffset: isize, size: isize) {
-        unsafe { self.fns.CopyBufferSubData(GLenum(readTarget as u32), GLenum(writeTarget as u32), readOffset, writeOffset, size) }
+        trace!(self, "glCopyBufferSubData", readTarget, writeTarget, readOffset, writeOffset, size);
+        unsafe { self.fns().CopyBufferSubData(GLenum(readTarget as u32), GLenum(writeTarget as u32), readOffset, writeOffset, size) }
     }
     
     pub fn glCopyTexImage1D(&self, target: TextureTarget, level: i32, internalformat: InternalFormat, x: i32, y: i32, width: u32, border: i32) {
-        unsafe { self.fns.CopyTexImage1D(GLenum(target as u32), level, GLenum(internalformat as u32), x, y, width as _, border) }
+        trace!(self, "glCopyTexImage1D", target, level, internalformat, x, y, width, border);
+        unsafe { self.fns().CopyTexImage1D(GLenum(target as u32), level, GLenum(internalformat as u32), x, y, width as _, border) }
     }
     
     pub fn glCopyTexImage2D(&self, target: TextureTarget, level: i32, internalformat: InternalFormat, x: i32, y: i32, width: u32, height: u32, border: i32) {
-        unsafe { self.fns.CopyTexImage2D(GLenum(target as u32), level, GLenum(internalformat as u32), x, y, width as _, height as _, border) }
+        trace!(self, "glCopyTexImage2D", target, level, internalformat, x, y, width, height, border);
+        unsafe { self.fns().CopyTexImage2D(GLenum(target as u32), level, GLenum(internalformat as u32), x, y, width as _, height as _, border) }
     }
     
     pub fn glCopyTexSubImage1D(&self, target: TextureTarget, level: i32, xoffset: i32, x: i32, y: i32, width: u32) {
-        unsafe { self.fns.CopyTexSubImage1D(GLenum(target as u32), level, xoffset, x, y, width as _) }
+        trace!(self, "glCopyTexSubImage1D", target, level, xoffset, x, y, width);
+        unsafe { self.fns().CopyTexSubImage1D(GLenum(target as u32), level, xoffset, x, y, width as _) }
     }
     
     pub fn glCopyTexSubImage2D(&self, target: TextureTarget, level: i32, xoffset: i32, yoffset: i32, x: i32, y: i32, width: u32, height: u32) {
-        unsafe { self.fns.CopyTexSubImage2D(GLenum(target as u32), level, xoffset, yoffset, x, y, width as _, height as _) }
+        trace!(self, "glCopyTexSubImage2D", target, level, xoffset, yoffset, x, y, width, height);
+        unsafe { self.fns().CopyTexSubImage2D(GLenum(target as u32), level, xoffset, yoffset, x, y, width as _, height as _) }
     }
     
     pub fn glCopyTexSubImage3D(&self, target: TextureTarget, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, x: i32, y: i32, width: u32, height: u32) {
-        unsafe { self.fns.CopyTexSubImage3D(GLenum(target as u32), level, xoffset, yoffset, zoffset, x, y, width as _, height as _) }
+        trace!(self, "glCopyTexSubImage3D", target, level, xoffset, yoffset, zoffset, x, y, width, height);
+        unsafe { self.fns().CopyTexSubImage3D(GLenum(target as u32), level, xoffset, yoffset, zoffset, x, y, width as _, height as _) }
     }
     
     pub fn glCreateProgram(&self) -> u32 {
-        self.fns.CreateProgram()
+        trace!(self, "glCreateProgram");
+        let program = self.fns().CreateProgram();
+        trace_output!(self, program);
+        program
     }
     
     pub fn glCreateShader(&self, type_: ShaderType) -> u32 {
-        self.fns.CreateShader(GLenum(type_ as u32))
+        trace!(self, "glCreateShader", type_);
+        let shader = self.fns().CreateShader(GLenum(type_ as u32));
+        trace_output!(self, shader);
+        shader
     }
     
     pub fn glCullFace(&self, mode: CullFaceMode) {
-        unsafe { self.fns.CullFace(mode) }
+        trace!(self, "glCullFace", mode);
+        unsafe { self.fns().CullFace(mode) }
     }
     
     pub fn glDeleteBuffers(&self, buffers: &[u32]) {
-        unsafe { self.fns.DeleteBuffers(buffers.len() as _, buffers.as_ptr()) }
+        trace!(self, "glDeleteBuffers", buffers);
+        unsafe { self.fns().DeleteBuffers(buffers.len() as _, buffers.as_ptr()) }
     }
     
     pub fn glDeleteFramebuffers(&self, framebuffers: &[u32]) {
-        unsafe { self.fns.DeleteFramebuffers(framebuffers.len() as _, framebuffers.as_ptr()) }
+        trace!(self, "glDeleteFramebuffers", framebuffers);
+        unsafe { self.fns().DeleteFramebuffers(framebuffers.len() as _, framebuffers.as_ptr()) }
     }
     
     pub fn glDeleteProgram(&self, program: u32) {
-        self.fns.DeleteProgram(program)
+        trace!(self, "glDeleteProgram", program);
+        self.fns().DeleteProgram(program)
     }
     
     pub fn glDeleteQueries(&self, ids: &[u32]) {
-        unsafe { self.fns.DeleteQueries(ids.len() as _, ids.as_ptr()) }
+        trace!(self, "glDeleteQueries", ids);
+        unsafe { self.fns().DeleteQueries(ids.len() as _, ids.as_ptr()) }
     }
     
     pub fn glDeleteRenderbuffers(&self, renderbuffers: &[u32]) {
-        unsafe { self.fns.DeleteRenderbuffers(renderbuffers.len() as _, renderbuffers.as_ptr()) }
+        trace!(self, "glDeleteRenderbuffers", renderbuffers);
+        unsafe { self.fns().DeleteRenderbuffers(renderbuffers.len() as _, renderbuffers.as_ptr()) }
     }
     
     pub fn glDeleteSamplers(&self, samplers: &[u32]) {
-        unsafe { self.fns.DeleteSamplers(samplers.len() as _, samplers.as_ptr()) }
+        trace!(self, "glDeleteSamplers", samplers);
+        unsafe { self.fns().DeleteSamplers(samplers.len() as _, samplers.as_ptr()) }
     }
     
     pub fn glDeleteShader(&self, shader: u32) {
-        self.fns.DeleteShader(shader)
+        trace!(self, "glDeleteShader", shader);
+        self.fns().DeleteShader(shader)
     }
     
     pub fn glDeleteSync(&self, sync: GLsync) {
-        unsafe { self.fns.DeleteSync(sync) }
+        trace!(self, "glDeleteSync", sync);
+        unsafe { self.fns().DeleteSync(sync) }
     }
     
     pub fn glDeleteTextures(&self, textures: &[u32]) {
-        unsafe { self.fns.DeleteTextures(textures.len() as _, textures.as_ptr()) }
+        trace!(self, "glDeleteTextures", textures);
+        unsafe { self.fns().DeleteTextures(textures.len() as _, textures.as_ptr()) }
     }
     
     pub fn glDeleteVertexArrays(&self, arrays: &[u32]) {
-        unsafe { self.fns.DeleteVertexArrays(arrays.len() as _, arrays.as_ptr()) }
+        trace!(self, "glDeleteVertexArrays", arrays);
+        unsafe { self.fns().DeleteVertexArrays(arrays.len() as _, arrays.as_ptr()) }
     }
     
     pub fn glDepthFunc(&self, func: DepthFunction) {
-        unsafe { self.fns.DepthFunc(GLenum(func as u32)) }
+        trace!(self, "glDepthFunc", func);
+        unsafe { self.fns().DepthFunc(GLenum(func as u32)) }
     }
     
     pub fn glDepthMask(&self, flag: u8) {
-        unsafe { self.fns.DepthMask(flag) }
+        trace!(self, "glDepthMask", flag);
+        unsafe { self.fns().DepthMask(flag) }
     }
     
     pub fn glDepthRange(&self, n: f64, f: f64) {
-        unsafe { self.fns.DepthRange(n, f) }
+        trace!(self, "glDepthRange", n, f);
+        unsafe { self.fns().DepthRange(n, f) }
     }
     
     pub fn glDetachShader(&self, program: u32, shader: u32) {
-        unsafe { self.fns.DetachShader(program, shader) }
+        trace!(self, "glDetachShader", program, shader);
+        unsafe { self.fns().DetachShader(program, shader) }
     }
     
     pub fn glDisable(&self, cap: EnableCap) {
-        unsafe { self.fns.Disable(GLenum(cap as u32)) }
+        trace!(self, "glDisable", cap);
+        unsafe { self.fns().Disable(GLenum(cap as u32)) }
     }
     
     pub fn glDisableVertexAttribArray(&self, index: u32) {
-        unsafe { self.fns.DisableVertexAttribArray(index) }
+        trace!(self, "glDisableVertexAttribArray", index);
+        unsafe { self.fns().DisableVertexAttribArray(index) }
     }
     
     pub fn glDisablei(&self, target: EnableCap, index: u32) {
-        unsafe { self.fns.Disablei(GLenum(target as u32), index) }
+        trace!(self, "glDisablei", target, index);
+        unsafe { self.fns().Disablei(GLenum(target as u32), index) }
     }
     
     pub fn glDrawArrays(&self, mode: PrimitiveType, first: i32, count: i32) {
-        unsafe { self.fns.DrawArrays(GLenum(mode as u32), first, count) }
+        trace!(self, "glDrawArrays", mode, first, count);
+        unsafe { self.fns().DrawArrays(GLenum(mode as u32), first, count) }
     }
     
     pub fn glDrawArraysInstanced(&self, mode: PrimitiveType, first: i32, count: i32, instancecount: u32) {
-        unsafe { self.fns.DrawArraysInstanced(GLenum(mode as u32), first, count, instancecount as _) }
+        trace!(self, "glDrawArraysInstanced", mode, first, count, instancecount);
+        unsafe { self.fns().DrawArraysInstanced(GLenum(mode as u32), first, count, instancecount as _) }
     }
     
     pub fn glDrawBuffer(&self, buf: DrawBufferMode) {
-        unsafe { self.fns.DrawBuffer(GLenum(buf as u32)) }
+        trace!(self, "glDrawBuffer", buf);
+        unsafe { self.fns().DrawBuffer(GLenum(buf as u32)) }
     }
     
     pub fn glDrawBuffers(&self, bufs: &[DrawBufferMode]) {
-        unsafe { self.fns.DrawBuffers(bufs.len() as _, bufs.as_ptr() as _) } // TODO: Verify that this pointer conversion is valid (it should be)
+        trace!(self, "glDrawBuffers", bufs);
+        unsafe { self.fns().DrawBuffers(bufs.len() as _, bufs.as_ptr() as _) } // TODO: Verify that this pointer conversion is valid (it should be)
     }
     
     pub fn glDrawElements(&self, mode: PrimitiveType, indices: &[u32]) {
-        unsafe { self.fns.DrawElements(GLenum(mode as u32), indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _) }
+        trace!(self, "glDrawElements", mode, indices);
+        unsafe { self.fns().DrawElements(GLenum(mode as u32), indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _) }
     }
     
     pub fn glDrawElementsBaseVertex(&self, mode: PrimitiveType, indices: &[u32], basevertex: i32) {
-        unsafe { self.fns.DrawElementsBaseVertex(GLenum(mode as u32), indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _, basevertex) }
+        trace!(self, "glDrawElementsBaseVertex", mode, indices, basevertex);
+        unsafe { self.fns().DrawElementsBaseVertex(GLenum(mode as u32), indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _, basevertex) }
     }
     
     pub fn glDrawElementsInstanced(&self, mode: PrimitiveType, indices: &[u32], instancecount: u32) {
-        unsafe { self.fns.DrawElementsInstanced(GLenum(mode as u32), indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _, instancecount as i32) }
+        trace!(self, "glDrawElementsInstanced", mode, indices, instancecount);
+        unsafe { self.fns().DrawElementsInstanced(GLenum(mode as u32), indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _, instancecount as i32) }
     }
     
     pub fn glDrawElementsInstancedBaseVertex(&self, mode: PrimitiveType, indices: &[u32], instancecount: i32, basevertex: i32) {
-        unsafe { self.fns.DrawElementsInstancedBaseVertex(GLenum(mode as u32), indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _, instancecount, basevertex) }
+        trace!(self, "glDrawElementsInstancedBaseVertex", mode, indices, instancecount, basevertex);
+        unsafe { self.fns().DrawElementsInstancedBaseVertex(GLenum(mode as u32), indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _, instancecount, basevertex) }
     }
     
     pub fn glDrawRangeElements(&self, mode: PrimitiveType, start: u32, end: u32, indices: &[u32]) {
-        unsafe { self.fns.DrawRangeElements(GLenum(mode as u32), start, end, indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _) }
+        trace!(self, "glDrawRangeElements", mode, start, end, indices);
+        unsafe { self.fns().DrawRangeElements(GLenum(mode as u32), start, end, indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _) }
     }
     
     pub fn glDrawRangeElementsBaseVertex(&self, mode: PrimitiveType, start: u32, end: u32, indices: &[u32], basevertex: i32) {
-        unsafe { self.fns.DrawRangeElementsBaseVertex(GLenum(mode as u32), start, end, indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _, basevertex) }
+        trace!(self, "glDrawRangeElementsBaseVertex", mode, start, end, indices, basevertex);
+        unsafe { self.fns().DrawRangeElementsBaseVertex(GLenum(mode as u32), start, end, indices.len() as _, GL_UNSIGNED_INT, indices.as_ptr() as _, basevertex) }
     }
     
     pub fn glEnable(&self, cap: EnableCap) {
-        unsafe { self.fns.Enable(GLenum(cap as u32)) }
+        trace!(self, "glEnable", cap);
+        unsafe { self.fns().Enable(GLenum(cap as u32)) }
     }
     
     pub fn glEnableVertexAttribArray(&self, index: u32) {
-        unsafe { self.fns.EnableVertexAttribArray(index) }
+        trace!(self, "glEnableVertexAttribArray", index);
+        unsafe { self.fns().EnableVertexAttribArray(index) }
     }
     
     pub fn glEnablei(&self, target: EnableCap, index: u32) {
-        unsafe { self.fns.Enablei(GLenum(target as u32), index) }
+        trace!(self, "glEnablei", target, index);
+        unsafe { self.fns().Enablei(GLenum(target as u32), index) }
     }
     
     pub fn glEndConditionalRender(&self) {
-        unsafe { self.fns.EndConditionalRender() }
+        trace!(self, "glEndConditionalRender");
+        unsafe { self.fns().EndConditionalRender() }
     }
     
     pub fn glEndQuery(&self, target: QueryTarget) {
-        unsafe { self.fns.EndQuery(GLenum(target as u32)) }
+        trace!(self, "glEndQuery", target);
+        unsafe { self.fns().EndQuery(GLenum(target as u32)) }
     }
     
     pub fn glEndTransformFeedback(&self) {
-        unsafe { self.fns.EndTransformFeedback() }
+        trace!(self, "glEndTransformFeedback");
+        unsafe { self.fns().EndTransformFeedback() }
     }
     
     pub fn glFenceSync(&self, condition: SyncCondition, flags: GLbitfield) -> GLsync {
-        unsafe { self.fns.FenceSync(GLenum(condition as u32), flags) }
+        trace!(self, "glFenceSync", condition, flags);
+        unsafe { self.fns().FenceSync(GLenum(condition as u32), flags) }
     }
     
     pub fn glFinish(&self) {
-        unsafe { self.fns.Finish() }
+        trace!(self, "glFinish");
+        unsafe { self.fns().Finish() }
     }
     
     pub fn glFlush(&self) {
-        unsafe { self.fns.Flush() }
+        trace!(self, "glFlush");
+        unsafe { self.fns().Flush() }
     }
     
     pub fn glFlushMappedBufferRange(&self, target: BufferTargetARB, offset: isize, length: isize) {
-        unsafe { self.fns.FlushMappedBufferRange(GLenum(target as u32), offset, length) }
+        trace!(self, "glFlushMappedBufferRange", target, offset, length);
+        unsafe { self.fns().FlushMappedBufferRange(GLenum(target as u32), offset, length) }
     }
     
     pub fn glFramebufferRenderbuffer(&self, target: FramebufferTarget, attachment: FramebufferAttachment, renderbuffertarget: RenderbufferTarget, renderbuffer: u32) {
-        unsafe { self.fns.FramebufferRenderbuffer(GLenum(target as u32), GLenum(attachment as u32), GLenum(renderbuffertarget as u32), renderbuffer) }
+        trace!(self, "glFramebufferRenderbuffer", target, attachment, renderbuffertarget, renderbuffer);
+        unsafe { self.fns().FramebufferRenderbuffer(GLenum(target as u32), GLenum(attachment as u32), GLenum(renderbuffertarget as u32), renderbuffer) }
     }
     
     pub fn glFramebufferTexture(&self, target: FramebufferTarget, attachment: FramebufferAttachment, texture: u32, level: i32) {
-        unsafe { self.fns.FramebufferTexture(GLenum(target as u32), GLenum(attachment as u32), texture, level) }
+        trace!(self, "glFramebufferTexture", target, attachment, texture, level);
+        unsafe { self.fns().FramebufferTexture(GLenum(target as u32), GLenum(attachment as u32), texture, level) }
     }
     
     pub fn glFramebufferTexture1D(&self, target: FramebufferTarget, attachment: FramebufferAttachment, textarget: TextureTarget, texture: u32, level: i32) {
-        unsafe { self.fns.FramebufferTexture1D(GLenum(target as u32), GLenum(attachment as u32), GLenum(textarget as u32), texture, level) }
+        trace!(self, "glFramebufferTexture1D", target, attachment, textarget, texture, level);
+        unsafe { self.fns().FramebufferTexture1D(GLenum(target as u32), GLenum(attachment as u32), GLenum(textarget as u32), texture, level) }
     }
     
     pub fn glFramebufferTexture2D(&self, target: FramebufferTarget, attachment: FramebufferAttachment, textarget: TextureTarget, texture: u32, level: i32) {
-        unsafe { self.fns.FramebufferTexture2D(GLenum(target as u32), GLenum(attachment as u32), GLenum(textarget as u32), texture, level) }
+        trace!(self, "glFramebufferTexture2D", target, attachment, textarget, texture, level);
+        unsafe { self.fns().FramebufferTexture2D(GLenum(target as u32), GLenum(attachment as u32), GLenum(textarget as u32), texture, level) }
     }
     
     pub fn glFramebufferTexture3D(&self, target: FramebufferTarget, attachment: FramebufferAttachment, textarget: TextureTarget, texture: u32, level: i32, zoffset: i32) {
-        unsafe { self.fns.FramebufferTexture3D(GLenum(target as u32), GLenum(attachment as u32), GLenum(textarget as u32), texture, level, zoffset) }
+        trace!(self, "glFramebufferTexture3D", target, attachment, textarget, texture, level, zoffset);
+        unsafe { self.fns().FramebufferTexture3D(GLenum(target as u32), GLenum(attachment as u32), GLenum(textarget as u32), texture, level, zoffset) }
     }
     
     pub fn glFramebufferTextureLayer(&self, target: FramebufferTarget, attachment: FramebufferAttachment, texture: u32, level: i32, layer: i32) {
-        unsafe { self.fns.FramebufferTextureLayer(GLenum(target as u32), GLenum(attachment as u32), texture, level, layer) }
+        trace!(self, "glFramebufferTextureLayer", target, attachment, texture, level, layer);
+        unsafe { self.fns().FramebufferTextureLayer(GLenum(target as u32), GLenum(attachment as u32), texture, level, layer) }
     }
     
     pub fn glFrontFace(&self, mode: FrontFaceDirection) {
-        unsafe { self.fns.FrontFace(GLenum(mode as u32)) }
+        trace!(self, "glFrontFace", mode);
+        unsafe { self.fns().FrontFace(GLenum(mode as u32)) }
     }
     
     pub fn glGenBuffers(&self, buffers: &mut [u32]) {
-        unsafe { self.fns.GenBuffers(buffers.len() as _, buffers.as_mut_ptr()) }
+        trace!(self, "glGenBuffers", buffers.len());
+        unsafe { self.fns().GenBuffers(buffers.len() as _, buffers.as_mut_ptr()) };
+        trace_output!(self, buffers);
     }
     
     pub fn glGenBuffer(&self, buffer: &mut u32) {
-        unsafe { self.fns.GenBuffers(1, buffer) }
+        trace!(self, "glGenBuffer");
+        unsafe { self.fns().GenBuffers(1, buffer) };
+        trace_output!(self, buffer);
     }
     
     pub fn glGenFramebuffers(&self, framebuffers: &mut [u32]) {
-        unsafe { self.fns.GenFramebuffers(framebuffers.len() as _, framebuffers.as_mut_ptr()) }
+        trace!(self, "glGenFramebuffers", framebuffers.len());
+        unsafe { self.fns().GenFramebuffers(framebuffers.len() as _, framebuffers.as_mut_ptr()) };
+        trace_output!(self, framebuffers);
     }
     
     pub fn glGenFramebuffer(&self, framebuffer: &mut u32) {
-        unsafe { self.fns.GenFramebuffers(1, framebuffer) }
+        trace!(self, "glGenFramebuffer");
+        unsafe { self.fns().GenFramebuffers(1, framebuffer) };
+        trace_output!(self, framebuffer);
     }
     
     pub fn glGenQueries(&self, ids: &mut [u32]) {
-        unsafe { self.fns.GenQueries(ids.len() as _, ids.as_mut_ptr()) }
+        trace!(self, "glGenQueries", ids.len());
+        unsafe { self.fns().GenQueries(ids.len() as _, ids.as_mut_ptr()) };
+        trace_output!(self, ids);
     }
     
     pub fn glGenQuery(&self, id: &mut u32) {
-        unsafe { self.fns.GenQueries(1, id) }
+        trace!(self, "glGenQuery");
+        unsafe { self.fns().GenQueries(1, id) };
+        trace_output!(self, id);
     }
     
     pub fn glGenRenderbuffers(&self, renderbuffers: &mut [u32]) {
-        unsafe { self.fns.GenRenderbuffers(renderbuffers.len() as _, renderbuffers.as_mut_ptr()) }
+        trace!(self, "glGenRenderbuffers", renderbuffers.len());
+        unsafe { self.fns().GenRenderbuffers(renderbuffers.len() as _, renderbuffers.as_mut_ptr()) };
+        trace_output!(self, renderbuffers);
     }
     
     pub fn glGenRenderbuffer(&self, renderbuffer: &mut u32) {
-        unsafe { self.fns.GenRenderbuffers(1, renderbuffer) }
+        trace!(self, "glGenRenderbuffer");
+        unsafe { self.fns().GenRenderbuffers(1, renderbuffer) };
+        trace_output!(self, renderbuffer);
     }
     
     pub fn glGenSamplers(&self, samplers: &mut [u32]) {
-        unsafe { self.fns.GenSamplers(samplers.len() as _, samplers.as_mut_ptr()) }
+        trace!(self, "glGenSamplers", samplers.len());
+        unsafe { self.fns().GenSamplers(samplers.len() as _, samplers.as_mut_ptr()) };
+        trace_output!(self, samplers);
     }
     
     pub fn glGenSampler(&self, sampler: &mut u32) {
-        unsafe { self.fns.GenSamplers(1, sampler) }
+        trace!(self, "glGenSampler");
+        unsafe { self.fns().GenSamplers(1, sampler) };
+        trace_output!(self, sampler);
     }
     
     pub fn glGenTextures(&self, textures: &mut [u32]) {
-        unsafe { self.fns.GenTextures(textures.len() as _, textures.as_mut_ptr()) }
+        trace!(self, "glGenTextures", textures.len());
+        unsafe { self.fns().GenTextures(textures.len() as _, textures.as_mut_ptr()) };
+        trace_output!(self, textures);
     }
     
     pub fn glGenTexture(&self, texture: &mut u32) {
-        unsafe { self.fns.GenTextures(1, texture) }
+        trace!(self, "glGenTexture");
+        unsafe { self.fns().GenTextures(1, texture) };
+        trace_output!(self, texture);
     }
     
     pub fn glGenVertexArrays(&self, arrays: &mut [u32]) {
-        unsafe { self.fns.GenVertexArrays(arrays.len() as _, arrays.as_mut_ptr()) }
+        trace!(self, "glGenVertexArrays", arrays.len());
+        unsafe { self.fns().GenVertexArrays(arrays.len() as _, arrays.as_mut_ptr()) };
+        trace_output!(self, arrays);
     }
     
     pub fn glGenVertexArray(&self, array: &mut u32) {
-        unsafe { self.fns.GenVertexArrays(1, array) }
+        trace!(self, "glGenVertexArray");
+        unsafe { self.fns().GenVertexArrays(1, array) };
+        trace_output!(self, array);
     }
     
     pub fn glGenerateMipmap(&self, target: TextureTarget) {
-        unsafe { self.fns.GenerateMipmap(GLenum(target as u32)) }
+        trace!(self, "glGenerateMipmap", target);
+        unsafe { self.fns().GenerateMipmap(GLenum(target as u32)) }
     }
 
     // TODO: NEEDS TESTING
     pub fn glGetActiveAttrib(&self, program: u32, index: u32) -> Attrib {
+        trace!(self, "glGetActiveAttrib", program, index);
         let mut name = [0u8; 100];
         let mut size = 0i32;
         let mut type_ = GL_INT;
         let mut length = 0i32;
 
-        unsafe { self.fns.GetActiveAttrib(program, index, 100, &mut length as _, &mut size as _, &mut type_ as _, name.as_mut_ptr()); }
+        unsafe { self.fns().GetActiveAttrib(program, index, 100, &mut length as _, &mut size as _, &mut type_ as _, name.as_mut_ptr()); }
 
         let name = String::from_utf8_lossy(&name[..length as _]).into_owned();
 
@@ -666,12 +854,13 @@ impl GLWrapper {
 
     // TODO: Something isn't right here
     pub fn glGetActiveUniform(&self, program: u32, index: u32) -> Uniform {
+        trace!(self, "glGetActiveUniform", program, index);
         let mut name = [0u8; 100];
         let mut size = 0i32;
         let mut type_ = GL_INT;
         let mut length = 0i32;
 
-        unsafe {self.fns.GetActiveUniform(program, index, 100, &mut length as _, &mut size as _, &mut type_ as _, name.as_mut_ptr()); }
+        unsafe {self.fns().GetActiveUniform(program, index, 100, &mut length as _, &mut size as _, &mut type_ as _, name.as_mut_ptr()); }
 
         let name = String::from_utf8_lossy(&name[0..length as _]).into_owned();
 
@@ -679,40 +868,45 @@ impl GLWrapper {
     }
     
     pub fn glGetActiveUniformBlockName(&self, program: u32, uniformBlockIndex: u32) -> String {
+        trace!(self, "glGetActiveUniformBlockName", program, uniformBlockIndex);
         let mut uniformBlockName = [0u8; 100];
         let mut length = 0i32;
 
-        unsafe { self.fns.GetActiveUniformBlockName(program, uniformBlockIndex, 100, &mut length as _, uniformBlockName.as_mut_ptr()); }
+        unsafe { self.fns().GetActiveUniformBlockName(program, uniformBlockIndex, 100, &mut length as _, uniformBlockName.as_mut_ptr()); }
 
         String::from_utf8_lossy(&uniformBlockName[..length as _]).into_owned()
     }
     
     pub unsafe fn glGetActiveUniformBlockiv(&self, program: u32, uniformBlockIndex: u32, pname: UniformBlockPName, params: *mut i32) {
-        self.fns.GetActiveUniformBlockiv(program, uniformBlockIndex, GLenum(pname as u32), params)
+        trace!(self, "glGetActiveUniformBlockiv", program, uniformBlockIndex, pname, params);
+        self.fns().GetActiveUniformBlockiv(program, uniformBlockIndex, GLenum(pname as u32), params)
     }
     
     pub fn glGetActiveUniformName(&self, program: u32, uniformIndex: u32) -> String {
+        trace!(self, "glGetActiveUniformName", program, uniformIndex);
         let mut uniformName = [0u8; 100];
         let mut length = 0i32;
 
-        unsafe { self.fns.GetActiveUniformName(program, uniformIndex, 100, &mut length as _, uniformName.as_mut_ptr()); }
+        unsafe { self.fns().GetActiveUniformName(program, uniformIndex, 100, &mut length as _, uniformName.as_mut_ptr()); }
 
         String::from_utf8_lossy(&uniformName[..length as _]).into_owned()
     }
     
     pub fn glGetActiveUniformsiv(&self, program: u32, uniformIndices: &[u32], pname: UniformPName) -> Box<[i32]> {
+        trace!(self, "glGetActiveUniformsiv", program, uniformIndices, pname);
         let mut params = vec![0; uniformIndices.len()].into_boxed_slice();
 
-        unsafe { self.fns.GetActiveUniformsiv(program, uniformIndices.len() as _, uniformIndices.as_ptr() as _, GLenum(pname as u32), params.as_mut_ptr()) }
+        unsafe { self.fns().GetActiveUniformsiv(program, uniformIndices.len() as _, uniformIndices.as_ptr() as _, GLenum(pname as u32), params.as_mut_ptr()) }
 
         params
     }
     
     pub fn glGetAttachedShaders(&self, program: u32) -> Box<[u32]> {
+        trace!(self, "glGetAttachedShaders", program);
         let mut shaders = [0u32; 100];
         let mut count = 0i32;
 
-        unsafe { self.fns.GetAttachedShaders(program, 100, &mut count as _, shaders.as_mut_ptr()); }
+        unsafe { self.fns().GetAttachedShaders(program, 100, &mut count as _, shaders.as_mut_ptr()); }
 
         let mut shaders = shaders.to_vec();
         shaders.truncate(count as _);
@@ -721,138 +915,170 @@ impl GLWrapper {
     }
     
     pub fn glGetAttribLocation(&self, program: u32, name: &str) -> i32 {
+        trace!(self, "glGetAttribLocation", program, name);
         let null_str = CString::new(name).unwrap();
-        unsafe { self.fns.GetAttribLocation(program, null_str.as_ptr() as _) }
+        unsafe { self.fns().GetAttribLocation(program, null_str.as_ptr() as _) }
     }
     
     pub unsafe fn glGetBooleani_v(&self, target: BufferTargetARB, index: u32, data: *mut u8) {
-        self.fns.GetBooleani_v(GLenum(target as u32), index, data)
+        trace!(self, "glGetBooleani_v", target, index, data);
+        self.fns().GetBooleani_v(GLenum(target as u32), index, data)
     }
     
     pub unsafe fn glGetBooleanv(&self, pname: GetPName, data: *mut u8) {
-        self.fns.GetBooleanv(GLenum(pname as u32), data)
+        trace!(self, "glGetBooleanv", pname, data);
+        self.fns().GetBooleanv(GLenum(pname as u32), data)
     }
     
     pub unsafe fn glGetBufferParameteri64v(&self, target: BufferTargetARB, pname: BufferPNameARB, params: *mut i64) {
-        self.fns.GetBufferParameteri64v(GLenum(target as u32), GLenum(pname as u32), params)
+        trace!(self, "glGetBufferParameteri64v", target, pname, params);
+        self.fns().GetBufferParameteri64v(GLenum(target as u32), GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetBufferParameteriv(&self, target: BufferTargetARB, pname: BufferPNameARB, params: *mut i32) {
-        self.fns.GetBufferParameteriv(GLenum(target as u32), GLenum(pname as u32), params)
+        trace!(self, "glGetBufferParameteriv", target, pname, params);
+        self.fns().GetBufferParameteriv(GLenum(target as u32), GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetBufferPointerv(&self, target: BufferTargetARB, pname: BufferPointerNameARB, params: *mut *mut c_void) {
-        self.fns.GetBufferPointerv(GLenum(target as u32), GLenum(pname as u32), params)
+        trace!(self, "glGetBufferPointerv", target, pname, params);
+        self.fns().GetBufferPointerv(GLenum(target as u32), GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetBufferSubData(&self, target: BufferTargetARB, offset: isize, size: isize, data: *mut c_void) {
-        self.fns.GetBufferSubData(GLenum(target as u32), offset, size, data)
+        trace!(self, "glGetBufferSubData", target, offset, size, data);
+        self.fns().GetBufferSubData(GLenum(target as u32), offset, size, data)
     }
     
     pub unsafe fn glGetCompressedTexImage(&self, target: TextureTarget, level: i32, img: *mut c_void) {
-        self.fns.GetCompressedTexImage(GLenum(target as u32), level, img)
+        trace!(self, "glGetCompressedTexImage", target, level, img);
+        self.fns().GetCompressedTexImage(GLenum(target as u32), level, img)
     }
     
     pub unsafe fn glGetDoublev(&self, pname: GetPName, data: *mut f64) {
-        self.fns.GetDoublev(GLenum(pname as u32), data)
+        trace!(self, "glGetDoublev", pname, data);
+        self.fns().GetDoublev(GLenum(pname as u32), data)
     }
     
     pub unsafe fn glGetError(&self) -> ErrorCode {
-        self.fns.GetError().0.try_into().unwrap()
+        trace!(self, "glGetError");
+        self.fns().GetError().0.try_into().unwrap()
     }
     
     pub unsafe fn glGetFloatv(&self, pname: GetPName, data: *mut f32) {
-        self.fns.GetFloatv(GLenum(pname as u32), data)
+        trace!(self, "glGetFloatv", pname, data);
+        self.fns().GetFloatv(GLenum(pname as u32), data)
     }
     
     pub fn glGetFragDataIndex(&self, program: u32, name: &str) -> i32 {
+        trace!(self, "glGetFragDataIndex", program, name);
         let null_str = CString::new(name).unwrap();
-        unsafe { self.fns.GetFragDataIndex(program, null_str.as_ptr() as _) }
+        unsafe { self.fns().GetFragDataIndex(program, null_str.as_ptr() as _) }
     }
     
     pub fn glGetFragDataLocation(&self, program: u32, name: &str) -> i32 {
+        trace!(self, "glGetFragDataLocation", program, name);
         let null_str = CString::new(name).unwrap();
-        unsafe { self.fns.GetFragDataLocation(program, null_str.as_ptr() as _) }
+        unsafe { self.fns().GetFragDataLocation(program, null_str.as_ptr() as _) }
     }
     
     pub unsafe fn glGetFramebufferAttachmentParameteriv(&self, target: FramebufferTarget, attachment: FramebufferAttachment, pname: FramebufferAttachmentParameterName, params: *mut i32) {
-        self.fns.GetFramebufferAttachmentParameteriv(GLenum(target as u32), GLenum(attachment as u32), GLenum(pname as u32), params)
+        trace!(self, "glGetFramebufferAttachmentParameteriv", target, attachment, pname, params);
+        self.fns().GetFramebufferAttachmentParameteriv(GLenum(target as u32), GLenum(attachment as u32), GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetInteger64i_v(&self, target: GetPName, index: u32, data: *mut i64) {
-        self.fns.GetInteger64i_v(GLenum(target as u32), index, data)
+        trace!(self, "glGetInteger64i_v", target, index, data);
+        self.fns().GetInteger64i_v(GLenum(target as u32), index, data)
     }
     
     pub unsafe fn glGetInteger64v(&self, pname: GetPName, data: *mut i64) {
-        self.fns.GetInteger64v(GLenum(pname as u32), data)
+        trace!(self, "glGetInteger64v", pname, data);
+        self.fns().GetInteger64v(GLenum(pname as u32), data)
     }
     
     pub unsafe fn glGetIntegeri_v(&self, target: GetPName, index: u32, data: *mut i32) {
-        self.fns.GetIntegeri_v(GLenum(target as u32), index, data)
+        trace!(self, "glGetIntegeri_v", target, index, data);
+        self.fns().GetIntegeri_v(GLenum(target as u32), index, data)
     }
     
     pub unsafe fn glGetIntegerv(&self, pname: GetPName, data: *mut i32) {
-        self.fns.GetIntegerv(GLenum(pname as u32), data)
+        trace!(self, "glGetIntegerv", pname, data);
+        self.fns().GetIntegerv(GLenum(pname as u32), data)
     }
     
     pub unsafe fn glGetMultisamplefv(&self, pname: GetMultisamplePNameNV, index: u32, val: *mut f32) {
-        self.fns.GetMultisamplefv(GLenum(pname as u32), index, val)
+        trace!(self, "glGetMultisamplefv", pname, index, val);
+        self.fns().GetMultisamplefv(GLenum(pname as u32), index, val)
     }
     
     pub unsafe fn glGetProgramInfoLog(&self, program: u32, bufSize: i32, length: *mut i32, infoLog: *mut u8) {
-        self.fns.GetProgramInfoLog(program, bufSize, length, infoLog)
+        trace!(self, "glGetProgramInfoLog", program, bufSize, length, infoLog);
+        self.fns().GetProgramInfoLog(program, bufSize, length, infoLog)
     }
     
     pub unsafe fn glGetProgramiv(&self, program: u32, pname: ProgramPropertyARB, params: *mut i32) {
-        self.fns.GetProgramiv(program, GLenum(pname as u32), params)
+        trace!(self, "glGetProgramiv", program, pname, params);
+        self.fns().GetProgramiv(program, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetQueryObjecti64v(&self, id: u32, pname: QueryObjectParameterName, params: *mut i64) {
-        self.fns.GetQueryObjecti64v(id, GLenum(pname as u32), params)
+        trace!(self, "glGetQueryObjecti64v", id, pname, params);
+        self.fns().GetQueryObjecti64v(id, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetQueryObjectiv(&self, id: u32, pname: QueryObjectParameterName, params: *mut i32) {
-        self.fns.GetQueryObjectiv(id, GLenum(pname as u32), params)
+        trace!(self, "glGetQueryObjectiv", id, pname, params);
+        self.fns().GetQueryObjectiv(id, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetQueryObjectui64v(&self, id: u32, pname: QueryObjectParameterName, params: *mut u64) {
-        self.fns.GetQueryObjectui64v(id, GLenum(pname as u32), params)
+        trace!(self, "glGetQueryObjectui64v", id, pname, params);
+        self.fns().GetQueryObjectui64v(id, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetQueryObjectuiv(&self, id: u32, pname: QueryObjectParameterName, params: *mut u32) {
-        self.fns.GetQueryObjectuiv(id, GLenum(pname as u32), params)
+        trace!(self, "glGetQueryObjectuiv", id, pname, params);
+        self.fns().GetQueryObjectuiv(id, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetQueryiv(&self, target: QueryTarget, pname: QueryParameterName, params: *mut i32) {
-        self.fns.GetQueryiv(GLenum(target as u32), GLenum(pname as u32), params)
+        trace!(self, "glGetQueryiv", target, pname, params);
+        self.fns().GetQueryiv(GLenum(target as u32), GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetRenderbufferParameteriv(&self, target: RenderbufferTarget, pname: RenderbufferParameterName, params: *mut i32) {
-        self.fns.GetRenderbufferParameteriv(GLenum(target as u32), GLenum(pname as u32), params)
+        trace!(self, "glGetRenderbufferParameteriv", target, pname, params);
+        self.fns().GetRenderbufferParameteriv(GLenum(target as u32), GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetSamplerParameterIiv(&self, sampler: u32, pname: SamplerParameterI, params: *mut i32) {
-        self.fns.GetSamplerParameterIiv(sampler, GLenum(pname as u32), params)
+        trace!(self, "glGetSamplerParameterIiv", sampler, pname, params);
+        self.fns().GetSamplerParameterIiv(sampler, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetSamplerParameterIuiv(&self, sampler: u32, pname: SamplerParameterI, params: *mut u32) {
-        self.fns.GetSamplerParameterIuiv(sampler, GLenum(pname as u32), params)
+        trace!(self, "glGetSamplerParameterIuiv", sampler, pname, params);
+        self.fns().GetSamplerParameterIuiv(sampler, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetSamplerParameterfv(&self, sampler: u32, pname: SamplerParameterF, params: *mut f32) {
-        self.fns.GetSamplerParameterfv(sampler, GLenum(pname as u32), params)
+        trace!(self, "glGetSamplerParameterfv", sampler, pname, params);
+        self.fns().GetSamplerParameterfv(sampler, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetSamplerParameteriv(&self, sampler: u32, pname: SamplerParameterI, params: *mut i32) {
-        self.fns.GetSamplerParameteriv(sampler, GLenum(pname as u32), params)
+        trace!(self, "glGetSamplerParameteriv", sampler, pname, params);
+        self.fns().GetSamplerParameteriv(sampler, GLenum(pname as u32), params)
     }
     
     pub fn glGetShaderInfoLog(&self, shader: u32) -> String {
+        trace!(self, "glGetShaderInfoLog", shader);
         let mut length = 0;
         self.glGetShaderiv(shader, ShaderParameterName::GL_INFO_LOG_LENGTH, &mut length);
 
         let mut buffer = vec![0u8; length as usize];
-        unsafe { self.fns.GetShaderInfoLog(shader, length, std::ptr::null_mut(), buffer.as_mut_ptr()) }
+        unsafe { self.fns().GetShaderInfoLog(shader, length, std::ptr::null_mut(), buffer.as_mut_ptr()) }
         // Trim null terminator and 2 newline u8acters
         buffer.truncate(buffer.len() - 3);
 
@@ -860,15 +1086,18 @@ impl GLWrapper {
     }
     
     pub unsafe fn glGetShaderSource(&self, shader: u32, bufSize: i32, length: *mut i32, source: *mut u8) {
-        self.fns.GetShaderSource(shader, bufSize, length, source)
+        trace!(self, "glGetShaderSource", shader, bufSize, length, source);
+        self.fns().GetShaderSource(shader, bufSize, length, source)
     }
     
     pub fn glGetShaderiv(&self, shader: u32, pname: ShaderParameterName, params: &mut i32) {
-        unsafe { self.fns.GetShaderiv(shader, GLenum(pname as u32), params) }
+        trace!(self, "glGetShaderiv", shader, pname);
+        unsafe { self.fns().GetShaderiv(shader, GLenum(pname as u32), params) }
     }
     
     pub unsafe fn glGetString(&self, name: StringName) -> String {
-        let raw_str = self.fns.GetString(GLenum(name as u32));
+        trace!(self, "glGetString", name);
+        let raw_str = self.fns().GetString(GLenum(name as u32));
 
         unsafe {
             let len = libc::strlen(raw_str as _);
@@ -878,7 +1107,8 @@ impl GLWrapper {
     }
     
     pub unsafe fn glGetStringi(&self, name: StringName, index: u32) -> String {
-        let raw_str = self.fns.GetStringi(GLenum(name as u32), index);
+        trace!(self, "glGetStringi", name, index);
+        let raw_str = self.fns().GetStringi(GLenum(name as u32), index);
 
         unsafe {
             let len = libc::strlen(raw_str as _);
@@ -888,977 +1118,1214 @@ impl GLWrapper {
     }
     
     pub unsafe fn glGetSynciv(&self, sync: GLsync, pname: SyncParameterName, count: i32, length: *mut i32, values: *mut i32) {
-        self.fns.GetSynciv(sync, GLenum(pname as u32), count, length, values)
+        trace!(self, "glGetSynciv", sync, pname, count, length, values);
+        self.fns().GetSynciv(sync, GLenum(pname as u32), count, length, values)
     }
     
     pub unsafe fn glGetTexImage(&self, target: TextureTarget, level: i32, format: PixelFormat, type_: PixelType, pixels: *mut c_void) {
-        self.fns.GetTexImage(GLenum(target as u32), level, GLenum(format as u32), GLenum(type_ as u32), pixels)
+        trace!(self, "glGetTexImage", target, level, format, type_, pixels);
+        self.fns().GetTexImage(GLenum(target as u32), level, GLenum(format as u32), GLenum(type_ as u32), pixels)
     }
     
     pub unsafe fn glGetTexLevelParameterfv(&self, target: TextureTarget, level: i32, pname: GetTextureParameter, params: *mut f32) {
-        self.fns.GetTexLevelParameterfv(GLenum(target as u32), level, GLenum(pname as u32), params)
+        trace!(self, "glGetTexLevelParameterfv", target, level, pname, params);
+        self.fns().GetTexLevelParameterfv(GLenum(target as u32), level, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetTexLevelParameteriv(&self, target: TextureTarget, level: i32, pname: GetTextureParameter, params: *mut i32) {
-        self.fns.GetTexLevelParameteriv(GLenum(target as u32), level, GLenum(pname as u32), params)
+        trace!(self, "glGetTexLevelParameteriv", target, level, pname, params);
+        self.fns().GetTexLevelParameteriv(GLenum(target as u32), level, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetTexParameterIiv(&self, target: TextureTarget, pname: GetTextureParameter, params: *mut i32) {
-        self.fns.GetTexParameterIiv(GLenum(target as u32), GLenum(pname as u32), params)
+        trace!(self, "glGetTexParameterIiv", target, pname, params);
+        self.fns().GetTexParameterIiv(GLenum(target as u32), GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetTexParameterIuiv(&self, target: TextureTarget, pname: GetTextureParameter, params: *mut u32) {
-        self.fns.GetTexParameterIuiv(GLenum(target as u32), GLenum(pname as u32), params)
+        trace!(self, "glGetTexParameterIuiv", target, pname, params);
+        self.fns().GetTexParameterIuiv(GLenum(target as u32), GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetTexParameterfv(&self, target: TextureTarget, pname: GetTextureParameter, params: *mut f32) {
-        self.fns.GetTexParameterfv(GLenum(target as u32), GLenum(pname as u32), params)
+        trace!(self, "glGetTexParameterfv", target, pname, params);
+        self.fns().GetTexParameterfv(GLenum(target as u32), GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetTexParameteriv(&self, target: TextureTarget, pname: GetTextureParameter, params: *mut i32) {
-        self.fns.GetTexParameteriv(GLenum(target as u32), GLenum(pname as u32), params)
+        trace!(self, "glGetTexParameteriv", target, pname, params);
+        self.fns().GetTexParameteriv(GLenum(target as u32), GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetTransformFeedbackVarying(&self, program: u32, index: u32, bufSize: i32, length: *mut i32, size: *mut i32, type_: *mut AttributeType, name: *mut u8) {
-        self.fns.GetTransformFeedbackVarying(program, index, bufSize, length, size, type_ as _, name)
+        trace!(self, "glGetTransformFeedbackVarying", program, index, bufSize, length, size, type_, name);
+        self.fns().GetTransformFeedbackVarying(program, index, bufSize, length, size, type_ as _, name)
     }
     
     pub fn glGetUniformBlockIndex(&self, program: u32, uniformBlockName: &str) -> u32 {
+        trace!(self, "glGetUniformBlockIndex", program, uniformBlockName);
         let null_str = CString::new(uniformBlockName).unwrap();
-        unsafe { self.fns.GetUniformBlockIndex(program, null_str.as_ptr() as _) }
+        unsafe { self.fns().GetUniformBlockIndex(program, null_str.as_ptr() as _) }
     }
     
     // NEEDS TESTING
     pub fn glGetUniformIndices(&self, program: u32, uniformNames: &[&str], uniformIndices: &mut [u32]) {
+        trace!(self, "glGetUniformIndices", program, uniformNames, uniformIndices.len());
         let uniformNames = CStringArray::new(uniformNames);
-        unsafe { self.fns.GetUniformIndices(program, uniformNames.len() as _, uniformNames.as_ptr(), uniformIndices.as_mut_ptr()) }
+        unsafe { self.fns().GetUniformIndices(program, uniformNames.len() as _, uniformNames.as_ptr(), uniformIndices.as_mut_ptr()) }
     }
     
     pub fn glGetUniformLocation(&self, program: u32, name: &str) -> GlUniformLocation {
+        trace!(self, "glGetUniformLocation", program, name);
         let null_str = CString::new(name).unwrap();
-        GlUniformLocation(unsafe { self.fns.GetUniformLocation(program, null_str.as_ptr() as *const  _) })
+        let location = GlUniformLocation(unsafe { self.fns().GetUniformLocation(program, null_str.as_ptr() as *const  _) });
+        trace_output!(self, location);
+        location
     }
     
     pub unsafe fn glGetUniformfv(&self, program: u32, location: i32, params: *mut f32) {
-        self.fns.GetUniformfv(program, location, params)
+        trace!(self, "glGetUniformfv", program, location, params);
+        self.fns().GetUniformfv(program, location, params)
     }
     
     pub unsafe fn glGetUniformiv(&self, program: u32, location: i32, params: *mut i32) {
-        self.fns.GetUniformiv(program, location, params)
+        trace!(self, "glGetUniformiv", program, location, params);
+        self.fns().GetUniformiv(program, location, params)
     }
     
     pub unsafe fn glGetUniformuiv(&self, program: u32, location: i32, params: *mut u32) {
-        self.fns.GetUniformuiv(program, location, params)
+        trace!(self, "glGetUniformuiv", program, location, params);
+        self.fns().GetUniformuiv(program, location, params)
     }
     
     pub unsafe fn glGetVertexAttribIiv(&self, index: u32, pname: VertexAttribEnum, params: *mut i32) {
-        self.fns.GetVertexAttribIiv(index, GLenum(pname as u32), params)
+        trace!(self, "glGetVertexAttribIiv", index, pname, params);
+        self.fns().GetVertexAttribIiv(index, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetVertexAttribIuiv(&self, index: u32, pname: VertexAttribEnum, params: *mut u32) {
-        self.fns.GetVertexAttribIuiv(index, GLenum(pname as u32), params)
+        trace!(self, "glGetVertexAttribIuiv", index, pname, params);
+        self.fns().GetVertexAttribIuiv(index, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetVertexAttribPointerv(&self, index: u32, pname: VertexAttribPointerPropertyARB, pointer: *mut *mut c_void) {
-        self.fns.GetVertexAttribPointerv(index, GLenum(pname as u32), pointer)
+        trace!(self, "glGetVertexAttribPointerv", index, pname, pointer);
+        self.fns().GetVertexAttribPointerv(index, GLenum(pname as u32), pointer)
     }
     
     pub unsafe fn glGetVertexAttribdv(&self, index: u32, pname: VertexAttribPropertyARB, params: *mut[f64; 4]) {
-        self.fns.GetVertexAttribdv(index, GLenum(pname as u32), params)
+        trace!(self, "glGetVertexAttribdv", index, pname, params);
+        self.fns().GetVertexAttribdv(index, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetVertexAttribfv(&self, index: u32, pname: VertexAttribPropertyARB, params: *mut[f32; 4]) {
-        self.fns.GetVertexAttribfv(index, GLenum(pname as u32), params)
+        trace!(self, "glGetVertexAttribfv", index, pname, params);
+        self.fns().GetVertexAttribfv(index, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glGetVertexAttribiv(&self, index: u32, pname: VertexAttribPropertyARB, params: *mut[i32; 4]) {
-        self.fns.GetVertexAttribiv(index, GLenum(pname as u32), params)
+        trace!(self, "glGetVertexAttribiv", index, pname, params);
+        self.fns().GetVertexAttribiv(index, GLenum(pname as u32), params)
     }
     
     pub unsafe fn glHint(&self, target: HintTarget, mode: HintMode) {
-        self.fns.Hint(GLenum(target as u32), GLenum(mode as u32))
+        trace!(self, "glHint", target, mode);
+        self.fns().Hint(GLenum(target as u32), GLenum(mode as u32))
     }
     
     pub unsafe fn glIsBuffer(&self, buffer: u32) -> u8 {
-        self.fns.IsBuffer(buffer)
+        trace!(self, "glIsBuffer", buffer);
+        self.fns().IsBuffer(buffer)
     }
     
     pub unsafe fn glIsEnabled(&self, cap: EnableCap) -> u8 {
-        self.fns.IsEnabled(GLenum(cap as u32))
+        trace!(self, "glIsEnabled", cap);
+        self.fns().IsEnabled(GLenum(cap as u32))
     }
     
     pub unsafe fn glIsEnabledi(&self, target: EnableCap, index: u32) -> u8 {
-        self.fns.IsEnabledi(GLenum(target as u32), index)
+        trace!(self, "glIsEnabledi", target, index);
+        self.fns().IsEnabledi(GLenum(target as u32), index)
     }
     
     pub unsafe fn glIsFramebuffer(&self, framebuffer: u32) -> u8 {
-        self.fns.IsFramebuffer(framebuffer)
+        trace!(self, "glIsFramebuffer", framebuffer);
+        self.fns().IsFramebuffer(framebuffer)
     }
     
     pub unsafe fn glIsProgram(&self, program: u32) -> u8 {
-        self.fns.IsProgram(program)
+        trace!(self, "glIsProgram", program);
+        self.fns().IsProgram(program)
     }
     
     pub unsafe fn glIsQuery(&self, id: u32) -> u8 {
-        self.fns.IsQuery(id)
+        trace!(self, "glIsQuery", id);
+        self.fns().IsQuery(id)
     }
     
     pub unsafe fn glIsRenderbuffer(&self, renderbuffer: u32) -> u8 {
-        self.fns.IsRenderbuffer(renderbuffer)
+        trace!(self, "glIsRenderbuffer", renderbuffer);
+        self.fns().IsRenderbuffer(renderbuffer)
     }
     
     pub unsafe fn glIsSampler(&self, sampler: u32) -> u8 {
-        self.fns.IsSampler(sampler)
+        trace!(self, "glIsSampler", sampler);
+        self.fns().IsSampler(sampler)
     }
     
     pub unsafe fn glIsShader(&self, shader: u32) -> u8 {
-        self.fns.IsShader(shader)
+        trace!(self, "glIsShader", shader);
+        self.fns().IsShader(shader)
     }
     
     pub unsafe fn glIsSync(&self, sync: GLsync) -> u8 {
-        self.fns.IsSync(sync)
+        trace!(self, "glIsSync", sync);
+        self.fns().IsSync(sync)
     }
     
     pub unsafe fn glIsTexture(&self, texture: u32) -> u8 {
-        self.fns.IsTexture(texture)
+        trace!(self, "glIsTexture", texture);
+        self.fns().IsTexture(texture)
     }
     
     pub unsafe fn glIsVertexArray(&self, array: u32) -> u8 {
-        self.fns.IsVertexArray(array)
+        trace!(self, "glIsVertexArray", array);
+        self.fns().IsVertexArray(array)
     }
     
     pub unsafe fn glLineWidth(&self, width: f32) {
-        self.fns.LineWidth(width as _)
+        trace!(self, "glLineWidth", width);
+        self.fns().LineWidth(width as _)
     }
     
     pub fn glLinkProgram(&self, program: u32) {
-        self.fns.LinkProgram(program)
+        trace!(self, "glLinkProgram", program);
+        self.fns().LinkProgram(program)
     }
     
     pub unsafe fn glLogicOp(&self, opcode: LogicOp) {
-        self.fns.LogicOp(GLenum(opcode as u32))
+        trace!(self, "glLogicOp", opcode);
+        self.fns().LogicOp(GLenum(opcode as u32))
     }
     
     pub unsafe fn glMapBuffer(&self, target: BufferTargetARB, access: BufferAccessARB) -> *mut c_void {
-        self.fns.MapBuffer(GLenum(target as u32), GLenum(access as u32))
+        trace!(self, "glMapBuffer", target, access);
+        self.fns().MapBuffer(GLenum(target as u32), GLenum(access as u32))
     }
     
     pub unsafe fn glMapBufferRange(&self, target: BufferTargetARB, offset: isize, length: isize, access: GLbitfield) -> *mut c_void {
-        self.fns.MapBufferRange(GLenum(target as u32), offset, length, access)
+        trace!(self, "glMapBufferRange", target, offset, length, access);
+        self.fns().MapBufferRange(GLenum(target as u32), offset, length, access)
     }
     
     pub unsafe fn glMultiDrawArrays(&self, mode: PrimitiveType, first: *const i32, count: *const i32, drawcount: i32) {
-        self.fns.MultiDrawArrays(GLenum(mode as u32), first, count, drawcount)
+        trace!(self, "glMultiDrawArrays", mode, first, count, drawcount);
+        self.fns().MultiDrawArrays(GLenum(mode as u32), first, count, drawcount)
     }
     
     pub unsafe fn glMultiDrawElements(&self, mode: PrimitiveType, count: *const i32, type_: DrawElementsType, indices: *const *const c_void, drawcount: i32) {
-        self.fns.MultiDrawElements(GLenum(mode as u32), count, GLenum(type_ as u32), indices, drawcount)
+        trace!(self, "glMultiDrawElements", mode, count, type_, indices, drawcount);
+        self.fns().MultiDrawElements(GLenum(mode as u32), count, GLenum(type_ as u32), indices, drawcount)
     }
     
     pub unsafe fn glMultiDrawElementsBaseVertex(&self, mode: PrimitiveType, count: *const i32, type_: DrawElementsType, indices: *const *const c_void, drawcount: i32, basevertex: *const i32) {
-        self.fns.MultiDrawElementsBaseVertex(GLenum(mode as u32), count, GLenum(type_ as u32), indices, drawcount, basevertex)
+        trace!(self, "glMultiDrawElementsBaseVertex", mode, count, type_, indices, drawcount, basevertex);
+        self.fns().MultiDrawElementsBaseVertex(GLenum(mode as u32), count, GLenum(type_ as u32), indices, drawcount, basevertex)
     }
     
     pub unsafe fn glPixelStoref(&self, pname: PixelStoreParameter, param: f32) {
-        self.fns.PixelStoref(GLenum(pname as u32), param)
+        trace!(self, "glPixelStoref", pname, param);
+        self.fns().PixelStoref(GLenum(pname as u32), param)
     }
     
     pub unsafe fn glPixelStorei(&self, pname: PixelStoreParameter, param: i32) {
-        self.fns.PixelStorei(GLenum(pname as u32), param)
+        trace!(self, "glPixelStorei", pname, param);
+        self.fns().PixelStorei(GLenum(pname as u32), param)
     }
     
     pub unsafe fn glPointParameterf(&self, pname: PointParameterNameARB, param: f32) {
-        self.fns.PointParameterf(GLenum(pname as u32), param)
+        trace!(self, "glPointParameterf", pname, param);
+        self.fns().PointParameterf(GLenum(pname as u32), param)
     }
     
     pub unsafe fn glPointParameterfv(&self, pname: PointParameterNameARB, params: *const f32) {
-        self.fns.PointParameterfv(GLenum(pname as u32), params)
+        trace!(self, "glPointParameterfv", pname, params);
+        self.fns().PointParameterfv(GLenum(pname as u32), params)
     }
     
     pub unsafe fn glPointParameteri(&self, pname: PointParameterNameARB, param: i32) {
-        self.fns.PointParameteri(GLenum(pname as u32), param)
+        trace!(self, "glPointParameteri", pname, param);
+        self.fns().PointParameteri(GLenum(pname as u32), param)
     }
     
     pub unsafe fn glPointParameteriv(&self, pname: PointParameterNameARB, params: *const i32) {
-        self.fns.PointParameteriv(GLenum(pname as u32), params)
+        trace!(self, "glPointParameteriv", pname, params);
+        self.fns().PointParameteriv(GLenum(pname as u32), params)
     }
     
     pub fn glPointSize(&self, size: f32) {
-        self.fns.PointSize(size)
+        trace!(self, "glPointSize", size);
+        self.fns().PointSize(size)
     }
     
     pub unsafe fn glPolygonMode(&self, face: MaterialFace, mode: PolygonMode) {
-        self.fns.PolygonMode(face, GLenum(mode as u32))
+        trace!(self, "glPolygonMode", face, mode);
+        self.fns().PolygonMode(face, GLenum(mode as u32))
     }
     
     pub unsafe fn glPolygonOffset(&self, factor: f32, units: f32) {
-        self.fns.PolygonOffset(factor, units)
+        trace!(self, "glPolygonOffset", factor, units);
+        self.fns().PolygonOffset(factor, units)
     }
     
     pub unsafe fn glPrimitiveRestartIndex(&self, index: u32) {
-        self.fns.PrimitiveRestartIndex(index)
+        trace!(self, "glPrimitiveRestartIndex", index);
+        self.fns().PrimitiveRestartIndex(index)
     }
     
     pub unsafe fn glProvokingVertex(&self, mode: VertexProvokingMode) {
-        self.fns.ProvokingVertex(GLenum(mode as u32))
+        trace!(self, "glProvokingVertex", mode);
+        self.fns().ProvokingVertex(GLenum(mode as u32))
     }
     
     pub unsafe fn glQueryCounter(&self, id: u32, target: QueryCounterTarget) {
-        self.fns.QueryCounter(id, GLenum(target as u32))
+        trace!(self, "glQueryCounter", id, target);
+        self.fns().QueryCounter(id, GLenum(target as u32))
     }
     
     pub unsafe fn glReadBuffer(&self, src: ReadBufferMode) {
-        self.fns.ReadBuffer(GLenum(src as u32))
+        trace!(self, "glReadBuffer", src);
+        self.fns().ReadBuffer(GLenum(src as u32))
     }
     
     pub unsafe fn glReadPixels(&self, x: i32, y: i32, width: u32, height: u32, format: PixelFormat, type_: PixelType, pixels: *mut c_void) {
-        self.fns.ReadPixels(x, y, width as _, height as _, GLenum(format as u32), GLenum(type_ as u32), pixels)
+        trace!(self, "glReadPixels", x, y, width, height, format, type_, pixels);
+        self.fns().ReadPixels(x, y, width as _, height as _, GLenum(format as u32), GLenum(type_ as u32), pixels)
     }
     
     pub unsafe fn glRenderbufferStorage(&self, target: RenderbufferTarget, internalformat: InternalFormat, width: u32, height: u32) {
-        self.fns.RenderbufferStorage(GLenum(target as u32), GLenum(internalformat as u32), width as _, height as _)
+        trace!(self, "glRenderbufferStorage", target, internalformat, width, height);
+        self.fns().RenderbufferStorage(GLenum(target as u32), GLenum(internalformat as u32), width as _, height as _)
     }
     
     pub unsafe fn glRenderbufferStorageMultisample(&self, target: RenderbufferTarget, samples: i32, internalformat: InternalFormat, width: u32, height: u32) {
-        self.fns.RenderbufferStorageMultisample(GLenum(target as u32), samples, GLenum(internalformat as u32), width as _, height as _)
+        trace!(self, "glRenderbufferStorageMultisample", target, samples, internalformat, width, height);
+        self.fns().RenderbufferStorageMultisample(GLenum(target as u32), samples, GLenum(internalformat as u32), width as _, height as _)
     }
     
     pub unsafe fn glSampleCoverage(&self, value: f32, invert: u8) {
-        self.fns.SampleCoverage(value, invert)
+        trace!(self, "glSampleCoverage", value, invert);
+        self.fns().SampleCoverage(value, invert)
     }
     
     pub unsafe fn glSampleMaski(&self, maskNumber: u32, mask: GLbitfield) {
-        self.fns.SampleMaski(maskNumber, mask)
+        trace!(self, "glSampleMaski", maskNumber, mask);
+        self.fns().SampleMaski(maskNumber, mask)
     }
     
     pub unsafe fn glSamplerParameterIiv(&self, sampler: u32, pname: SamplerParameterI, param: *const i32) {
-        self.fns.SamplerParameterIiv(sampler, GLenum(pname as u32), param)
+        trace!(self, "glSamplerParameterIiv", sampler, pname, param);
+        self.fns().SamplerParameterIiv(sampler, GLenum(pname as u32), param)
     }
     
     pub unsafe fn glSamplerParameterIuiv(&self, sampler: u32, pname: SamplerParameterI, param: *const u32) {
-        self.fns.SamplerParameterIuiv(sampler, GLenum(pname as u32), param)
+        trace!(self, "glSamplerParameterIuiv", sampler, pname, param);
+        self.fns().SamplerParameterIuiv(sampler, GLenum(pname as u32), param)
     }
     
     pub unsafe fn glSamplerParameterf(&self, sampler: u32, pname: SamplerParameterF, param: f32) {
-        self.fns.SamplerParameterf(sampler, GLenum(pname as u32), param)
+        trace!(self, "glSamplerParameterf", sampler, pname, param);
+        self.fns().SamplerParameterf(sampler, GLenum(pname as u32), param)
     }
     
     pub unsafe fn glSamplerParameterfv(&self, sampler: u32, pname: SamplerParameterF, param: *const f32) {
-        self.fns.SamplerParameterfv(sampler, GLenum(pname as u32), param)
+        trace!(self, "glSamplerParameterfv", sampler, pname, param);
+        self.fns().SamplerParameterfv(sampler, GLenum(pname as u32), param)
     }
     
     pub unsafe fn glSamplerParameteri(&self, sampler: u32, pname: SamplerParameterI, param: i32) {
-        self.fns.SamplerParameteri(sampler, GLenum(pname as u32), param)
+        trace!(self, "glSamplerParameteri", sampler, pname, param);
+        self.fns().SamplerParameteri(sampler, GLenum(pname as u32), param)
     }
     
     pub unsafe fn glSamplerParameteriv(&self, sampler: u32, pname: SamplerParameterI, param: *const i32) {
-        self.fns.SamplerParameteriv(sampler, GLenum(pname as u32), param)
+        trace!(self, "glSamplerParameteriv", sampler, pname, param);
+        self.fns().SamplerParameteriv(sampler, GLenum(pname as u32), param)
     }
     
     pub unsafe fn glScissor(&self, x: i32, y: i32, width: u32, height: u32) {
-        self.fns.Scissor(x, y, width as _, height as _)
+        trace!(self, "glScissor", x, y, width, height);
+        self.fns().Scissor(x, y, width as _, height as _)
     }
     
     // NEEDS TESTING
     pub fn glShaderSource(&self, shader: u32, string: &str) {
+        trace!(self, "glShaderSource", shader, string);
         let string_pointer = string.as_ptr();
         let len = string.len() as i32;
         
-        unsafe { self.fns.ShaderSource(shader, 1, &string_pointer, &len) }
+        unsafe { self.fns().ShaderSource(shader, 1, &string_pointer, &len) }
     }
     
     pub unsafe fn glStencilFunc(&self, func: StencilFunction, ref_: i32, mask: u32) {
-        self.fns.StencilFunc(GLenum(func as u32), ref_, mask)
+        trace!(self, "glStencilFunc", func, ref_, mask);
+        self.fns().StencilFunc(GLenum(func as u32), ref_, mask)
     }
     
     pub unsafe fn glStencilFuncSeparate(&self, face: StencilFaceDirection, func: StencilFunction, ref_: i32, mask: u32) {
-        self.fns.StencilFuncSeparate(face, GLenum(func as u32), ref_, mask)
+        trace!(self, "glStencilFuncSeparate", face, func, ref_, mask);
+        self.fns().StencilFuncSeparate(face, GLenum(func as u32), ref_, mask)
     }
     
     pub unsafe fn glStencilMask(&self, mask: u32) {
-        self.fns.StencilMask(mask)
+        trace!(self, "glStencilMask", mask);
+        self.fns().StencilMask(mask)
     }
     
     pub unsafe fn glStencilMaskSeparate(&self, face: StencilFaceDirection, mask: u32) {
-        self.fns.StencilMaskSeparate(face, mask)
+        trace!(self, "glStencilMaskSeparate", face, mask);
+        self.fns().StencilMaskSeparate(face, mask)
     }
     
     pub unsafe fn glStencilOp(&self, fail: StencilOp, zfail: StencilOp, zpass: StencilOp) {
-        self.fns.StencilOp(GLenum(fail as u32), GLenum(zfail as u32), GLenum(zpass as u32))
+        trace!(self, "glStencilOp", fail, zfail, zpass);
+        self.fns().StencilOp(GLenum(fail as u32), GLenum(zfail as u32), GLenum(zpass as u32))
     }
     
     pub unsafe fn glStencilOpSeparate(&self, face: StencilFaceDirection, sfail: StencilOp, dpfail: StencilOp, dppass: StencilOp) {
-        self.fns.StencilOpSeparate(face, GLenum(sfail as u32), GLenum(dpfail as u32), GLenum(dppass as u32))
+        trace!(self, "glStencilOpSeparate", face, sfail, dpfail, dppass);
+        self.fns().StencilOpSeparate(face, GLenum(sfail as u32), GLenum(dpfail as u32), GLenum(dppass as u32))
     }
     
     pub unsafe fn glTexBuffer(&self, target: TextureTarget, internalformat: InternalFormat, buffer: u32) {
-        self.fns.TexBuffer(GLenum(target as u32), GLenum(internalformat as u32), buffer)
+        trace!(self, "glTexBuffer", target, internalformat, buffer);
+        self.fns().TexBuffer(GLenum(target as u32), GLenum(internalformat as u32), buffer)
     }
     
     pub unsafe fn glTexImage1D(&self, target: TextureTarget, level: i32, internalformat: InternalFormat, width: u32, border: i32, format: PixelFormat, type_: PixelType, pixels: *const c_void) {
-        self.fns.TexImage1D(GLenum(target as u32), level, internalformat as u32 as i32, width as _, border, GLenum(format as u32), GLenum(type_ as u32), pixels)
+        trace!(self, "glTexImage1D", target, level, internalformat, width, border, format, type_, pixels);
+        self.fns().TexImage1D(GLenum(target as u32), level, internalformat as u32 as i32, width as _, border, GLenum(format as u32), GLenum(type_ as u32), pixels)
     }
     
     pub unsafe fn glTexImage2D(&self, target: TextureTarget, level: i32, internalformat: InternalFormat, width: u32, height: u32, border: i32, format: PixelFormat, type_: PixelType, pixels: &[u8]) {
-        self.fns.TexImage2D(GLenum(target as u32), level, internalformat as u32 as i32, width as _, height as _, border, GLenum(format as u32), GLenum(type_ as u32), pixels.as_ptr() as _)
+        trace!(self, "glTexImage2D", target, level, internalformat, width, height, border, format, type_, TraceValue::bytes(pixels));
+        self.fns().TexImage2D(GLenum(target as u32), level, internalformat as u32 as i32, width as _, height as _, border, GLenum(format as u32), GLenum(type_ as u32), pixels.as_ptr() as _)
     }
     
     pub unsafe fn glTexImage2DMultisample(&self, target: TextureTarget, samples: i32, internalformat: InternalFormat, width: u32, height: u32, fixedsamplelocations: u8) {
-        self.fns.TexImage2DMultisample(GLenum(target as u32), samples, GLenum(internalformat as u32), width as _, height as _, fixedsamplelocations)
+        trace!(self, "glTexImage2DMultisample", target, samples, internalformat, width, height, fixedsamplelocations);
+        self.fns().TexImage2DMultisample(GLenum(target as u32), samples, GLenum(internalformat as u32), width as _, height as _, fixedsamplelocations)
     }
     
     pub unsafe fn glTexImage3D(&self, target: TextureTarget, level: i32, internalformat: InternalFormat, width: u32, height: u32, depth: u32, border: i32, format: PixelFormat, type_: PixelType, pixels: *const c_void) {
-        self.fns.TexImage3D(GLenum(target as u32), level, internalformat as u32 as i32, width as _, height as _, depth as _, border, GLenum(format as u32), GLenum(type_ as u32), pixels)
+        trace!(self, "glTexImage3D", target, level, internalformat, width, height, depth, border, format, type_, pixels);
+        self.fns().TexImage3D(GLenum(target as u32), level, internalformat as u32 as i32, width as _, height as _, depth as _, border, GLenum(format as u32), GLenum(type_ as u32), pixels)
     }
     
     pub unsafe fn glTexImage3DMultisample(&self, target: TextureTarget, samples: i32, internalformat: InternalFormat, width: u32, height: u32, depth: u32, fixedsamplelocations: u8) {
-        self.fns.TexImage3DMultisample(GLenum(target as u32), samples, GLenum(internalformat as u32), width as _, height as _, depth as _, fixedsamplelocations)
+        trace!(self, "glTexImage3DMultisample", target, samples, internalformat, width, height, depth, fixedsamplelocations);
+        self.fns().TexImage3DMultisample(GLenum(target as u32), samples, GLenum(internalformat as u32), width as _, height as _, depth as _, fixedsamplelocations)
     }
     
     pub unsafe fn glTexParameterIiv(&self, target: TextureTarget, pname: TextureParameterName, params: *const i32) {
-        self.fns.TexParameterIiv(GLenum(target as u32), GLenum(pname as u32), params)
+        trace!(self, "glTexParameterIiv", target, pname, params);
+        self.fns().TexParameterIiv(GLenum(target as u32), GLenum(pname as u32), params)
     }
     
     pub unsafe fn glTexParameterIuiv(&self, target: TextureTarget, pname: TextureParameterName, params: *const u32) {
-        self.fns.TexParameterIuiv(GLenum(target as u32), GLenum(pname as u32), params)
+        trace!(self, "glTexParameterIuiv", target, pname, params);
+        self.fns().TexParameterIuiv(GLenum(target as u32), GLenum(pname as u32), params)
     }
     
     pub unsafe fn glTexParameterf(&self, target: TextureTarget, pname: TextureParameterName, param: f32) {
-        self.fns.TexParameterf(GLenum(target as u32), GLenum(pname as u32), param)
+        trace!(self, "glTexParameterf", target, pname, param);
+        self.fns().TexParameterf(GLenum(target as u32), GLenum(pname as u32), param)
     }
     
     pub unsafe fn glTexParameterfv(&self, target: TextureTarget, pname: TextureParameterName, params: *const f32) {
-        self.fns.TexParameterfv(GLenum(target as u32), GLenum(pname as u32), params)
+        trace!(self, "glTexParameterfv", target, pname, params);
+        self.fns().TexParameterfv(GLenum(target as u32), GLenum(pname as u32), params)
     }
     
     pub fn glTexParameteri(&self, target: TextureTarget, pname: TextureParameterName, param: GLenum) {
-        unsafe { self.fns.TexParameteri(GLenum(target as u32), GLenum(pname as u32), param.0 as _) }
+        trace!(self, "glTexParameteri", target, pname, param);
+        unsafe { self.fns().TexParameteri(GLenum(target as u32), GLenum(pname as u32), param.0 as _) }
     }
     
     pub unsafe fn glTexParameteriv(&self, target: TextureTarget, pname: TextureParameterName, params: *const i32) {
-        self.fns.TexParameteriv(GLenum(target as u32), GLenum(pname as u32), params)
+        trace!(self, "glTexParameteriv", target, pname, params);
+        self.fns().TexParameteriv(GLenum(target as u32), GLenum(pname as u32), params)
     }
     
     pub unsafe fn glTexSubImage1D(&self, target: TextureTarget, level: i32, xoffset: i32, width: u32, format: PixelFormat, type_: PixelType, pixels: *const c_void) {
-        self.fns.TexSubImage1D(GLenum(target as u32), level, xoffset, width as _, GLenum(format as u32), GLenum(type_ as u32), pixels)
+        trace!(self, "glTexSubImage1D", target, level, xoffset, width, format, type_, pixels);
+        self.fns().TexSubImage1D(GLenum(target as u32), level, xoffset, width as _, GLenum(format as u32), GLenum(type_ as u32), pixels)
     }
     
     pub unsafe fn glTexSubImage2D(&self, target: TextureTarget, level: i32, xoffset: i32, yoffset: i32, width: u32, height: u32, format: PixelFormat, type_: PixelType, pixels: *const c_void) {
-        self.fns.TexSubImage2D(GLenum(target as u32), level, xoffset, yoffset, width as _, height as _, GLenum(format as u32), GLenum(type_ as u32), pixels)
+        trace!(self, "glTexSubImage2D", target, level, xoffset, yoffset, width, height, format, type_, pixels);
+        self.fns().TexSubImage2D(GLenum(target as u32), level, xoffset, yoffset, width as _, height as _, GLenum(format as u32), GLenum(type_ as u32), pixels)
     }
     
     pub unsafe fn glTexSubImage3D(&self, target: TextureTarget, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, width: u32, height: u32, depth: u32, format: PixelFormat, type_: PixelType, pixels: *const c_void) {
-        self.fns.TexSubImage3D(GLenum(target as u32), level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, GLenum(format as u32), GLenum(type_ as u32), pixels)
+        trace!(self, "glTexSubImage3D", target, level, xoffset, yoffset, zoffset, width, height, depth, format, type_, pixels);
+        self.fns().TexSubImage3D(GLenum(target as u32), level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, GLenum(format as u32), GLenum(type_ as u32), pixels)
     }
     
     pub fn glTransformFeedbackVaryings(&self, program: u32, varyings: &[&str], bufferMode: TransformFeedbackBufferMode) {
+        trace!(self, "glTransformFeedbackVaryings", program, varyings, bufferMode);
         let varyings = CStringArray::new(varyings);
-        unsafe { self.fns.TransformFeedbackVaryings(program, varyings.len() as _, varyings.as_ptr(), GLenum(bufferMode as u32)) }
+        unsafe { self.fns().TransformFeedbackVaryings(program, varyings.len() as _, varyings.as_ptr(), GLenum(bufferMode as u32)) }
     }
     
     pub fn glUniform1f(&self, location: GlUniformLocation, v0: f32) {
-        unsafe { self.fns.Uniform1f(location.0, v0) }
+        trace!(self, "glUniform1f", location, v0);
+        unsafe { self.fns().Uniform1f(location.0, v0) }
     }
     
     pub unsafe fn glUniform1fv(&self, location: i32, count: i32, value: *const f32) {
-        self.fns.Uniform1fv(location, count, value)
+        trace!(self, "glUniform1fv", location, count, value);
+        self.fns().Uniform1fv(location, count, value)
     }
     
     pub fn glUniform1i(&self, location: GlUniformLocation, v0: i32) {
-        unsafe { self.fns.Uniform1i(location.0, v0) }
+        trace!(self, "glUniform1i", location, v0);
+        unsafe { self.fns().Uniform1i(location.0, v0) }
     }
     
     pub unsafe fn glUniform1iv(&self, location: i32, count: i32, value: *const i32) {
-        self.fns.Uniform1iv(location, count, value)
+        trace!(self, "glUniform1iv", location, count, value);
+        self.fns().Uniform1iv(location, count, value)
     }
     
     pub unsafe fn glUniform1ui(&self, location: i32, v0: u32) {
-        self.fns.Uniform1ui(location, v0)
+        trace!(self, "glUniform1ui", location, v0);
+        self.fns().Uniform1ui(location, v0)
     }
     
     pub unsafe fn glUniform1uiv(&self, location: i32, count: i32, value: *const u32) {
-        self.fns.Uniform1uiv(location, count, value)
+        trace!(self, "glUniform1uiv", location, count, value);
+        self.fns().Uniform1uiv(location, count, value)
     }
     
     pub fn glUniform2f(&self, location: GlUniformLocation, v0: f32, v1: f32) {
-        unsafe { self.fns.Uniform2f(location.0, v0, v1) }
+        trace!(self, "glUniform2f", location, v0, v1);
+        unsafe { self.fns().Uniform2f(location.0, v0, v1) }
     }
     
     pub unsafe fn glUniform2fv(&self, location: i32, count: i32, value: *const f32) {
-        self.fns.Uniform2fv(location, count, value)
+        trace!(self, "glUniform2fv", location, count, value);
+        self.fns().Uniform2fv(location, count, value)
     }
     
     pub unsafe fn glUniform2i(&self, location: i32, v0: i32, v1: i32) {
-        self.fns.Uniform2i(location, v0, v1)
+        trace!(self, "glUniform2i", location, v0, v1);
+        self.fns().Uniform2i(location, v0, v1)
     }
     
     pub unsafe fn glUniform2iv(&self, location: i32, count: i32, value: *const i32) {
-        self.fns.Uniform2iv(location, count, value)
+        trace!(self, "glUniform2iv", location, count, value);
+        self.fns().Uniform2iv(location, count, value)
     }
     
     pub fn glUniform2ui(&self, location: GlUniformLocation, v0: u32, v1: u32) {
-        unsafe { self.fns.Uniform2ui(location.0, v0, v1) }
+        trace!(self, "glUniform2ui", location, v0, v1);
+        unsafe { self.fns().Uniform2ui(location.0, v0, v1) }
     }
     
     pub unsafe fn glUniform2uiv(&self, location: i32, count: i32, value: *const u32) {
-        self.fns.Uniform2uiv(location, count, value)
+        trace!(self, "glUniform2uiv", location, count, value);
+        self.fns().Uniform2uiv(location, count, value)
     }
     
     pub fn glUniform3f(&self, location: GlUniformLocation, v0: f32, v1: f32, v2: f32) {
-        unsafe { self.fns.Uniform3f(location.0, v0, v1, v2) }
+        trace!(self, "glUniform3f", location, v0, v1, v2);
+        unsafe { self.fns().Uniform3f(location.0, v0, v1, v2) }
     }
     
     pub unsafe fn glUniform3fv(&self, location: i32, count: i32, value: *const f32) {
-        self.fns.Uniform3fv(location, count, value)
+        trace!(self, "glUniform3fv", location, count, value);
+        self.fns().Uniform3fv(location, count, value)
     }
     
     pub unsafe fn glUniform3i(&self, location: i32, v0: i32, v1: i32, v2: i32) {
-        self.fns.Uniform3i(location, v0, v1, v2)
+        trace!(self, "glUniform3i", location, v0, v1, v2);
+        self.fns().Uniform3i(location, v0, v1, v2)
     }
     
     pub unsafe fn glUniform3iv(&self, location: i32, count: i32, value: *const i32) {
-        self.fns.Uniform3iv(location, count, value)
+        trace!(self, "glUniform3iv", location, count, value);
+        self.fns().Uniform3iv(location, count, value)
     }
     
     pub unsafe fn glUniform3ui(&self, location: i32, v0: u32, v1: u32, v2: u32) {
-        self.fns.Uniform3ui(location, v0, v1, v2)
+        trace!(self, "glUniform3ui", location, v0, v1, v2);
+        self.fns().Uniform3ui(location, v0, v1, v2)
     }
     
     pub unsafe fn glUniform3uiv(&self, location: i32, count: i32, value: *const u32) {
-        self.fns.Uniform3uiv(location, count, value)
+        trace!(self, "glUniform3uiv", location, count, value);
+        self.fns().Uniform3uiv(location, count, value)
     }
     
     pub unsafe fn glUniform4f(&self, location: i32, v0: f32, v1: f32, v2: f32, v3: f32) {
-        self.fns.Uniform4f(location, v0, v1, v2, v3)
+        trace!(self, "glUniform4f", location, v0, v1, v2, v3);
+        self.fns().Uniform4f(location, v0, v1, v2, v3)
     }
     
     pub unsafe fn glUniform4fv(&self, location: i32, count: i32, value: *const f32) {
-        self.fns.Uniform4fv(location, count, value)
+        trace!(self, "glUniform4fv", location, count, value);
+        self.fns().Uniform4fv(location, count, value)
     }
     
     pub unsafe fn glUniform4i(&self, location: i32, v0: i32, v1: i32, v2: i32, v3: i32) {
-        self.fns.Uniform4i(location, v0, v1, v2, v3)
+        trace!(self, "glUniform4i", location, v0, v1, v2, v3);
+        self.fns().Uniform4i(location, v0, v1, v2, v3)
     }
     
     pub unsafe fn glUniform4iv(&self, location: i32, count: i32, value: *const i32) {
-        self.fns.Uniform4iv(location, count, value)
+        trace!(self, "glUniform4iv", location, count, value);
+        self.fns().Uniform4iv(location, count, value)
     }
     
     pub unsafe fn glUniform4ui(&self, location: i32, v0: u32, v1: u32, v2: u32, v3: u32) {
-        self.fns.Uniform4ui(location, v0, v1, v2, v3)
+        trace!(self, "glUniform4ui", location, v0, v1, v2, v3);
+        self.fns().Uniform4ui(location, v0, v1, v2, v3)
     }
     
     pub unsafe fn glUniform4uiv(&self, location: i32, count: i32, value: *const u32) {
-        self.fns.Uniform4uiv(location, count, value)
+        trace!(self, "glUniform4uiv", location, count, value);
+        self.fns().Uniform4uiv(location, count, value)
     }
     
     pub unsafe fn glUniformBlockBinding(&self, program: u32, uniformBlockIndex: u32, uniformBlockBinding: u32) {
-        self.fns.UniformBlockBinding(program, uniformBlockIndex, uniformBlockBinding)
+        trace!(self, "glUniformBlockBinding", program, uniformBlockIndex, uniformBlockBinding);
+        self.fns().UniformBlockBinding(program, uniformBlockIndex, uniformBlockBinding)
     }
     
     pub unsafe fn glUniformMatrix2fv(&self, location: i32, count: i32, transpose: u8, value: *const f32) {
-        self.fns.UniformMatrix2fv(location, count, transpose, value)
+        trace!(self, "glUniformMatrix2fv", location, count, transpose, value);
+        self.fns().UniformMatrix2fv(location, count, transpose, value)
     }
     
     pub unsafe fn glUniformMatrix2x3fv(&self, location: i32, count: i32, transpose: u8, value: *const f32) {
-        self.fns.UniformMatrix2x3fv(location, count, transpose, value)
+        trace!(self, "glUniformMatrix2x3fv", location, count, transpose, value);
+        self.fns().UniformMatrix2x3fv(location, count, transpose, value)
     }
     
     pub unsafe fn glUniformMatrix2x4fv(&self, location: i32, count: i32, transpose: u8, value: *const f32) {
-        self.fns.UniformMatrix2x4fv(location, count, transpose, value)
+        trace!(self, "glUniformMatrix2x4fv", location, count, transpose, value);
+        self.fns().UniformMatrix2x4fv(location, count, transpose, value)
     }
     
     pub unsafe fn glUniformMatrix3fv(&self, location: i32, count: i32, transpose: u8, value: *const f32) {
-        self.fns.UniformMatrix3fv(location, count, transpose, value)
+        trace!(self, "glUniformMatrix3fv", location, count, transpose, value);
+        self.fns().UniformMatrix3fv(location, count, transpose, value)
     }
     
     pub unsafe fn glUniformMatrix3x2fv(&self, location: i32, count: i32, transpose: u8, value: *const f32) {
-        self.fns.UniformMatrix3x2fv(location, count, transpose, value)
+        trace!(self, "glUniformMatrix3x2fv", location, count, transpose, value);
+        self.fns().UniformMatrix3x2fv(location, count, transpose, value)
     }
     
     pub unsafe fn glUniformMatrix3x4fv(&self, location: i32, count: i32, transpose: u8, value: *const f32) {
-        self.fns.UniformMatrix3x4fv(location, count, transpose, value)
+        trace!(self, "glUniformMatrix3x4fv", location, count, transpose, value);
+        self.fns().UniformMatrix3x4fv(location, count, transpose, value)
     }
     
     pub fn glUniformMatrix4f(&self, location: GlUniformLocation, transpose: bool, value: &Mat4) {
-        unsafe { self.fns.UniformMatrix4fv(location.0, 1, transpose as u8, value.as_slice()[0].as_ptr() as _) };    
+        trace!(self, "glUniformMatrix4f", location, transpose, value);
+        unsafe { self.fns().UniformMatrix4fv(location.0, 1, transpose as u8, value.as_slice()[0].as_ptr() as _) };    
     }
     
     pub fn glUniformMatrix4fv(&self, location: GlUniformLocation, transpose: bool, value: &[Mat4]) {
-        unsafe { self.fns.UniformMatrix4fv(location.0, value.len() as _, transpose as u8, value.as_ptr() as _) };    
+        trace!(self, "glUniformMatrix4fv", location, transpose, value);
+        unsafe { self.fns().UniformMatrix4fv(location.0, value.len() as _, transpose as u8, value.as_ptr() as _) };    
     }
     
     pub unsafe fn glUniformMatrix4x2fv(&self, location: i32, count: i32, transpose: u8, value: *const f32) {
-        self.fns.UniformMatrix4x2fv(location, count, transpose, value)
+        trace!(self, "glUniformMatrix4x2fv", location, count, transpose, value);
+        self.fns().UniformMatrix4x2fv(location, count, transpose, value)
     }
     
     pub unsafe fn glUniformMatrix4x3fv(&self, location: i32, count: i32, transpose: u8, value: *const f32) {
-        self.fns.UniformMatrix4x3fv(location, count, transpose, value)
+        trace!(self, "glUniformMatrix4x3fv", location, count, transpose, value);
+        self.fns().UniformMatrix4x3fv(location, count, transpose, value)
     }
     
     pub unsafe fn glUnmapBuffer(&self, target: BufferTargetARB) -> u8 {
-        self.fns.UnmapBuffer(GLenum(target as u32))
+        trace!(self, "glUnmapBuffer", target);
+        self.fns().UnmapBuffer(GLenum(target as u32))
     }
     
     pub fn glUseProgram(&self, program: u32) {
-        self.fns.UseProgram(program)
+        trace!(self, "glUseProgram", program);
+        self.fns().UseProgram(program)
     }
     
     pub unsafe fn glValidateProgram(&self, program: u32) {
-        self.fns.ValidateProgram(program)
+        trace!(self, "glValidateProgram", program);
+        self.fns().ValidateProgram(program)
     }
     
     pub unsafe fn glVertexAttrib1d(&self, index: u32, x: f64) {
-        self.fns.VertexAttrib1d(index, x)
+        trace!(self, "glVertexAttrib1d", index, x);
+        self.fns().VertexAttrib1d(index, x)
     }
     
     pub unsafe fn glVertexAttrib1dv(&self, index: u32, v: *const f64) {
-        self.fns.VertexAttrib1dv(index, v)
+        trace!(self, "glVertexAttrib1dv", index, v);
+        self.fns().VertexAttrib1dv(index, v)
     }
     
     pub unsafe fn glVertexAttrib1f(&self, index: u32, x: f32) {
-        self.fns.VertexAttrib1f(index, x)
+        trace!(self, "glVertexAttrib1f", index, x);
+        self.fns().VertexAttrib1f(index, x)
     }
     
     pub unsafe fn glVertexAttrib1fv(&self, index: u32, v: *const f32) {
-        self.fns.VertexAttrib1fv(index, v)
+        trace!(self, "glVertexAttrib1fv", index, v);
+        self.fns().VertexAttrib1fv(index, v)
     }
     
     pub unsafe fn glVertexAttrib1s(&self, index: u32, x: i16) {
-        self.fns.VertexAttrib1s(index, x)
+        trace!(self, "glVertexAttrib1s", index, x);
+        self.fns().VertexAttrib1s(index, x)
     }
     
     pub unsafe fn glVertexAttrib1sv(&self, index: u32, v: *const i16) {
-        self.fns.VertexAttrib1sv(index, v)
+        trace!(self, "glVertexAttrib1sv", index, v);
+        self.fns().VertexAttrib1sv(index, v)
     }
     
     pub unsafe fn glVertexAttrib2d(&self, index: u32, x: f64, y: f64) {
-        self.fns.VertexAttrib2d(index, x, y)
+        trace!(self, "glVertexAttrib2d", index, x, y);
+        self.fns().VertexAttrib2d(index, x, y)
     }
     
     pub unsafe fn glVertexAttrib2dv(&self, index: u32, v: *const[f64; 2]) {
-        self.fns.VertexAttrib2dv(index, v)
+        trace!(self, "glVertexAttrib2dv", index, v);
+        self.fns().VertexAttrib2dv(index, v)
     }
     
     pub unsafe fn glVertexAttrib2f(&self, index: u32, x: f32, y: f32) {
-        self.fns.VertexAttrib2f(index, x, y)
+        trace!(self, "glVertexAttrib2f", index, x, y);
+        self.fns().VertexAttrib2f(index, x, y)
     }
     
     pub unsafe fn glVertexAttrib2fv(&self, index: u32, v: *const[f32; 2]) {
-        self.fns.VertexAttrib2fv(index, v)
+        trace!(self, "glVertexAttrib2fv", index, v);
+        self.fns().VertexAttrib2fv(index, v)
     }
     
     pub unsafe fn glVertexAttrib2s(&self, index: u32, x: i16, y: i16) {
-        self.fns.VertexAttrib2s(index, x, y)
+        trace!(self, "glVertexAttrib2s", index, x, y);
+        self.fns().VertexAttrib2s(index, x, y)
     }
     
     pub unsafe fn glVertexAttrib2sv(&self, index: u32, v: *const[i16; 2]) {
-        self.fns.VertexAttrib2sv(index, v)
+        trace!(self, "glVertexAttrib2sv", index, v);
+        self.fns().VertexAttrib2sv(index, v)
     }
     
     pub unsafe fn glVertexAttrib3d(&self, index: u32, x: f64, y: f64, z: f64) {
-        self.fns.VertexAttrib3d(index, x, y, z)
+        trace!(self, "glVertexAttrib3d", index, x, y, z);
+        self.fns().VertexAttrib3d(index, x, y, z)
     }
     
     pub unsafe fn glVertexAttrib3dv(&self, index: u32, v: *const[f64; 3]) {
-        self.fns.VertexAttrib3dv(index, v)
+        trace!(self, "glVertexAttrib3dv", index, v);
+        self.fns().VertexAttrib3dv(index, v)
     }
     
     pub unsafe fn glVertexAttrib3f(&self, index: u32, x: f32, y: f32, z: f32) {
-        self.fns.VertexAttrib3f(index, x, y, z)
+        trace!(self, "glVertexAttrib3f", index, x, y, z);
+        self.fns().VertexAttrib3f(index, x, y, z)
     }
     
     pub unsafe fn glVertexAttrib3fv(&self, index: u32, v: *const[f32; 3]) {
-        self.fns.VertexAttrib3fv(index, v)
+        trace!(self, "glVertexAttrib3fv", index, v);
+        self.fns().VertexAttrib3fv(index, v)
     }
     
     pub unsafe fn glVertexAttrib3s(&self, index: u32, x: i16, y: i16, z: i16) {
-        self.fns.VertexAttrib3s(index, x, y, z)
+        trace!(self, "glVertexAttrib3s", index, x, y, z);
+        self.fns().VertexAttrib3s(index, x, y, z)
     }
     
     pub unsafe fn glVertexAttrib3sv(&self, index: u32, v: *const[i16; 3]) {
-        self.fns.VertexAttrib3sv(index, v)
+        trace!(self, "glVertexAttrib3sv", index, v);
+        self.fns().VertexAttrib3sv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4Nbv(&self, index: u32, v: *const[i8; 4]) {
-        self.fns.VertexAttrib4Nbv(index, v)
+        trace!(self, "glVertexAttrib4Nbv", index, v);
+        self.fns().VertexAttrib4Nbv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4Niv(&self, index: u32, v: *const[i32; 4]) {
-        self.fns.VertexAttrib4Niv(index, v)
+        trace!(self, "glVertexAttrib4Niv", index, v);
+        self.fns().VertexAttrib4Niv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4Nsv(&self, index: u32, v: *const[i16; 4]) {
-        self.fns.VertexAttrib4Nsv(index, v)
+        trace!(self, "glVertexAttrib4Nsv", index, v);
+        self.fns().VertexAttrib4Nsv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4Nub(&self, index: u32, x: u8, y: u8, z: u8, w: u8) {
-        self.fns.VertexAttrib4Nub(index, x, y, z, w)
+        trace!(self, "glVertexAttrib4Nub", index, x, y, z, w);
+        self.fns().VertexAttrib4Nub(index, x, y, z, w)
     }
     
     pub unsafe fn glVertexAttrib4Nubv(&self, index: u32, v: *const[u8; 4]) {
-        self.fns.VertexAttrib4Nubv(index, v)
+        trace!(self, "glVertexAttrib4Nubv", index, v);
+        self.fns().VertexAttrib4Nubv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4Nuiv(&self, index: u32, v: *const[u32; 4]) {
-        self.fns.VertexAttrib4Nuiv(index, v)
+        trace!(self, "glVertexAttrib4Nuiv", index, v);
+        self.fns().VertexAttrib4Nuiv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4Nusv(&self, index: u32, v: *const[u16; 4]) {
-        self.fns.VertexAttrib4Nusv(index, v)
+        trace!(self, "glVertexAttrib4Nusv", index, v);
+        self.fns().VertexAttrib4Nusv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4bv(&self, index: u32, v: *const[i8; 4]) {
-        self.fns.VertexAttrib4bv(index, v)
+        trace!(self, "glVertexAttrib4bv", index, v);
+        self.fns().VertexAttrib4bv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4d(&self, index: u32, x: f64, y: f64, z: f64, w: f64) {
-        self.fns.VertexAttrib4d(index, x, y, z, w)
+        trace!(self, "glVertexAttrib4d", index, x, y, z, w);
+        self.fns().VertexAttrib4d(index, x, y, z, w)
     }
     
     pub unsafe fn glVertexAttrib4dv(&self, index: u32, v: *const[f64; 4]) {
-        self.fns.VertexAttrib4dv(index, v)
+        trace!(self, "glVertexAttrib4dv", index, v);
+        self.fns().VertexAttrib4dv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4f(&self, index: u32, x: f32, y: f32, z: f32, w: f32) {
-        self.fns.VertexAttrib4f(index, x, y, z, w)
+        trace!(self, "glVertexAttrib4f", index, x, y, z, w);
+        self.fns().VertexAttrib4f(index, x, y, z, w)
     }
     
     pub unsafe fn glVertexAttrib4fv(&self, index: u32, v: *const[f32; 4]) {
-        self.fns.VertexAttrib4fv(index, v)
+        trace!(self, "glVertexAttrib4fv", index, v);
+        self.fns().VertexAttrib4fv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4iv(&self, index: u32, v: *const[i32; 4]) {
-        self.fns.VertexAttrib4iv(index, v)
+        trace!(self, "glVertexAttrib4iv", index, v);
+        self.fns().VertexAttrib4iv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4s(&self, index: u32, x: i16, y: i16, z: i16, w: i16) {
-        self.fns.VertexAttrib4s(index, x, y, z, w)
+        trace!(self, "glVertexAttrib4s", index, x, y, z, w);
+        self.fns().VertexAttrib4s(index, x, y, z, w)
     }
     
     pub unsafe fn glVertexAttrib4sv(&self, index: u32, v: *const[i16; 4]) {
-        self.fns.VertexAttrib4sv(index, v)
+        trace!(self, "glVertexAttrib4sv", index, v);
+        self.fns().VertexAttrib4sv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4ubv(&self, index: u32, v: *const[u8; 4]) {
-        self.fns.VertexAttrib4ubv(index, v)
+        trace!(self, "glVertexAttrib4ubv", index, v);
+        self.fns().VertexAttrib4ubv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4uiv(&self, index: u32, v: *const[u32; 4]) {
-        self.fns.VertexAttrib4uiv(index, v)
+        trace!(self, "glVertexAttrib4uiv", index, v);
+        self.fns().VertexAttrib4uiv(index, v)
     }
     
     pub unsafe fn glVertexAttrib4usv(&self, index: u32, v: *const[u16; 4]) {
-        self.fns.VertexAttrib4usv(index, v)
+        trace!(self, "glVertexAttrib4usv", index, v);
+        self.fns().VertexAttrib4usv(index, v)
     }
     
     pub unsafe fn glVertexAttribDivisor(&self, index: u32, divisor: u32) {
-        self.fns.VertexAttribDivisor(index, divisor)
+        trace!(self, "glVertexAttribDivisor", index, divisor);
+        self.fns().VertexAttribDivisor(index, divisor)
     }
     
     pub unsafe fn glVertexAttribI1i(&self, index: u32, x: i32) {
-        self.fns.VertexAttribI1i(index, x)
+        trace!(self, "glVertexAttribI1i", index, x);
+        self.fns().VertexAttribI1i(index, x)
     }
     
     pub unsafe fn glVertexAttribI1iv(&self, index: u32, v: *const i32) {
-        self.fns.VertexAttribI1iv(index, v)
+        trace!(self, "glVertexAttribI1iv", index, v);
+        self.fns().VertexAttribI1iv(index, v)
     }
     
     pub unsafe fn glVertexAttribI1ui(&self, index: u32, x: u32) {
-        self.fns.VertexAttribI1ui(index, x)
+        trace!(self, "glVertexAttribI1ui", index, x);
+        self.fns().VertexAttribI1ui(index, x)
     }
     
     pub unsafe fn glVertexAttribI1uiv(&self, index: u32, v: *const u32) {
-        self.fns.VertexAttribI1uiv(index, v)
+        trace!(self, "glVertexAttribI1uiv", index, v);
+        self.fns().VertexAttribI1uiv(index, v)
     }
     
     pub unsafe fn glVertexAttribI2i(&self, index: u32, x: i32, y: i32) {
-        self.fns.VertexAttribI2i(index, x, y)
+        trace!(self, "glVertexAttribI2i", index, x, y);
+        self.fns().VertexAttribI2i(index, x, y)
     }
     
     pub unsafe fn glVertexAttribI2iv(&self, index: u32, v: *const[i32; 2]) {
-        self.fns.VertexAttribI2iv(index, v)
+        trace!(self, "glVertexAttribI2iv", index, v);
+        self.fns().VertexAttribI2iv(index, v)
     }
     
     pub unsafe fn glVertexAttribI2ui(&self, index: u32, x: u32, y: u32) {
-        self.fns.VertexAttribI2ui(index, x, y)
+        trace!(self, "glVertexAttribI2ui", index, x, y);
+        self.fns().VertexAttribI2ui(index, x, y)
     }
     
     pub unsafe fn glVertexAttribI2uiv(&self, index: u32, v: *const[u32; 2]) {
-        self.fns.VertexAttribI2uiv(index, v)
+        trace!(self, "glVertexAttribI2uiv", index, v);
+        self.fns().VertexAttribI2uiv(index, v)
     }
     
     pub unsafe fn glVertexAttribI3i(&self, index: u32, x: i32, y: i32, z: i32) {
-        self.fns.VertexAttribI3i(index, x, y, z)
+        trace!(self, "glVertexAttribI3i", index, x, y, z);
+        self.fns().VertexAttribI3i(index, x, y, z)
     }
     
     pub unsafe fn glVertexAttribI3iv(&self, index: u32, v: *const[i32; 3]) {
-        self.fns.VertexAttribI3iv(index, v)
+        trace!(self, "glVertexAttribI3iv", index, v);
+        self.fns().VertexAttribI3iv(index, v)
     }
     
     pub unsafe fn glVertexAttribI3ui(&self, index: u32, x: u32, y: u32, z: u32) {
-        self.fns.VertexAttribI3ui(index, x, y, z)
+        trace!(self, "glVertexAttribI3ui", index, x, y, z);
+        self.fns().VertexAttribI3ui(index, x, y, z)
     }
     
     pub unsafe fn glVertexAttribI3uiv(&self, index: u32, v: *const[u32; 3]) {
-        self.fns.VertexAttribI3uiv(index, v)
+        trace!(self, "glVertexAttribI3uiv", index, v);
+        self.fns().VertexAttribI3uiv(index, v)
     }
     
     pub unsafe fn glVertexAttribI4bv(&self, index: u32, v: *const[i8; 4]) {
-        self.fns.VertexAttribI4bv(index, v)
+        trace!(self, "glVertexAttribI4bv", index, v);
+        self.fns().VertexAttribI4bv(index, v)
     }
     
     pub unsafe fn glVertexAttribI4i(&self, index: u32, x: i32, y: i32, z: i32, w: i32) {
-        self.fns.VertexAttribI4i(index, x, y, z, w)
+        trace!(self, "glVertexAttribI4i", index, x, y, z, w);
+        self.fns().VertexAttribI4i(index, x, y, z, w)
     }
     
     pub unsafe fn glVertexAttribI4iv(&self, index: u32, v: *const[i32; 4]) {
-        self.fns.VertexAttribI4iv(index, v)
+        trace!(self, "glVertexAttribI4iv", index, v);
+        self.fns().VertexAttribI4iv(index, v)
     }
     
     pub unsafe fn glVertexAttribI4sv(&self, index: u32, v: *const[i16; 4]) {
-        self.fns.VertexAttribI4sv(index, v)
+        trace!(self, "glVertexAttribI4sv", index, v);
+        self.fns().VertexAttribI4sv(index, v)
     }
     
     pub unsafe fn glVertexAttribI4ubv(&self, index: u32, v: *const[u8; 4]) {
-        self.fns.VertexAttribI4ubv(index, v)
+        trace!(self, "glVertexAttribI4ubv", index, v);
+        self.fns().VertexAttribI4ubv(index, v)
     }
     
     pub unsafe fn glVertexAttribI4ui(&self, index: u32, x: u32, y: u32, z: u32, w: u32) {
-        self.fns.VertexAttribI4ui(index, x, y, z, w)
+        trace!(self, "glVertexAttribI4ui", index, x, y, z, w);
+        self.fns().VertexAttribI4ui(index, x, y, z, w)
     }
     
     pub unsafe fn glVertexAttribI4uiv(&self, index: u32, v: *const[u32; 4]) {
-        self.fns.VertexAttribI4uiv(index, v)
+        trace!(self, "glVertexAttribI4uiv", index, v);
+        self.fns().VertexAttribI4uiv(index, v)
     }
     
     pub unsafe fn glVertexAttribI4usv(&self, index: u32, v: *const[u16; 4]) {
-        self.fns.VertexAttribI4usv(index, v)
+        trace!(self, "glVertexAttribI4usv", index, v);
+        self.fns().VertexAttribI4usv(index, v)
     }
     
     pub unsafe fn glVertexAttribIPointer(&self, index: u32, size: i32, type_: VertexAttribIType, stride: i32, pointer: *const c_void) {
-        self.fns.VertexAttribIPointer(index, size, GLenum(type_ as u32), stride, pointer)
+        trace!(self, "glVertexAttribIPointer", index, size, type_, stride, pointer);
+        self.fns().VertexAttribIPointer(index, size, GLenum(type_ as u32), stride, pointer)
     }
     
     pub unsafe fn glVertexAttribP1ui(&self, index: u32, type_: VertexAttribPointerType, normalized: u8, value: u32) {
-        self.fns.VertexAttribP1ui(index, GLenum(type_ as u32), normalized, value)
+        trace!(self, "glVertexAttribP1ui", index, type_, normalized, value);
+        self.fns().VertexAttribP1ui(index, GLenum(type_ as u32), normalized, value)
     }
     
     pub unsafe fn glVertexAttribP1uiv(&self, index: u32, type_: VertexAttribPointerType, normalized: u8, value: *const u32) {
-        self.fns.VertexAttribP1uiv(index, GLenum(type_ as u32), normalized, value)
+        trace!(self, "glVertexAttribP1uiv", index, type_, normalized, value);
+        self.fns().VertexAttribP1uiv(index, GLenum(type_ as u32), normalized, value)
     }
     
     pub unsafe fn glVertexAttribP2ui(&self, index: u32, type_: VertexAttribPointerType, normalized: u8, value: u32) {
-        self.fns.VertexAttribP2ui(index, GLenum(type_ as u32), normalized, value)
+        trace!(self, "glVertexAttribP2ui", index, type_, normalized, value);
+        self.fns().VertexAttribP2ui(index, GLenum(type_ as u32), normalized, value)
     }
     
     pub unsafe fn glVertexAttribP2uiv(&self, index: u32, type_: VertexAttribPointerType, normalized: u8, value: *const u32) {
-        self.fns.VertexAttribP2uiv(index, GLenum(type_ as u32), normalized, value)
+        trace!(self, "glVertexAttribP2uiv", index, type_, normalized, value);
+        self.fns().VertexAttribP2uiv(index, GLenum(type_ as u32), normalized, value)
     }
     
     pub unsafe fn glVertexAttribP3ui(&self, index: u32, type_: VertexAttribPointerType, normalized: u8, value: u32) {
-        self.fns.VertexAttribP3ui(index, GLenum(type_ as u32), normalized, value)
+        trace!(self, "glVertexAttribP3ui", index, type_, normalized, value);
+        self.fns().VertexAttribP3ui(index, GLenum(type_ as u32), normalized, value)
     }
     
     pub unsafe fn glVertexAttribP3uiv(&self, index: u32, type_: VertexAttribPointerType, normalized: u8, value: *const u32) {
-        self.fns.VertexAttribP3uiv(index, GLenum(type_ as u32), normalized, value)
+        trace!(self, "glVertexAttribP3uiv", index, type_, normalized, value);
+        self.fns().VertexAttribP3uiv(index, GLenum(type_ as u32), normalized, value)
     }
     
     pub unsafe fn glVertexAttribP4ui(&self, index: u32, type_: VertexAttribPointerType, normalized: u8, value: u32) {
-        self.fns.VertexAttribP4ui(index, GLenum(type_ as u32), normalized, value)
+        trace!(self, "glVertexAttribP4ui", index, type_, normalized, value);
+        self.fns().VertexAttribP4ui(index, GLenum(type_ as u32), normalized, value)
     }
     
     pub unsafe fn glVertexAttribP4uiv(&self, index: u32, type_: VertexAttribPointerType, normalized: u8, value: *const u32) {
-        self.fns.VertexAttribP4uiv(index, GLenum(type_ as u32), normalized, value)
+        trace!(self, "glVertexAttribP4uiv", index, type_, normalized, value);
+        self.fns().VertexAttribP4uiv(index, GLenum(type_ as u32), normalized, value)
     }
     
     pub fn glVertexAttribPointer(&self, index: u32, size: i32, type_: VertexAttribPointerType, normalized: bool, stride: i32, offset: u32) {
-        unsafe { self.fns.VertexAttribPointer(index, size, GLenum(type_ as u32), normalized as _, stride, offset as _) }
+        trace!(self, "glVertexAttribPointer", index, size, type_, normalized, stride, offset);
+        unsafe { self.fns().VertexAttribPointer(index, size, GLenum(type_ as u32), normalized as _, stride, offset as _) }
     }
     
     pub unsafe fn glViewport(&self, x: i32, y: i32, width: u32, height: u32) {
-        self.fns.Viewport(x, y, width as _, height as _)
+        trace!(self, "glViewport", x, y, width, height);
+        self.fns().Viewport(x, y, width as _, height as _)
     }
     
     pub unsafe fn glWaitSync(&self, sync: GLsync, flags: GLbitfield, timeout: u64) {
-        self.fns.WaitSync(sync, flags, timeout)
+        trace!(self, "glWaitSync", sync, flags, timeout);
+        self.fns().WaitSync(sync, flags, timeout)
     }
     
     pub unsafe fn glDebugMessageCallback(&self, callback: GLDEBUGPROC, userParam: *const c_void) {
-        self.fns.DebugMessageCallback(callback, userParam)
+        trace!(self, "glDebugMessageCallback", callback, userParam);
+        self.fns().DebugMessageCallback(callback, userParam)
     }
     
     pub unsafe fn glDebugMessageControl(&self, source: DebugSource, type_: DebugType, severity: DebugSeverity, count: i32, ids: *const u32, enabled: u8) {
-        self.fns.DebugMessageControl(GLenum(source as u32), GLenum(type_ as u32), GLenum(severity as u32), count, ids, enabled)
+        trace!(self, "glDebugMessageControl", source, type_, severity, count, ids, enabled);
+        self.fns().DebugMessageControl(GLenum(source as u32), GLenum(type_ as u32), GLenum(severity as u32), count, ids, enabled)
     }
     
     pub fn glDebugMessageInsert(&self, source: DebugSource, type_: DebugType, id: u32, severity: DebugSeverity, buf: &str) {
-        unsafe { self.fns.DebugMessageInsert(GLenum(source as u32), GLenum(type_ as u32), id, GLenum(severity as u32), buf.len() as _, buf.as_ptr()) }
+        trace!(self, "glDebugMessageInsert", source, type_, id, severity, buf);
+        unsafe { self.fns().DebugMessageInsert(GLenum(source as u32), GLenum(type_ as u32), id, GLenum(severity as u32), buf.len() as _, buf.as_ptr()) }
     }
     
     pub unsafe fn glGetDebugMessageLog(&self, count: u32, bufSize: i32, sources: *mut DebugSource, types: *mut DebugType, ids: *mut u32, severities: *mut DebugSeverity, lengths: *mut i32, messageLog: *mut u8) -> u32 {
-        self.fns.GetDebugMessageLog(count, bufSize, sources as _, types as _, ids, severities as _, lengths, messageLog)
+        trace!(self, "glGetDebugMessageLog", count, bufSize, sources, types, ids, severities, lengths, messageLog);
+        self.fns().GetDebugMessageLog(count, bufSize, sources as _, types as _, ids, severities as _, lengths, messageLog)
     }
     
     pub unsafe fn glGetObjectLabel(&self, identifier: ObjectIdentifier, name: u32, bufSize: i32, length: *mut i32, label: *mut u8) {
-        self.fns.GetObjectLabel(GLenum(identifier as u32), name, bufSize, length, label)
+        trace!(self, "glGetObjectLabel", identifier, name, bufSize, length, label);
+        self.fns().GetObjectLabel(GLenum(identifier as u32), name, bufSize, length, label)
     }
     
     pub unsafe fn glGetObjectPtrLabel(&self, ptr: *const c_void, bufSize: i32, length: *mut i32, label: *mut u8) {
-        self.fns.GetObjectPtrLabel(ptr, bufSize, length, label)
+        trace!(self, "glGetObjectPtrLabel", ptr, bufSize, length, label);
+        self.fns().GetObjectPtrLabel(ptr, bufSize, length, label)
     }
     
     pub unsafe fn glGetPointerv(&self, pname: GetPointervPName, params: *mut *mut c_void) {
-        self.fns.GetPointerv(GLenum(pname as u32), params)
+        trace!(self, "glGetPointerv", pname, params);
+        self.fns().GetPointerv(GLenum(pname as u32), params)
     }
     
     pub fn glObjectLabel(&self, identifier: ObjectIdentifier, name: u32, label: &str) {
-        unsafe { self.fns.ObjectLabel(GLenum(identifier as u32), name, label.len() as _, label.as_ptr()) }
+        trace!(self, "glObjectLabel", identifier, name, label);
+        unsafe { self.fns().ObjectLabel(GLenum(identifier as u32), name, label.len() as _, label.as_ptr()) }
     }
     
     pub fn glObjectPtrLabel(&self, ptr: *const c_void, label: &str) {
-        unsafe { self.fns.ObjectPtrLabel(ptr, label.len() as _, label.as_ptr()) }
+        trace!(self, "glObjectPtrLabel", ptr, label);
+        unsafe { self.fns().ObjectPtrLabel(ptr, label.len() as _, label.as_ptr()) }
     }
     
     pub unsafe fn glPopDebugGroup(&self) {
-        self.fns.PopDebugGroup()
+        trace!(self, "glPopDebugGroup");
+        self.fns().PopDebugGroup()
     }
     
     pub fn glPushDebugGroup(&self, source: DebugSource, id: u32, message: &str) {
-        unsafe { self.fns.PushDebugGroup(GLenum(source as u32), id, message.len() as _, message.as_ptr()) }
+        trace!(self, "glPushDebugGroup", source, id, message);
+        unsafe { self.fns().PushDebugGroup(GLenum(source as u32), id, message.len() as _, message.as_ptr()) }
     }
     
     // Functions new to gl46
     pub fn glActiveShaderProgram(&self, pipeline: u32, program: u32) {
-        unsafe { self.fns.ActiveShaderProgram(pipeline, program) }
+        trace!(self, "glActiveShaderProgram", pipeline, program);
+        unsafe { self.fns().ActiveShaderProgram(pipeline, program) }
     }
     
     pub fn glBeginQueryIndexed(&self, target: QueryTarget, index: u32, id: u32) {
-        unsafe { self.fns.BeginQueryIndexed(GLenum(target as u32), index, id) }
+        trace!(self, "glBeginQueryIndexed", target, index, id);
+        unsafe { self.fns().BeginQueryIndexed(GLenum(target as u32), index, id) }
     }
     
     pub fn glBindBuffersBase(&self, target: BufferTargetARB, index: u32, buffer: u32) {
-        unsafe { self.fns.BindBufferBase(GLenum(target as u32), index, buffer) }
+        trace!(self, "glBindBuffersBase", target, index, buffer);
+        unsafe { self.fns().BindBufferBase(GLenum(target as u32), index, buffer) }
     }
     
     pub fn glBindBuffersRange(&self, target: BufferTargetARB, index: u32, buffer: u32, offset: isize, size: isize) {
-        unsafe { self.fns.BindBufferRange(GLenum(target as u32), index, buffer, offset, size) }
+        trace!(self, "glBindBuffersRange", target, index, buffer, offset, size);
+        unsafe { self.fns().BindBufferRange(GLenum(target as u32), index, buffer, offset, size) }
     }
     
     pub fn glBindImageTexture(&self, unit: u32, texture: u32, level: i32, layered: u8, layer: i32, access: BufferAccessARB, format: InternalFormat) {
-        unsafe { self.fns.BindImageTexture(unit, texture, level, layered, layer, GLenum(access as u32), GLenum(format as u32)) }
+        trace!(self, "glBindImageTexture", unit, texture, level, layered, layer, access, format);
+        unsafe { self.fns().BindImageTexture(unit, texture, level, layered, layer, GLenum(access as u32), GLenum(format as u32)) }
     }
     
     pub fn glBindImageTextures(&self, first: u32, textures: &[u32]) {
-        unsafe { self.fns.BindImageTextures(first, textures.len() as i32, textures.as_ptr()) }
+        trace!(self, "glBindImageTextures", first, textures);
+        unsafe { self.fns().BindImageTextures(first, textures.len() as i32, textures.as_ptr()) }
     }
     
     pub fn glBindProgramPipeline(&self, pipeline: u32) {
-        unsafe { self.fns.BindProgramPipeline(pipeline) }
+        trace!(self, "glBindProgramPipeline", pipeline);
+        unsafe { self.fns().BindProgramPipeline(pipeline) }
     }
     
     pub fn glBindSamplers(&self, first: u32, samplers: &[u32]) {
-        unsafe { self.fns.BindSamplers(first, samplers.len() as _, samplers.as_ptr()) }
+        trace!(self, "glBindSamplers", first, samplers);
+        unsafe { self.fns().BindSamplers(first, samplers.len() as _, samplers.as_ptr()) }
     }
     
     pub fn glBindTextureUnit(&self, unit: u32, texture: u32) {
-        unsafe { self.fns.BindTextureUnit(unit, texture) }
+        trace!(self, "glBindTextureUnit", unit, texture);
+        unsafe { self.fns().BindTextureUnit(unit, texture) }
     }
     
     pub fn glBindTextures(&self, first: u32, textures: &[u32]) {
-        unsafe { self.fns.BindTextures(first, textures.len() as _, textures.as_ptr()) }
+        trace!(self, "glBindTextures", first, textures);
+        unsafe { self.fns().BindTextures(first, textures.len() as _, textures.as_ptr()) }
     }
     
     pub fn glBindTransformFeedback(&self, target: BindTransformFeedbackTarget, id: u32) {
-        unsafe { self.fns.BindTransformFeedback(GLenum(target as u32), id) }
+        trace!(self, "glBindTransformFeedback", target, id);
+        unsafe { self.fns().BindTransformFeedback(GLenum(target as u32), id) }
     }
     
     pub fn glBindVertexBuffer(&self, bindingindex: u32, buffer: u32, offset: isize, stride: i32) {
-        unsafe { self.fns.BindVertexBuffer(bindingindex, buffer, offset, stride) }
+        trace!(self, "glBindVertexBuffer", bindingindex, buffer, offset, stride);
+        unsafe { self.fns().BindVertexBuffer(bindingindex, buffer, offset, stride) }
     }
     
     pub fn glBindVertexBuffers(&self, first: u32, buffers: &[u32], offsets: &[isize], strides: &[i32]) {
+        trace!(self, "glBindVertexBuffers", first, buffers, offsets, strides);
         if buffers.len() != offsets.len() || offsets.len() != strides.len() {
             panic!("buffers, offsets, and strides must all be the same length!");
         }
 
-        unsafe { self.fns.BindVertexBuffers(first, buffers.len() as _, buffers.as_ptr(), offsets.as_ptr(), strides.as_ptr()) }
+        unsafe { self.fns().BindVertexBuffers(first, buffers.len() as _, buffers.as_ptr(), offsets.as_ptr(), strides.as_ptr()) }
     }
     
     pub fn glBlendEquationSeparatei(&self, buf: u32, modeRGB: BlendEquationModeEXT, modeAlpha: BlendEquationModeEXT) {
-        unsafe { self.fns.BlendEquationSeparatei(buf, GLenum(modeRGB as u32), GLenum(modeAlpha as u32)) }
+        trace!(self, "glBlendEquationSeparatei", buf, modeRGB, modeAlpha);
+        unsafe { self.fns().BlendEquationSeparatei(buf, GLenum(modeRGB as u32), GLenum(modeAlpha as u32)) }
     }
     
     pub fn glBlendEquationi(&self, buf: u32, mode: BlendEquationModeEXT) {
-        unsafe { self.fns.BlendEquationi(buf, GLenum(mode as u32)) }
+        trace!(self, "glBlendEquationi", buf, mode);
+        unsafe { self.fns().BlendEquationi(buf, GLenum(mode as u32)) }
     }
     
     pub fn glBlendFuncSeparatei(&self, buf: u32, srcRGB: BlendingFactor, dstRGB: BlendingFactor, srcAlpha: BlendingFactor, dstAlpha: BlendingFactor) {
-        unsafe { self.fns.BlendFuncSeparatei(buf, GLenum(srcRGB as u32), GLenum(dstRGB as u32), GLenum(srcAlpha as u32), GLenum(dstAlpha as u32)) }
+        trace!(self, "glBlendFuncSeparatei", buf, srcRGB, dstRGB, srcAlpha, dstAlpha);
+        unsafe { self.fns().BlendFuncSeparatei(buf, GLenum(srcRGB as u32), GLenum(dstRGB as u32), GLenum(srcAlpha as u32), GLenum(dstAlpha as u32)) }
     }
     
     pub fn glBlendFunci(&self, buf: u32, src: BlendingFactor, dst: BlendingFactor) {
-        unsafe { self.fns.BlendFunci(buf, GLenum(src as u32), GLenum(dst as u32)) }
+        trace!(self, "glBlendFunci", buf, src, dst);
+        unsafe { self.fns().BlendFunci(buf, GLenum(src as u32), GLenum(dst as u32)) }
     }
     
     pub fn glBlitNamedFramebuffer(&self, readFramebuffer: u32, drawFramebuffer: u32, srcX0: i32, srcY0: i32, srcX1: i32, srcY1: i32, dstX0: i32, dstY0: i32, dstX1: i32, dstY1: i32, mask: GLbitfield, filter: BlitFramebufferFilter) {
-        unsafe { self.fns.BlitNamedFramebuffer(readFramebuffer, drawFramebuffer, srcX0, srcY0, srcX1, srcY1, dstX0, dstY0, dstX1, dstY1, mask, GLenum(filter as u32)) }
+        trace!(self, "glBlitNamedFramebuffer", readFramebuffer, drawFramebuffer, srcX0, srcY0, srcX1, srcY1, dstX0, dstY0, dstX1, dstY1, mask, filter);
+        unsafe { self.fns().BlitNamedFramebuffer(readFramebuffer, drawFramebuffer, srcX0, srcY0, srcX1, srcY1, dstX0, dstY0, dstX1, dstY1, mask, GLenum(filter as u32)) }
     }
     
     pub fn glBufferStorage<T>(&self, target: BufferStorageTarget, data: &[T], flags: GLbitfield) {
-        unsafe { self.fns.BufferStorage(GLenum(target as u32), (std::mem::size_of::<T>() * data.len()) as _, data.as_ptr() as _, flags) }
+        trace!(self, "glBufferStorage", target, TraceValue::bytes(data), flags);
+        unsafe { self.fns().BufferStorage(GLenum(target as u32), (std::mem::size_of::<T>() * data.len()) as _, data.as_ptr() as _, flags) }
     }
     
     pub fn glCheckNamedFramebufferStatus(&self, framebuffer: u32, target: FramebufferTarget) -> FramebufferStatus {
-        unsafe { self.fns.CheckNamedFramebufferStatus(framebuffer, GLenum(target as u32)) }.0.try_into().unwrap()
+        trace!(self, "glCheckNamedFramebufferStatus", framebuffer, target);
+        unsafe { self.fns().CheckNamedFramebufferStatus(framebuffer, GLenum(target as u32)) }.0.try_into().unwrap()
     }
     
 
     // TODO: This seems wrong
     pub fn glClearBufferData<T: GLType>(&self, target: BufferStorageTarget, internalformat: InternalFormat, format: PixelFormat, data: &[T]) {
+        trace!(self, "glClearBufferData", target, internalformat, format, TraceValue::bytes(data));
         if data.len() != internalformat.size() {
             panic!("Incorrect value size!")
         }
 
-        unsafe { self.fns.ClearBufferData(GLenum(target as u32), GLenum(internalformat as u32), GLenum(format as u32), GLenum(T::gl_type() as u32), data.as_ptr() as _) }
+        unsafe { self.fns().ClearBufferData(GLenum(target as u32), GLenum(internalformat as u32), GLenum(format as u32), GLenum(T::gl_type() as u32), data.as_ptr() as _) }
     }
     
     // TODO: This seems wrong
     pub fn glClearBufferSubData<T: GLType>(&self, target: BufferStorageTarget, internalformat: InternalFormat, offset: isize, size: isize, format: PixelFormat, data: &[T]) {
+        trace!(self, "glClearBufferSubData", target, internalformat, offset, size, format, TraceValue::bytes(data));
         if data.len() != internalformat.size() {
             panic!("Incorrect value size!")
         }
 
-        unsafe { self.fns.ClearBufferSubData(GLenum(target as u32), GLenum(internalformat as u32), offset, size, GLenum(format as u32), GLenum(T::gl_type() as u32), data.as_ptr() as _) }
+        unsafe { self.fns().ClearBufferSubData(GLenum(target as u32), GLenum(internalformat as u32), offset, size, GLenum(format as u32), GLenum(T::gl_type() as u32), data.as_ptr() as _) }
     }
     
     pub fn glClearDepthf(&self, d: f32) {
-        unsafe { self.fns.ClearDepthf(d) }
+        trace!(self, "glClearDepthf", d);
+        unsafe { self.fns().ClearDepthf(d) }
     }
     
     // TODO: This seems wrong
     pub fn glClearNamedBufferData<T: GLType>(&self, buffer: u32, internalformat: InternalFormat, format: PixelFormat, data: &[T]) {
+        trace!(self, "glClearNamedBufferData", buffer, internalformat, format, TraceValue::bytes(data));
         if data.len() != internalformat.size() {
             panic!("Incorrect value size!")
         }
 
-        unsafe { self.fns.ClearNamedBufferData(buffer, GLenum(internalformat as u32), GLenum(format as u32), GLenum(T::gl_type() as u32), data.as_ptr() as _) }
+        unsafe { self.fns().ClearNamedBufferData(buffer, GLenum(internalformat as u32), GLenum(format as u32), GLenum(T::gl_type() as u32), data.as_ptr() as _) }
     }
     
     // TODO: This seems wrong
     pub fn glClearNamedBufferSubData<T: GLType>(&self, buffer: u32, internalformat: InternalFormat, offset: isize, size: isize, format: PixelFormat, data: &[T]) {
+        trace!(self, "glClearNamedBufferSubData", buffer, internalformat, offset, size, format, TraceValue::bytes(data));
         if data.len() != internalformat.size() {
             panic!("Incorrect value size!")
         }
 
-        unsafe { self.fns.ClearNamedBufferSubData(buffer, GLenum(internalformat as u32), offset, size, GLenum(format as u32), GLenum(T::gl_type() as u32), data.as_ptr() as _) }
+        unsafe { self.fns().ClearNamedBufferSubData(buffer, GLenum(internalformat as u32), offset, size, GLenum(format as u32), GLenum(T::gl_type() as u32), data.as_ptr() as _) }
     }
     
     pub fn glClearNamedFramebufferfi(&self, framebuffer: u32, buffer: Buffer, drawbuffer: i32, depth: f32, stencil: i32) {
-        unsafe { self.fns.ClearNamedFramebufferfi(framebuffer, GLenum(buffer as u32), drawbuffer, depth as _, stencil) }
+        trace!(self, "glClearNamedFramebufferfi", framebuffer, buffer, drawbuffer, depth, stencil);
+        unsafe { self.fns().ClearNamedFramebufferfi(framebuffer, GLenum(buffer as u32), drawbuffer, depth as _, stencil) }
     }
     
     pub fn glClearNamedFramebufferfv(&self, framebuffer: u32, buffer: Buffer, drawbuffer: i32, value: &[f32]) {
+        trace!(self, "glClearNamedFramebufferfv", framebuffer, buffer, drawbuffer, value);
         let expected_size = match buffer {
             Buffer::GL_COLOR => 4,
             Buffer::GL_DEPTH => 1,
@@ -1869,10 +2336,11 @@ impl GLWrapper {
             panic!("Incorrect value size!")
         }
 
-        unsafe { self.fns.ClearNamedFramebufferfv(framebuffer, GLenum(buffer as u32), drawbuffer, value.as_ptr()) }
+        unsafe { self.fns().ClearNamedFramebufferfv(framebuffer, GLenum(buffer as u32), drawbuffer, value.as_ptr()) }
     }
     
     pub fn glClearNamedFramebufferiv(&self, framebuffer: u32, buffer: Buffer, drawbuffer: i32, value: &[i32]) {
+        trace!(self, "glClearNamedFramebufferiv", framebuffer, buffer, drawbuffer, value);
         let expected_size = match buffer {
             Buffer::GL_COLOR => 4,
             Buffer::GL_DEPTH => 1,
@@ -1883,10 +2351,11 @@ impl GLWrapper {
             panic!("Incorrect value size!")
         }
 
-        unsafe { self.fns.ClearNamedFramebufferiv(framebuffer, GLenum(buffer as u32), drawbuffer, value.as_ptr() as _) }
+        unsafe { self.fns().ClearNamedFramebufferiv(framebuffer, GLenum(buffer as u32), drawbuffer, value.as_ptr() as _) }
     }
     
     pub fn glClearNamedFramebufferuiv(&self, framebuffer: u32, buffer: Buffer, drawbuffer: i32, value: &[u32]) {
+        trace!(self, "glClearNamedFramebufferuiv", framebuffer, buffer, drawbuffer, value);
         let expected_size = match buffer {
             Buffer::GL_COLOR => 4,
             Buffer::GL_DEPTH => 1,
@@ -1897,1315 +2366,1636 @@ impl GLWrapper {
             panic!("Incorrect value size!")
         }
 
-        unsafe { self.fns.ClearNamedFramebufferuiv(framebuffer, GLenum(buffer as u32), drawbuffer, value.as_ptr() as _) }
+        unsafe { self.fns().ClearNamedFramebufferuiv(framebuffer, GLenum(buffer as u32), drawbuffer, value.as_ptr() as _) }
     }
     
     pub unsafe fn glClearTexImage<T: GLType>(&self, texture: u32, level: i32, format: PixelFormat, type_: PixelType, data: *const c_void ) {
-        self.fns.ClearTexImage(texture, level, GLenum(format as u32), GLenum(type_ as u32), data)
+        trace!(self, "glClearTexImage", texture, level, format, type_, data);
+        self.fns().ClearTexImage(texture, level, GLenum(format as u32), GLenum(type_ as u32), data)
     }
     
     pub unsafe fn glClearTexSubImage(&self, texture: u32, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, width: u32, height: u32, depth: u32, format: PixelFormat, type_: PixelType, data: *const c_void) {
-        self.fns.ClearTexSubImage(texture, level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, GLenum(format as u32), GLenum(type_ as u32), data)
+        trace!(self, "glClearTexSubImage", texture, level, xoffset, yoffset, zoffset, width, height, depth, format, type_, data);
+        self.fns().ClearTexSubImage(texture, level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, GLenum(format as u32), GLenum(type_ as u32), data)
     }
     
     pub fn glClipControl(&self, origin: ClipControlOrigin, depth: ClipControlDepth) {
-        unsafe { self.fns.ClipControl(GLenum(origin as u32), GLenum(depth as u32)) }
+        trace!(self, "glClipControl", origin, depth);
+        unsafe { self.fns().ClipControl(GLenum(origin as u32), GLenum(depth as u32)) }
     }
     
     pub fn glCompressedTextureSubImage1D<T>(&self, target: TextureTarget, level: i32, internalformat: InternalFormat, width: u32, border: i32, data: &[T]) {
-        unsafe { self.fns.CompressedTexImage1D(GLenum(target as u32), level, GLenum(internalformat as u32), width as _, border, (std::mem::size_of::<T>() * data.len()) as _, data.as_ptr() as _) }
+        trace!(self, "glCompressedTextureSubImage1D", target, level, internalformat, width, border, TraceValue::bytes(data));
+        unsafe { self.fns().CompressedTexImage1D(GLenum(target as u32), level, GLenum(internalformat as u32), width as _, border, (std::mem::size_of::<T>() * data.len()) as _, data.as_ptr() as _) }
     }
     
     pub fn glCompressedTextureSubImage2D<T>(&self, target: TextureTarget, level: i32, internalformat: InternalFormat, width: u32, height: u32, border: i32, data: &[T]) {
-        unsafe { self.fns.CompressedTexImage2D(GLenum(target as u32), level, GLenum(internalformat as u32), width as _, height as _, border, (std::mem::size_of::<T>() * data.len()) as _, data.as_ptr() as _) }
+        trace!(self, "glCompressedTextureSubImage2D", target, level, internalformat, width, height, border, TraceValue::bytes(data));
+        unsafe { self.fns().CompressedTexImage2D(GLenum(target as u32), level, GLenum(internalformat as u32), width as _, height as _, border, (std::mem::size_of::<T>() * data.len()) as _, data.as_ptr() as _) }
     }
     
     pub fn glCompressedTextureSubImage3D<T>(&self, target: TextureTarget, level: i32, internalformat: InternalFormat, width: u32, height: u32, depth: u32, border: i32, data: &[T]) {
-        unsafe { self.fns.CompressedTexImage3D(GLenum(target as u32), level, GLenum(internalformat as u32), width as _, height as _, depth as _, border, (std::mem::size_of::<T>() * data.len()) as _, data.as_ptr() as _) }
+        trace!(self, "glCompressedTextureSubImage3D", target, level, internalformat, width, height, depth, border, TraceValue::bytes(data));
+        unsafe { self.fns().CompressedTexImage3D(GLenum(target as u32), level, GLenum(internalformat as u32), width as _, height as _, depth as _, border, (std::mem::size_of::<T>() * data.len()) as _, data.as_ptr() as _) }
     }
     
     pub fn glCopyImageSubData(&self, srcName: u32, srcTarget: CopyImageSubDataTarget, srcLevel: i32, srcX: i32, srcY: i32, srcZ: i32, dstName: u32, dstTarget: CopyImageSubDataTarget, dstLevel: i32, dstX: i32, dstY: i32, dstZ: i32, srcWidth: u32, srcHeight: u32, srcDepth: u32) {
-        unsafe { self.fns.CopyImageSubData(srcName, GLenum(srcTarget as u32), srcLevel, srcX, srcY, srcZ, dstName, GLenum(dstTarget as u32), dstLevel, dstX, dstY, dstZ, srcWidth as _, srcHeight as _, srcDepth as _) }
+        trace!(self, "glCopyImageSubData", srcName, srcTarget, srcLevel, srcX, srcY, srcZ, dstName, dstTarget, dstLevel, dstX, dstY, dstZ, srcWidth, srcHeight, srcDepth);
+        unsafe { self.fns().CopyImageSubData(srcName, GLenum(srcTarget as u32), srcLevel, srcX, srcY, srcZ, dstName, GLenum(dstTarget as u32), dstLevel, dstX, dstY, dstZ, srcWidth as _, srcHeight as _, srcDepth as _) }
     }
     
     pub fn glCopyNamedBufferSubData(&self, readBuffer: u32, writeBuffer: u32, readOffset: isize, writeOffset: isize, size: usize) {
-        unsafe { self.fns.CopyNamedBufferSubData(readBuffer, writeBuffer, readOffset, writeOffset, size as _) }
+        trace!(self, "glCopyNamedBufferSubData", readBuffer, writeBuffer, readOffset, writeOffset, size);
+        unsafe { self.fns().CopyNamedBufferSubData(readBuffer, writeBuffer, readOffset, writeOffset, size as _) }
     }
 
     pub fn glCopyTextureSubImage1D(&self, texture: u32, level: i32, xoffset: i32, x: i32, y: i32, width: u32) {
-        unsafe { self.fns.CopyTextureSubImage1D(texture, level, xoffset, x, y, width as _) }
+        trace!(self, "glCopyTextureSubImage1D", texture, level, xoffset, x, y, width);
+        unsafe { self.fns().CopyTextureSubImage1D(texture, level, xoffset, x, y, width as _) }
     }
 
     pub fn glCopyTextureSubImage2D(&self, texture: u32, level: i32, xoffset: i32, yoffset: i32, x: i32, y: i32, width: u32, height: u32) {
-        unsafe { self.fns.CopyTextureSubImage2D(texture, level, xoffset, yoffset, x, y, width as _, height as _) }
+        trace!(self, "glCopyTextureSubImage2D", texture, level, xoffset, yoffset, x, y, width, height);
+        unsafe { self.fns().CopyTextureSubImage2D(texture, level, xoffset, yoffset, x, y, width as _, height as _) }
     }
 
     pub fn glCopyTextureSubImage3D(&self, texture: u32, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, x: i32, y: i32, width: u32, height: u32) {
-        unsafe { self.fns.CopyTextureSubImage3D(texture, level, xoffset, yoffset, zoffset, x, y, width as _, height as _) }
+        trace!(self, "glCopyTextureSubImage3D", texture, level, xoffset, yoffset, zoffset, x, y, width, height);
+        unsafe { self.fns().CopyTextureSubImage3D(texture, level, xoffset, yoffset, zoffset, x, y, width as _, height as _) }
     }
 
     pub fn glCreateBuffers(&self, buffers: &mut [u32]) {
-        unsafe { self.fns.CreateBuffers(buffers.len() as _, buffers.as_mut_ptr()) }
+        trace!(self, "glCreateBuffers", buffers.len());
+        unsafe { self.fns().CreateBuffers(buffers.len() as _, buffers.as_mut_ptr()) };
+        trace_output!(self, buffers);
     }
 
     pub fn glCreateFramebuffers(&self, framebuffers: &mut [u32]) {
-        unsafe { self.fns.CreateFramebuffers(framebuffers.len() as _, framebuffers.as_mut_ptr()) }
+        trace!(self, "glCreateFramebuffers", framebuffers.len());
+        unsafe { self.fns().CreateFramebuffers(framebuffers.len() as _, framebuffers.as_mut_ptr()) };
+        trace_output!(self, framebuffers);
     }
 
     pub fn glCreateProgramPipelines(&self, pipelines: &mut [u32]) {
-        unsafe { self.fns.CreateProgramPipelines(pipelines.len() as _, pipelines.as_mut_ptr()) }
+        trace!(self, "glCreateProgramPipelines", pipelines.len());
+        unsafe { self.fns().CreateProgramPipelines(pipelines.len() as _, pipelines.as_mut_ptr()) };
+        trace_output!(self, pipelines);
     }
 
     pub fn glCreateQueries(&self, target: QueryTarget, ids: &mut [u32]) {
-        unsafe { self.fns.CreateQueries(GLenum(target as u32), ids.len() as _, ids.as_mut_ptr()) }
+        trace!(self, "glCreateQueries", target, ids.len());
+        unsafe { self.fns().CreateQueries(GLenum(target as u32), ids.len() as _, ids.as_mut_ptr()) };
+        trace_output!(self, ids);
     }
 
     pub fn glCreateRenderbuffers(&self, renderbuffers: &mut [u32]) {
-        unsafe { self.fns.CreateRenderbuffers(renderbuffers.len() as _, renderbuffers.as_mut_ptr()) }
+        trace!(self, "glCreateRenderbuffers", renderbuffers.len());
+        unsafe { self.fns().CreateRenderbuffers(renderbuffers.len() as _, renderbuffers.as_mut_ptr()) };
+        trace_output!(self, renderbuffers);
     }
 
     pub fn glCreateSamplers(&self, samplers: &mut [u32]) {
-        unsafe { self.fns.CreateSamplers(samplers.len() as _, samplers.as_mut_ptr()) }
+        trace!(self, "glCreateSamplers", samplers.len());
+        unsafe { self.fns().CreateSamplers(samplers.len() as _, samplers.as_mut_ptr()) };
+        trace_output!(self, samplers);
     }
 
     pub fn glCreateShaderProgramv(&self, type_: ShaderType, strings: &[&str]) -> u32 {
+        trace!(self, "glCreateShaderProgramv", type_, strings);
         let strings = CStringArray::new(strings);
-        unsafe { self.fns.CreateShaderProgramv(GLenum(type_ as u32), strings.len() as _, strings.as_ptr()) }
+        let program = unsafe { self.fns().CreateShaderProgramv(GLenum(type_ as u32), strings.len() as _, strings.as_ptr()) };
+        trace_output!(self, program);
+        program
     }
 
     pub fn glCreateTextures(&self, target: TextureTarget, textures: &mut [u32]) {
-        unsafe { self.fns.CreateTextures(GLenum(target as u32), textures.len() as _, textures.as_mut_ptr()) }
+        trace!(self, "glCreateTextures", target, textures.len());
+        unsafe { self.fns().CreateTextures(GLenum(target as u32), textures.len() as _, textures.as_mut_ptr()) };
+        trace_output!(self, textures);
     }
 
     pub fn glCreateTransformFeedbacks(&self, ids: &mut [u32]) {
-        unsafe { self.fns.CreateTransformFeedbacks(ids.len() as _, ids.as_mut_ptr()) }
+        trace!(self, "glCreateTransformFeedbacks", ids.len());
+        unsafe { self.fns().CreateTransformFeedbacks(ids.len() as _, ids.as_mut_ptr()) };
+        trace_output!(self, ids);
     }
 
     pub fn glCreateVertexArrays(&self, arrays: &mut [u32]) {
-        unsafe { self.fns.CreateVertexArrays(arrays.len() as _, arrays.as_mut_ptr()) }
+        trace!(self, "glCreateVertexArrays", arrays.len());
+        unsafe { self.fns().CreateVertexArrays(arrays.len() as _, arrays.as_mut_ptr()) };
+        trace_output!(self, arrays);
     }
 
     pub fn glDeleteProgramPipelines(&self, pipelines: &[u32]) {
-        unsafe { self.fns.DeleteProgramPipelines(pipelines.len() as _, pipelines.as_ptr()) }
+        trace!(self, "glDeleteProgramPipelines", pipelines);
+        unsafe { self.fns().DeleteProgramPipelines(pipelines.len() as _, pipelines.as_ptr()) }
     }
 
     pub fn glDeleteTransformFeedbacks(&self, ids: &[u32]) {
-        unsafe { self.fns.DeleteTransformFeedbacks(ids.len() as _, ids.as_ptr()) }
+        trace!(self, "glDeleteTransformFeedbacks", ids);
+        unsafe { self.fns().DeleteTransformFeedbacks(ids.len() as _, ids.as_ptr()) }
     }
 
     pub fn glDepthRangeArrayv(&self, first: u32, v: &[(f64, f64)]) {
-        unsafe { self.fns.DepthRangeArrayv(first, v.len() as _, v.as_ptr() as _) }
+        trace!(self, "glDepthRangeArrayv", first, TraceValue::bytes(v));
+        unsafe { self.fns().DepthRangeArrayv(first, v.len() as _, v.as_ptr() as _) }
     }
 
     pub fn glDepthRangeIndexed(&self, index: u32, n: f64, f: f64) {
-        unsafe { self.fns.DepthRangeIndexed(index, n, f) }
+        trace!(self, "glDepthRangeIndexed", index, n, f);
+        unsafe { self.fns().DepthRangeIndexed(index, n, f) }
     }
 
     pub fn glDepthRangef(&self, n: f32, f: f32) {
-        unsafe { self.fns.DepthRangef(n, f) }
+        trace!(self, "glDepthRangef", n, f);
+        unsafe { self.fns().DepthRangef(n, f) }
     }
 
     pub fn glDisableVertexArrayAttrib(&self, vaobj: u32, index: u32) {
-        unsafe { self.fns.DisableVertexArrayAttrib(vaobj, index) }
+        trace!(self, "glDisableVertexArrayAttrib", vaobj, index);
+        unsafe { self.fns().DisableVertexArrayAttrib(vaobj, index) }
     }
 
     pub fn glDispatchCompute(&self, num_groups_x: u32, num_groups_y: u32, num_groups_z: u32) {
-        unsafe { self.fns.DispatchCompute(num_groups_x, num_groups_y, num_groups_z) }
+        trace!(self, "glDispatchCompute", num_groups_x, num_groups_y, num_groups_z);
+        unsafe { self.fns().DispatchCompute(num_groups_x, num_groups_y, num_groups_z) }
     }
 
     pub fn glDispatchComputeIndirect(&self, indirect: isize) {
-        unsafe { self.fns.DispatchComputeIndirect(indirect) }
+        trace!(self, "glDispatchComputeIndirect", indirect);
+        unsafe { self.fns().DispatchComputeIndirect(indirect) }
     }
 
     pub unsafe fn glDrawArraysIndirect(&self, mode: PrimitiveType, indirect: *const c_void) {
+        trace!(self, "glDrawArraysIndirect", mode, indirect);
         // Needs Special Attention
-        unsafe { self.fns.DrawArraysIndirect(GLenum(mode as u32), indirect) }
+        unsafe { self.fns().DrawArraysIndirect(GLenum(mode as u32), indirect) }
     }
 
     pub fn glDrawArraysInstancedBaseInstance(&self, mode: PrimitiveType, first: i32, count: u32, instancecount: u32, baseinstance: u32) {
-        unsafe { self.fns.DrawArraysInstancedBaseInstance(GLenum(mode as u32), first, count as _, instancecount as _, baseinstance) }
+        trace!(self, "glDrawArraysInstancedBaseInstance", mode, first, count, instancecount, baseinstance);
+        unsafe { self.fns().DrawArraysInstancedBaseInstance(GLenum(mode as u32), first, count as _, instancecount as _, baseinstance) }
     }
 
     pub fn glDrawElementsIndirect(&self, mode: PrimitiveType, type_: DrawElementsType, indirect: *const c_void) {
+        trace!(self, "glDrawElementsIndirect", mode, type_, indirect);
         // Unreviewed
-        unsafe { self.fns.DrawElementsIndirect(GLenum(mode as u32), GLenum(type_ as u32), indirect) }
+        unsafe { self.fns().DrawElementsIndirect(GLenum(mode as u32), GLenum(type_ as u32), indirect) }
     }
 
     pub fn glDrawElementsInstancedBaseInstance(&self, mode: PrimitiveType, count: u32, type_: PrimitiveType, indices: *const c_void, instancecount: u32, baseinstance: u32) {
+        trace!(self, "glDrawElementsInstancedBaseInstance", mode, count, type_, indices, instancecount, baseinstance);
         // Unreviewed
-        unsafe { self.fns.DrawElementsInstancedBaseInstance(GLenum(mode as u32), count as _, GLenum(type_ as u32), indices, instancecount as _, baseinstance) }
+        unsafe { self.fns().DrawElementsInstancedBaseInstance(GLenum(mode as u32), count as _, GLenum(type_ as u32), indices, instancecount as _, baseinstance) }
     }
 
     pub fn glDrawElementsInstancedBaseVertexBaseInstance(&self, mode: PrimitiveType, count: u32, type_: DrawElementsType, indices: *const c_void, instancecount: u32, basevertex: i32, baseinstance: u32) {
+        trace!(self, "glDrawElementsInstancedBaseVertexBaseInstance", mode, count, type_, indices, instancecount, basevertex, baseinstance);
         // Unreviewed
-        unsafe { self.fns.DrawElementsInstancedBaseVertexBaseInstance(GLenum(mode as u32), count as _, GLenum(type_ as u32), indices, instancecount as _, basevertex, baseinstance) }
+        unsafe { self.fns().DrawElementsInstancedBaseVertexBaseInstance(GLenum(mode as u32), count as _, GLenum(type_ as u32), indices, instancecount as _, basevertex, baseinstance) }
     }
 
     pub fn glDrawTransformFeedback(&self, mode: PrimitiveType, id: u32) {
-        unsafe { self.fns.DrawTransformFeedback(GLenum(mode as u32), id) }
+        trace!(self, "glDrawTransformFeedback", mode, id);
+        unsafe { self.fns().DrawTransformFeedback(GLenum(mode as u32), id) }
     }
 
     pub fn glDrawTransformFeedbackInstanced(&self, mode: PrimitiveType, id: u32, instancecount: u32) {
-        unsafe { self.fns.DrawTransformFeedbackInstanced(GLenum(mode as u32), id, instancecount as _) }
+        trace!(self, "glDrawTransformFeedbackInstanced", mode, id, instancecount);
+        unsafe { self.fns().DrawTransformFeedbackInstanced(GLenum(mode as u32), id, instancecount as _) }
     }
 
     pub fn glDrawTransformFeedbackStream(&self, mode: PrimitiveType, id: u32, stream: u32) {
-        unsafe { self.fns.DrawTransformFeedbackStream(GLenum(mode as u32), id, stream) }
+        trace!(self, "glDrawTransformFeedbackStream", mode, id, stream);
+        unsafe { self.fns().DrawTransformFeedbackStream(GLenum(mode as u32), id, stream) }
     }
 
     pub fn glDrawTransformFeedbackStreamInstanced(&self, mode: PrimitiveType, id: u32, stream: u32, instancecount: u32) {
-        unsafe { self.fns.DrawTransformFeedbackStreamInstanced(GLenum(mode as u32), id, stream, instancecount as _) }
+        trace!(self, "glDrawTransformFeedbackStreamInstanced", mode, id, stream, instancecount);
+        unsafe { self.fns().DrawTransformFeedbackStreamInstanced(GLenum(mode as u32), id, stream, instancecount as _) }
     }
 
     pub fn glEnableVertexArrayAttrib(&self, vaobj: u32, index: u32) {
-        unsafe { self.fns.EnableVertexArrayAttrib(vaobj, index) }
+        trace!(self, "glEnableVertexArrayAttrib", vaobj, index);
+        unsafe { self.fns().EnableVertexArrayAttrib(vaobj, index) }
     }
 
     pub fn glEndQueryIndexed(&self, target: QueryTarget, index: u32) {
-        unsafe { self.fns.EndQueryIndexed(GLenum(target as u32), index) }
+        trace!(self, "glEndQueryIndexed", target, index);
+        unsafe { self.fns().EndQueryIndexed(GLenum(target as u32), index) }
     }
 
     pub fn glFlushMappedNamedBufferRange(&self, buffer: u32, offset: isize, length: usize) {
-        unsafe { self.fns.FlushMappedNamedBufferRange(buffer, offset, length as _) }
+        trace!(self, "glFlushMappedNamedBufferRange", buffer, offset, length);
+        unsafe { self.fns().FlushMappedNamedBufferRange(buffer, offset, length as _) }
     }
 
     pub fn glFramebufferParameteri(&self, target: FramebufferTarget, pname: FramebufferParameterName, param: i32) {
-        unsafe { self.fns.FramebufferParameteri(GLenum(target as u32), GLenum(pname as u32), param) }
+        trace!(self, "glFramebufferParameteri", target, pname, param);
+        unsafe { self.fns().FramebufferParameteri(GLenum(target as u32), GLenum(pname as u32), param) }
     }
 
     pub fn glGenProgramPipelines(&self, n: u32, pipelines: *mut u32) {
+        trace!(self, "glGenProgramPipelines", n, pipelines);
         // Unreviewed
-        unsafe { self.fns.GenProgramPipelines(n as _, pipelines) }
+        unsafe { self.fns().GenProgramPipelines(n as _, pipelines) }
     }
 
     pub fn glGenTransformFeedbacks(&self, n: u32, ids: *mut u32) {
+        trace!(self, "glGenTransformFeedbacks", n, ids);
         // Unreviewed
-        unsafe { self.fns.GenTransformFeedbacks(n as _, ids) }
+        unsafe { self.fns().GenTransformFeedbacks(n as _, ids) }
     }
 
     pub fn glGenerateTextureMipmap(&self, texture: u32) {
-        unsafe { self.fns.GenerateTextureMipmap(texture) }
+        trace!(self, "glGenerateTextureMipmap", texture);
+        unsafe { self.fns().GenerateTextureMipmap(texture) }
     }
 
     pub fn glGetActiveAtomicCounterBufferiv(&self, program: u32, bufferIndex: u32, pname: AtomicCounterBufferPName, params: *mut i32) {
+        trace!(self, "glGetActiveAtomicCounterBufferiv", program, bufferIndex, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetActiveAtomicCounterBufferiv(program, bufferIndex, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetActiveAtomicCounterBufferiv(program, bufferIndex, GLenum(pname as u32), params) }
     }
 
     pub fn glGetActiveSubroutineName(&self, program: u32, shadertype: ShaderType, index: u32, bufSize: u32, length: *mut u32, name: *mut u8) {
+        trace!(self, "glGetActiveSubroutineName", program, shadertype, index, bufSize, length, name);
         // Unreviewed
-        unsafe { self.fns.GetActiveSubroutineName(program, GLenum(shadertype as u32), index, bufSize as _, length as _, name) }
+        unsafe { self.fns().GetActiveSubroutineName(program, GLenum(shadertype as u32), index, bufSize as _, length as _, name) }
     }
 
     pub fn glGetActiveSubroutineUniformName(&self, program: u32, shadertype: ShaderType, index: u32, bufSize: u32, length: *mut u32, name: *mut u8) {
+        trace!(self, "glGetActiveSubroutineUniformName", program, shadertype, index, bufSize, length, name);
         // Unreviewed
-        unsafe { self.fns.GetActiveSubroutineUniformName(program, GLenum(shadertype as u32), index, bufSize as _, length as _, name) }
+        unsafe { self.fns().GetActiveSubroutineUniformName(program, GLenum(shadertype as u32), index, bufSize as _, length as _, name) }
     }
 
     pub fn glGetActiveSubroutineUniformiv(&self, program: u32, shadertype: ShaderType, index: u32, pname: SubroutineParameterName, values: *mut i32) {
+        trace!(self, "glGetActiveSubroutineUniformiv", program, shadertype, index, pname, values);
         // Unreviewed
-        unsafe { self.fns.GetActiveSubroutineUniformiv(program, GLenum(shadertype as u32), index, GLenum(pname as u32), values) }
+        unsafe { self.fns().GetActiveSubroutineUniformiv(program, GLenum(shadertype as u32), index, GLenum(pname as u32), values) }
     }
 
     pub fn glGetCompressedTextureImage(&self, texture: u32, level: i32, bufSize: u32, pixels: *mut c_void) {
+        trace!(self, "glGetCompressedTextureImage", texture, level, bufSize, pixels);
         // Unreviewed
-        unsafe { self.fns.GetCompressedTextureImage(texture, level, bufSize as _, pixels) }
+        unsafe { self.fns().GetCompressedTextureImage(texture, level, bufSize as _, pixels) }
     }
 
     pub fn glGetCompressedTextureSubImage(&self, texture: u32, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, width: u32, height: u32, depth: u32, bufSize: u32, pixels: *mut c_void) {
+        trace!(self, "glGetCompressedTextureSubImage", texture, level, xoffset, yoffset, zoffset, width, height, depth, bufSize, pixels);
         // Unreviewed
-        unsafe { self.fns.GetCompressedTextureSubImage(texture, level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, bufSize as _, pixels) }
+        unsafe { self.fns().GetCompressedTextureSubImage(texture, level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, bufSize as _, pixels) }
     }
 
     pub fn glGetDoublei_v(&self, target: GetPName, index: u32, data: *mut f64) {
+        trace!(self, "glGetDoublei_v", target, index, data);
         // Unreviewed
-        unsafe { self.fns.GetDoublei_v(GLenum(target as u32), index, data) }
+        unsafe { self.fns().GetDoublei_v(GLenum(target as u32), index, data) }
     }
 
     pub fn glGetFloati_v(&self, target: GetPName, index: u32, data: *mut f32) {
+        trace!(self, "glGetFloati_v", target, index, data);
         // Unreviewed
-        unsafe { self.fns.GetFloati_v(GLenum(target as u32), index, data) }
+        unsafe { self.fns().GetFloati_v(GLenum(target as u32), index, data) }
     }
 
     pub fn glGetFramebufferParameteriv(&self, target: FramebufferTarget, pname: FramebufferAttachmentParameterName, params: *mut i32) {
+        trace!(self, "glGetFramebufferParameteriv", target, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetFramebufferParameteriv(GLenum(target as u32), GLenum(pname as u32), params) }
+        unsafe { self.fns().GetFramebufferParameteriv(GLenum(target as u32), GLenum(pname as u32), params) }
     }
 
     pub fn glGetGraphicsResetStatus(&self) -> GraphicsResetStatus {
-        unsafe { self.fns.GetGraphicsResetStatus() }.0.try_into().unwrap()
+        trace!(self, "glGetGraphicsResetStatus");
+        unsafe { self.fns().GetGraphicsResetStatus() }.0.try_into().unwrap()
     }
 
     pub fn glGetInternalformati64v(&self, target: TextureTarget, internalformat: InternalFormat, pname: InternalFormatPName, count: u32, params: *mut i64) {
+        trace!(self, "glGetInternalformati64v", target, internalformat, pname, count, params);
         // Unreviewed
-        unsafe { self.fns.GetInternalformati64v(GLenum(target as u32), GLenum(internalformat as u32), GLenum(pname as u32), count as _, params) }
+        unsafe { self.fns().GetInternalformati64v(GLenum(target as u32), GLenum(internalformat as u32), GLenum(pname as u32), count as _, params) }
     }
 
     pub fn glGetInternalformativ(&self, target: TextureTarget, internalformat: InternalFormat, pname: InternalFormatPName, count: u32, params: *mut i32) {
+        trace!(self, "glGetInternalformativ", target, internalformat, pname, count, params);
         // Unreviewed
-        unsafe { self.fns.GetInternalformativ(GLenum(target as u32), GLenum(internalformat as u32), GLenum(pname as u32), count as _, params) }
+        unsafe { self.fns().GetInternalformativ(GLenum(target as u32), GLenum(internalformat as u32), GLenum(pname as u32), count as _, params) }
     }
 
     pub fn glGetNamedBufferParameteri64v(&self, buffer: u32, pname: BufferPNameARB, params: *mut i64) {
+        trace!(self, "glGetNamedBufferParameteri64v", buffer, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetNamedBufferParameteri64v(buffer, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetNamedBufferParameteri64v(buffer, GLenum(pname as u32), params) }
     }
 
     pub fn glGetNamedBufferParameteriv(&self, buffer: u32, pname: BufferPNameARB, params: *mut i32) {
+        trace!(self, "glGetNamedBufferParameteriv", buffer, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetNamedBufferParameteriv(buffer, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetNamedBufferParameteriv(buffer, GLenum(pname as u32), params) }
     }
 
     pub fn glGetNamedBufferPointerv(&self, buffer: u32, pname: BufferPointerNameARB, params: *mut *mut c_void) {
+        trace!(self, "glGetNamedBufferPointerv", buffer, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetNamedBufferPointerv(buffer, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetNamedBufferPointerv(buffer, GLenum(pname as u32), params) }
     }
 
     pub fn glGetNamedBufferSubData(&self, buffer: u32, offset: isize, size: usize, data: *mut c_void) {
+        trace!(self, "glGetNamedBufferSubData", buffer, offset, size, data);
         // Unreviewed
-        unsafe { self.fns.GetNamedBufferSubData(buffer, offset, size as _, data) }
+        unsafe { self.fns().GetNamedBufferSubData(buffer, offset, size as _, data) }
     }
 
     pub fn glGetNamedFramebufferAttachmentParameteriv(&self, framebuffer: u32, attachment: FramebufferAttachment, pname: FramebufferAttachmentParameterName, params: *mut i32) {
+        trace!(self, "glGetNamedFramebufferAttachmentParameteriv", framebuffer, attachment, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetNamedFramebufferAttachmentParameteriv(framebuffer, GLenum(attachment as u32), GLenum(pname as u32), params) }
+        unsafe { self.fns().GetNamedFramebufferAttachmentParameteriv(framebuffer, GLenum(attachment as u32), GLenum(pname as u32), params) }
     }
 
     pub fn glGetNamedFramebufferParameteriv(&self, framebuffer: u32, pname: GetFramebufferParameter, param: *mut i32) {
+        trace!(self, "glGetNamedFramebufferParameteriv", framebuffer, pname, param);
         // Unreviewed
-        unsafe { self.fns.GetNamedFramebufferParameteriv(framebuffer, GLenum(pname as u32), param) }
+        unsafe { self.fns().GetNamedFramebufferParameteriv(framebuffer, GLenum(pname as u32), param) }
     }
 
     pub fn glGetNamedRenderbufferParameteriv(&self, renderbuffer: u32, pname: RenderbufferParameterName, params: *mut i32) {
+        trace!(self, "glGetNamedRenderbufferParameteriv", renderbuffer, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetNamedRenderbufferParameteriv(renderbuffer, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetNamedRenderbufferParameteriv(renderbuffer, GLenum(pname as u32), params) }
     }
 
     pub fn glGetProgramBinary(&self, program: u32, bufSize: u32, length: *mut u32, binaryFormat: *mut GLenum, binary: *mut c_void) {
+        trace!(self, "glGetProgramBinary", program, bufSize, length, binaryFormat, binary);
         // Unreviewed
-        unsafe { self.fns.GetProgramBinary(program, bufSize as _, length as _, binaryFormat, binary) }
+        unsafe { self.fns().GetProgramBinary(program, bufSize as _, length as _, binaryFormat, binary) }
     }
 
     pub fn glGetProgramInterfaceiv(&self, program: u32, programInterface: ProgramInterface, pname: ProgramInterfacePName, params: *mut i32) {
+        trace!(self, "glGetProgramInterfaceiv", program, programInterface, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetProgramInterfaceiv(program, GLenum(programInterface as u32), GLenum(pname as u32), params) }
+        unsafe { self.fns().GetProgramInterfaceiv(program, GLenum(programInterface as u32), GLenum(pname as u32), params) }
     }
 
     pub fn glGetProgramPipelineInfoLog(&self, pipeline: u32, bufSize: u32, length: *mut u32, infoLog: *mut u8) {
+        trace!(self, "glGetProgramPipelineInfoLog", pipeline, bufSize, length, infoLog);
         // Unreviewed
-        unsafe { self.fns.GetProgramPipelineInfoLog(pipeline, bufSize as _, length as _, infoLog) }
+        unsafe { self.fns().GetProgramPipelineInfoLog(pipeline, bufSize as _, length as _, infoLog) }
     }
 
     pub fn glGetProgramPipelineiv(&self, pipeline: u32, pname: PipelineParameterName, params: *mut i32) {
+        trace!(self, "glGetProgramPipelineiv", pipeline, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetProgramPipelineiv(pipeline, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetProgramPipelineiv(pipeline, GLenum(pname as u32), params) }
     }
 
     pub fn glGetProgramResourceIndex(&self, program: u32, programInterface: ProgramInterface, name: *const u8) -> u32 {
+        trace!(self, "glGetProgramResourceIndex", program, programInterface, name);
         // Unreviewed
-        unsafe { self.fns.GetProgramResourceIndex(program, GLenum(programInterface as u32), name) }
+        unsafe { self.fns().GetProgramResourceIndex(program, GLenum(programInterface as u32), name) }
     }
 
     pub fn glGetProgramResourceLocation(&self, program: u32, programInterface: ProgramInterface, name: *const u8) -> i32 {
+        trace!(self, "glGetProgramResourceLocation", program, programInterface, name);
         // Unreviewed
-        unsafe { self.fns.GetProgramResourceLocation(program, GLenum(programInterface as u32), name) }
+        unsafe { self.fns().GetProgramResourceLocation(program, GLenum(programInterface as u32), name) }
     }
 
     pub fn glGetProgramResourceLocationIndex(&self, program: u32, programInterface: ProgramInterface, name: *const u8) -> i32 {
+        trace!(self, "glGetProgramResourceLocationIndex", program, programInterface, name);
         // Unreviewed
-        unsafe { self.fns.GetProgramResourceLocationIndex(program, GLenum(programInterface as u32), name) }
+        unsafe { self.fns().GetProgramResourceLocationIndex(program, GLenum(programInterface as u32), name) }
     }
 
     pub fn glGetProgramResourceName(&self, program: u32, programInterface: ProgramInterface, index: u32, bufSize: u32, length: *mut u32, name: *mut u8) {
+        trace!(self, "glGetProgramResourceName", program, programInterface, index, bufSize, length, name);
         // Unreviewed
-        unsafe { self.fns.GetProgramResourceName(program, GLenum(programInterface as u32), index, bufSize as _, length as _, name) }
+        unsafe { self.fns().GetProgramResourceName(program, GLenum(programInterface as u32), index, bufSize as _, length as _, name) }
     }
 
     pub fn glGetProgramResourceiv(&self, program: u32, programInterface: ProgramInterface, index: u32, propCount: u32, props: *const ProgramResourceProperty, count: u32, length: *mut u32, params: *mut i32) {
+        trace!(self, "glGetProgramResourceiv", program, programInterface, index, propCount, props, count, length, params);
         // Unreviewed
-        unsafe { self.fns.GetProgramResourceiv(program, GLenum(programInterface as u32), index, propCount as _, props as _, count as _, length as _, params) }
+        unsafe { self.fns().GetProgramResourceiv(program, GLenum(programInterface as u32), index, propCount as _, props as _, count as _, length as _, params) }
     }
 
     pub fn glGetProgramStageiv(&self, program: u32, shadertype: ShaderType, pname: ProgramStagePName, values: *mut i32) {
+        trace!(self, "glGetProgramStageiv", program, shadertype, pname, values);
         // Unreviewed
-        unsafe { self.fns.GetProgramStageiv(program, GLenum(shadertype as u32), GLenum(pname as u32), values) }
+        unsafe { self.fns().GetProgramStageiv(program, GLenum(shadertype as u32), GLenum(pname as u32), values) }
     }
 
     pub fn glGetQueryBufferObjecti64v(&self, id: u32, buffer: u32, pname: QueryObjectParameterName, offset: isize) {
-        unsafe { self.fns.GetQueryBufferObjecti64v(id, buffer, GLenum(pname as u32), offset) }
+        trace!(self, "glGetQueryBufferObjecti64v", id, buffer, pname, offset);
+        unsafe { self.fns().GetQueryBufferObjecti64v(id, buffer, GLenum(pname as u32), offset) }
     }
 
     pub fn glGetQueryBufferObjectiv(&self, id: u32, buffer: u32, pname: QueryObjectParameterName, offset: isize) {
-        unsafe { self.fns.GetQueryBufferObjectiv(id, buffer, GLenum(pname as u32), offset) }
+        trace!(self, "glGetQueryBufferObjectiv", id, buffer, pname, offset);
+        unsafe { self.fns().GetQueryBufferObjectiv(id, buffer, GLenum(pname as u32), offset) }
     }
 
     pub fn glGetQueryBufferObjectui64v(&self, id: u32, buffer: u32, pname: QueryObjectParameterName, offset: isize) {
-        unsafe { self.fns.GetQueryBufferObjectui64v(id, buffer, GLenum(pname as u32), offset) }
+        trace!(self, "glGetQueryBufferObjectui64v", id, buffer, pname, offset);
+        unsafe { self.fns().GetQueryBufferObjectui64v(id, buffer, GLenum(pname as u32), offset) }
     }
 
     pub fn glGetQueryBufferObjectuiv(&self, id: u32, buffer: u32, pname: QueryObjectParameterName, offset: isize) {
-        unsafe { self.fns.GetQueryBufferObjectuiv(id, buffer, GLenum(pname as u32), offset) }
+        trace!(self, "glGetQueryBufferObjectuiv", id, buffer, pname, offset);
+        unsafe { self.fns().GetQueryBufferObjectuiv(id, buffer, GLenum(pname as u32), offset) }
     }
 
     pub fn glGetQueryIndexediv(&self, target: QueryTarget, index: u32, pname: QueryParameterName, params: *mut i32) {
+        trace!(self, "glGetQueryIndexediv", target, index, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetQueryIndexediv(GLenum(target as u32), index, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetQueryIndexediv(GLenum(target as u32), index, GLenum(pname as u32), params) }
     }
 
     pub fn glGetShaderPrecisionFormat(&self, shadertype: ShaderType, precisiontype: PrecisionType, range: *mut [i32; 2], precision: *mut i32) {
+        trace!(self, "glGetShaderPrecisionFormat", shadertype, precisiontype, range, precision);
         // Unreviewed
-        unsafe { self.fns.GetShaderPrecisionFormat(GLenum(shadertype as u32), GLenum(precisiontype as u32), range, precision) }
+        unsafe { self.fns().GetShaderPrecisionFormat(GLenum(shadertype as u32), GLenum(precisiontype as u32), range, precision) }
     }
 
     pub fn glGetSubroutineIndex(&self, program: u32, shadertype: ShaderType, name: *const u8) -> u32 {
+        trace!(self, "glGetSubroutineIndex", program, shadertype, name);
         // Unreviewed
-        unsafe { self.fns.GetSubroutineIndex(program, GLenum(shadertype as u32), name) }
+        unsafe { self.fns().GetSubroutineIndex(program, GLenum(shadertype as u32), name) }
     }
 
     pub fn glGetSubroutineUniformLocation(&self, program: u32, shadertype: ShaderType, name: *const u8) -> i32 {
+        trace!(self, "glGetSubroutineUniformLocation", program, shadertype, name);
         // Unreviewed
-        unsafe { self.fns.GetSubroutineUniformLocation(program, GLenum(shadertype as u32), name) }
+        unsafe { self.fns().GetSubroutineUniformLocation(program, GLenum(shadertype as u32), name) }
     }
 
     pub fn glGetTextureImage(&self, texture: u32, level: i32, format: PixelFormat, type_: PixelType, bufSize: u32, pixels: *mut c_void) {
+        trace!(self, "glGetTextureImage", texture, level, format, type_, bufSize, pixels);
         // Unreviewed
-        unsafe { self.fns.GetTextureImage(texture, level, GLenum(format as u32), GLenum(type_ as u32), bufSize as _, pixels) }
+        unsafe { self.fns().GetTextureImage(texture, level, GLenum(format as u32), GLenum(type_ as u32), bufSize as _, pixels) }
     }
 
     pub fn glGetTextureLevelParameterfv(&self, texture: u32, level: i32, pname: GetTextureParameter, params: *mut f32) {
+        trace!(self, "glGetTextureLevelParameterfv", texture, level, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetTextureLevelParameterfv(texture, level, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetTextureLevelParameterfv(texture, level, GLenum(pname as u32), params) }
     }
 
     pub fn glGetTextureLevelParameteriv(&self, texture: u32, level: i32, pname: GetTextureParameter, params: *mut i32) {
+        trace!(self, "glGetTextureLevelParameteriv", texture, level, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetTextureLevelParameteriv(texture, level, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetTextureLevelParameteriv(texture, level, GLenum(pname as u32), params) }
     }
 
     pub fn glGetTextureParameterIiv(&self, texture: u32, pname: GetTextureParameter, params: *mut i32) {
+        trace!(self, "glGetTextureParameterIiv", texture, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetTextureParameterIiv(texture, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetTextureParameterIiv(texture, GLenum(pname as u32), params) }
     }
 
     pub fn glGetTextureParameterIuiv(&self, texture: u32, pname: GetTextureParameter, params: *mut u32) {
+        trace!(self, "glGetTextureParameterIuiv", texture, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetTextureParameterIuiv(texture, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetTextureParameterIuiv(texture, GLenum(pname as u32), params) }
     }
 
     pub fn glGetTextureParameterfv(&self, texture: u32, pname: GetTextureParameter, params: *mut f32) {
+        trace!(self, "glGetTextureParameterfv", texture, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetTextureParameterfv(texture, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetTextureParameterfv(texture, GLenum(pname as u32), params) }
     }
 
     pub fn glGetTextureParameteriv(&self, texture: u32, pname: GetTextureParameter, params: *mut i32) {
+        trace!(self, "glGetTextureParameteriv", texture, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetTextureParameteriv(texture, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetTextureParameteriv(texture, GLenum(pname as u32), params) }
     }
 
     pub fn glGetTextureSubImage(&self, texture: u32, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, width: u32, height: u32, depth: u32, format: PixelFormat, type_: PixelType, bufSize: u32, pixels: *mut c_void) {
+        trace!(self, "glGetTextureSubImage", texture, level, xoffset, yoffset, zoffset, width, height, depth, format, type_, bufSize, pixels);
         // Unreviewed
-        unsafe { self.fns.GetTextureSubImage(texture, level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, GLenum(format as u32), GLenum(type_ as u32), bufSize as _, pixels) }
+        unsafe { self.fns().GetTextureSubImage(texture, level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, GLenum(format as u32), GLenum(type_ as u32), bufSize as _, pixels) }
     }
 
     pub fn glGetTransformFeedbacki64_v(&self, xfb: u32, pname: TransformFeedbackPName, index: u32, param: *mut i64) {
+        trace!(self, "glGetTransformFeedbacki64_v", xfb, pname, index, param);
         // Unreviewed
-        unsafe { self.fns.GetTransformFeedbacki64_v(xfb, GLenum(pname as u32), index, param) }
+        unsafe { self.fns().GetTransformFeedbacki64_v(xfb, GLenum(pname as u32), index, param) }
     }
 
     pub fn glGetTransformFeedbacki_v(&self, xfb: u32, pname: TransformFeedbackPName, index: u32, param: *mut i32) {
+        trace!(self, "glGetTransformFeedbacki_v", xfb, pname, index, param);
         // Unreviewed
-        unsafe { self.fns.GetTransformFeedbacki_v(xfb, GLenum(pname as u32), index, param) }
+        unsafe { self.fns().GetTransformFeedbacki_v(xfb, GLenum(pname as u32), index, param) }
     }
 
     pub fn glGetTransformFeedbackiv(&self, xfb: u32, pname: TransformFeedbackPName, param: *mut i32) {
+        trace!(self, "glGetTransformFeedbackiv", xfb, pname, param);
         // Unreviewed
-        unsafe { self.fns.GetTransformFeedbackiv(xfb, GLenum(pname as u32), param) }
+        unsafe { self.fns().GetTransformFeedbackiv(xfb, GLenum(pname as u32), param) }
     }
 
     pub fn glGetUniformSubroutineuiv(&self, shadertype: ShaderType, location: i32, params: *mut u32) {
+        trace!(self, "glGetUniformSubroutineuiv", shadertype, location, params);
         // Unreviewed
-        unsafe { self.fns.GetUniformSubroutineuiv(GLenum(shadertype as u32), location, params) }
+        unsafe { self.fns().GetUniformSubroutineuiv(GLenum(shadertype as u32), location, params) }
     }
 
     pub fn glGetUniformdv(&self, program: u32, location: i32, params: *mut f64) {
+        trace!(self, "glGetUniformdv", program, location, params);
         // Unreviewed
-        unsafe { self.fns.GetUniformdv(program, location, params) }
+        unsafe { self.fns().GetUniformdv(program, location, params) }
     }
 
     pub fn glGetVertexArrayIndexed64iv(&self, vaobj: u32, index: u32, pname: VertexArrayPName, param: *mut i64) {
+        trace!(self, "glGetVertexArrayIndexed64iv", vaobj, index, pname, param);
         // Unreviewed
-        unsafe { self.fns.GetVertexArrayIndexed64iv(vaobj, index, GLenum(pname as u32), param) }
+        unsafe { self.fns().GetVertexArrayIndexed64iv(vaobj, index, GLenum(pname as u32), param) }
     }
 
     pub fn glGetVertexArrayIndexediv(&self, vaobj: u32, index: u32, pname: VertexArrayPName, param: *mut i32) {
+        trace!(self, "glGetVertexArrayIndexediv", vaobj, index, pname, param);
         // Unreviewed
-        unsafe { self.fns.GetVertexArrayIndexediv(vaobj, index, GLenum(pname as u32), param) }
+        unsafe { self.fns().GetVertexArrayIndexediv(vaobj, index, GLenum(pname as u32), param) }
     }
 
     pub fn glGetVertexArrayiv(&self, vaobj: u32, pname: VertexArrayPName, param: *mut i32) {
+        trace!(self, "glGetVertexArrayiv", vaobj, pname, param);
         // Unreviewed
-        unsafe { self.fns.GetVertexArrayiv(vaobj, GLenum(pname as u32), param) }
+        unsafe { self.fns().GetVertexArrayiv(vaobj, GLenum(pname as u32), param) }
     }
 
     pub fn glGetVertexAttribLdv(&self, index: u32, pname: VertexAttribEnum, params: *mut f64) {
+        trace!(self, "glGetVertexAttribLdv", index, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetVertexAttribLdv(index, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetVertexAttribLdv(index, GLenum(pname as u32), params) }
     }
 
     pub fn glGetnCompressedTexImage(&self, target: TextureTarget, lod: i32, bufSize: u32, pixels: *mut c_void) {
+        trace!(self, "glGetnCompressedTexImage", target, lod, bufSize, pixels);
         // Unreviewed
-        unsafe { self.fns.GetnCompressedTexImage(GLenum(target as u32), lod, bufSize as _, pixels) }
+        unsafe { self.fns().GetnCompressedTexImage(GLenum(target as u32), lod, bufSize as _, pixels) }
     }
 
     pub fn glGetnTexImage(&self, target: TextureTarget, level: i32, format: PixelFormat, type_: PixelType, bufSize: u32, pixels: *mut c_void) {
+        trace!(self, "glGetnTexImage", target, level, format, type_, bufSize, pixels);
         // Unreviewed
-        unsafe { self.fns.GetnTexImage(GLenum(target as u32), level, GLenum(format as u32), GLenum(type_ as u32), bufSize as _, pixels) }
+        unsafe { self.fns().GetnTexImage(GLenum(target as u32), level, GLenum(format as u32), GLenum(type_ as u32), bufSize as _, pixels) }
     }
 
     pub fn glGetnUniformdv(&self, program: u32, location: i32, bufSize: u32, params: *mut f64) {
+        trace!(self, "glGetnUniformdv", program, location, bufSize, params);
         // Unreviewed
-        unsafe { self.fns.GetnUniformdv(program, location, bufSize as _, params) }
+        unsafe { self.fns().GetnUniformdv(program, location, bufSize as _, params) }
     }
 
     pub fn glGetnUniformfv(&self, program: u32, location: i32, bufSize: u32, params: *mut f32) {
+        trace!(self, "glGetnUniformfv", program, location, bufSize, params);
         // Unreviewed
-        unsafe { self.fns.GetnUniformfv(program, location, bufSize as _, params) }
+        unsafe { self.fns().GetnUniformfv(program, location, bufSize as _, params) }
     }
 
     pub fn glGetnUniformiv(&self, program: u32, location: i32, bufSize: u32, params: *mut i32) {
+        trace!(self, "glGetnUniformiv", program, location, bufSize, params);
         // Unreviewed
-        unsafe { self.fns.GetnUniformiv(program, location, bufSize as _, params) }
+        unsafe { self.fns().GetnUniformiv(program, location, bufSize as _, params) }
     }
 
     pub fn glGetnUniformuiv(&self, program: u32, location: i32, bufSize: u32, params: *mut u32) {
+        trace!(self, "glGetnUniformuiv", program, location, bufSize, params);
         // Unreviewed
-        unsafe { self.fns.GetnUniformuiv(program, location, bufSize as _, params) }
+        unsafe { self.fns().GetnUniformuiv(program, location, bufSize as _, params) }
     }
 
     pub fn glInvalidateBufferData(&self, buffer: u32) {
-        unsafe { self.fns.InvalidateBufferData(buffer) }
+        trace!(self, "glInvalidateBufferData", buffer);
+        unsafe { self.fns().InvalidateBufferData(buffer) }
     }
 
     pub fn glInvalidateBufferSubData(&self, buffer: u32, offset: isize, length: usize) {
-        unsafe { self.fns.InvalidateBufferSubData(buffer, offset, length as _) }
+        trace!(self, "glInvalidateBufferSubData", buffer, offset, length);
+        unsafe { self.fns().InvalidateBufferSubData(buffer, offset, length as _) }
     }
 
     pub fn glInvalidateFramebuffer(&self, target: FramebufferTarget, numAttachments: u32, attachments: *const InvalidateFramebufferAttachment) {
+        trace!(self, "glInvalidateFramebuffer", target, numAttachments, attachments);
         // Unreviewed
-        unsafe { self.fns.InvalidateFramebuffer(GLenum(target as u32), numAttachments as _, attachments as _) }
+        unsafe { self.fns().InvalidateFramebuffer(GLenum(target as u32), numAttachments as _, attachments as _) }
     }
 
     pub fn glInvalidateNamedFramebufferData(&self, framebuffer: u32, numAttachments: u32, attachments: *const FramebufferAttachment) {
+        trace!(self, "glInvalidateNamedFramebufferData", framebuffer, numAttachments, attachments);
         // Unreviewed
-        unsafe { self.fns.InvalidateNamedFramebufferData(framebuffer, numAttachments as _, attachments as _) }
+        unsafe { self.fns().InvalidateNamedFramebufferData(framebuffer, numAttachments as _, attachments as _) }
     }
 
     pub fn glInvalidateNamedFramebufferSubData(&self, framebuffer: u32, numAttachments: u32, attachments: *const FramebufferAttachment, x: i32, y: i32, width: u32, height: u32) {
+        trace!(self, "glInvalidateNamedFramebufferSubData", framebuffer, numAttachments, attachments, x, y, width, height);
         // Unreviewed
-        unsafe { self.fns.InvalidateNamedFramebufferSubData(framebuffer, numAttachments as _, attachments as _, x, y, width as _, height as _) }
+        unsafe { self.fns().InvalidateNamedFramebufferSubData(framebuffer, numAttachments as _, attachments as _, x, y, width as _, height as _) }
     }
 
     pub fn glInvalidateSubFramebuffer(&self, target: FramebufferTarget, numAttachments: u32, attachments: *const InvalidateFramebufferAttachment, x: i32, y: i32, width: u32, height: u32) {
+        trace!(self, "glInvalidateSubFramebuffer", target, numAttachments, attachments, x, y, width, height);
         // Unreviewed
-        unsafe { self.fns.InvalidateSubFramebuffer(GLenum(target as u32), numAttachments as _, attachments as _, x, y, width as _, height as _) }
+        unsafe { self.fns().InvalidateSubFramebuffer(GLenum(target as u32), numAttachments as _, attachments as _, x, y, width as _, height as _) }
     }
 
     pub fn glInvalidateTexImage(&self, texture: u32, level: i32) {
-        unsafe { self.fns.InvalidateTexImage(texture, level) }
+        trace!(self, "glInvalidateTexImage", texture, level);
+        unsafe { self.fns().InvalidateTexImage(texture, level) }
     }
 
     pub fn glInvalidateTexSubImage(&self, texture: u32, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, width: u32, height: u32, depth: u32) {
-        unsafe { self.fns.InvalidateTexSubImage(texture, level, xoffset, yoffset, zoffset, width as _, height as _, depth as _) }
+        trace!(self, "glInvalidateTexSubImage", texture, level, xoffset, yoffset, zoffset, width, height, depth);
+        unsafe { self.fns().InvalidateTexSubImage(texture, level, xoffset, yoffset, zoffset, width as _, height as _, depth as _) }
     }
 
     pub fn glIsProgramPipeline(&self, pipeline: u32) -> bool {
-        unsafe { self.fns.IsProgramPipeline(pipeline) > 0 }
+        trace!(self, "glIsProgramPipeline", pipeline);
+        unsafe { self.fns().IsProgramPipeline(pipeline) > 0 }
     }
 
     pub fn glIsTransformFeedback(&self, id: u32) -> bool {
-        unsafe { self.fns.IsTransformFeedback(id) > 0 }
+        trace!(self, "glIsTransformFeedback", id);
+        unsafe { self.fns().IsTransformFeedback(id) > 0 }
     }
 
     pub fn glMapNamedBuffer(&self, buffer: u32, access: BufferAccessARB) -> *mut c_void {
+        trace!(self, "glMapNamedBuffer", buffer, access);
         // Unreviewed
-        unsafe { self.fns.MapNamedBuffer(buffer, GLenum(access as u32)) }
+        unsafe { self.fns().MapNamedBuffer(buffer, GLenum(access as u32)) }
     }
 
     pub fn glMapNamedBufferRange(&self, buffer: u32, offset: isize, length: usize, access: GLbitfield) -> *mut c_void {
+        trace!(self, "glMapNamedBufferRange", buffer, offset, length, access);
         // Unreviewed
-        unsafe { self.fns.MapNamedBufferRange(buffer, offset, length as _, access) }
+        unsafe { self.fns().MapNamedBufferRange(buffer, offset, length as _, access) }
     }
 
     pub fn glMemoryBarrier(&self, barriers: GLbitfield) {
-        unsafe { self.fns.MemoryBarrier(barriers) }
+        trace!(self, "glMemoryBarrier", barriers);
+        unsafe { self.fns().MemoryBarrier(barriers) }
     }
 
     pub fn glMemoryBarrierByRegion(&self, barriers: GLbitfield) {
-        unsafe { self.fns.MemoryBarrierByRegion(barriers) }
+        trace!(self, "glMemoryBarrierByRegion", barriers);
+        unsafe { self.fns().MemoryBarrierByRegion(barriers) }
     }
 
     pub fn glMinSampleShading(&self, value: f32) {
-        unsafe { self.fns.MinSampleShading(value) }
+        trace!(self, "glMinSampleShading", value);
+        unsafe { self.fns().MinSampleShading(value) }
     }
 
     pub fn glMultiDrawArraysIndirect(&self, mode: PrimitiveType, indirect: *const c_void, drawcount: u32, stride: u32) {
+        trace!(self, "glMultiDrawArraysIndirect", mode, indirect, drawcount, stride);
         // Unreviewed
-        unsafe { self.fns.MultiDrawArraysIndirect(GLenum(mode as u32), indirect, drawcount as _, stride as _) }
+        unsafe { self.fns().MultiDrawArraysIndirect(GLenum(mode as u32), indirect, drawcount as _, stride as _) }
     }
 
     pub fn glMultiDrawArraysIndirectCount(&self, mode: PrimitiveType, indirect: *const c_void, drawcount: isize, maxdrawcount: u32, stride: u32) {
+        trace!(self, "glMultiDrawArraysIndirectCount", mode, indirect, drawcount, maxdrawcount, stride);
         // Unreviewed
-        unsafe { self.fns.MultiDrawArraysIndirectCount(GLenum(mode as u32), indirect, drawcount, maxdrawcount as _, stride as _) }
+        unsafe { self.fns().MultiDrawArraysIndirectCount(GLenum(mode as u32), indirect, drawcount, maxdrawcount as _, stride as _) }
     }
 
     pub fn glMultiDrawElementsIndirect(&self, mode: PrimitiveType, type_: DrawElementsType, indirect: *const c_void, drawcount: u32, stride: u32) {
+        trace!(self, "glMultiDrawElementsIndirect", mode, type_, indirect, drawcount, stride);
         // Unreviewed
-        unsafe { self.fns.MultiDrawElementsIndirect(GLenum(mode as u32), GLenum(type_ as u32), indirect, drawcount as _, stride as _) }
+        unsafe { self.fns().MultiDrawElementsIndirect(GLenum(mode as u32), GLenum(type_ as u32), indirect, drawcount as _, stride as _) }
     }
 
     pub fn glMultiDrawElementsIndirectCount(&self, mode: PrimitiveType, type_: DrawElementsType, indirect: *const c_void, drawcount: isize, maxdrawcount: u32, stride: u32) {
+        trace!(self, "glMultiDrawElementsIndirectCount", mode, type_, indirect, drawcount, maxdrawcount, stride);
         // Unreviewed
-        unsafe { self.fns.MultiDrawElementsIndirectCount(GLenum(mode as u32), GLenum(type_ as u32), indirect, drawcount, maxdrawcount as _, stride as _) }
+        unsafe { self.fns().MultiDrawElementsIndirectCount(GLenum(mode as u32), GLenum(type_ as u32), indirect, drawcount, maxdrawcount as _, stride as _) }
     }
 
     pub fn glNamedBufferData(&self, buffer: u32, size: usize, data: *const c_void, usage: VertexBufferObjectUsage) {
+        trace!(self, "glNamedBufferData", buffer, size, data, usage);
         // Unreviewed
-        unsafe { self.fns.NamedBufferData(buffer, size as _, data, usage) }
+        unsafe { self.fns().NamedBufferData(buffer, size as _, data, usage) }
     }
 
     pub fn glNamedBufferStorage(&self, buffer: u32, size: usize, data: *const c_void, flags: GLbitfield) {
+        trace!(self, "glNamedBufferStorage", buffer, size, data, flags);
         // Unreviewed
-        unsafe { self.fns.NamedBufferStorage(buffer, size as _, data, flags) }
+        unsafe { self.fns().NamedBufferStorage(buffer, size as _, data, flags) }
     }
 
     pub fn glNamedBufferSubData(&self, buffer: u32, offset: isize, size: usize, data: *const c_void) {
+        trace!(self, "glNamedBufferSubData", buffer, offset, size, data);
         // Unreviewed
-        unsafe { self.fns.NamedBufferSubData(buffer, offset, size as _, data) }
+        unsafe { self.fns().NamedBufferSubData(buffer, offset, size as _, data) }
     }
 
     pub fn glNamedFramebufferDrawBuffer(&self, framebuffer: u32, buf: ColorBuffer) {
-        unsafe { self.fns.NamedFramebufferDrawBuffer(framebuffer, GLenum(buf as u32)) }
+        trace!(self, "glNamedFramebufferDrawBuffer", framebuffer, buf);
+        unsafe { self.fns().NamedFramebufferDrawBuffer(framebuffer, GLenum(buf as u32)) }
     }
 
     pub fn glNamedFramebufferDrawBuffers(&self, framebuffer: u32, n: u32, bufs: *const ColorBuffer) {
+        trace!(self, "glNamedFramebufferDrawBuffers", framebuffer, n, bufs);
         // Unreviewed
-        unsafe { self.fns.NamedFramebufferDrawBuffers(framebuffer, n as _, bufs as _) }
+        unsafe { self.fns().NamedFramebufferDrawBuffers(framebuffer, n as _, bufs as _) }
     }
 
     pub fn glNamedFramebufferParameteri(&self, framebuffer: u32, pname: FramebufferParameterName, param: i32) {
-        unsafe { self.fns.NamedFramebufferParameteri(framebuffer, GLenum(pname as u32), param) }
+        trace!(self, "glNamedFramebufferParameteri", framebuffer, pname, param);
+        unsafe { self.fns().NamedFramebufferParameteri(framebuffer, GLenum(pname as u32), param) }
     }
 
     pub fn glNamedFramebufferReadBuffer(&self, framebuffer: u32, src: ColorBuffer) {
-        unsafe { self.fns.NamedFramebufferReadBuffer(framebuffer, GLenum(src as u32)) }
+        trace!(self, "glNamedFramebufferReadBuffer", framebuffer, src);
+        unsafe { self.fns().NamedFramebufferReadBuffer(framebuffer, GLenum(src as u32)) }
     }
 
     pub fn glNamedFramebufferRenderbuffer(&self, framebuffer: u32, attachment: FramebufferAttachment, renderbuffertarget: RenderbufferTarget, renderbuffer: u32) {
-        unsafe { self.fns.NamedFramebufferRenderbuffer(framebuffer, GLenum(attachment as u32), GLenum(renderbuffertarget as u32), renderbuffer) }
+        trace!(self, "glNamedFramebufferRenderbuffer", framebuffer, attachment, renderbuffertarget, renderbuffer);
+        unsafe { self.fns().NamedFramebufferRenderbuffer(framebuffer, GLenum(attachment as u32), GLenum(renderbuffertarget as u32), renderbuffer) }
     }
 
     pub fn glNamedFramebufferTexture(&self, framebuffer: u32, attachment: FramebufferAttachment, texture: u32, level: i32) {
-        unsafe { self.fns.NamedFramebufferTexture(framebuffer, GLenum(attachment as u32), texture, level) }
+        trace!(self, "glNamedFramebufferTexture", framebuffer, attachment, texture, level);
+        unsafe { self.fns().NamedFramebufferTexture(framebuffer, GLenum(attachment as u32), texture, level) }
     }
 
     pub fn glNamedFramebufferTextureLayer(&self, framebuffer: u32, attachment: FramebufferAttachment, texture: u32, level: i32, layer: i32) {
-        unsafe { self.fns.NamedFramebufferTextureLayer(framebuffer, GLenum(attachment as u32), texture, level, layer) }
+        trace!(self, "glNamedFramebufferTextureLayer", framebuffer, attachment, texture, level, layer);
+        unsafe { self.fns().NamedFramebufferTextureLayer(framebuffer, GLenum(attachment as u32), texture, level, layer) }
     }
 
     pub fn glNamedRenderbufferStorage(&self, renderbuffer: u32, internalformat: InternalFormat, width: u32, height: u32) {
-        unsafe { self.fns.NamedRenderbufferStorage(renderbuffer, GLenum(internalformat as u32), width as _, height as _) }
+        trace!(self, "glNamedRenderbufferStorage", renderbuffer, internalformat, width, height);
+        unsafe { self.fns().NamedRenderbufferStorage(renderbuffer, GLenum(internalformat as u32), width as _, height as _) }
     }
 
     pub fn glNamedRenderbufferStorageMultisample(&self, renderbuffer: u32, samples: u32, internalformat: InternalFormat, width: u32, height: u32) {
-        unsafe { self.fns.NamedRenderbufferStorageMultisample(renderbuffer, samples as _, GLenum(internalformat as u32), width as _, height as _) }
+        trace!(self, "glNamedRenderbufferStorageMultisample", renderbuffer, samples, internalformat, width, height);
+        unsafe { self.fns().NamedRenderbufferStorageMultisample(renderbuffer, samples as _, GLenum(internalformat as u32), width as _, height as _) }
     }
 
     pub fn glPatchParameterfv(&self, pname: PatchParameterName, values: *const f32) {
+        trace!(self, "glPatchParameterfv", pname, values);
         // Unreviewed
-        unsafe { self.fns.PatchParameterfv(GLenum(pname as u32), values) }
+        unsafe { self.fns().PatchParameterfv(GLenum(pname as u32), values) }
     }
 
     pub fn glPatchParameteri(&self, pname: PatchParameterName, value: i32) {
-        unsafe { self.fns.PatchParameteri(GLenum(pname as u32), value) }
+        trace!(self, "glPatchParameteri", pname, value);
+        unsafe { self.fns().PatchParameteri(GLenum(pname as u32), value) }
     }
 
     pub fn glPauseTransformFeedback(&self) {
-        unsafe { self.fns.PauseTransformFeedback() }
+        trace!(self, "glPauseTransformFeedback");
+        unsafe { self.fns().PauseTransformFeedback() }
     }
 
     pub fn glPolygonOffsetClamp(&self, factor: f32, units: f32, clamp: f32) {
-        unsafe { self.fns.PolygonOffsetClamp(factor, units, clamp) }
+        trace!(self, "glPolygonOffsetClamp", factor, units, clamp);
+        unsafe { self.fns().PolygonOffsetClamp(factor, units, clamp) }
     }
 
     pub fn glProgramBinary(&self, program: u32, binaryFormat: GLenum, binary: *const c_void, length: u32) {
+        trace!(self, "glProgramBinary", program, binaryFormat, binary, length);
         // Unreviewed
-        unsafe { self.fns.ProgramBinary(program, binaryFormat, binary, length as _) }
+        unsafe { self.fns().ProgramBinary(program, binaryFormat, binary, length as _) }
     }
 
     pub fn glProgramParameteri(&self, program: u32, pname: ProgramParameterPName, value: i32) {
-        unsafe { self.fns.ProgramParameteri(program, GLenum(pname as u32), value) }
+        trace!(self, "glProgramParameteri", program, pname, value);
+        unsafe { self.fns().ProgramParameteri(program, GLenum(pname as u32), value) }
     }
 
     pub fn glProgramUniform1d(&self, program: u32, location: i32, v0: f64) {
-        unsafe { self.fns.ProgramUniform1d(program, location, v0) }
+        trace!(self, "glProgramUniform1d", program, location, v0);
+        unsafe { self.fns().ProgramUniform1d(program, location, v0) }
     }
 
     pub fn glProgramUniform1dv(&self, program: u32, location: i32, count: u32, value: *const f64) {
+        trace!(self, "glProgramUniform1dv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform1dv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform1dv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform1f(&self, program: u32, location: i32, v0: f32) {
-        unsafe { self.fns.ProgramUniform1f(program, location, v0) }
+        trace!(self, "glProgramUniform1f", program, location, v0);
+        unsafe { self.fns().ProgramUniform1f(program, location, v0) }
     }
 
     pub fn glProgramUniform1fv(&self, program: u32, location: i32, count: u32, value: *const f32) {
+        trace!(self, "glProgramUniform1fv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform1fv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform1fv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform1i(&self, program: u32, location: i32, v0: i32) {
-        unsafe { self.fns.ProgramUniform1i(program, location, v0) }
+        trace!(self, "glProgramUniform1i", program, location, v0);
+        unsafe { self.fns().ProgramUniform1i(program, location, v0) }
     }
 
     pub fn glProgramUniform1iv(&self, program: u32, location: i32, count: u32, value: *const i32) {
+        trace!(self, "glProgramUniform1iv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform1iv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform1iv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform1ui(&self, program: u32, location: i32, v0: u32) {
-        unsafe { self.fns.ProgramUniform1ui(program, location, v0) }
+        trace!(self, "glProgramUniform1ui", program, location, v0);
+        unsafe { self.fns().ProgramUniform1ui(program, location, v0) }
     }
 
     pub fn glProgramUniform1uiv(&self, program: u32, location: i32, count: u32, value: *const u32) {
+        trace!(self, "glProgramUniform1uiv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform1uiv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform1uiv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform2d(&self, program: u32, location: i32, v0: f64, v1: f64) {
-        unsafe { self.fns.ProgramUniform2d(program, location, v0, v1) }
+        trace!(self, "glProgramUniform2d", program, location, v0, v1);
+        unsafe { self.fns().ProgramUniform2d(program, location, v0, v1) }
     }
 
     pub fn glProgramUniform2dv(&self, program: u32, location: i32, count: u32, value: *const f64) {
+        trace!(self, "glProgramUniform2dv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform2dv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform2dv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform2f(&self, program: u32, location: i32, v0: f32, v1: f32) {
-        unsafe { self.fns.ProgramUniform2f(program, location, v0, v1) }
+        trace!(self, "glProgramUniform2f", program, location, v0, v1);
+        unsafe { self.fns().ProgramUniform2f(program, location, v0, v1) }
     }
 
     pub fn glProgramUniform2fv(&self, program: u32, location: i32, count: u32, value: *const f32) {
+        trace!(self, "glProgramUniform2fv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform2fv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform2fv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform2i(&self, program: u32, location: i32, v0: i32, v1: i32) {
-        unsafe { self.fns.ProgramUniform2i(program, location, v0, v1) }
+        trace!(self, "glProgramUniform2i", program, location, v0, v1);
+        unsafe { self.fns().ProgramUniform2i(program, location, v0, v1) }
     }
 
     pub fn glProgramUniform2iv(&self, program: u32, location: i32, count: u32, value: *const i32) {
+        trace!(self, "glProgramUniform2iv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform2iv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform2iv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform2ui(&self, program: u32, location: i32, v0: u32, v1: u32) {
-        unsafe { self.fns.ProgramUniform2ui(program, location, v0, v1) }
+        trace!(self, "glProgramUniform2ui", program, location, v0, v1);
+        unsafe { self.fns().ProgramUniform2ui(program, location, v0, v1) }
     }
 
     pub fn glProgramUniform2uiv(&self, program: u32, location: i32, count: u32, value: *const u32) {
+        trace!(self, "glProgramUniform2uiv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform2uiv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform2uiv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform3d(&self, program: u32, location: i32, v0: f64, v1: f64, v2: f64) {
-        unsafe { self.fns.ProgramUniform3d(program, location, v0, v1, v2) }
+        trace!(self, "glProgramUniform3d", program, location, v0, v1, v2);
+        unsafe { self.fns().ProgramUniform3d(program, location, v0, v1, v2) }
     }
 
     pub fn glProgramUniform3dv(&self, program: u32, location: i32, count: u32, value: *const f64) {
+        trace!(self, "glProgramUniform3dv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform3dv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform3dv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform3f(&self, program: u32, location: i32, v0: f32, v1: f32, v2: f32) {
-        unsafe { self.fns.ProgramUniform3f(program, location, v0, v1, v2) }
+        trace!(self, "glProgramUniform3f", program, location, v0, v1, v2);
+        unsafe { self.fns().ProgramUniform3f(program, location, v0, v1, v2) }
     }
 
     pub fn glProgramUniform3fv(&self, program: u32, location: i32, count: u32, value: *const f32) {
+        trace!(self, "glProgramUniform3fv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform3fv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform3fv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform3i(&self, program: u32, location: i32, v0: i32, v1: i32, v2: i32) {
-        unsafe { self.fns.ProgramUniform3i(program, location, v0, v1, v2) }
+        trace!(self, "glProgramUniform3i", program, location, v0, v1, v2);
+        unsafe { self.fns().ProgramUniform3i(program, location, v0, v1, v2) }
     }
 
     pub fn glProgramUniform3iv(&self, program: u32, location: i32, count: u32, value: *const i32) {
+        trace!(self, "glProgramUniform3iv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform3iv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform3iv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform3ui(&self, program: u32, location: i32, v0: u32, v1: u32, v2: u32) {
-        unsafe { self.fns.ProgramUniform3ui(program, location, v0, v1, v2) }
+        trace!(self, "glProgramUniform3ui", program, location, v0, v1, v2);
+        unsafe { self.fns().ProgramUniform3ui(program, location, v0, v1, v2) }
     }
 
     pub fn glProgramUniform3uiv(&self, program: u32, location: i32, count: u32, value: *const u32) {
+        trace!(self, "glProgramUniform3uiv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform3uiv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform3uiv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform4d(&self, program: u32, location: i32, v0: f64, v1: f64, v2: f64, v3: f64) {
-        unsafe { self.fns.ProgramUniform4d(program, location, v0, v1, v2, v3) }
+        trace!(self, "glProgramUniform4d", program, location, v0, v1, v2, v3);
+        unsafe { self.fns().ProgramUniform4d(program, location, v0, v1, v2, v3) }
     }
 
     pub fn glProgramUniform4dv(&self, program: u32, location: i32, count: u32, value: *const f64) {
+        trace!(self, "glProgramUniform4dv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform4dv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform4dv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform4f(&self, program: u32, location: i32, v0: f32, v1: f32, v2: f32, v3: f32) {
-        unsafe { self.fns.ProgramUniform4f(program, location, v0, v1, v2, v3) }
+        trace!(self, "glProgramUniform4f", program, location, v0, v1, v2, v3);
+        unsafe { self.fns().ProgramUniform4f(program, location, v0, v1, v2, v3) }
     }
 
     pub fn glProgramUniform4fv(&self, program: u32, location: i32, count: u32, value: *const f32) {
+        trace!(self, "glProgramUniform4fv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform4fv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform4fv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform4i(&self, program: u32, location: i32, v0: i32, v1: i32, v2: i32, v3: i32) {
-        unsafe { self.fns.ProgramUniform4i(program, location, v0, v1, v2, v3) }
+        trace!(self, "glProgramUniform4i", program, location, v0, v1, v2, v3);
+        unsafe { self.fns().ProgramUniform4i(program, location, v0, v1, v2, v3) }
     }
 
     pub fn glProgramUniform4iv(&self, program: u32, location: i32, count: u32, value: *const i32) {
+        trace!(self, "glProgramUniform4iv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform4iv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform4iv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniform4ui(&self, program: u32, location: i32, v0: u32, v1: u32, v2: u32, v3: u32) {
-        unsafe { self.fns.ProgramUniform4ui(program, location, v0, v1, v2, v3) }
+        trace!(self, "glProgramUniform4ui", program, location, v0, v1, v2, v3);
+        unsafe { self.fns().ProgramUniform4ui(program, location, v0, v1, v2, v3) }
     }
 
     pub fn glProgramUniform4uiv(&self, program: u32, location: i32, count: u32, value: *const u32) {
+        trace!(self, "glProgramUniform4uiv", program, location, count, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniform4uiv(program, location, count as _, value) }
+        unsafe { self.fns().ProgramUniform4uiv(program, location, count as _, value) }
     }
 
     pub fn glProgramUniformMatrix2dv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glProgramUniformMatrix2dv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix2dv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix2dv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix2fv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f32) {
+        trace!(self, "glProgramUniformMatrix2fv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix2fv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix2fv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix2x3dv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glProgramUniformMatrix2x3dv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix2x3dv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix2x3dv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix2x3fv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f32) {
+        trace!(self, "glProgramUniformMatrix2x3fv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix2x3fv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix2x3fv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix2x4dv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glProgramUniformMatrix2x4dv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix2x4dv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix2x4dv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix2x4fv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f32) {
+        trace!(self, "glProgramUniformMatrix2x4fv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix2x4fv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix2x4fv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix3dv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glProgramUniformMatrix3dv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix3dv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix3dv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix3fv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f32) {
+        trace!(self, "glProgramUniformMatrix3fv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix3fv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix3fv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix3x2dv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glProgramUniformMatrix3x2dv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix3x2dv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix3x2dv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix3x2fv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f32) {
+        trace!(self, "glProgramUniformMatrix3x2fv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix3x2fv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix3x2fv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix3x4dv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glProgramUniformMatrix3x4dv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix3x4dv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix3x4dv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix3x4fv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f32) {
+        trace!(self, "glProgramUniformMatrix3x4fv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix3x4fv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix3x4fv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix4dv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glProgramUniformMatrix4dv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix4dv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix4dv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix4fv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f32) {
+        trace!(self, "glProgramUniformMatrix4fv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix4fv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix4fv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix4x2dv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glProgramUniformMatrix4x2dv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix4x2dv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix4x2dv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix4x2fv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f32) {
+        trace!(self, "glProgramUniformMatrix4x2fv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix4x2fv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix4x2fv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix4x3dv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glProgramUniformMatrix4x3dv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix4x3dv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix4x3dv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glProgramUniformMatrix4x3fv(&self, program: u32, location: i32, count: u32, transpose: bool, value: *const f32) {
+        trace!(self, "glProgramUniformMatrix4x3fv", program, location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformMatrix4x3fv(program, location, count as _, transpose as _, value) }
+        unsafe { self.fns().ProgramUniformMatrix4x3fv(program, location, count as _, transpose as _, value) }
     }
 
     pub fn glReadnPixels(&self, x: i32, y: i32, width: u32, height: u32, format: PixelFormat, type_: PixelType, bufSize: u32, data: *mut c_void) {
+        trace!(self, "glReadnPixels", x, y, width, height, format, type_, bufSize, data);
         // Unreviewed
-        unsafe { self.fns.ReadnPixels(x, y, width as _, height as _, GLenum(format as u32), GLenum(type_ as u32), bufSize as _, data) }
+        unsafe { self.fns().ReadnPixels(x, y, width as _, height as _, GLenum(format as u32), GLenum(type_ as u32), bufSize as _, data) }
     }
 
     pub fn glReleaseShaderCompiler(&self) {
-        unsafe { self.fns.ReleaseShaderCompiler() }
+        trace!(self, "glReleaseShaderCompiler");
+        unsafe { self.fns().ReleaseShaderCompiler() }
     }
 
     pub fn glResumeTransformFeedback(&self) {
-        unsafe { self.fns.ResumeTransformFeedback() }
+        trace!(self, "glResumeTransformFeedback");
+        unsafe { self.fns().ResumeTransformFeedback() }
     }
 
     pub fn glScissorArrayv(&self, first: u32, count: u32, v: *const i32) {
+        trace!(self, "glScissorArrayv", first, count, v);
         // Unreviewed
-        unsafe { self.fns.ScissorArrayv(first, count as _, v) }
+        unsafe { self.fns().ScissorArrayv(first, count as _, v) }
     }
 
     pub fn glScissorIndexed(&self, index: u32, left: i32, bottom: i32, width: u32, height: u32) {
-        unsafe { self.fns.ScissorIndexed(index, left, bottom, width as _, height as _) }
+        trace!(self, "glScissorIndexed", index, left, bottom, width, height);
+        unsafe { self.fns().ScissorIndexed(index, left, bottom, width as _, height as _) }
     }
 
     pub fn glScissorIndexedv(&self, index: u32, v: *const [i32; 4]) {
+        trace!(self, "glScissorIndexedv", index, v);
         // Unreviewed
-        unsafe { self.fns.ScissorIndexedv(index, v) }
+        unsafe { self.fns().ScissorIndexedv(index, v) }
     }
 
     pub fn glShaderBinary(&self, count: u32, shaders: *const u32, binaryFormat: ShaderBinaryFormat, binary: *const c_void, length: u32) {
+        trace!(self, "glShaderBinary", count, shaders, binaryFormat, binary, length);
         // Unreviewed
-        unsafe { self.fns.ShaderBinary(count as _, shaders, GLenum(binaryFormat as u32), binary, length as _) }
+        unsafe { self.fns().ShaderBinary(count as _, shaders, GLenum(binaryFormat as u32), binary, length as _) }
     }
 
     pub fn glShaderStorageBlockBinding(&self, program: u32, storageBlockIndex: u32, storageBlockBinding: u32) {
-        unsafe { self.fns.ShaderStorageBlockBinding(program, storageBlockIndex, storageBlockBinding) }
+        trace!(self, "glShaderStorageBlockBinding", program, storageBlockIndex, storageBlockBinding);
+        unsafe { self.fns().ShaderStorageBlockBinding(program, storageBlockIndex, storageBlockBinding) }
     }
 
     pub fn glSpecializeShader(&self, shader: u32, pEntryPoint: *const u8, numSpecializationConstants: u32, pConstantIndex: *const u32, pConstantValue: *const u32) {
+        trace!(self, "glSpecializeShader", shader, pEntryPoint, numSpecializationConstants, pConstantIndex, pConstantValue);
         // Unreviewed
-        unsafe { self.fns.SpecializeShader(shader, pEntryPoint, numSpecializationConstants, pConstantIndex, pConstantValue) }
+        unsafe { self.fns().SpecializeShader(shader, pEntryPoint, numSpecializationConstants, pConstantIndex, pConstantValue) }
     }
 
     pub fn glTexBufferRange(&self, target: TextureTarget, internalformat: InternalFormat, buffer: u32, offset: isize, size: usize) {
-        unsafe { self.fns.TexBufferRange(GLenum(target as u32), GLenum(internalformat as u32), buffer, offset, size as _) }
+        trace!(self, "glTexBufferRange", target, internalformat, buffer, offset, size);
+        unsafe { self.fns().TexBufferRange(GLenum(target as u32), GLenum(internalformat as u32), buffer, offset, size as _) }
     }
 
     pub fn glTexStorage1D(&self, target: TextureTarget, levels: u32, internalformat: InternalFormat, width: u32) {
-        unsafe { self.fns.TexStorage1D(GLenum(target as u32), levels as _, GLenum(internalformat as u32), width as _) }
+        trace!(self, "glTexStorage1D", target, levels, internalformat, width);
+        unsafe { self.fns().TexStorage1D(GLenum(target as u32), levels as _, GLenum(internalformat as u32), width as _) }
     }
 
     pub fn glTexStorage2D(&self, target: TextureTarget, levels: u32, internalformat: InternalFormat, width: u32, height: u32) {
-        unsafe { self.fns.TexStorage2D(GLenum(target as u32), levels as _, GLenum(internalformat as u32), width as _, height as _) }
+        trace!(self, "glTexStorage2D", target, levels, internalformat, width, height);
+        unsafe { self.fns().TexStorage2D(GLenum(target as u32), levels as _, GLenum(internalformat as u32), width as _, height as _) }
     }
 
     pub fn glTexStorage2DMultisample(&self, target: TextureTarget, samples: u32, internalformat: InternalFormat, width: u32, height: u32, fixedsamplelocations: bool) {
-        unsafe { self.fns.TexStorage2DMultisample(GLenum(target as u32), samples as _, GLenum(internalformat as u32), width as _, height as _, fixedsamplelocations as _) }
+        trace!(self, "glTexStorage2DMultisample", target, samples, internalformat, width, height, fixedsamplelocations);
+        unsafe { self.fns().TexStorage2DMultisample(GLenum(target as u32), samples as _, GLenum(internalformat as u32), width as _, height as _, fixedsamplelocations as _) }
     }
 
     pub fn glTexStorage3D(&self, target: TextureTarget, levels: u32, internalformat: InternalFormat, width: u32, height: u32, depth: u32) {
-        unsafe { self.fns.TexStorage3D(GLenum(target as u32), levels as _, GLenum(internalformat as u32), width as _, height as _, depth as _) }
+        trace!(self, "glTexStorage3D", target, levels, internalformat, width, height, depth);
+        unsafe { self.fns().TexStorage3D(GLenum(target as u32), levels as _, GLenum(internalformat as u32), width as _, height as _, depth as _) }
     }
 
     pub fn glTexStorage3DMultisample(&self, target: TextureTarget, samples: u32, internalformat: InternalFormat, width: u32, height: u32, depth: u32, fixedsamplelocations: bool) {
-        unsafe { self.fns.TexStorage3DMultisample(GLenum(target as u32), samples as _, GLenum(internalformat as u32), width as _, height as _, depth as _, fixedsamplelocations as _) }
+        trace!(self, "glTexStorage3DMultisample", target, samples, internalformat, width, height, depth, fixedsamplelocations);
+        unsafe { self.fns().TexStorage3DMultisample(GLenum(target as u32), samples as _, GLenum(internalformat as u32), width as _, height as _, depth as _, fixedsamplelocations as _) }
     }
 
     pub fn glTextureBarrier(&self) {
-        unsafe { self.fns.TextureBarrier() }
+        trace!(self, "glTextureBarrier");
+        unsafe { self.fns().TextureBarrier() }
     }
 
     pub fn glTextureBuffer(&self, texture: u32, internalformat: InternalFormat, buffer: u32) {
-        unsafe { self.fns.TextureBuffer(texture, GLenum(internalformat as u32), buffer) }
+        trace!(self, "glTextureBuffer", texture, internalformat, buffer);
+        unsafe { self.fns().TextureBuffer(texture, GLenum(internalformat as u32), buffer) }
     }
 
     pub fn glTextureBufferRange(&self, texture: u32, internalformat: InternalFormat, buffer: u32, offset: isize, size: usize) {
-        unsafe { self.fns.TextureBufferRange(texture, GLenum(internalformat as u32), buffer, offset, size as _) }
+        trace!(self, "glTextureBufferRange", texture, internalformat, buffer, offset, size);
+        unsafe { self.fns().TextureBufferRange(texture, GLenum(internalformat as u32), buffer, offset, size as _) }
     }
 
     pub fn glTextureParameterIiv(&self, texture: u32, pname: TextureParameterName, params: *const i32) {
+        trace!(self, "glTextureParameterIiv", texture, pname, params);
         // Unreviewed
-        unsafe { self.fns.TextureParameterIiv(texture, GLenum(pname as u32), params) }
+        unsafe { self.fns().TextureParameterIiv(texture, GLenum(pname as u32), params) }
     }
 
     pub fn glTextureParameterIuiv(&self, texture: u32, pname: TextureParameterName, params: *const u32) {
+        trace!(self, "glTextureParameterIuiv", texture, pname, params);
         // Unreviewed
-        unsafe { self.fns.TextureParameterIuiv(texture, GLenum(pname as u32), params) }
+        unsafe { self.fns().TextureParameterIuiv(texture, GLenum(pname as u32), params) }
     }
 
     pub fn glTextureParameterf(&self, texture: u32, pname: TextureParameterName, param: f32) {
-        unsafe { self.fns.TextureParameterf(texture, GLenum(pname as u32), param) }
+        trace!(self, "glTextureParameterf", texture, pname, param);
+        unsafe { self.fns().TextureParameterf(texture, GLenum(pname as u32), param) }
     }
 
     pub fn glTextureParameterfv(&self, texture: u32, pname: TextureParameterName, param: *const f32) {
+        trace!(self, "glTextureParameterfv", texture, pname, param);
         // Unreviewed
-        unsafe { self.fns.TextureParameterfv(texture, GLenum(pname as u32), param) }
+        unsafe { self.fns().TextureParameterfv(texture, GLenum(pname as u32), param) }
     }
 
     pub fn glTextureParameteri(&self, texture: TextureTarget, pname: TextureParameterName, param: GLenum) {
-        unsafe { self.fns.TextureParameteri(texture as u32, GLenum(pname as u32), param.0 as _) }
+        trace!(self, "glTextureParameteri", texture, pname, param);
+        unsafe { self.fns().TextureParameteri(texture as u32, GLenum(pname as u32), param.0 as _) }
     }
 
     pub fn glTextureParameteriv(&self, texture: u32, pname: TextureParameterName, param: *const i32) {
+        trace!(self, "glTextureParameteriv", texture, pname, param);
         // Unreviewed
-        unsafe { self.fns.TextureParameteriv(texture, GLenum(pname as u32), param) }
+        unsafe { self.fns().TextureParameteriv(texture, GLenum(pname as u32), param) }
     }
 
     pub fn glTextureStorage1D(&self, texture: u32, levels: u32, internalformat: InternalFormat, width: u32) {
-        unsafe { self.fns.TextureStorage1D(texture, levels as _, GLenum(internalformat as u32), width as _) }
+        trace!(self, "glTextureStorage1D", texture, levels, internalformat, width);
+        unsafe { self.fns().TextureStorage1D(texture, levels as _, GLenum(internalformat as u32), width as _) }
     }
 
     pub fn glTextureStorage2D(&self, texture: u32, levels: u32, internalformat: InternalFormat, width: u32, height: u32) {
-        unsafe { self.fns.TextureStorage2D(texture, levels as _, GLenum(internalformat as u32), width as _, height as _) }
+        trace!(self, "glTextureStorage2D", texture, levels, internalformat, width, height);
+        unsafe { self.fns().TextureStorage2D(texture, levels as _, GLenum(internalformat as u32), width as _, height as _) }
     }
 
     pub fn glTextureStorage2DMultisample(&self, texture: u32, samples: u32, internalformat: InternalFormat, width: u32, height: u32, fixedsamplelocations: bool) {
-        unsafe { self.fns.TextureStorage2DMultisample(texture, samples as _, GLenum(internalformat as u32), width as _, height as _, fixedsamplelocations as _) }
+        trace!(self, "glTextureStorage2DMultisample", texture, samples, internalformat, width, height, fixedsamplelocations);
+        unsafe { self.fns().TextureStorage2DMultisample(texture, samples as _, GLenum(internalformat as u32), width as _, height as _, fixedsamplelocations as _) }
     }
 
     pub fn glTextureStorage3D(&self, texture: u32, levels: u32, internalformat: InternalFormat, width: u32, height: u32, depth: u32) {
-        unsafe { self.fns.TextureStorage3D(texture, levels as _, GLenum(internalformat as u32), width as _, height as _, depth as _) }
+        trace!(self, "glTextureStorage3D", texture, levels, internalformat, width, height, depth);
+        unsafe { self.fns().TextureStorage3D(texture, levels as _, GLenum(internalformat as u32), width as _, height as _, depth as _) }
     }
 
     pub fn glTextureStorage3DMultisample(&self, texture: u32, samples: u32, internalformat: InternalFormat, width: u32, height: u32, depth: u32, fixedsamplelocations: bool) {
-        unsafe { self.fns.TextureStorage3DMultisample(texture, samples as _, GLenum(internalformat as u32), width as _, height as _, depth as _, fixedsamplelocations as _) }
+        trace!(self, "glTextureStorage3DMultisample", texture, samples, internalformat, width, height, depth, fixedsamplelocations);
+        unsafe { self.fns().TextureStorage3DMultisample(texture, samples as _, GLenum(internalformat as u32), width as _, height as _, depth as _, fixedsamplelocations as _) }
     }
 
     pub unsafe fn glTextureSubImage1D(&self, texture: u32, level: i32, xoffset: i32, width: u32, format: PixelFormat, type_: PixelType, pixels: &[u8]) {
-        self.fns.TextureSubImage1D(texture, level, xoffset, width as _, GLenum(format as u32), GLenum(type_ as u32), pixels.as_ptr() as _)
+        trace!(self, "glTextureSubImage1D", texture, level, xoffset, width, format, type_, TraceValue::bytes(pixels));
+        self.fns().TextureSubImage1D(texture, level, xoffset, width as _, GLenum(format as u32), GLenum(type_ as u32), pixels.as_ptr() as _)
     }
 
     pub unsafe fn glTextureSubImage2D(&self, texture: u32, level: i32, xoffset: i32, yoffset: i32, width: u32, height: u32, format: PixelFormat, type_: PixelType, pixels: &[u8]) {
-        self.fns.TextureSubImage2D(texture, level, xoffset, yoffset, width as _, height as _, GLenum(format as u32), GLenum(type_ as u32), pixels.as_ptr() as _)
+        trace!(self, "glTextureSubImage2D", texture, level, xoffset, yoffset, width, height, format, type_, TraceValue::bytes(pixels));
+        self.fns().TextureSubImage2D(texture, level, xoffset, yoffset, width as _, height as _, GLenum(format as u32), GLenum(type_ as u32), pixels.as_ptr() as _)
     }
 
     pub unsafe fn glTextureSubImage3D(&self, texture: u32, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, width: u32, height: u32, depth: u32, format: PixelFormat, type_: PixelType, pixels: &[u8]) {
-        self.fns.TextureSubImage3D(texture, level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, GLenum(format as u32), GLenum(type_ as u32), pixels.as_ptr() as _)
+        trace!(self, "glTextureSubImage3D", texture, level, xoffset, yoffset, zoffset, width, height, depth, format, type_, TraceValue::bytes(pixels));
+        self.fns().TextureSubImage3D(texture, level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, GLenum(format as u32), GLenum(type_ as u32), pixels.as_ptr() as _)
     }
 
     pub fn glTextureView(&self, texture: u32, target: TextureTarget, origtexture: u32, internalformat: InternalFormat, minlevel: u32, numlevels: u32, minlayer: u32, numlayers: u32) {
-        unsafe { self.fns.TextureView(texture, GLenum(target as u32), origtexture, GLenum(internalformat as u32), minlevel, numlevels, minlayer, numlayers) }
+        trace!(self, "glTextureView", texture, target, origtexture, internalformat, minlevel, numlevels, minlayer, numlayers);
+        unsafe { self.fns().TextureView(texture, GLenum(target as u32), origtexture, GLenum(internalformat as u32), minlevel, numlevels, minlayer, numlayers) }
     }
 
     pub fn glTransformFeedbackBufferBase(&self, xfb: u32, index: u32, buffer: u32) {
-        unsafe { self.fns.TransformFeedbackBufferBase(xfb, index, buffer) }
+        trace!(self, "glTransformFeedbackBufferBase", xfb, index, buffer);
+        unsafe { self.fns().TransformFeedbackBufferBase(xfb, index, buffer) }
     }
 
     pub fn glTransformFeedbackBufferRange(&self, xfb: u32, index: u32, buffer: u32, offset: isize, size: usize) {
-        unsafe { self.fns.TransformFeedbackBufferRange(xfb, index, buffer, offset, size as _) }
+        trace!(self, "glTransformFeedbackBufferRange", xfb, index, buffer, offset, size);
+        unsafe { self.fns().TransformFeedbackBufferRange(xfb, index, buffer, offset, size as _) }
     }
 
     pub fn glUniform1d(&self, location: i32, x: f64) {
-        unsafe { self.fns.Uniform1d(location, x) }
+        trace!(self, "glUniform1d", location, x);
+        unsafe { self.fns().Uniform1d(location, x) }
     }
 
     pub fn glUniform1dv(&self, location: i32, count: u32, value: *const f64) {
+        trace!(self, "glUniform1dv", location, count, value);
         // Unreviewed
-        unsafe { self.fns.Uniform1dv(location, count as _, value) }
+        unsafe { self.fns().Uniform1dv(location, count as _, value) }
     }
 
     pub fn glUniform2d(&self, location: i32, x: f64, y: f64) {
-        unsafe { self.fns.Uniform2d(location, x, y) }
+        trace!(self, "glUniform2d", location, x, y);
+        unsafe { self.fns().Uniform2d(location, x, y) }
     }
 
     pub fn glUniform2dv(&self, location: i32, count: u32, value: *const f64) {
+        trace!(self, "glUniform2dv", location, count, value);
         // Unreviewed
-        unsafe { self.fns.Uniform2dv(location, count as _, value) }
+        unsafe { self.fns().Uniform2dv(location, count as _, value) }
     }
 
     pub fn glUniform3d(&self, location: i32, x: f64, y: f64, z: f64) {
-        unsafe { self.fns.Uniform3d(location, x, y, z) }
+        trace!(self, "glUniform3d", location, x, y, z);
+        unsafe { self.fns().Uniform3d(location, x, y, z) }
     }
 
     pub fn glUniform3dv(&self, location: i32, count: u32, value: *const f64) {
+        trace!(self, "glUniform3dv", location, count, value);
         // Unreviewed
-        unsafe { self.fns.Uniform3dv(location, count as _, value) }
+        unsafe { self.fns().Uniform3dv(location, count as _, value) }
     }
 
     pub fn glUniform4d(&self, location: i32, x: f64, y: f64, z: f64, w: f64) {
-        unsafe { self.fns.Uniform4d(location, x, y, z, w) }
+        trace!(self, "glUniform4d", location, x, y, z, w);
+        unsafe { self.fns().Uniform4d(location, x, y, z, w) }
     }
 
     pub fn glUniform4dv(&self, location: i32, count: u32, value: *const f64) {
+        trace!(self, "glUniform4dv", location, count, value);
         // Unreviewed
-        unsafe { self.fns.Uniform4dv(location, count as _, value) }
+        unsafe { self.fns().Uniform4dv(location, count as _, value) }
     }
 
     pub fn glUniformMatrix2dv(&self, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glUniformMatrix2dv", location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.UniformMatrix2dv(location, count as _, transpose as _, value) }
+        unsafe { self.fns().UniformMatrix2dv(location, count as _, transpose as _, value) }
     }
 
     pub fn glUniformMatrix2x3dv(&self, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glUniformMatrix2x3dv", location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.UniformMatrix2x3dv(location, count as _, transpose as _, value) }
+        unsafe { self.fns().UniformMatrix2x3dv(location, count as _, transpose as _, value) }
     }
 
     pub fn glUniformMatrix2x4dv(&self, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glUniformMatrix2x4dv", location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.UniformMatrix2x4dv(location, count as _, transpose as _, value) }
+        unsafe { self.fns().UniformMatrix2x4dv(location, count as _, transpose as _, value) }
     }
 
     pub fn glUniformMatrix3dv(&self, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glUniformMatrix3dv", location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.UniformMatrix3dv(location, count as _, transpose as _, value) }
+        unsafe { self.fns().UniformMatrix3dv(location, count as _, transpose as _, value) }
     }
 
     pub fn glUniformMatrix3x2dv(&self, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glUniformMatrix3x2dv", location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.UniformMatrix3x2dv(location, count as _, transpose as _, value) }
+        unsafe { self.fns().UniformMatrix3x2dv(location, count as _, transpose as _, value) }
     }
 
     pub fn glUniformMatrix3x4dv(&self, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glUniformMatrix3x4dv", location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.UniformMatrix3x4dv(location, count as _, transpose as _, value) }
+        unsafe { self.fns().UniformMatrix3x4dv(location, count as _, transpose as _, value) }
     }
 
     pub fn glUniformMatrix4dv(&self, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glUniformMatrix4dv", location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.UniformMatrix4dv(location, count as _, transpose as _, value) }
+        unsafe { self.fns().UniformMatrix4dv(location, count as _, transpose as _, value) }
     }
 
     pub fn glUniformMatrix4x2dv(&self, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glUniformMatrix4x2dv", location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.UniformMatrix4x2dv(location, count as _, transpose as _, value) }
+        unsafe { self.fns().UniformMatrix4x2dv(location, count as _, transpose as _, value) }
     }
 
     pub fn glUniformMatrix4x3dv(&self, location: i32, count: u32, transpose: bool, value: *const f64) {
+        trace!(self, "glUniformMatrix4x3dv", location, count, transpose, value);
         // Unreviewed
-        unsafe { self.fns.UniformMatrix4x3dv(location, count as _, transpose as _, value) }
+        unsafe { self.fns().UniformMatrix4x3dv(location, count as _, transpose as _, value) }
     }
 
     pub fn glUniformSubroutinesuiv(&self, shadertype: ShaderType, count: u32, indices: *const u32) {
+        trace!(self, "glUniformSubroutinesuiv", shadertype, count, indices);
         // Unreviewed
-        unsafe { self.fns.UniformSubroutinesuiv(GLenum(shadertype as u32), count as _, indices) }
+        unsafe { self.fns().UniformSubroutinesuiv(GLenum(shadertype as u32), count as _, indices) }
     }
 
     pub fn glUnmapNamedBuffer(&self, buffer: u32) -> bool {
-        unsafe { self.fns.UnmapNamedBuffer(buffer) > 0 }
+        trace!(self, "glUnmapNamedBuffer", buffer);
+        unsafe { self.fns().UnmapNamedBuffer(buffer) > 0 }
     }
 
     pub fn glUseProgramStages(&self, pipeline: u32, stages: GLbitfield, program: u32) {
-        unsafe { self.fns.UseProgramStages(pipeline, stages, program) }
+        trace!(self, "glUseProgramStages", pipeline, stages, program);
+        unsafe { self.fns().UseProgramStages(pipeline, stages, program) }
     }
 
     pub fn glValidateProgramPipeline(&self, pipeline: u32) {
-        unsafe { self.fns.ValidateProgramPipeline(pipeline) }
+        trace!(self, "glValidateProgramPipeline", pipeline);
+        unsafe { self.fns().ValidateProgramPipeline(pipeline) }
     }
 
     pub fn glVertexArrayAttribBinding(&self, vaobj: u32, attribindex: u32, bindingindex: u32) {
-        unsafe { self.fns.VertexArrayAttribBinding(vaobj, attribindex, bindingindex) }
+        trace!(self, "glVertexArrayAttribBinding", vaobj, attribindex, bindingindex);
+        unsafe { self.fns().VertexArrayAttribBinding(vaobj, attribindex, bindingindex) }
     }
 
     pub fn glVertexArrayAttribFormat(&self, vaobj: u32, attribindex: u32, size: i32, type_: VertexAttribType, normalized: bool, relativeoffset: u32) {
-        unsafe { self.fns.VertexArrayAttribFormat(vaobj, attribindex, size, GLenum(type_ as u32), normalized as _, relativeoffset) }
+        trace!(self, "glVertexArrayAttribFormat", vaobj, attribindex, size, type_, normalized, relativeoffset);
+        unsafe { self.fns().VertexArrayAttribFormat(vaobj, attribindex, size, GLenum(type_ as u32), normalized as _, relativeoffset) }
     }
 
     pub fn glVertexArrayAttribIFormat(&self, vaobj: u32, attribindex: u32, size: i32, type_: VertexAttribIType, relativeoffset: u32) {
-        unsafe { self.fns.VertexArrayAttribIFormat(vaobj, attribindex, size, GLenum(type_ as u32), relativeoffset) }
+        trace!(self, "glVertexArrayAttribIFormat", vaobj, attribindex, size, type_, relativeoffset);
+        unsafe { self.fns().VertexArrayAttribIFormat(vaobj, attribindex, size, GLenum(type_ as u32), relativeoffset) }
     }
 
     pub fn glVertexArrayAttribLFormat(&self, vaobj: u32, attribindex: u32, size: i32, type_: VertexAttribLType, relativeoffset: u32) {
-        unsafe { self.fns.VertexArrayAttribLFormat(vaobj, attribindex, size, GLenum(type_ as u32), relativeoffset) }
+        trace!(self, "glVertexArrayAttribLFormat", vaobj, attribindex, size, type_, relativeoffset);
+        unsafe { self.fns().VertexArrayAttribLFormat(vaobj, attribindex, size, GLenum(type_ as u32), relativeoffset) }
     }
 
     pub fn glVertexArrayBindingDivisor(&self, vaobj: u32, bindingindex: u32, divisor: u32) {
-        unsafe { self.fns.VertexArrayBindingDivisor(vaobj, bindingindex, divisor) }
+        trace!(self, "glVertexArrayBindingDivisor", vaobj, bindingindex, divisor);
+        unsafe { self.fns().VertexArrayBindingDivisor(vaobj, bindingindex, divisor) }
     }
 
     pub fn glVertexArrayElementBuffer(&self, vaobj: u32, buffer: u32) {
-        unsafe { self.fns.VertexArrayElementBuffer(vaobj, buffer) }
+        trace!(self, "glVertexArrayElementBuffer", vaobj, buffer);
+        unsafe { self.fns().VertexArrayElementBuffer(vaobj, buffer) }
     }
 
     pub fn glVertexArrayVertexBuffer(&self, vaobj: u32, bindingindex: u32, buffer: u32, offset: isize, stride: u32) {
-        unsafe { self.fns.VertexArrayVertexBuffer(vaobj, bindingindex, buffer, offset, stride as _) }
+        trace!(self, "glVertexArrayVertexBuffer", vaobj, bindingindex, buffer, offset, stride);
+        unsafe { self.fns().VertexArrayVertexBuffer(vaobj, bindingindex, buffer, offset, stride as _) }
     }
 
     pub fn glVertexArrayVertexBuffers(&self, vaobj: u32, first: u32, count: u32, buffers: *const u32, offsets: *const isize, strides: *const u32) {
+        trace!(self, "glVertexArrayVertexBuffers", vaobj, first, count, buffers, offsets, strides);
         // Unreviewed
-        unsafe { self.fns.VertexArrayVertexBuffers(vaobj, first, count as _, buffers, offsets, strides as _) }
+        unsafe { self.fns().VertexArrayVertexBuffers(vaobj, first, count as _, buffers, offsets, strides as _) }
     }
 
     pub fn glVertexAttribBinding(&self, attribindex: u32, bindingindex: u32) {
-        unsafe { self.fns.VertexAttribBinding(attribindex, bindingindex) }
+        trace!(self, "glVertexAttribBinding", attribindex, bindingindex);
+        unsafe { self.fns().VertexAttribBinding(attribindex, bindingindex) }
     }
 
     pub fn glVertexAttribFormat(&self, attribindex: u32, size: i32, type_: VertexAttribType, normalized: bool, relativeoffset: u32) {
-        unsafe { self.fns.VertexAttribFormat(attribindex, size, GLenum(type_ as u32), normalized as _, relativeoffset) }
+        trace!(self, "glVertexAttribFormat", attribindex, size, type_, normalized, relativeoffset);
+        unsafe { self.fns().VertexAttribFormat(attribindex, size, GLenum(type_ as u32), normalized as _, relativeoffset) }
     }
 
     pub fn glVertexAttribIFormat(&self, attribindex: u32, size: i32, type_: VertexAttribIType, relativeoffset: u32) {
-        unsafe { self.fns.VertexAttribIFormat(attribindex, size, GLenum(type_ as u32), relativeoffset) }
+        trace!(self, "glVertexAttribIFormat", attribindex, size, type_, relativeoffset);
+        unsafe { self.fns().VertexAttribIFormat(attribindex, size, GLenum(type_ as u32), relativeoffset) }
     }
 
     pub fn glVertexAttribL1d(&self, index: u32, x: f64) {
-        unsafe { self.fns.VertexAttribL1d(index, x) }
+        trace!(self, "glVertexAttribL1d", index, x);
+        unsafe { self.fns().VertexAttribL1d(index, x) }
     }
 
     pub fn glVertexAttribL1dv(&self, index: u32, v: *const f64) {
+        trace!(self, "glVertexAttribL1dv", index, v);
         // Unreviewed
-        unsafe { self.fns.VertexAttribL1dv(index, v) }
+        unsafe { self.fns().VertexAttribL1dv(index, v) }
     }
 
     pub fn glVertexAttribL2d(&self, index: u32, x: f64, y: f64) {
-        unsafe { self.fns.VertexAttribL2d(index, x, y) }
+        trace!(self, "glVertexAttribL2d", index, x, y);
+        unsafe { self.fns().VertexAttribL2d(index, x, y) }
     }
 
     pub fn glVertexAttribL2dv(&self, index: u32, v: *const [f64; 2]) {
+        trace!(self, "glVertexAttribL2dv", index, v);
         // Unreviewed
-        unsafe { self.fns.VertexAttribL2dv(index, v) }
+        unsafe { self.fns().VertexAttribL2dv(index, v) }
     }
 
     pub fn glVertexAttribL3d(&self, index: u32, x: f64, y: f64, z: f64) {
-        unsafe { self.fns.VertexAttribL3d(index, x, y, z) }
+        trace!(self, "glVertexAttribL3d", index, x, y, z);
+        unsafe { self.fns().VertexAttribL3d(index, x, y, z) }
     }
 
     pub fn glVertexAttribL3dv(&self, index: u32, v: *const [f64; 3]) {
+        trace!(self, "glVertexAttribL3dv", index, v);
         // Unreviewed
-        unsafe { self.fns.VertexAttribL3dv(index, v) }
+        unsafe { self.fns().VertexAttribL3dv(index, v) }
     }
 
     pub fn glVertexAttribL4d(&self, index: u32, x: f64, y: f64, z: f64, w: f64) {
-        unsafe { self.fns.VertexAttribL4d(index, x, y, z, w) }
+        trace!(self, "glVertexAttribL4d", index, x, y, z, w);
+        unsafe { self.fns().VertexAttribL4d(index, x, y, z, w) }
     }
 
     pub fn glVertexAttribL4dv(&self, index: u32, v: *const [f64; 4]) {
+        trace!(self, "glVertexAttribL4dv", index, v);
         // Unreviewed
-        unsafe { self.fns.VertexAttribL4dv(index, v) }
+        unsafe { self.fns().VertexAttribL4dv(index, v) }
     }
 
     pub fn glVertexAttribLFormat(&self, attribindex: u32, size: i32, type_: VertexAttribLType, relativeoffset: u32) {
-        unsafe { self.fns.VertexAttribLFormat(attribindex, size, GLenum(type_ as u32), relativeoffset) }
+        trace!(self, "glVertexAttribLFormat", attribindex, size, type_, relativeoffset);
+        unsafe { self.fns().VertexAttribLFormat(attribindex, size, GLenum(type_ as u32), relativeoffset) }
     }
 
     pub fn glVertexAttribLPointer(&self, index: u32, size: i32, type_: VertexAttribLType, stride: u32, pointer: *const c_void) {
+        trace!(self, "glVertexAttribLPointer", index, size, type_, stride, pointer);
         // Unreviewed
-        unsafe { self.fns.VertexAttribLPointer(index, size, GLenum(type_ as u32), stride as _, pointer) }
+        unsafe { self.fns().VertexAttribLPointer(index, size, GLenum(type_ as u32), stride as _, pointer) }
     }
 
     pub fn glVertexBindingDivisor(&self, bindingindex: u32, divisor: u32) {
-        unsafe { self.fns.VertexBindingDivisor(bindingindex, divisor) }
+        trace!(self, "glVertexBindingDivisor", bindingindex, divisor);
+        unsafe { self.fns().VertexBindingDivisor(bindingindex, divisor) }
     }
 
     pub fn glViewportArrayv(&self, first: u32, count: u32, v: *const f32) {
+        trace!(self, "glViewportArrayv", first, count, v);
         // Unreviewed
-        unsafe { self.fns.ViewportArrayv(first, count as _, v) }
+        unsafe { self.fns().ViewportArrayv(first, count as _, v) }
     }
 
     pub fn glViewportIndexedf(&self, index: u32, x: f32, y: f32, w: f32, h: f32) {
-        unsafe { self.fns.ViewportIndexedf(index, x, y, w, h) }
+        trace!(self, "glViewportIndexedf", index, x, y, w, h);
+        unsafe { self.fns().ViewportIndexedf(index, x, y, w, h) }
     }
 
     pub fn glViewportIndexedfv(&self, index: u32, v: *const [f32; 4]) {
+        trace!(self, "glViewportIndexedfv", index, v);
         // Unreviewed
-        unsafe { self.fns.ViewportIndexedfv(index, v) }
+        unsafe { self.fns().ViewportIndexedfv(index, v) }
     }
 
     pub fn glGetImageHandleARB(&self, texture: u32, level: i32, layered: bool, layer: i32, format: PixelFormat) -> u64 {
-        unsafe { self.fns.GetImageHandleARB(texture, level, layered as _, layer, GLenum(format as u32)) }
+        trace!(self, "glGetImageHandleARB", texture, level, layered, layer, format);
+        unsafe { self.fns().GetImageHandleARB(texture, level, layered as _, layer, GLenum(format as u32)) }
     }
 
     pub fn glGetTextureHandleARB(&self, texture: u32) -> u64 {
+        trace!(self, "glGetTextureHandleARB", texture);
         // Unreviewed
-        unsafe { self.fns.GetTextureHandleARB(texture) }
+        unsafe { self.fns().GetTextureHandleARB(texture) }
     }
 
     pub fn glGetTextureSamplerHandleARB(&self, texture: u32, sampler: u32) -> u64 {
-        unsafe { self.fns.GetTextureSamplerHandleARB(texture, sampler) }
+        trace!(self, "glGetTextureSamplerHandleARB", texture, sampler);
+        unsafe { self.fns().GetTextureSamplerHandleARB(texture, sampler) }
     }
 
     pub fn glGetVertexAttribLui64vARB(&self, index: u32, pname: VertexAttribEnum, params: *mut u64) {
+        trace!(self, "glGetVertexAttribLui64vARB", index, pname, params);
         // Unreviewed
-        unsafe { self.fns.GetVertexAttribLui64vARB(index, GLenum(pname as u32), params) }
+        unsafe { self.fns().GetVertexAttribLui64vARB(index, GLenum(pname as u32), params) }
     }
 
     pub fn glIsImageHandleResidentARB(&self, handle: u64) -> bool {
-        unsafe { self.fns.IsImageHandleResidentARB(handle) > 0 }
+        trace!(self, "glIsImageHandleResidentARB", handle);
+        unsafe { self.fns().IsImageHandleResidentARB(handle) > 0 }
     }
 
     pub fn glIsTextureHandleResidentARB(&self, handle: u64) -> bool {
-        unsafe { self.fns.IsTextureHandleResidentARB(handle) > 0 }
+        trace!(self, "glIsTextureHandleResidentARB", handle);
+        unsafe { self.fns().IsTextureHandleResidentARB(handle) > 0 }
     }
 
     pub fn glMakeImageHandleNonResidentARB(&self, handle: u64) {
-        unsafe { self.fns.MakeImageHandleNonResidentARB(handle) }
+        trace!(self, "glMakeImageHandleNonResidentARB", handle);
+        unsafe { self.fns().MakeImageHandleNonResidentARB(handle) }
     }
 
     pub fn glMakeImageHandleResidentARB(&self, handle: u64, access: GLenum) {
-        unsafe { self.fns.MakeImageHandleResidentARB(handle, access) }
+        trace!(self, "glMakeImageHandleResidentARB", handle, access);
+        unsafe { self.fns().MakeImageHandleResidentARB(handle, access) }
     }
 
     pub fn glMakeTextureHandleNonResidentARB(&self, handle: u64) {
-        unsafe { self.fns.MakeTextureHandleNonResidentARB(handle) }
+        trace!(self, "glMakeTextureHandleNonResidentARB", handle);
+        unsafe { self.fns().MakeTextureHandleNonResidentARB(handle) }
     }
 
     pub fn glMakeTextureHandleResidentARB(&self, handle: u64) {
-        unsafe { self.fns.MakeTextureHandleResidentARB(handle) }
+        trace!(self, "glMakeTextureHandleResidentARB", handle);
+        unsafe { self.fns().MakeTextureHandleResidentARB(handle) }
     }
 
     pub fn glProgramUniformHandleui64ARB(&self, program: u32, location: i32, value: u64) {
-        unsafe { self.fns.ProgramUniformHandleui64ARB(program, location, value) }
+        trace!(self, "glProgramUniformHandleui64ARB", program, location, value);
+        unsafe { self.fns().ProgramUniformHandleui64ARB(program, location, value) }
     }
 
     pub fn glProgramUniformHandleui64vARB(&self, program: u32, location: i32, count: u32, values: *const u64) {
+        trace!(self, "glProgramUniformHandleui64vARB", program, location, count, values);
         // Unreviewed
-        unsafe { self.fns.ProgramUniformHandleui64vARB(program, location, count as _, values) }
+        unsafe { self.fns().ProgramUniformHandleui64vARB(program, location, count as _, values) }
     }
 
     pub fn glTexPageCommitmentARB(&self, target: GLenum, level: i32, xoffset: i32, yoffset: i32, zoffset: i32, width: u32, height: u32, depth: u32, commit: bool) {
-        unsafe { self.fns.TexPageCommitmentARB(target, level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, commit as _) }
+        trace!(self, "glTexPageCommitmentARB", target, level, xoffset, yoffset, zoffset, width, height, depth, commit);
+        unsafe { self.fns().TexPageCommitmentARB(target, level, xoffset, yoffset, zoffset, width as _, height as _, depth as _, commit as _) }
     }
 
     pub fn glUniformHandleui64ARB(&self, location: i32, value: u64) {
-        unsafe { self.fns.UniformHandleui64ARB(location, value) }
+        trace!(self, "glUniformHandleui64ARB", location, value);
+        unsafe { self.fns().UniformHandleui64ARB(location, value) }
     }
 
     pub fn glUniformHandleui64vARB(&self, location: i32, count: u32, value: *const u64) {
+        trace!(self, "glUniformHandleui64vARB", location, count, value);
         // Unreviewed
-        unsafe { self.fns.UniformHandleui64vARB(location, count as _, value) }
+        unsafe { self.fns().UniformHandleui64vARB(location, count as _, value) }
     }
 
     pub fn glVertexAttribL1ui64ARB(&self, index: u32, x: u64) {
-        unsafe { self.fns.VertexAttribL1ui64ARB(index, x) }
+        trace!(self, "glVertexAttribL1ui64ARB", index, x);
+        unsafe { self.fns().VertexAttribL1ui64ARB(index, x) }
     }
 
     pub fn glVertexAttribL1ui64vARB(&self, index: u32, v: *const u64) {
+        trace!(self, "glVertexAttribL1ui64vARB", index, v);
         // Unreviewed
-        unsafe { self.fns.VertexAttribL1ui64vARB(index, v) }
+        unsafe { self.fns().VertexAttribL1ui64vARB(index, v) }
+    }
+}
+
+pub(in crate::engine::graphics) struct Fns<'a> {
+    gl: &'a GLWrapper
+}
+
+impl Deref for Fns<'_> {
+    type Target = GlFns;
+
+    fn deref(&self) -> &GlFns {
+        &self.gl.fns
+    }
+}
+
+impl Drop for Fns<'_> {
+    fn drop(&mut self) {
+        if let Some(context) = &self.gl.software {
+            SoftwareContext::release_current(context);
+        }
     }
 }
 
@@ -1,11 +1,11 @@
-use std::{cell::RefCell, collections::hash_map::DefaultHasher, hash::{Hash, Hasher}, ops::{Deref, Not}, os::raw::c_void};
+use std::{cell::RefCell, collections::hash_map::DefaultHasher, hash::{Hash, Hasher}, ops::{Deref, Not}, os::raw::c_void, time::Instant};
 
-use glfw::{fail_on_errors, Glfw, Context, PWindow, GlfwReceiver, WindowEvent, Monitor};
+use glfw::{fail_on_errors, Glfw, Context, PWindow, GlfwReceiver, WindowEvent, Monitor, JoystickId};
 
 use libc::strlen;
 use libffi::high::Closure0;
 
-use crate::engine::{WindowMode, errors::{Error, GraphicsError, Result}, graphics::gl_enums::PixelStoreParameter};
+use crate::engine::{WindowMode, errors::{Error, GraphicsError, Result}, graphics::gl_enums::PixelStoreParameter, input::{GamepadSnapshot, MAX_GAMEPADS}};
 
 use super::GLWrapper;
 
@@ -83,11 +83,42 @@ fn get_monitor_fingerprint(monitor: &Monitor) -> u64 {
     hasher.finish()
 }
 
+// Set GL_TRACE to a file path to record every gl call from the moment the context is created
+fn trace_from_env(gl: &GLWrapper) -> Result<()> {
+    match std::env::var_os("GL_TRACE") {
+        Some(path) => gl.start_trace(path),
+        None => Ok(())
+    }
+}
+
+enum Backend {
+    Glfw {
+        glfw: Glfw,
+        window: PWindow,
+        events: GlfwReceiver<(f64, WindowEvent)>
+    },
+    // No window and no driver, GL calls go to the software implementation
+    Headless {
+        start: Instant,
+        should_close: bool,
+        cursor_mode: CursorMode,
+        raw_mouse_motion: bool
+    }
+}
+
+const HEADLESS_SIZE: (u32, u32) = (1280, 720);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CursorMode {
+    Normal,
+    Hidden,
+    /// Hides the cursor and keeps it in the window, the reported position is unbounded. Meant for FPS cameras.
+    Locked
+}
+
 pub struct Graphics {
     gl: GLWrapper,
-    glfw: Glfw,
-    window: PWindow,
-    events: GlfwReceiver<(f64, WindowEvent)>
+    backend: Backend
 }
 
 impl Graphics {
@@ -141,10 +172,24 @@ impl Graphics {
 
         let window = window.into_inner();
 
+        trace_from_env(&gl)?;
+
         unsafe { gl.glPixelStorei(PixelStoreParameter::GL_UNPACK_ALIGNMENT, 1) };
 
 
-        let gfx = Graphics { gl, glfw, window, events };
+        let gfx = Graphics { gl, backend: Backend::Glfw { glfw, window, events } };
+
+        Ok(gfx)
+    }
+
+    pub fn init_headless() -> Result<Graphics> {
+        let gl = GLWrapper::init_software()?;
+
+        trace_from_env(&gl)?;
+
+        unsafe { gl.glPixelStorei(PixelStoreParameter::GL_UNPACK_ALIGNMENT, 1) };
+
+        let gfx = Graphics { gl, backend: Backend::Headless { start: Instant::now(), should_close: false, cursor_mode: CursorMode::Normal, raw_mouse_motion: false } };
 
         Ok(gfx)
     }
@@ -172,61 +217,178 @@ impl Graphics {
         unsafe { gl.glPixelStorei(PixelStoreParameter::GL_UNPACK_ALIGNMENT, 1) };
 
 
-        let gfx = Graphics { gl, glfw, window, events };
+        let gfx = Graphics { gl, backend: Backend::Glfw { glfw, window, events } };
 
         Ok(gfx)
     }
 
+    pub fn is_headless(&self) -> bool {
+        matches!(self.backend, Backend::Headless { .. })
+    }
+
     pub fn window_mode(&self) -> WindowMode {
-        self.window.with_window_mode(|mode| {
-            match mode {
-                glfw::WindowMode::FullScreen(monitor) => WindowMode::FullScreen(Some(get_monitor_fingerprint(monitor))),
-                glfw::WindowMode::Windowed => WindowMode::Windowed,
-            }
-        })
+        match &self.backend {
+            Backend::Glfw { window, .. } => window.with_window_mode(|mode| {
+                match mode {
+                    glfw::WindowMode::FullScreen(monitor) => WindowMode::FullScreen(Some(get_monitor_fingerprint(monitor))),
+                    glfw::WindowMode::Windowed => WindowMode::Windowed,
+                }
+            }),
+            Backend::Headless { .. } => WindowMode::Windowed
+        }
     }
 
     pub fn swap_buffers(&mut self) {
-        self.window.swap_buffers();
+        self.gl.trace_frame();
+
+        if let Backend::Glfw { window, .. } = &mut self.backend {
+            window.swap_buffers();
+        }
     }
 
     pub fn poll_events(&mut self) {
-        self.glfw.poll_events();
+        if let Backend::Glfw { glfw, .. } = &mut self.backend {
+            glfw.poll_events();
+        }
     }
 
     pub fn get_glfw_time(&self) -> f64 {
-        self.glfw.get_time()
+        match &self.backend {
+            Backend::Glfw { glfw, .. } => glfw.get_time(),
+            Backend::Headless { start, .. } => start.elapsed().as_secs_f64()
+        }
     }
 
     pub fn flush_messages(&self) -> std::vec::IntoIter<(f64, WindowEvent)> {
-        glfw::flush_messages(&self.events).collect::<Vec<(f64, WindowEvent)>>().into_iter()
+        match &self.backend {
+            Backend::Glfw { events, .. } => glfw::flush_messages(events).collect::<Vec<(f64, WindowEvent)>>().into_iter(),
+            Backend::Headless { .. } => Vec::new().into_iter()
+        }
+    }
+
+    /// Current state of every joystick slot, `None` where no gamepad is connected. Always empty in headless mode.
+    pub fn poll_gamepads(&self) -> Vec<(JoystickId, Option<GamepadSnapshot>)> {
+        match &self.backend {
+            Backend::Glfw { glfw, .. } => (0..MAX_GAMEPADS as i32).filter_map(JoystickId::from_i32).map(|id| {
+                let joystick = glfw.get_joystick(id);
+                let state = if joystick.is_present() && joystick.is_gamepad() {
+                    joystick.get_gamepad_state().map(GamepadSnapshot::from)
+                } else {
+                    None
+                };
+
+                (id, state)
+            }).collect(),
+            Backend::Headless { .. } => Vec::new()
+        }
     }
 
     pub fn should_close(&self) -> bool {
-        self.window.should_close()
+        match &self.backend {
+            Backend::Glfw { window, .. } => window.should_close(),
+            Backend::Headless { should_close, .. } => *should_close
+        }
     }
 
     pub fn set_should_close(&mut self, value: bool) {
-        self.window.set_should_close(value);
+        match &mut self.backend {
+            Backend::Glfw { window, .. } => window.set_should_close(value),
+            Backend::Headless { should_close, .. } => *should_close = value
+        }
+    }
+
+    pub fn window_size(&self) -> (u32, u32) {
+        match &self.backend {
+            Backend::Glfw { window, .. } => {
+                let (width, height) = window.get_size();
+                (width as u32, height as u32)
+            },
+            Backend::Headless { .. } => HEADLESS_SIZE
+        }
+    }
+
+    pub fn cursor_position(&self) -> (f64, f64) {
+        match &self.backend {
+            Backend::Glfw { window, .. } => window.get_cursor_pos(),
+            Backend::Headless { .. } => (0.0, 0.0)
+        }
+    }
+
+    pub fn cursor_mode(&self) -> CursorMode {
+        match &self.backend {
+            Backend::Glfw { window, .. } => match window.get_cursor_mode() {
+                glfw::CursorMode::Hidden => CursorMode::Hidden,
+                glfw::CursorMode::Disabled => CursorMode::Locked,
+                _ => CursorMode::Normal
+            },
+            Backend::Headless { cursor_mode, .. } => *cursor_mode
+        }
+    }
+
+    pub fn set_cursor_mode(&mut self, mode: CursorMode) {
+        match &mut self.backend {
+            Backend::Glfw { window, .. } => window.set_cursor_mode(match mode {
+                CursorMode::Normal => glfw::CursorMode::Normal,
+                CursorMode::Hidden => glfw::CursorMode::Hidden,
+                CursorMode::Locked => glfw::CursorMode::Disabled
+            }),
+            Backend::Headless { cursor_mode, .. } => *cursor_mode = mode
+        }
+    }
+
+    /// Unaccelerated mouse motion, only used while the cursor is locked. Returns false if the platform doesn't support it.
+    pub fn set_raw_mouse_motion(&mut self, enabled: bool) -> bool {
+        match &mut self.backend {
+            Backend::Glfw { glfw, window, .. } => {
+                if !glfw.supports_raw_motion() {
+                    return false;
+                }
+
+                window.set_raw_mouse_motion(enabled);
+                true
+            },
+            Backend::Headless { raw_mouse_motion, .. } => {
+                *raw_mouse_motion = enabled;
+                true
+            }
+        }
+    }
+
+    pub fn raw_mouse_motion(&self) -> bool {
+        match &self.backend {
+            Backend::Glfw { window, .. } => window.uses_raw_mouse_motion(),
+            Backend::Headless { raw_mouse_motion, .. } => *raw_mouse_motion
+        }
     }
 
     pub fn set_fullscreen(&mut self, monitor: Monitor) {
-        let mode = monitor.get_video_mode().unwrap();
-        self.window.set_monitor(glfw::WindowMode::FullScreen(&monitor), 0, 0, mode.width, mode.height, None);
+        if let Backend::Glfw { window, .. } = &mut self.backend {
+            let mode = monitor.get_video_mode().unwrap();
+            window.set_monitor(glfw::WindowMode::FullScreen(&monitor), 0, 0, mode.width, mode.height, None);
+        }
     }
 
     // This will be deleted once glfw is properly wrapped
     pub fn __get_glfw<'a>(&'a self) -> &'a Glfw {
-        &self.glfw
+        match &self.backend {
+            Backend::Glfw { glfw, .. } => glfw,
+            Backend::Headless { .. } => panic!("There is no glfw instance in headless mode")
+        }
     }
 
     // This will be deleted once glfw is properly wrapped
     pub fn __get_glfw_mut<'a>(&'a mut self) ->&'a mut Glfw {
-        &mut self.glfw
+        match &mut self.backend {
+            Backend::Glfw { glfw, .. } => glfw,
+            Backend::Headless { .. } => panic!("There is no glfw instance in headless mode")
+        }
     }
 
     pub fn is_supported(&mut self, gl_fn_name: &'static str) -> bool {
-        self.window.get_proc_address(&gl_fn_name).is_null().not()
+        match &mut self.backend {
+            Backend::Glfw { window, .. } => window.get_proc_address(&gl_fn_name).is_null().not(),
+            Backend::Headless { .. } => super::software_gl::is_emulated(gl_fn_name)
+        }
     }
 
     // // This will be deleted once window is properly wrapped
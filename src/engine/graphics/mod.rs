@@ -5,6 +5,8 @@ mod shader_program;
 mod vertex_buffer;
 mod texture;
 mod camera;
+mod software_gl;
+mod gl_trace;
 
 pub mod sprite_renderer;
 pub mod image;
@@ -18,6 +20,8 @@ pub use shader_program::*;
 pub use vertex_buffer::*;
 pub use texture::*;
 pub use camera::*;
+pub use software_gl::*;
+pub use gl_trace::*;
 
 #[cfg(test)]
 pub mod test_lock {
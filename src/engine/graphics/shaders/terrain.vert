@@ -3,6 +3,7 @@
 layout(location = 0) in vec3 position;
 
 uniform mat4 vp;
+uniform mat4 model;
 uniform uvec2 terrainDimensions;
 uniform float heightScale;
 
@@ -97,7 +98,9 @@ void main()
         outPosition += vec3(0, height, 0);
     }
 
-    gl_Position = vp * vec4(outPosition, 1); 
-    fragPos = outPosition;
+    vec4 worldPosition = model * vec4(outPosition, 1);
+
+    gl_Position = vp * worldPosition;
+    fragPos = worldPosition.xyz;
     uv = uvs[gl_VertexID];
 }
\ No newline at end of file
@@ -0,0 +1,1178 @@
+use std::{cell::{Cell, Ref, RefCell}, collections::{BTreeMap, HashMap, HashSet}, ffi::{c_void, CStr}, rc::Rc};
+
+use gl46::{GL_ARRAY_BUFFER, GL_BGR, GL_BGRA, GL_BYTE, GL_COMPILE_STATUS, GL_DEPTH_COMPONENT, GL_ELEMENT_ARRAY_BUFFER, GL_FLOAT, GL_HALF_FLOAT, GL_INFO_LOG_LENGTH, GL_INT, GL_INVALID_ENUM, GL_INVALID_OPERATION, GL_INVALID_VALUE, GL_LINK_STATUS, GL_NO_ERROR, GL_RED, GL_RED_INTEGER, GL_RG, GL_RGB, GL_RGBA, GL_RG_INTEGER, GL_RGB_INTEGER, GL_RGBA_INTEGER, GL_SHADER_SOURCE_LENGTH, GL_SHADER_TYPE, GL_SHORT, GL_STENCIL_INDEX, GL_TEXTURE0, GL_UNPACK_ALIGNMENT, GL_UNSIGNED_BYTE, GL_UNSIGNED_INT, GL_UNSIGNED_SHORT, GL_ATTACHED_SHADERS, GL_DELETE_STATUS, GL_ACTIVE_UNIFORMS};
+use gl_types::matrices::{Mat4, MatN};
+use lazy_static::lazy_static;
+use log::error;
+use regex::Regex;
+
+use crate::engine::graphics::gl_enums::{BufferTargetARB, EnableCap, TextureTarget, TextureUnit};
+
+// Software implementation of the subset of OpenGL that the engine actually uses. GLWrapper
+// loads these functions in place of the driver's, so none of the wrapper code has to know
+// which backend it is talking to. Everything else is recorded by name and returns zero.
+//
+// Like a real GL context, the software context is current on the thread that created it.
+
+thread_local! {
+    // Pushed by the wrapper around every call it makes, so each wrapper reaches its own context. Calls can nest
+    static CURRENT: RefCell<Vec<Rc<RefCell<SoftwareContext>>>> = const { RefCell::new(Vec::new()) };
+}
+
+thread_local! {
+    // Set by the wrapper before each call, so the shared stub can report which function it stood in for
+    static CALLING: Cell<&'static str> = const { Cell::new("") };
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SoftwareBuffer {
+    pub data: Vec<u8>,
+    pub usage: u32
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SoftwareAttribute {
+    pub enabled: bool,
+    pub buffer: u32,
+    pub size: i32,
+    pub type_: u32,
+    pub normalized: bool,
+    pub stride: i32,
+    pub offset: usize,
+    pub divisor: u32
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SoftwareVertexArray {
+    pub attributes: BTreeMap<u32, SoftwareAttribute>,
+    pub element_buffer: u32
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SoftwareTexture {
+    pub target: u32,
+    pub width: u32,
+    pub height: u32,
+    pub internal_format: u32,
+    pub format: u32,
+    pub type_: u32,
+    pub parameters: HashMap<u32, i32>,
+    pub data: Vec<u8>
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SoftwareShader {
+    pub type_: u32,
+    pub source: String,
+    pub compiled: bool
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UniformValue {
+    Float(Box<[f32]>),
+    Int(Box<[i32]>),
+    UInt(Box<[u32]>),
+    Mat4(Mat4)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SoftwareProgram {
+    pub shaders: Vec<u32>,
+    pub linked: bool,
+    uniform_locations: HashMap<String, i32>,
+    uniforms: HashMap<i32, UniformValue>
+}
+
+impl SoftwareProgram {
+    pub fn uniform_location(&self, name: &str) -> Option<i32> {
+        self.uniform_locations.get(name).copied()
+    }
+
+    pub fn uniform(&self, name: &str) -> Option<&UniformValue> {
+        self.uniforms.get(self.uniform_locations.get(name)?)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DrawCall {
+    pub mode: u32,
+    pub first: i32,
+    pub count: i32,
+    pub instance_count: u32,
+    pub indices: Option<Box<[u32]>>,
+    pub program: u32,
+    pub vertex_array: u32,
+    pub textures: BTreeMap<u32, u32>
+}
+
+pub struct SoftwareContext {
+    next_name: u32,
+    error: u32,
+    calls: Vec<&'static str>,
+    draw_calls: Vec<DrawCall>,
+    buffers: HashMap<u32, SoftwareBuffer>,
+    buffer_bindings: HashMap<u32, u32>,
+    indexed_buffer_bindings: HashMap<(u32, u32), u32>,
+    vertex_arrays: HashMap<u32, SoftwareVertexArray>,
+    bound_vertex_array: u32,
+    textures: HashMap<u32, SoftwareTexture>,
+    active_texture: u32,
+    texture_bindings: HashMap<(u32, u32), u32>,
+    shaders: HashMap<u32, SoftwareShader>,
+    programs: HashMap<u32, SoftwareProgram>,
+    current_program: u32,
+    capabilities: HashSet<u32>,
+    unpack_alignment: i32,
+    clear_color: [f32; 4],
+    clear_depth: f64,
+    viewport: [i32; 4]
+}
+
+impl SoftwareContext {
+    pub(in crate::engine::graphics) fn new() -> SoftwareContext {
+        let mut vertex_arrays = HashMap::new();
+        // Vertex array 0 always exists, even though core profile won't let you draw with it
+        vertex_arrays.insert(0, SoftwareVertexArray::default());
+
+        SoftwareContext {
+            next_name: 1,
+            error: GL_NO_ERROR.0,
+            calls: Vec::new(),
+            draw_calls: Vec::new(),
+            buffers: HashMap::new(),
+            buffer_bindings: HashMap::new(),
+            indexed_buffer_bindings: HashMap::new(),
+            vertex_arrays,
+            bound_vertex_array: 0,
+            textures: HashMap::new(),
+            active_texture: 0,
+            texture_bindings: HashMap::new(),
+            shaders: HashMap::new(),
+            programs: HashMap::new(),
+            current_program: 0,
+            capabilities: HashSet::new(),
+            unpack_alignment: 4,
+            clear_color: [0.0; 4],
+            clear_depth: 1.0,
+            viewport: [0; 4]
+        }
+    }
+
+    pub(in crate::engine::graphics) fn make_current(context: &Rc<RefCell<SoftwareContext>>) {
+        CURRENT.with(|current| current.borrow_mut().push(context.clone()));
+    }
+
+    pub(in crate::engine::graphics) fn release_current(context: &Rc<RefCell<SoftwareContext>>) {
+        CURRENT.with(|current| {
+            let mut current = current.borrow_mut();
+            if let Some(i) = current.iter().rposition(|c| Rc::ptr_eq(c, context)) {
+                current.remove(i);
+            }
+        });
+    }
+
+    /// Every GL function called on this context, in order.
+    pub fn calls(&self) -> &[&'static str] {
+        &self.calls
+    }
+
+    pub fn draw_calls(&self) -> &[DrawCall] {
+        &self.draw_calls
+    }
+
+    /// Clears the recorded calls and draw calls. Object state is left alone.
+    pub fn clear_log(&mut self) {
+        self.calls.clear();
+        self.draw_calls.clear();
+    }
+
+    pub fn buffer(&self, buffer: u32) -> Option<&SoftwareBuffer> {
+        self.buffers.get(&buffer)
+    }
+
+    pub fn bound_buffer(&self, target: BufferTargetARB) -> u32 {
+        self.buffer_bindings.get(&(target as u32)).copied().unwrap_or(0)
+    }
+
+    pub fn indexed_buffer(&self, target: BufferTargetARB, index: u32) -> u32 {
+        self.indexed_buffer_bindings.get(&(target as u32, index)).copied().unwrap_or(0)
+    }
+
+    pub fn vertex_array(&self, array: u32) -> Option<&SoftwareVertexArray> {
+        self.vertex_arrays.get(&array)
+    }
+
+    pub fn bound_vertex_array(&self) -> u32 {
+        self.bound_vertex_array
+    }
+
+    pub fn texture(&self, texture: u32) -> Option<&SoftwareTexture> {
+        self.textures.get(&texture)
+    }
+
+    pub fn bound_texture(&self, unit: TextureUnit, target: TextureTarget) -> u32 {
+        let unit = unit as u32 - GL_TEXTURE0.0;
+        self.texture_bindings.get(&(unit, target as u32)).copied().unwrap_or(0)
+    }
+
+    pub fn shader(&self, shader: u32) -> Option<&SoftwareShader> {
+        self.shaders.get(&shader)
+    }
+
+    pub fn program(&self, program: u32) -> Option<&SoftwareProgram> {
+        self.programs.get(&program)
+    }
+
+    pub fn current_program(&self) -> u32 {
+        self.current_program
+    }
+
+    pub fn is_enabled(&self, cap: EnableCap) -> bool {
+        self.capabilities.contains(&(cap as u32))
+    }
+
+    pub fn clear_color(&self) -> [f32; 4] {
+        self.clear_color
+    }
+
+    pub fn clear_depth(&self) -> f64 {
+        self.clear_depth
+    }
+
+    pub fn viewport(&self) -> [i32; 4] {
+        self.viewport
+    }
+
+    fn set_error(&mut self, error: u32) {
+        // Like GL, only the first error is kept until glGetError is called
+        if self.error == GL_NO_ERROR.0 {
+            self.error = error;
+        }
+    }
+
+    fn take_error(&mut self) -> u32 {
+        std::mem::replace(&mut self.error, GL_NO_ERROR.0)
+    }
+
+    fn gen_names(&mut self, n: i32) -> Vec<u32> {
+        (0..n.max(0)).map(|_| {
+            let name = self.next_name;
+            self.next_name += 1;
+            name
+        }).collect()
+    }
+
+    fn bind_buffer(&mut self, target: u32, buffer: u32) {
+        if buffer != 0 && !self.buffers.contains_key(&buffer) {
+            return self.set_error(GL_INVALID_OPERATION.0);
+        }
+
+        if target == GL_ELEMENT_ARRAY_BUFFER.0 {
+            let vao = self.bound_vertex_array;
+            self.vertex_arrays.entry(vao).or_default().element_buffer = buffer;
+        }
+
+        self.buffer_bindings.insert(target, buffer);
+    }
+
+    fn bind_buffer_base(&mut self, target: u32, index: u32, buffer: u32) {
+        if buffer != 0 && !self.buffers.contains_key(&buffer) {
+            return self.set_error(GL_INVALID_OPERATION.0);
+        }
+
+        // Binding to an indexed target also binds to the generic binding point
+        self.indexed_buffer_bindings.insert((target, index), buffer);
+        self.buffer_bindings.insert(target, buffer);
+    }
+
+    fn bound_buffer_mut(&mut self, target: u32) -> Option<&mut SoftwareBuffer> {
+        let buffer = if target == GL_ELEMENT_ARRAY_BUFFER.0 {
+            self.vertex_arrays.get(&self.bound_vertex_array).map(|vao| vao.element_buffer).unwrap_or(0)
+        } else {
+            self.buffer_bindings.get(&target).copied().unwrap_or(0)
+        };
+
+        self.buffers.get_mut(&buffer)
+    }
+
+    fn buffer_data(&mut self, target: u32, size: isize, data: *const u8, usage: u32) {
+        if size < 0 {
+            return self.set_error(GL_INVALID_VALUE.0);
+        }
+
+        let Some(buffer) = self.bound_buffer_mut(target) else { return self.set_error(GL_INVALID_OPERATION.0) };
+
+        buffer.usage = usage;
+        buffer.data = if data.is_null() {
+            vec![0; size as usize]
+        } else {
+            unsafe { std::slice::from_raw_parts(data, size as usize) }.to_vec()
+        };
+    }
+
+    fn buffer_sub_data(&mut self, target: u32, offset: isize, size: isize, data: *const u8) {
+        let Some(buffer) = self.bound_buffer_mut(target) else { return self.set_error(GL_INVALID_OPERATION.0) };
+
+        if offset < 0 || size < 0 || (offset + size) as usize > buffer.data.len() {
+            return self.set_error(GL_INVALID_VALUE.0);
+        }
+
+        let (offset, size) = (offset as usize, size as usize);
+        buffer.data[offset..offset + size].copy_from_slice(unsafe { std::slice::from_raw_parts(data, size) });
+    }
+
+    fn get_buffer_sub_data(&mut self, target: u32, offset: isize, size: isize, data: *mut u8) {
+        let Some(buffer) = self.bound_buffer_mut(target) else { return self.set_error(GL_INVALID_OPERATION.0) };
+
+        if offset < 0 || size < 0 || (offset + size) as usize > buffer.data.len() {
+            return self.set_error(GL_INVALID_VALUE.0);
+        }
+
+        let (offset, size) = (offset as usize, size as usize);
+        unsafe { std::slice::from_raw_parts_mut(data, size) }.copy_from_slice(&buffer.data[offset..offset + size]);
+    }
+
+    fn bind_vertex_array(&mut self, array: u32) {
+        if !self.vertex_arrays.contains_key(&array) {
+            return self.set_error(GL_INVALID_OPERATION.0);
+        }
+
+        self.bound_vertex_array = array;
+        let element_buffer = self.vertex_arrays[&array].element_buffer;
+        self.buffer_bindings.insert(GL_ELEMENT_ARRAY_BUFFER.0, element_buffer);
+    }
+
+    fn modify_attribute<F: FnOnce(&mut SoftwareAttribute)>(&mut self, index: u32, f: F) {
+        if self.bound_vertex_array == 0 {
+            return self.set_error(GL_INVALID_OPERATION.0);
+        }
+
+        let vao = self.vertex_arrays.entry(self.bound_vertex_array).or_default();
+        f(vao.attributes.entry(index).or_default());
+    }
+
+    fn bind_texture(&mut self, target: u32, texture: u32) {
+        if texture != 0 {
+            let Some(t) = self.textures.get_mut(&texture) else { return self.set_error(GL_INVALID_OPERATION.0) };
+
+            // A texture takes on the target it is first bound to
+            if t.target == 0 {
+                t.target = target;
+            } else if t.target != target {
+                return self.set_error(GL_INVALID_OPERATION.0);
+            }
+        }
+
+        self.texture_bindings.insert((self.active_texture, target), texture);
+    }
+
+    fn bound_texture_mut(&mut self, target: u32) -> Option<&mut SoftwareTexture> {
+        let texture = self.texture_bindings.get(&(self.active_texture, target)).copied().unwrap_or(0);
+        self.textures.get_mut(&texture)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn tex_image_2d(&mut self, target: u32, level: i32, internal_format: u32, width: i32, height: i32, format: u32, type_: u32, pixels: *const u8) {
+        let Some(bytes_per_pixel) = bytes_per_pixel(format, type_) else { return self.set_error(GL_INVALID_ENUM.0) };
+        if width < 0 || height < 0 {
+            return self.set_error(GL_INVALID_VALUE.0);
+        }
+
+        let alignment = self.unpack_alignment;
+        let Some(texture) = self.bound_texture_mut(target) else { return self.set_error(GL_INVALID_OPERATION.0) };
+
+        // Only the base level is stored
+        if level != 0 {
+            return;
+        }
+
+        texture.width = width as u32;
+        texture.height = height as u32;
+        texture.internal_format = internal_format;
+        texture.format = format;
+        texture.type_ = type_;
+        texture.data = vec![0; width as usize * height as usize * bytes_per_pixel];
+
+        if !pixels.is_null() {
+            unpack_rows(&mut texture.data, width as usize, 0, 0, width as usize, height as usize, bytes_per_pixel, alignment, pixels);
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn texture_sub_image_2d(&mut self, texture: u32, level: i32, xoffset: i32, yoffset: i32, width: i32, height: i32, format: u32, type_: u32, pixels: *const u8) {
+        let Some(bytes_per_pixel) = bytes_per_pixel(format, type_) else { return self.set_error(GL_INVALID_ENUM.0) };
+
+        let alignment = self.unpack_alignment;
+        let Some(texture) = self.textures.get_mut(&texture) else { return self.set_error(GL_INVALID_OPERATION.0) };
+
+        if xoffset < 0 || yoffset < 0 || width < 0 || height < 0 || (xoffset + width) as u32 > texture.width || (yoffset + height) as u32 > texture.height {
+            return self.set_error(GL_INVALID_VALUE.0);
+        }
+
+        if level != 0 || pixels.is_null() {
+            return;
+        }
+
+        let texture_width = texture.width as usize;
+        unpack_rows(&mut texture.data, texture_width, xoffset as usize, yoffset as usize, width as usize, height as usize, bytes_per_pixel, alignment, pixels);
+    }
+
+    fn link_program(&mut self, program: u32) {
+        let Some(p) = self.programs.get(&program) else { return self.set_error(GL_INVALID_VALUE.0) };
+
+        lazy_static! {
+            static ref UNIFORM: Regex = Regex::new(r"uniform\s+\w+\s+(\w+)\s*(\[[^\]]*\])?\s*(=[^;]*)?;").unwrap();
+        }
+
+        let mut uniform_locations = HashMap::new();
+        for shader in &p.shaders {
+            let Some(shader) = self.shaders.get(shader) else { continue };
+
+            for captures in UNIFORM.captures_iter(&shader.source) {
+                let next = uniform_locations.len() as i32;
+                uniform_locations.entry(captures[1].to_owned()).or_insert(next);
+            }
+        }
+
+        let p = self.programs.get_mut(&program).unwrap();
+        p.uniform_locations = uniform_locations;
+        p.uniforms.clear();
+        p.linked = true;
+    }
+
+    fn set_uniform(&mut self, location: i32, value: UniformValue) {
+        // Location -1 is silently ignored, same as GL
+        if location == -1 {
+            return;
+        }
+
+        let Some(program) = self.programs.get_mut(&self.current_program) else { return self.set_error(GL_INVALID_OPERATION.0) };
+
+        if !program.uniform_locations.values().any(|l| *l == location) {
+            return self.set_error(GL_INVALID_OPERATION.0);
+        }
+
+        program.uniforms.insert(location, value);
+    }
+
+    fn draw(&mut self, mode: u32, first: i32, count: i32, instance_count: i32, indices: Option<(u32, *const c_void)>) {
+        if count < 0 || instance_count < 0 {
+            return self.set_error(GL_INVALID_VALUE.0);
+        }
+
+        if self.current_program == 0 || self.bound_vertex_array == 0 {
+            return self.set_error(GL_INVALID_OPERATION.0);
+        }
+
+        let indices = match indices {
+            Some((type_, offset)) => match self.read_indices(type_, offset, count as usize) {
+                Some(indices) => Some(indices),
+                None => return self.set_error(GL_INVALID_OPERATION.0)
+            },
+            None => None
+        };
+
+        let textures = self.texture_bindings.iter()
+            .filter(|(_, texture)| **texture != 0)
+            .map(|((unit, _), texture)| (*unit, *texture))
+            .collect();
+
+        self.draw_calls.push(DrawCall {
+            mode,
+            first,
+            count,
+            instance_count: instance_count as u32,
+            indices,
+            program: self.current_program,
+            vertex_array: self.bound_vertex_array,
+            textures
+        });
+    }
+
+    fn read_indices(&self, type_: u32, offset: *const c_void, count: usize) -> Option<Box<[u32]>> {
+        let element_buffer = self.vertex_arrays.get(&self.bound_vertex_array)?.element_buffer;
+
+        // Without an element buffer, indices come straight from client memory
+        if element_buffer == 0 {
+            let indices = match type_ {
+                t if t == GL_UNSIGNED_BYTE.0 => unsafe { std::slice::from_raw_parts(offset as *const u8, count) }.iter().map(|i| *i as u32).collect(),
+                t if t == GL_UNSIGNED_SHORT.0 => unsafe { std::slice::from_raw_parts(offset as *const u16, count) }.iter().map(|i| *i as u32).collect(),
+                t if t == GL_UNSIGNED_INT.0 => unsafe { std::slice::from_raw_parts(offset as *const u32, count) }.into(),
+                _ => return None
+            };
+
+            return Some(indices);
+        }
+
+        let data = &self.buffers.get(&element_buffer)?.data;
+        let offset = offset as usize;
+        let size = match type_ {
+            t if t == GL_UNSIGNED_BYTE.0 => 1,
+            t if t == GL_UNSIGNED_SHORT.0 => 2,
+            t if t == GL_UNSIGNED_INT.0 => 4,
+            _ => return None
+        };
+
+        let bytes = data.get(offset..offset + count * size)?;
+        Some(bytes.chunks_exact(size).map(|chunk| match size {
+            1 => chunk[0] as u32,
+            2 => u16::from_ne_bytes([chunk[0], chunk[1]]) as u32,
+            _ => u32::from_ne_bytes([chunk[0], chunk[1], chunk[2], chunk[3]])
+        }).collect())
+    }
+}
+
+fn bytes_per_pixel(format: u32, type_: u32) -> Option<usize> {
+    let components = match format {
+        f if f == GL_RED.0 || f == GL_RED_INTEGER.0 || f == GL_DEPTH_COMPONENT.0 || f == GL_STENCIL_INDEX.0 => 1,
+        f if f == GL_RG.0 || f == GL_RG_INTEGER.0 => 2,
+        f if f == GL_RGB.0 || f == GL_BGR.0 || f == GL_RGB_INTEGER.0 => 3,
+        f if f == GL_RGBA.0 || f == GL_BGRA.0 || f == GL_RGBA_INTEGER.0 => 4,
+        _ => return None
+    };
+
+    let size = match type_ {
+        t if t == GL_UNSIGNED_BYTE.0 || t == GL_BYTE.0 => 1,
+        t if t == GL_UNSIGNED_SHORT.0 || t == GL_SHORT.0 || t == GL_HALF_FLOAT.0 => 2,
+        t if t == GL_UNSIGNED_INT.0 || t == GL_INT.0 || t == GL_FLOAT.0 => 4,
+        _ => return None
+    };
+
+    Some(components * size)
+}
+
+// Copies client pixel rows into tightly packed texture storage, respecting GL_UNPACK_ALIGNMENT
+#[allow(clippy::too_many_arguments)]
+fn unpack_rows(dst: &mut [u8], dst_width: usize, x: usize, y: usize, width: usize, height: usize, bytes_per_pixel: usize, alignment: i32, pixels: *const u8) {
+    let row_size = width * bytes_per_pixel;
+    let alignment = alignment.max(1) as usize;
+    let stride = row_size.div_ceil(alignment) * alignment;
+
+    for row in 0..height {
+        let src = unsafe { std::slice::from_raw_parts(pixels.add(row * stride), row_size) };
+        let start = ((y + row) * dst_width + x) * bytes_per_pixel;
+        dst[start..start + row_size].copy_from_slice(src);
+    }
+}
+
+fn with_context<R: Default, F: FnOnce(&mut SoftwareContext) -> R>(name: &'static str, f: F) -> R {
+    CURRENT.with(|current| {
+        let current = current.borrow();
+        let Some(context) = current.last() else {
+            error!("{} called with no current software GL context", name);
+            return R::default();
+        };
+
+        // Panicking here would abort, since we're inside an extern fn
+        let Ok(mut context) = context.try_borrow_mut() else {
+            error!("{} called while the software GL context is borrowed", name);
+            return R::default();
+        };
+
+        context.calls.push(name);
+        f(&mut context)
+    })
+}
+
+pub(in crate::engine::graphics) fn get_proc_address(name: &str) -> *const c_void {
+    match emulated_function(name) {
+        Some(ptr) => ptr,
+        None => unemulated as *const c_void
+    }
+}
+
+pub(in crate::engine::graphics) fn is_emulated(name: &str) -> bool {
+    emulated_function(name).is_some()
+}
+
+pub(in crate::engine::graphics) fn set_calling(name: &'static str) {
+    CALLING.set(name);
+}
+
+// Every function that isn't emulated loads as this no-op. It takes and returns nothing, so only functions returning void are safe to call through it
+extern "system" fn unemulated() {
+    let name = CALLING.get();
+    error!("{} is not emulated by the software GL backend", name);
+    with_context(name, |_| ());
+}
+
+fn emulated_function(name: &str) -> Option<*const c_void> {
+    let ptr = match name {
+        "glGetError" => gl_get_error as *const c_void,
+        "glGenBuffers" => gl_gen_buffers as _,
+        "glDeleteBuffers" => gl_delete_buffers as _,
+        "glBindBuffer" => gl_bind_buffer as _,
+        "glBindBufferBase" => gl_bind_buffer_base as _,
+        "glBufferData" => gl_buffer_data as _,
+        "glBufferSubData" => gl_buffer_sub_data as _,
+        "glGetBufferSubData" => gl_get_buffer_sub_data as _,
+        "glGenVertexArrays" => gl_gen_vertex_arrays as _,
+        "glDeleteVertexArrays" => gl_delete_vertex_arrays as _,
+        "glBindVertexArray" => gl_bind_vertex_array as _,
+        "glVertexAttribPointer" => gl_vertex_attrib_pointer as _,
+        "glEnableVertexAttribArray" => gl_enable_vertex_attrib_array as _,
+        "glDisableVertexAttribArray" => gl_disable_vertex_attrib_array as _,
+        "glVertexAttribDivisor" => gl_vertex_attrib_divisor as _,
+        "glGenTextures" => gl_gen_textures as _,
+        "glDeleteTextures" => gl_delete_textures as _,
+        "glActiveTexture" => gl_active_texture as _,
+        "glBindTexture" => gl_bind_texture as _,
+        "glTexParameteri" => gl_tex_parameteri as _,
+        "glTexImage2D" => gl_tex_image_2d as _,
+        "glTexSubImage2D" => gl_tex_sub_image_2d as _,
+        "glTextureSubImage2D" => gl_texture_sub_image_2d as _,
+        "glPixelStorei" => gl_pixel_storei as _,
+        "glCreateShader" => gl_create_shader as _,
+        "glDeleteShader" => gl_delete_shader as _,
+        "glShaderSource" => gl_shader_source as _,
+        "glCompileShader" => gl_compile_shader as _,
+        "glGetShaderiv" => gl_get_shaderiv as _,
+        "glGetShaderInfoLog" => gl_get_shader_info_log as _,
+        "glCreateProgram" => gl_create_program as _,
+        "glDeleteProgram" => gl_delete_program as _,
+        "glAttachShader" => gl_attach_shader as _,
+        "glLinkProgram" => gl_link_program as _,
+        "glGetProgramiv" => gl_get_programiv as _,
+        "glUseProgram" => gl_use_program as _,
+        "glGetUniformLocation" => gl_get_uniform_location as _,
+        "glUniform1f" => gl_uniform_1f as _,
+        "glUniform2f" => gl_uniform_2f as _,
+        "glUniform3f" => gl_uniform_3f as _,
+        "glUniform4f" => gl_uniform_4f as _,
+        "glUniform1i" => gl_uniform_1i as _,
+        "glUniform2i" => gl_uniform_2i as _,
+        "glUniform3i" => gl_uniform_3i as _,
+        "glUniform4i" => gl_uniform_4i as _,
+        "glUniform1ui" => gl_uniform_1ui as _,
+        "glUniform2ui" => gl_uniform_2ui as _,
+        "glUniform3ui" => gl_uniform_3ui as _,
+        "glUniform4ui" => gl_uniform_4ui as _,
+        "glUniformMatrix4fv" => gl_uniform_matrix_4fv as _,
+        "glDrawArrays" => gl_draw_arrays as _,
+        "glDrawArraysInstanced" => gl_draw_arrays_instanced as _,
+        "glDrawElements" => gl_draw_elements as _,
+        "glDrawElementsInstanced" => gl_draw_elements_instanced as _,
+        "glEnable" => gl_enable as _,
+        "glDisable" => gl_disable as _,
+        "glIsEnabled" => gl_is_enabled as _,
+        "glClear" => gl_clear as _,
+        "glClearColor" => gl_clear_color as _,
+        "glClearDepth" => gl_clear_depth as _,
+        "glViewport" => gl_viewport as _,
+        _ => return None
+    };
+
+    Some(ptr)
+}
+
+extern "system" fn gl_get_error() -> u32 {
+    with_context("glGetError", |gl| gl.take_error())
+}
+
+extern "system" fn gl_gen_buffers(n: i32, buffers: *mut u32) {
+    with_context("glGenBuffers", |gl| {
+        for (i, name) in gl.gen_names(n).into_iter().enumerate() {
+            gl.buffers.insert(name, SoftwareBuffer::default());
+            unsafe { *buffers.add(i) = name };
+        }
+    })
+}
+
+extern "system" fn gl_delete_buffers(n: i32, buffers: *const u32) {
+    with_context("glDeleteBuffers", |gl| {
+        let names = unsafe { std::slice::from_raw_parts(buffers, n.max(0) as usize) };
+
+        for name in names {
+            if gl.buffers.remove(name).is_some() {
+                gl.buffer_bindings.values_mut().chain(gl.indexed_buffer_bindings.values_mut()).filter(|b| **b == *name).for_each(|b| *b = 0);
+            }
+        }
+    })
+}
+
+extern "system" fn gl_bind_buffer(target: u32, buffer: u32) {
+    with_context("glBindBuffer", |gl| gl.bind_buffer(target, buffer))
+}
+
+extern "system" fn gl_bind_buffer_base(target: u32, index: u32, buffer: u32) {
+    with_context("glBindBufferBase", |gl| gl.bind_buffer_base(target, index, buffer))
+}
+
+extern "system" fn gl_buffer_data(target: u32, size: isize, data: *const c_void, usage: u32) {
+    with_context("glBufferData", |gl| gl.buffer_data(target, size, data as _, usage))
+}
+
+extern "system" fn gl_buffer_sub_data(target: u32, offset: isize, size: isize, data: *const c_void) {
+    with_context("glBufferSubData", |gl| gl.buffer_sub_data(target, offset, size, data as _))
+}
+
+extern "system" fn gl_get_buffer_sub_data(target: u32, offset: isize, size: isize, data: *mut c_void) {
+    with_context("glGetBufferSubData", |gl| gl.get_buffer_sub_data(target, offset, size, data as _))
+}
+
+extern "system" fn gl_gen_vertex_arrays(n: i32, arrays: *mut u32) {
+    with_context("glGenVertexArrays", |gl| {
+        for (i, name) in gl.gen_names(n).into_iter().enumerate() {
+            gl.vertex_arrays.insert(name, SoftwareVertexArray::default());
+            unsafe { *arrays.add(i) = name };
+        }
+    })
+}
+
+extern "system" fn gl_delete_vertex_arrays(n: i32, arrays: *const u32) {
+    with_context("glDeleteVertexArrays", |gl| {
+        let names = unsafe { std::slice::from_raw_parts(arrays, n.max(0) as usize) };
+
+        for name in names.iter().filter(|name| **name != 0) {
+            gl.vertex_arrays.remove(name);
+
+            if gl.bound_vertex_array == *name {
+                gl.bound_vertex_array = 0;
+            }
+        }
+    })
+}
+
+extern "system" fn gl_bind_vertex_array(array: u32) {
+    with_context("glBindVertexArray", |gl| gl.bind_vertex_array(array))
+}
+
+extern "system" fn gl_vertex_attrib_pointer(index: u32, size: i32, type_: u32, normalized: u8, stride: i32, pointer: *const c_void) {
+    with_context("glVertexAttribPointer", |gl| {
+        let buffer = gl.buffer_bindings.get(&GL_ARRAY_BUFFER.0).copied().unwrap_or(0);
+
+        gl.modify_attribute(index, |attribute| {
+            attribute.buffer = buffer;
+            attribute.size = size;
+            attribute.type_ = type_;
+            attribute.normalized = normalized != 0;
+            attribute.stride = stride;
+            attribute.offset = pointer as usize;
+        })
+    })
+}
+
+extern "system" fn gl_enable_vertex_attrib_array(index: u32) {
+    with_context("glEnableVertexAttribArray", |gl| gl.modify_attribute(index, |attribute| attribute.enabled = true))
+}
+
+extern "system" fn gl_disable_vertex_attrib_array(index: u32) {
+    with_context("glDisableVertexAttribArray", |gl| gl.modify_attribute(index, |attribute| attribute.enabled = false))
+}
+
+extern "system" fn gl_vertex_attrib_divisor(index: u32, divisor: u32) {
+    with_context("glVertexAttribDivisor", |gl| gl.modify_attribute(index, |attribute| attribute.divisor = divisor))
+}
+
+extern "system" fn gl_gen_textures(n: i32, textures: *mut u32) {
+    with_context("glGenTextures", |gl| {
+        for (i, name) in gl.gen_names(n).into_iter().enumerate() {
+            gl.textures.insert(name, SoftwareTexture::default());
+            unsafe { *textures.add(i) = name };
+        }
+    })
+}
+
+extern "system" fn gl_delete_textures(n: i32, textures: *const u32) {
+    with_context("glDeleteTextures", |gl| {
+        let names = unsafe { std::slice::from_raw_parts(textures, n.max(0) as usize) };
+
+        for name in names {
+            if gl.textures.remove(name).is_some() {
+                gl.texture_bindings.values_mut().filter(|t| **t == *name).for_each(|t| *t = 0);
+            }
+        }
+    })
+}
+
+extern "system" fn gl_active_texture(texture: u32) {
+    with_context("glActiveTexture", |gl| {
+        if texture < GL_TEXTURE0.0 {
+            return gl.set_error(GL_INVALID_ENUM.0);
+        }
+
+        gl.active_texture = texture - GL_TEXTURE0.0;
+    })
+}
+
+extern "system" fn gl_bind_texture(target: u32, texture: u32) {
+    with_context("glBindTexture", |gl| gl.bind_texture(target, texture))
+}
+
+extern "system" fn gl_tex_parameteri(target: u32, pname: u32, param: i32) {
+    with_context("glTexParameteri", |gl| match gl.bound_texture_mut(target) {
+        Some(texture) => { texture.parameters.insert(pname, param); },
+        None => gl.set_error(GL_INVALID_OPERATION.0)
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+extern "system" fn gl_tex_image_2d(target: u32, level: i32, internal_format: i32, width: i32, height: i32, _border: i32, format: u32, type_: u32, pixels: *const c_void) {
+    with_context("glTexImage2D", |gl| gl.tex_image_2d(target, level, internal_format as u32, width, height, format, type_, pixels as _))
+}
+
+#[allow(clippy::too_many_arguments)]
+extern "system" fn gl_tex_sub_image_2d(target: u32, level: i32, xoffset: i32, yoffset: i32, width: i32, height: i32, format: u32, type_: u32, pixels: *const c_void) {
+    with_context("glTexSubImage2D", |gl| {
+        let texture = gl.texture_bindings.get(&(gl.active_texture, target)).copied().unwrap_or(0);
+        gl.texture_sub_image_2d(texture, level, xoffset, yoffset, width, height, format, type_, pixels as _)
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+extern "system" fn gl_texture_sub_image_2d(texture: u32, level: i32, xoffset: i32, yoffset: i32, width: i32, height: i32, format: u32, type_: u32, pixels: *const c_void) {
+    with_context("glTextureSubImage2D", |gl| gl.texture_sub_image_2d(texture, level, xoffset, yoffset, width, height, format, type_, pixels as _))
+}
+
+extern "system" fn gl_pixel_storei(pname: u32, param: i32) {
+    with_context("glPixelStorei", |gl| {
+        if pname == GL_UNPACK_ALIGNMENT.0 {
+            gl.unpack_alignment = param;
+        }
+    })
+}
+
+extern "system" fn gl_create_shader(type_: u32) -> u32 {
+    with_context("glCreateShader", |gl| {
+        let name = gl.gen_names(1)[0];
+        gl.shaders.insert(name, SoftwareShader { type_, ..Default::default() });
+        name
+    })
+}
+
+extern "system" fn gl_delete_shader(shader: u32) {
+    with_context("glDeleteShader", |gl| { gl.shaders.remove(&shader); })
+}
+
+extern "system" fn gl_shader_source(shader: u32, count: i32, strings: *const *const u8, lengths: *const i32) {
+    with_context("glShaderSource", |gl| {
+        let mut source = String::new();
+
+        for i in 0..count.max(0) as usize {
+            let string = unsafe { *strings.add(i) };
+            let length = if lengths.is_null() { -1 } else { unsafe { *lengths.add(i) } };
+
+            let bytes = if length < 0 {
+                unsafe { CStr::from_ptr(string as _) }.to_bytes()
+            } else {
+                unsafe { std::slice::from_raw_parts(string, length as usize) }
+            };
+
+            source += &String::from_utf8_lossy(bytes);
+        }
+
+        match gl.shaders.get_mut(&shader) {
+            Some(s) => s.source = source,
+            None => gl.set_error(GL_INVALID_VALUE.0)
+        }
+    })
+}
+
+extern "system" fn gl_compile_shader(shader: u32) {
+    with_context("glCompileShader", |gl| match gl.shaders.get_mut(&shader) {
+        Some(s) => s.compiled = true,
+        None => gl.set_error(GL_INVALID_VALUE.0)
+    })
+}
+
+extern "system" fn gl_get_shaderiv(shader: u32, pname: u32, params: *mut i32) {
+    with_context("glGetShaderiv", |gl| {
+        let Some(s) = gl.shaders.get(&shader) else { return gl.set_error(GL_INVALID_VALUE.0) };
+
+        let value = match pname {
+            p if p == GL_SHADER_TYPE.0 => s.type_ as i32,
+            p if p == GL_COMPILE_STATUS.0 => s.compiled as i32,
+            p if p == GL_SHADER_SOURCE_LENGTH.0 => s.source.len() as i32 + 1,
+            p if p == GL_INFO_LOG_LENGTH.0 || p == GL_DELETE_STATUS.0 => 0,
+            _ => return gl.set_error(GL_INVALID_ENUM.0)
+        };
+
+        unsafe { *params = value };
+    })
+}
+
+extern "system" fn gl_get_shader_info_log(_shader: u32, buf_size: i32, length: *mut i32, info_log: *mut u8) {
+    // Compilation never fails, so the log is always empty
+    with_context("glGetShaderInfoLog", |_| {
+        if !length.is_null() {
+            unsafe { *length = 0 };
+        }
+
+        if buf_size > 0 && !info_log.is_null() {
+            unsafe { *info_log = 0 };
+        }
+    })
+}
+
+extern "system" fn gl_create_program() -> u32 {
+    with_context("glCreateProgram", |gl| {
+        let name = gl.gen_names(1)[0];
+        gl.programs.insert(name, SoftwareProgram::default());
+        name
+    })
+}
+
+extern "system" fn gl_delete_program(program: u32) {
+    with_context("glDeleteProgram", |gl| { gl.programs.remove(&program); })
+}
+
+extern "system" fn gl_attach_shader(program: u32, shader: u32) {
+    with_context("glAttachShader", |gl| {
+        if !gl.shaders.contains_key(&shader) {
+            return gl.set_error(GL_INVALID_VALUE.0);
+        }
+
+        match gl.programs.get_mut(&program) {
+            Some(p) => p.shaders.push(shader),
+            None => gl.set_error(GL_INVALID_VALUE.0)
+        }
+    })
+}
+
+extern "system" fn gl_link_program(program: u32) {
+    with_context("glLinkProgram", |gl| gl.link_program(program))
+}
+
+extern "system" fn gl_get_programiv(program: u32, pname: u32, params: *mut i32) {
+    with_context("glGetProgramiv", |gl| {
+        let Some(p) = gl.programs.get(&program) else { return gl.set_error(GL_INVALID_VALUE.0) };
+
+        let value = match pname {
+            n if n == GL_LINK_STATUS.0 => p.linked as i32,
+            n if n == GL_ATTACHED_SHADERS.0 => p.shaders.len() as i32,
+            n if n == GL_ACTIVE_UNIFORMS.0 => p.uniform_locations.len() as i32,
+            n if n == GL_INFO_LOG_LENGTH.0 || n == GL_DELETE_STATUS.0 => 0,
+            _ => return gl.set_error(GL_INVALID_ENUM.0)
+        };
+
+        unsafe { *params = value };
+    })
+}
+
+extern "system" fn gl_use_program(program: u32) {
+    with_context("glUseProgram", |gl| {
+        if program != 0 && !gl.programs.get(&program).is_some_and(|p| p.linked) {
+            return gl.set_error(GL_INVALID_OPERATION.0);
+        }
+
+        gl.current_program = program;
+    })
+}
+
+extern "system" fn gl_get_uniform_location(program: u32, name: *const u8) -> i32 {
+    with_context("glGetUniformLocation", |gl| {
+        let name = unsafe { CStr::from_ptr(name as _) }.to_string_lossy();
+
+        match gl.programs.get(&program) {
+            Some(p) if p.linked => p.uniform_location(&name).unwrap_or(-1),
+            _ => {
+                gl.set_error(GL_INVALID_OPERATION.0);
+                -1
+            }
+        }
+    })
+}
+
+extern "system" fn gl_uniform_1f(location: i32, v0: f32) {
+    with_context("glUniform1f", |gl| gl.set_uniform(location, UniformValue::Float(Box::new([v0]))))
+}
+
+extern "system" fn gl_uniform_2f(location: i32, v0: f32, v1: f32) {
+    with_context("glUniform2f", |gl| gl.set_uniform(location, UniformValue::Float(Box::new([v0, v1]))))
+}
+
+extern "system" fn gl_uniform_3f(location: i32, v0: f32, v1: f32, v2: f32) {
+    with_context("glUniform3f", |gl| gl.set_uniform(location, UniformValue::Float(Box::new([v0, v1, v2]))))
+}
+
+extern "system" fn gl_uniform_4f(location: i32, v0: f32, v1: f32, v2: f32, v3: f32) {
+    with_context("glUniform4f", |gl| gl.set_uniform(location, UniformValue::Float(Box::new([v0, v1, v2, v3]))))
+}
+
+extern "system" fn gl_uniform_1i(location: i32, v0: i32) {
+    with_context("glUniform1i", |gl| gl.set_uniform(location, UniformValue::Int(Box::new([v0]))))
+}
+
+extern "system" fn gl_uniform_2i(location: i32, v0: i32, v1: i32) {
+    with_context("glUniform2i", |gl| gl.set_uniform(location, UniformValue::Int(Box::new([v0, v1]))))
+}
+
+extern "system" fn gl_uniform_3i(location: i32, v0: i32, v1: i32, v2: i32) {
+    with_context("glUniform3i", |gl| gl.set_uniform(location, UniformValue::Int(Box::new([v0, v1, v2]))))
+}
+
+extern "system" fn gl_uniform_4i(location: i32, v0: i32, v1: i32, v2: i32, v3: i32) {
+    with_context("glUniform4i", |gl| gl.set_uniform(location, UniformValue::Int(Box::new([v0, v1, v2, v3]))))
+}
+
+extern "system" fn gl_uniform_1ui(location: i32, v0: u32) {
+    with_context("glUniform1ui", |gl| gl.set_uniform(location, UniformValue::UInt(Box::new([v0]))))
+}
+
+extern "system" fn gl_uniform_2ui(location: i32, v0: u32, v1: u32) {
+    with_context("glUniform2ui", |gl| gl.set_uniform(location, UniformValue::UInt(Box::new([v0, v1]))))
+}
+
+extern "system" fn gl_uniform_3ui(location: i32, v0: u32, v1: u32, v2: u32) {
+    with_context("glUniform3ui", |gl| gl.set_uniform(location, UniformValue::UInt(Box::new([v0, v1, v2]))))
+}
+
+extern "system" fn gl_uniform_4ui(location: i32, v0: u32, v1: u32, v2: u32, v3: u32) {
+    with_context("glUniform4ui", |gl| gl.set_uniform(location, UniformValue::UInt(Box::new([v0, v1, v2, v3]))))
+}
+
+extern "system" fn gl_uniform_matrix_4fv(location: i32, count: i32, transpose: u8, value: *const f32) {
+    with_context("glUniformMatrix4fv", |gl| {
+        match count {
+            ..0 => return gl.set_error(GL_INVALID_VALUE.0),
+            0 => return,
+            _ => ()
+        }
+
+        // Uniform arrays aren't emulated, every uniform is a single value. GL fails the same way for count > 1 on those
+        if count != 1 {
+            return gl.set_error(GL_INVALID_OPERATION.0);
+        }
+
+        let values = unsafe { std::slice::from_raw_parts(value, 16) };
+        let mut columns = [[0.0; 4]; 4];
+        for (i, v) in values.iter().enumerate() {
+            columns[i / 4][i % 4] = *v;
+        }
+
+        let mut matrix = Mat4::from_array(columns);
+        if transpose != 0 {
+            matrix = gl_types::functions::matrix::transpose(matrix);
+        }
+
+        gl.set_uniform(location, UniformValue::Mat4(matrix))
+    })
+}
+
+extern "system" fn gl_draw_arrays(mode: u32, first: i32, count: i32) {
+    with_context("glDrawArrays", |gl| gl.draw(mode, first, count, 1, None))
+}
+
+extern "system" fn gl_draw_arrays_instanced(mode: u32, first: i32, count: i32, instance_count: i32) {
+    with_context("glDrawArraysInstanced", |gl| gl.draw(mode, first, count, instance_count, None))
+}
+
+extern "system" fn gl_draw_elements(mode: u32, count: i32, type_: u32, indices: *const c_void) {
+    with_context("glDrawElements", |gl| gl.draw(mode, 0, count, 1, Some((type_, indices))))
+}
+
+extern "system" fn gl_draw_elements_instanced(mode: u32, count: i32, type_: u32, indices: *const c_void, instance_count: i32) {
+    with_context("glDrawElementsInstanced", |gl| gl.draw(mode, 0, count, instance_count, Some((type_, indices))))
+}
+
+extern "system" fn gl_enable(cap: u32) {
+    with_context("glEnable", |gl| { gl.capabilities.insert(cap); })
+}
+
+extern "system" fn gl_disable(cap: u32) {
+    with_context("glDisable", |gl| { gl.capabilities.remove(&cap); })
+}
+
+extern "system" fn gl_is_enabled(cap: u32) -> u8 {
+    with_context("glIsEnabled", |gl| gl.capabilities.contains(&cap) as u8)
+}
+
+extern "system" fn gl_clear(_mask: u32) {
+    with_context("glClear", |_| ())
+}
+
+extern "system" fn gl_clear_color(red: f32, green: f32, blue: f32, alpha: f32) {
+    with_context("glClearColor", |gl| gl.clear_color = [red, green, blue, alpha])
+}
+
+extern "system" fn gl_clear_depth(depth: f64) {
+    with_context("glClearDepth", |gl| gl.clear_depth = depth)
+}
+
+extern "system" fn gl_viewport(x: i32, y: i32, width: i32, height: i32) {
+    with_context("glViewport", |gl| gl.viewport = [x, y, width, height])
+}
+
+impl std::fmt::Debug for SoftwareContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SoftwareContext").field("calls", &self.calls.len()).field("draw_calls", &self.draw_calls.len()).finish()
+    }
+}
+
+pub type SoftwareContextRef<'a> = Ref<'a, SoftwareContext>;
+
+#[cfg(test)]
+mod tests {
+    use crate::engine::graphics::{Graphics, builder::TextureBuilder, gl_enums::{BufferTargetARB, BufferUsageARB, InternalFormat, PixelFormat, TextureTarget}};
+
+    #[test]
+    fn software_buffers_and_textures() {
+        let gfx = Graphics::init_headless().unwrap();
+
+        let mut buffer = 0;
+        gfx.glGenBuffer(&mut buffer);
+        gfx.glBindBuffer(BufferTargetARB::GL_ARRAY_BUFFER, buffer);
+        gfx.glBufferNull(BufferTargetARB::GL_ARRAY_BUFFER, 8, BufferUsageARB::GL_STATIC_DRAW);
+        gfx.glBufferSubData(BufferTargetARB::GL_ARRAY_BUFFER, 4, &[0xAABBCCDDu32]);
+
+        // 3x2 RGB texture with 1 byte unpack alignment, set by Graphics
+        let pixels: Vec<u8> = (0..18).collect();
+        let texture = unsafe { TextureBuilder::from_raw_pixels_unchecked(&pixels, 3, 2, InternalFormat::GL_RGB, PixelFormat::GL_RGB) }.finish(&gfx);
+        unsafe { texture.update_texture(&gfx, &[255; 18], PixelFormat::GL_RGB) };
+
+        let gl = gfx.software_context().unwrap();
+
+        assert_eq!(gl.buffer(buffer).unwrap().data, [0, 0, 0, 0, 0xDD, 0xCC, 0xBB, 0xAA]);
+        assert_eq!(gl.bound_buffer(BufferTargetARB::GL_ARRAY_BUFFER), buffer);
+
+        let software_texture = gl.texture(texture.texture_id()).unwrap();
+        assert_eq!(software_texture.target, TextureTarget::GL_TEXTURE_2D as u32);
+        assert_eq!((software_texture.width, software_texture.height), (3, 2));
+        assert_eq!(software_texture.data, [255; 18]);
+
+        assert!(gl.calls().contains(&"glTextureSubImage2D"));
+
+        drop(gl);
+        texture.delete(&gfx);
+    }
+
+    #[test]
+    fn separate_contexts() {
+        let outer = Graphics::init_headless().unwrap();
+        let outer_calls = outer.software_context().unwrap().calls().len();
+
+        {
+            let inner = Graphics::init_headless().unwrap();
+            let inner_calls = inner.software_context().unwrap().calls().len();
+
+            // Each wrapper reaches its own context, no matter which one was created last
+            let mut buffer = 0;
+            outer.glGenBuffer(&mut buffer);
+            outer.glBindBuffer(BufferTargetARB::GL_ARRAY_BUFFER, buffer);
+            outer.glBufferNull(BufferTargetARB::GL_ARRAY_BUFFER, 8, BufferUsageARB::GL_STATIC_DRAW);
+            assert_eq!(outer.software_context().unwrap().buffer(buffer).unwrap().data.len(), 8);
+            assert_eq!(outer.software_context().unwrap().calls().len(), outer_calls + 3);
+            assert_eq!(inner.software_context().unwrap().calls().len(), inner_calls);
+            assert_eq!(inner.software_context().unwrap().bound_buffer(BufferTargetARB::GL_ARRAY_BUFFER), 0);
+
+            let mut other = 0;
+            inner.glGenBuffer(&mut other);
+            assert_eq!(inner.software_context().unwrap().calls().len(), inner_calls + 1);
+        }
+
+        let mut buffer = 0;
+        outer.glGenBuffer(&mut buffer);
+        assert_eq!(outer.software_context().unwrap().calls().len(), outer_calls + 4);
+    }
+
+    #[test]
+    fn unemulated_calls() {
+        let gl = Graphics::init_headless().unwrap();
+
+        // Functions without an emulation share one stub, which still records what was called
+        gl.glFinish();
+        gl.glFlush();
+        let context = gl.software_context().unwrap();
+        assert_eq!(&context.calls()[context.calls().len() - 2..], &["glFinish", "glFlush"]);
+    }
+}
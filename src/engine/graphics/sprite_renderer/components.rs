@@ -1,27 +1,28 @@
-use std::{collections::VecDeque, i32, path::Path};
+use std::{collections::HashSet, path::Path};
 
-use gl_types::{vec2, vec3};
+use gl_types::{vec2, vectors::Vec2};
 
-use crate::engine::{Engine, errors::Result, game_object::{ObjectID, component::Component}, graphics::{image::Image, sprite_renderer::SpriteSheetID}};
+use crate::engine::{Engine, errors::Result, game_object::{Changed, ComponentID, FilterQuery, ObjectID, SceneComponent, SceneFields, World, component::{Component, components::Transform}}, graphics::{image::Image, sprite_renderer::SpriteSheetID}};
 
 use super::SpriteData;
 
 pub struct SpriteSheet {
     id: Option<SpriteSheetID>,
-    filename: Option<String>,
-    sprite_definitions: VecDeque<(u32, u32, u32, u32)>,
-    count: usize
+    filename: String,
+    // Every sprite ever added, the ones past `uploaded` still have to be sent to the renderer
+    sprite_definitions: Vec<(u32, u32, u32, u32)>,
+    uploaded: usize
 }
 
 impl SpriteSheet {
     pub fn new(file_name: &str) -> SpriteSheet {
-        SpriteSheet { id: None, filename: Some(file_name.to_owned()), sprite_definitions: VecDeque::new(), count: 0 }
+        SpriteSheet { id: None, filename: file_name.to_owned(), sprite_definitions: Vec::new(), uploaded: 0 }
     }
 
     pub fn add_sprite(&mut self, x: u32, y: u32, width: u32, height: u32) -> u32 {
-        let idx = self.sprite_definitions.len() + self.count;
+        let idx = self.sprite_definitions.len();
 
-        self.sprite_definitions.push_back((x, y, width, height));
+        self.sprite_definitions.push((x, y, width, height));
 
         idx as u32
     }
@@ -36,27 +37,25 @@ impl SpriteSheet {
 impl Component for SpriteSheet {
     // TODO: Is defferring initialization until the next frame actaully better than initializing in the constructor??
     fn init(&mut self, engine: &mut Engine, _owner: ObjectID) -> Result<()> {
-        let path = Path::new(self.filename.as_ref().unwrap());
+        let path = Path::new(&self.filename);
         let sprite_map = Image::load_from_file(path)?;
         let name: Option<_> = (|| Some(path.file_name()?.to_str()?))();
 
         // If add_sprite_sheet returns None it should panic, so rewrap the unwrapped result.
         self.id = Some(engine.sprite_renderer.add_sprite_sheet(name.ok_or("None value")?, &engine.gfx, 1024, sprite_map).ok_or("None value")?);
-        self.filename = None;
 
         Ok(())
     }
 
     fn fixed_update(&mut self, engine: &mut Engine, _owner: ObjectID, _delta_time: f32) -> Result<()> {
-        let mut update = false;
-        while !self.sprite_definitions.is_empty() {
-            let (x, y, width, height) = self.sprite_definitions.pop_front().unwrap();
+        let pending = &self.sprite_definitions[self.uploaded..];
+
+        for &(x, y, width, height) in pending {
             engine.sprite_renderer.add_sprite(self.id.unwrap(), x, y, width, height);
-            self.count += 1;
-            update = true;
         }
 
-        if update {
+        if !pending.is_empty() {
+            self.uploaded = self.sprite_definitions.len();
             engine.sprite_renderer.update_sprite_map(&engine.gfx, self.id.unwrap());
         }
 
@@ -74,51 +73,146 @@ impl Component for SpriteSheet {
     }
 }
 
-enum SpriteSheetEnum {
-    ID(SpriteSheetID),
-    Name(String)
+impl SceneComponent for SpriteSheet {
+    const NAME: &'static str = "SpriteSheet";
+
+    fn save(&self, fields: &mut Vec<String>) -> Result<()> {
+        fields.push(self.filename.clone());
+        fields.extend(self.sprite_definitions.iter().map(|(x, y, width, height)| format!("{x},{y},{width},{height}")));
+
+        Ok(())
+    }
+
+    fn load(fields: &mut SceneFields) -> std::result::Result<Self, String> {
+        let mut sheet = SpriteSheet::new(fields.next_str()?);
+
+        while !fields.is_empty() {
+            let sprite = fields.next_str()?;
+            let values: Vec<u32> = sprite.split(',').map(|value| value.parse().map_err(|_| format!("Invalid sprite \"{sprite}\""))).collect::<std::result::Result<_, _>>()?;
+            let [x, y, width, height] = values[..] else { Err(format!("Invalid sprite \"{sprite}\""))? };
+
+            sheet.add_sprite(x, y, width, height);
+        }
+
+        Ok(sheet)
+    }
 }
 
+// Rendered at the owner's world position, always facing the camera
+#[derive(Clone)]
 pub struct Sprite {
-    sprite_sheet_id: SpriteSheetEnum,
-    pub data: SpriteData
+    sprite_sheet: String,
+    sprite_sheet_id: Option<SpriteSheetID>,
+    pub anchor: Vec2,
+    pub dimensions: Vec2,
+    pub sprite_index: u32
 }
 
 impl Sprite {
     pub fn new(sprite_sheet_name: &str, sprite_index: u32) -> Sprite {
         Sprite {
-            sprite_sheet_id: SpriteSheetEnum::Name(sprite_sheet_name.to_owned()),
-            data: SpriteData { 
-                position: vec3!(0),
-                anchor: vec2!(0),
-                dimensions: vec2!(1),
-                sprite_id: sprite_index
-            }
+            sprite_sheet: sprite_sheet_name.to_owned(),
+            sprite_sheet_id: None,
+            anchor: vec2!(0),
+            dimensions: vec2!(1),
+            sprite_index
         }
     }
 }
 
 impl Component for Sprite {
-    fn init(&mut self, engine: &mut Engine, _owner: ObjectID) -> Result<()> {
-        self.sprite_sheet_id = SpriteSheetEnum::ID(match &self.sprite_sheet_id {
-            SpriteSheetEnum::ID(_) => panic!("no"),
-            SpriteSheetEnum::Name(name) => engine.sprite_renderer.get_sprite_sheet_by_name(name).ok_or(format!("Sprite sheet \"{}\" not found.", name))?,
-        });
+    fn init(&mut self, engine: &mut Engine, owner: ObjectID) -> Result<()> {
+        let name = &self.sprite_sheet;
+        self.sprite_sheet_id = Some(engine.sprite_renderer.get_sprite_sheet_by_name(name).ok_or(format!("Sprite sheet \"{}\" not found.", name))?);
 
-        Ok(())
+        requeue(&engine.world, owner)
     }
 
-    fn update(&mut self, engine: &mut Engine, _owner: ObjectID, _delta_time: f32) -> Result<()> {
-        let SpriteSheetEnum::ID(sprite_sheet) = self.sprite_sheet_id else { return Ok(()); };
-        engine.sprite_renderer.queue_sprite_instance(
-            self.data,
-            sprite_sheet
-        );
+    // Inactive sprites were dropped by the renderer
+    fn on_enable(&mut self, engine: &mut Engine, owner: ObjectID) -> Result<()> {
+        requeue(&engine.world, owner)
+    }
+}
+
+// Running sprites are outside their storage and don't know their own id, marking every sprite on the owner does no harm
+fn requeue(world: &World, owner: ObjectID) -> Result<()> {
+    for sprite in world.get_components::<Sprite>(owner)?.iter() {
+        world.set_changed(*sprite);
+    }
+
+    Ok(())
+}
+
+impl SceneComponent for Sprite {
+    const NAME: &'static str = "Sprite";
+
+    fn save(&self, fields: &mut Vec<String>) -> Result<()> {
+        fields.push(self.sprite_sheet.clone());
+        fields.push(self.sprite_index.to_string());
+        fields.extend([self.anchor.x(), self.anchor.y(), self.dimensions.x(), self.dimensions.y()].map(|value| format!("{:?}", value)));
 
         Ok(())
     }
 
-    fn priority(&self) -> &'static i32 {
-        &i32::MAX
+    fn load(fields: &mut SceneFields) -> std::result::Result<Self, String> {
+        let mut sprite = Sprite::new(fields.next_str()?, fields.next()?);
+        sprite.anchor = vec2!(fields.next::<f32>()?, fields.next::<f32>()?);
+        sprite.dimensions = vec2!(fields.next::<f32>()?, fields.next::<f32>()?);
+
+        Ok(sprite)
+    }
+}
+
+// Runs in PreRender, after everything had a chance to move. Instances stay with the renderer between frames,
+// so only sprites that were added, changed or moved since the last run are sent again
+pub(in crate::engine) fn queue_sprites(engine: &mut Engine, _delta_time: f32) -> Result<()> {
+    let world = &engine.world;
+    let renderer = &mut engine.sprite_renderer;
+    let since = renderer.queued_at;
+
+    // Removed and inactive sprites stop being drawn right away
+    let stale: Vec<ComponentID> = renderer.sprite_instances().filter(|sprite| !world.is_component_active(*sprite) || world.borrow_component::<Sprite>(*sprite).is_err()).collect();
+    for sprite in stale {
+        renderer.remove_sprite_instance(sprite);
     }
-}
\ No newline at end of file
+
+    // Moving an object moves everything below it, without changing their own transforms
+    let mut moved: Vec<ObjectID> = world.query::<Transform>()?.filter::<Changed<Transform>>(since).iter().map(|(owner, _)| owner).collect();
+    let mut owners = HashSet::new();
+
+    while let Some(object) = moved.pop() {
+        if owners.insert(object) {
+            moved.extend(world.get_children(object)?);
+        }
+    }
+
+    owners.extend(world.query::<Sprite>()?.filter::<Changed<Sprite>>(since).iter().map(|(owner, _)| owner));
+
+    for owner in owners {
+        for component in world.get_components::<Sprite>(owner)?.iter().copied() {
+            if !world.is_component_active(component) {
+                continue;
+            }
+
+            let sprite = world.borrow_component::<Sprite>(component)?;
+
+            // Sprites that haven't been initialized don't know their sprite sheet yet, init sends them again
+            let Some(sprite_sheet) = sprite.sprite_sheet_id else { continue; };
+
+            // Billboards ignore rotation, the camera decides which way they face
+            let scale = world.world_scale(owner)?;
+            let data = SpriteData {
+                position: world.world_position(owner)?,
+                anchor: sprite.anchor,
+                dimensions: vec2!(sprite.dimensions.x() * scale.x(), sprite.dimensions.y() * scale.y()),
+                sprite_id: sprite.sprite_index
+            };
+
+            renderer.set_sprite_instance(component, data, sprite_sheet);
+        }
+    }
+
+    renderer.queued_at = world.change_tick();
+
+    Ok(())
+}
@@ -11,6 +11,7 @@ use crate::engine::graphics::image::Image;
 use crate::engine::graphics::{BufferedMesh, FragmentShader, GlUniformLocation, Graphics, Mesh, ShaderProgram, ShaderProgramBuilder, Texture, UV, VBOBufferer, Vertex, VertexShader};
 
 use crate::engine::errors::Result;
+use crate::engine::game_object::{ComponentID, Tick};
 
 const SSBO_OFFSET: isize = 16;
 
@@ -49,9 +50,27 @@ pub struct SpriteData {
     pub sprite_id: u32
 }
 
+impl From<SpriteData> for GLSpriteStruct {
+    fn from(sprite: SpriteData) -> Self {
+        let SpriteData { position, dimensions, anchor, sprite_id } = sprite;
+
+        GLSpriteStruct {
+            position: AlignedVec3(position),
+            dimensions: vec4!(anchor, dimensions),
+            id: sprite_id
+        }
+    }
+}
+
 struct SpriteSheet {
     name: String,
+    // Kept between frames, in the order they are uploaded. `instance_keys` lines up with `instances`
+    instances: Vec<GLSpriteStruct>,
+    instance_keys: Vec<ComponentID>,
+    // Only drawn by the next render, after the instances
     render_queue: Vec<GLSpriteStruct>,
+    // The buffer doesn't match the instances and queue anymore
+    dirty: bool,
     buffersize: usize,
     sprite_ssbo: u32,
     spritesheet_ssbo: u32,
@@ -61,7 +80,12 @@ struct SpriteSheet {
 
 impl SpriteSheet {
     fn buffer_sprite_data(&mut self, gfx: &Graphics) {
-        let data_size = self.render_queue.len() * std::mem::size_of::<GLSpriteStruct>() + SSBO_OFFSET as usize;
+        if !self.dirty {
+            return;
+        }
+
+        let len = self.instances.len() + self.render_queue.len();
+        let data_size = len * std::mem::size_of::<GLSpriteStruct>() + SSBO_OFFSET as usize;
         gfx.glBindBuffer(BufferTargetARB::GL_SHADER_STORAGE_BUFFER, self.sprite_ssbo);
 
         if data_size > self.buffersize {
@@ -72,10 +96,15 @@ impl SpriteSheet {
         }
 
         // Buffer length data
-        gfx.glBufferSubData(BufferTargetARB::GL_SHADER_STORAGE_BUFFER, 0, &[self.render_queue.len()]); 
+        gfx.glBufferSubData(BufferTargetARB::GL_SHADER_STORAGE_BUFFER, 0, &[len]); 
         // Buffer sprite data
-        gfx.glBufferSubData(BufferTargetARB::GL_SHADER_STORAGE_BUFFER, SSBO_OFFSET, &self.render_queue[..]); 
+        gfx.glBufferSubData(BufferTargetARB::GL_SHADER_STORAGE_BUFFER, SSBO_OFFSET, &self.instances[..]); 
+        let queue_offset = SSBO_OFFSET + (self.instances.len() * std::mem::size_of::<GLSpriteStruct>()) as isize;
+        gfx.glBufferSubData(BufferTargetARB::GL_SHADER_STORAGE_BUFFER, queue_offset, &self.render_queue[..]); 
         gfx.glBindBuffer(BufferTargetARB::GL_SHADER_STORAGE_BUFFER, 0);
+
+        // Queued sprites have to be dropped from the buffer again next frame
+        self.dirty = !self.render_queue.is_empty();
     }
 }
 
@@ -87,6 +116,10 @@ pub struct SpriteRenderer {
     mesh: BufferedMesh,
     sprite_sheets: VecAllocator<SpriteSheet>,
     sprite_sheet_index: HashMap<String, AllocationIndex>,
+    // Which sheet and slot each instance is in
+    instance_slots: HashMap<ComponentID, (AllocationIndex, usize)>,
+    // Change tick of the last `queue_sprites`, sprites changed after it have to be sent again
+    pub(in crate::engine::graphics::sprite_renderer) queued_at: Tick,
     view_location: GlUniformLocation,
     projection_location: GlUniformLocation,
     texel_offset_location: GlUniformLocation
@@ -142,7 +175,7 @@ impl SpriteRenderer {
 
         let mesh = mesh.take();
 
-        Ok(SpriteRenderer { program, mesh, sprite_sheets: VecAllocator::new(), sprite_sheet_index: HashMap::new(), view_location, projection_location, texel_offset_location })
+        Ok(SpriteRenderer { program, mesh, sprite_sheets: VecAllocator::new(), sprite_sheet_index: HashMap::new(), instance_slots: HashMap::new(), queued_at: Tick::default(), view_location, projection_location, texel_offset_location })
     }
 
     pub fn add_sprite_sheet(&mut self, name: &str, gfx: &Graphics, initial_buffer_size: usize, sprite_sheet: Image) -> Option<SpriteSheetID> {
@@ -163,7 +196,10 @@ impl SpriteRenderer {
 
         let sprite_sheet = SpriteSheet {
             name: name.to_owned(),
+            instances: Vec::new(),
+            instance_keys: Vec::new(),
             render_queue: Vec::new(),
+            dirty: true,
             buffersize: initial_buffer_size,
             sprite_ssbo,
             spritesheet_ssbo,
@@ -181,6 +217,7 @@ impl SpriteRenderer {
         let Ok(old) = self.sprite_sheets.remove(sprite_sheet.0) else { return };
 
         self.sprite_sheet_index.remove(&old.name);
+        self.instance_slots.retain(|_, (sheet, _)| *sheet != sprite_sheet.0);
         old.sprite_sheet.delete(gfx);
         gfx.glDeleteBuffers(&[old.sprite_ssbo, old.spritesheet_ssbo]);
     }
@@ -216,21 +253,50 @@ impl SpriteRenderer {
         gfx.glBindBuffer(BufferTargetARB::GL_SHADER_STORAGE_BUFFER, 0);
     }
 
+    /// Draws the sprite in the next render only.
     pub fn queue_sprite_instance(&mut self, sprite: SpriteData, sprite_sheet: SpriteSheetID) {
         let Ok(sheet) = self.sprite_sheets.get_mut(sprite_sheet.0) else { return; };
 
-        let SpriteData { position, dimensions, anchor, sprite_id } = sprite;
-        let dimensions = vec4!(anchor, dimensions);
-        let position = AlignedVec3(position);
-        let id = sprite_id;
-
-        let sprite_data = GLSpriteStruct {
-            position,
-            dimensions,
-            id
-        };
+        sheet.render_queue.push(sprite.into());
+        sheet.dirty = true;
+    }
+
+    /// Draws the sprite every render until it's removed, replacing whatever `key` was set to before.
+    pub fn set_sprite_instance(&mut self, key: ComponentID, sprite: SpriteData, sprite_sheet: SpriteSheetID) {
+        if self.instance_slots.get(&key).is_some_and(|(sheet, _)| *sheet != sprite_sheet.0) {
+            self.remove_sprite_instance(key);
+        }
+
+        let Ok(sheet) = self.sprite_sheets.get_mut(sprite_sheet.0) else { return; };
+
+        match self.instance_slots.get(&key) {
+            Some(&(_, slot)) => sheet.instances[slot] = sprite.into(),
+            None => {
+                self.instance_slots.insert(key, (sprite_sheet.0, sheet.instances.len()));
+                sheet.instances.push(sprite.into());
+                sheet.instance_keys.push(key);
+            }
+        }
+
+        sheet.dirty = true;
+    }
+
+    pub fn remove_sprite_instance(&mut self, key: ComponentID) {
+        let Some((sheet_index, slot)) = self.instance_slots.remove(&key) else { return; };
+        let Ok(sheet) = self.sprite_sheets.get_mut(sheet_index) else { return; };
+
+        sheet.instances.swap_remove(slot);
+        sheet.instance_keys.swap_remove(slot);
+        sheet.dirty = true;
 
-        sheet.render_queue.push(sprite_data);
+        // The last instance took its place
+        if let Some(&moved) = sheet.instance_keys.get(slot) {
+            self.instance_slots.insert(moved, (sheet_index, slot));
+        }
+    }
+
+    pub fn sprite_instances(&self) -> impl Iterator<Item = ComponentID> + '_ {
+        self.instance_slots.keys().copied()
     }
 
     pub fn render(&mut self, gfx: &Graphics, view_matrix: &Mat4, projection_matrix: &Mat4) {
@@ -247,7 +313,7 @@ impl SpriteRenderer {
             gfx.glUniformMatrix4f(self.projection_location, false, &projection_matrix);
             gfx.glUniform2f(self.texel_offset_location, texel_offset.x(), texel_offset.y());
 
-            gfx.glDrawArraysInstanced(PrimitiveType::GL_TRIANGLES, 0, self.mesh.len() as _, sheet.render_queue.len() as u32);
+            gfx.glDrawArraysInstanced(PrimitiveType::GL_TRIANGLES, 0, self.mesh.len() as _, (sheet.instances.len() + sheet.render_queue.len()) as u32);
             sheet.render_queue.clear();
         }
     }
@@ -255,7 +321,7 @@ impl SpriteRenderer {
 
 #[cfg(test)]
 mod tests {
-    use gl_types::{vec3, vec4};
+    use gl_types::{matrices::{Mat4, MatN}, vec2, vec3, vec4};
     use embed_shader_source::embed_shader_source;
     
     impl PartialEq for GLSpriteStruct {
@@ -264,7 +330,9 @@ mod tests {
         }
     }
 
-    use crate::engine::graphics::{FragmentShader, Graphics, ShaderProgramBuilder, VertexShader, gl_enums::{BufferTargetARB, BufferUsageARB, PrimitiveType}, sprite_renderer::sprite_renderer::{AlignedVec3, GLSpriteStruct, SSBO_OFFSET}};
+    use crate::engine::graphics::{FragmentShader, Graphics, ShaderProgramBuilder, UniformValue, VertexShader, gl_enums::{BufferTargetARB, BufferUsageARB, PrimitiveType}, image::Image, sprite_renderer::sprite_renderer::{AlignedVec3, GLSpriteStruct, SSBO_OFFSET, SpriteData}};
+
+    use crate::engine::{game_object::component::components::Transform, graphics::sprite_renderer::components::Sprite, Engine};
 
     use super::SpriteRenderer;
 
@@ -332,4 +400,91 @@ mod tests {
         drop(gfx);
         drop(lock);
     }
+
+    #[test]
+    pub fn sprite_renderer_headless() {
+        let gfx = Graphics::init_headless().unwrap();
+
+        let mut renderer = SpriteRenderer::new(&gfx).unwrap();
+        let sheet = renderer.add_sprite_sheet("sheet", &gfx, 0, Image::empty(4, 2)).unwrap();
+
+        renderer.queue_sprite_instance(SpriteData { position: vec3!(1, 2, 3), anchor: vec2!(4, 5), dimensions: vec2!(6, 7), sprite_id: 8 }, sheet);
+        renderer.queue_sprite_instance(SpriteData { position: vec3!(9, 10, 11), anchor: vec2!(12, 13), dimensions: vec2!(14, 15), sprite_id: 16 }, sheet);
+
+        let view = Mat4::from_array([[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [3.0, 4.0, 5.0, 1.0]]);
+        let projection = Mat4::from_array([[2.0, 0.0, 0.0, 0.0], [0.0, 2.0, 0.0, 0.0], [0.0, 0.0, 1.0, 0.0], [0.0, 0.0, 0.0, 1.0]]);
+        renderer.render(&gfx, &view, &projection);
+
+        let gl = gfx.software_context().unwrap();
+
+        let ssbo = gl.indexed_buffer(BufferTargetARB::GL_SHADER_STORAGE_BUFFER, 2);
+        let data = &gl.buffer(ssbo).unwrap().data;
+
+        let len = usize::from_ne_bytes(data[..size_of::<usize>()].try_into().unwrap());
+        assert_eq!(len, 2);
+
+        let mut sprites = [GLSpriteStruct::default(); 2];
+        let size = size_of::<GLSpriteStruct>() * sprites.len();
+        unsafe { std::ptr::copy_nonoverlapping(data[SSBO_OFFSET as usize..].as_ptr(), sprites.as_mut_ptr() as *mut u8, size) };
+
+        assert_eq!(sprites, [
+            GLSpriteStruct { position: AlignedVec3(vec3!(1, 2, 3)), dimensions: vec4!(4, 5, 6, 7), id: 8 },
+            GLSpriteStruct { position: AlignedVec3(vec3!(9, 10, 11)), dimensions: vec4!(12, 13, 14, 15), id: 16 },
+        ]);
+
+        let [draw] = gl.draw_calls() else { panic!("Expected a single draw call") };
+        assert_eq!(draw.mode, PrimitiveType::GL_TRIANGLES as u32);
+        assert_eq!(draw.count, 6);
+        assert_eq!(draw.instance_count, 2);
+        assert_eq!(draw.program, renderer.program.program());
+
+        let program = gl.program(draw.program).unwrap();
+        assert_eq!(program.uniform("view"), Some(&UniformValue::Mat4(view)));
+        assert_eq!(program.uniform("projection"), Some(&UniformValue::Mat4(projection)));
+        assert_eq!(program.uniform("texelOffset"), Some(&UniformValue::Float(Box::new([0.125, 0.25]))));
+
+        drop(gl);
+        renderer.remove_sprite_sheet(&gfx, sheet);
+    }
+
+    #[test]
+    pub fn queued_sprites() {
+        let mut engine = Engine::create_headless().unwrap();
+        let sheet = engine.sprite_renderer.add_sprite_sheet("sheet", &engine.gfx, 0, Image::empty(4, 2)).unwrap();
+        let instances = |engine: &Engine| engine.sprite_renderer.sprite_sheets.get(sheet.0).unwrap().instances.iter().map(|sprite| (sprite.position, sprite.id)).collect::<Vec<_>>();
+
+        let parent = engine.world.create_game_object("Parent", engine.world.get_root()).unwrap();
+        let goblin = engine.world.create_game_object("Goblin", parent).unwrap();
+        engine.world.add_component(goblin, Sprite::new("sheet", 1)).unwrap();
+        let sprite = engine.world.get_component::<Sprite>(goblin).unwrap();
+        engine.step(0.0).unwrap();
+        assert_eq!(instances(&engine), [(AlignedVec3(vec3!(0.0, 0.0, 0.0)), 1)]);
+
+        // Untouched sprites aren't sent again
+        engine.sprite_renderer.sprite_sheets.get_mut(sheet.0).unwrap().instances[0].id = 99;
+        engine.step(0.0).unwrap();
+        assert_eq!(instances(&engine), [(AlignedVec3(vec3!(0.0, 0.0, 0.0)), 99)]);
+
+        // Moving the parent moves the sprite
+        let transform = engine.world.get_component::<Transform>(parent).unwrap();
+        engine.world.borrow_component_mut::<Transform>(transform).unwrap().set_position(vec3!(2.0, 0.0, 0.0));
+        engine.step(0.0).unwrap();
+        assert_eq!(instances(&engine), [(AlignedVec3(vec3!(2.0, 0.0, 0.0)), 1)]);
+
+        engine.world.borrow_component_mut::<Sprite>(sprite).unwrap().sprite_index = 3;
+        engine.step(0.0).unwrap();
+        assert_eq!(instances(&engine), [(AlignedVec3(vec3!(2.0, 0.0, 0.0)), 3)]);
+
+        engine.world.set_active(parent, false).unwrap();
+        engine.step(0.0).unwrap();
+        assert!(instances(&engine).is_empty());
+        engine.world.set_active(parent, true).unwrap();
+        engine.step(0.0).unwrap();
+        assert_eq!(instances(&engine), [(AlignedVec3(vec3!(2.0, 0.0, 0.0)), 3)]);
+
+        engine.world.destroy(goblin).unwrap();
+        engine.step(0.0).unwrap();
+        assert!(instances(&engine).is_empty());
+        assert_eq!(engine.sprite_renderer.sprite_instances().count(), 0);
+    }
 }
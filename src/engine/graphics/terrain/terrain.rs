@@ -1,6 +1,6 @@
 use image::{ImageBuffer, Luma, imageops};
 
-use crate::engine::{errors::{BasicError}, game_object::component::Component, graphics::{Graphics, Texture, builder::TextureBuilder, gl_enums::{InternalFormat, PixelFormat, TextureMagFilter, TextureMinFilter}}};
+use crate::engine::{errors::{BasicError}, game_object::{component::Component, SceneComponent, SceneFields}, graphics::{Graphics, Texture, builder::TextureBuilder, gl_enums::{InternalFormat, PixelFormat, TextureMagFilter, TextureMinFilter}}};
 
 pub enum Corner {
     TopLeft,
@@ -79,7 +79,10 @@ enum TerrainInner {
         height_texture: Texture,
         color_texture: Texture,
         height_dirty: bool,
-        color_dirty: bool
+        color_dirty: bool,
+        // Kept so the terrain can be saved, edits made at runtime aren't
+        height_file: String,
+        color_file: String
     },
     Uninitialized {
         height_file: String,
@@ -103,7 +106,7 @@ impl Terrain {
         Terrain(TerrainInner::Uninitialized { height_file: height_file.to_owned(), color_file: color_file.to_owned() })
     } 
 
-    unsafe fn from_raw_unchecked(gfx :&Graphics, height_data: Box<[u8]>, color_data: Box<[u8]>, width: u32, height: u32) -> Terrain {
+    unsafe fn from_raw_unchecked(gfx :&Graphics, height_data: Box<[u8]>, color_data: Box<[u8]>, width: u32, height: u32, height_file: String, color_file: String) -> Terrain {
         let height_texture = unsafe { TextureBuilder::from_raw_pixels_unchecked(&height_data, width + 1, height + 1, InternalFormat::GL_RED, PixelFormat::GL_RED) }
             .min_filter(TextureMinFilter::GL_NEAREST)
             .mag_filter(TextureMagFilter::GL_NEAREST)
@@ -113,10 +116,10 @@ impl Terrain {
             .mag_filter(TextureMagFilter::GL_NEAREST)
             .finish(gfx);
 
-        Terrain(TerrainInner::Initialized { height_data, color_data, width, height, height_texture, color_texture, height_dirty: false, color_dirty: false })
+        Terrain(TerrainInner::Initialized { height_data, color_data, width, height, height_texture, color_texture, height_dirty: false, color_dirty: false, height_file, color_file })
     }
 
-    fn from_raw(gfx :&Graphics, height_data: Box<[u8]>, color_data: Box<[u8]>, width: u32, height: u32) -> Terrain {
+    fn from_raw(gfx :&Graphics, height_data: Box<[u8]>, color_data: Box<[u8]>, width: u32, height: u32, height_file: String, color_file: String) -> Terrain {
         // Height data is per corner, rather than per cell, so each dimension needs one extra value to represent all corners
         if height_data.len() != ((width + 1) * (height + 1)) as usize {
             panic!("Height data size does not match given dimensions. ({})", height_data.len());
@@ -126,7 +129,7 @@ impl Terrain {
             panic!("Color data size does not match given dimensions.");
         }
 
-        unsafe { Self::from_raw_unchecked(gfx, height_data, color_data, width, height) }
+        unsafe { Self::from_raw_unchecked(gfx, height_data, color_data, width, height, height_file, color_file) }
     }
 
     pub fn get_raw_height(&self) -> Option<&[u8]> {
@@ -211,6 +214,13 @@ impl Terrain {
 
     }
 
+    /// The height and color maps the terrain is loaded from.
+    pub fn files(&self) -> (&str, &str) {
+        match &self.0 {
+            TerrainInner::Initialized { height_file, color_file, .. } | TerrainInner::Uninitialized { height_file, color_file } => (height_file, color_file)
+        }
+    }
+
     pub fn width(&self) -> Result<u32, BasicError> {
         let Self(TerrainInner::Initialized { width, .. }) = self else { return Err(BasicError::Uninitialized)? };
         Ok(*width)
@@ -221,6 +231,10 @@ impl Terrain {
         Ok(*height)
     }
 
+    fn is_dirty(&self) -> bool {
+        matches!(self.0, TerrainInner::Initialized { height_dirty: true, .. } | TerrainInner::Initialized { color_dirty: true, .. })
+    }
+
     pub(in crate::engine::graphics::terrain) fn update_textures(&mut self, gfx: &Graphics) -> Result<(), BasicError> {
         let Self(TerrainInner::Initialized { height_dirty, height_texture, height_data, color_data, color_dirty, color_texture, .. }) = self else { return Err(BasicError::Uninitialized)? };
 
@@ -244,11 +258,11 @@ impl Component for Terrain {
     fn init(&mut self, engine: &mut crate::engine::Engine, _owner: crate::engine::game_object::ObjectID) -> crate::engine::errors::Result<()> {
         let TerrainInner::Uninitialized { height_file, color_file  } = std::mem::take(&mut self.0) else { Err(BasicError::Uninitialized)? };
 
-        let grid = image::ImageReader::open(color_file)?.decode()?;
+        let grid = image::ImageReader::open(&color_file)?.decode()?;
         let mut grid = grid.to_rgb8();
         imageops::flip_vertical_in_place(&mut grid);
 
-        let height_map = image::ImageReader::open(height_file)?.decode()?;
+        let height_map = image::ImageReader::open(&height_file)?.decode()?;
         let height_map = height_map.to_rgb8();
         let (width, height) = height_map.dimensions();
         let height_map: Vec<u8> = height_map.into_raw().into_iter().step_by(3).collect();
@@ -258,21 +272,50 @@ impl Component for Terrain {
         // Height map uses offset pixel grid, so it ends up being +1 in each dimension.
         let (width, height) = (width - 1, height - 1);
 
-        *self = Self::from_raw(&engine.gfx, height_map.into_raw().into_boxed_slice(), grid.into_raw().into_boxed_slice(), width, height);
+        *self = Self::from_raw(&engine.gfx, height_map.into_raw().into_boxed_slice(), grid.into_raw().into_boxed_slice(), width, height, height_file, color_file);
         Ok(())
     }
 
-    fn update(&mut self, engine: &mut crate::engine::Engine, _owner: crate::engine::game_object::ObjectID, _delta_time: f32) -> crate::engine::errors::Result<()> {
-        self.update_textures(&engine.gfx)?;
+    fn on_remove(&mut self, _engine: &mut crate::engine::Engine, _owner: crate::engine::game_object::ObjectID) -> crate::engine::errors::Result<()> {
+        Err("Unimplemented")?
+    }
+}
+
+impl SceneComponent for Terrain {
+    const NAME: &'static str = "Terrain";
+
+    fn save(&self, fields: &mut Vec<String>) -> crate::engine::errors::Result<()> {
+        let (height_file, color_file) = self.files();
+        fields.extend([height_file.to_owned(), color_file.to_owned()]);
 
-        let TerrainInner::Initialized { width, height, height_texture, color_texture, .. } = &self.0 else { Err(BasicError::Uninitialized)? };
-        engine.terrain_renderer.queue_terrain(*width, *height, height_texture.texture_id(), color_texture.texture_id());
         Ok(())
     }
 
-    fn on_remove(&mut self, _engine: &mut crate::engine::Engine, _owner: crate::engine::game_object::ObjectID) -> crate::engine::errors::Result<()> {
-        Err("Unimplemented")?
+    fn load(fields: &mut SceneFields) -> std::result::Result<Self, String> {
+        Ok(Terrain::new(fields.next_str()?, fields.next_str()?))
+    }
+}
+
+// Runs in PreRender, uploading whatever was edited this frame. Only terrain that gets uploaded is borrowed mutably, so Changed<Terrain> stays meaningful
+pub(in crate::engine) fn queue_terrain(engine: &mut crate::engine::Engine, _delta_time: f32) -> crate::engine::errors::Result<()> {
+    let edited: Vec<_> = engine.world.query::<Terrain>()?.iter().filter(|(_, terrain)| terrain.is_dirty()).map(|(owner, _)| owner).collect();
+
+    for owner in edited {
+        for component in engine.world.get_components::<Terrain>(owner)?.iter().copied() {
+            let mut terrain = engine.world.borrow_component_mut::<Terrain>(component)?;
+
+            if terrain.is_dirty() {
+                terrain.update_textures(&engine.gfx)?;
+            }
+        }
     }
 
-    fn priority(&self) -> &'static i32 { &0 }
-}
\ No newline at end of file
+    for (owner, terrain) in &engine.world.query::<Terrain>()? {
+        // Terrain that hasn't been initialized yet has nothing to draw
+        let TerrainInner::Initialized { width, height, height_texture, color_texture, .. } = &terrain.0 else { continue; };
+        let model = engine.world.world_matrix(owner)?;
+        engine.terrain_renderer.queue_terrain(model, *width, *height, height_texture.texture_id(), color_texture.texture_id());
+    }
+
+    Ok(())
+}
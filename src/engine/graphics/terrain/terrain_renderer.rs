@@ -46,6 +46,7 @@ const TERRAIN_CELL_ELEMENTS: &[u32] = &[
 ];
 
 struct TerrainInfo {
+    model: Mat4,
     width: u32,
     height: u32,
     height_texture: u32,
@@ -57,6 +58,7 @@ pub struct TerrainRenderer {
     mesh: BufferedMesh,
     render_queue: Vec<TerrainInfo>,
     vp_location: GlUniformLocation,
+    model_location: GlUniformLocation,
     terrain_dimensions_location: GlUniformLocation,
     height_scale_location: GlUniformLocation,
     view_pos_location: GlUniformLocation,
@@ -83,6 +85,7 @@ impl TerrainRenderer {
         gfx.glUseProgram(shader_program.program());
 
         let vp_location = gfx.glGetUniformLocation(shader_program.program(), "vp");
+        let model_location = gfx.glGetUniformLocation(shader_program.program(), "model");
         let terrain_dimensions_location = gfx.glGetUniformLocation(shader_program.program(), "terrainDimensions");
         let height_scale_location = gfx.glGetUniformLocation(shader_program.program(), "heightScale");
         let view_pos_location = gfx.glGetUniformLocation(shader_program.program(), "viewPos");
@@ -107,11 +110,11 @@ impl TerrainRenderer {
             .wrap_t(TextureWrapMode::GL_REPEAT)
             .finish(gfx);
 
-        Ok(TerrainRenderer { shader_program, mesh, render_queue: Vec::new(), vp_location, terrain_dimensions_location, height_scale_location, view_pos_location, pixel_size_location, noise_map_size_location, noise_texture })
+        Ok(TerrainRenderer { shader_program, mesh, render_queue: Vec::new(), vp_location, model_location, terrain_dimensions_location, height_scale_location, view_pos_location, pixel_size_location, noise_map_size_location, noise_texture })
     }
 
-    pub fn queue_terrain(&mut self, width: u32, height: u32, height_texture: u32, color_texture: u32) {
-        self.render_queue.push(TerrainInfo { width, height, height_texture, color_texture });
+    pub fn queue_terrain(&mut self, model: Mat4, width: u32, height: u32, height_texture: u32, color_texture: u32) {
+        self.render_queue.push(TerrainInfo { model, width, height, height_texture, color_texture });
     }
 
     pub fn render(&mut self, gfx: &Graphics, view_matrix: Mat4, projection_matrix: Mat4, camera_pos: Vec3) {
@@ -131,6 +134,8 @@ impl TerrainRenderer {
             // uniform mat4 vp;
             let vp = projection_matrix * view_matrix;
             gfx.glUniformMatrix4f(self.vp_location, false, &vp);
+            // uniform mat4 model;
+            gfx.glUniformMatrix4f(self.model_location, false, &terrain.model);
             // uniform uvec2 terrainDimensions;
             gfx.glUniform2ui(self.terrain_dimensions_location, terrain.width, terrain.height);
             // uniform float heightScale;
@@ -0,0 +1,106 @@
+use glfw::{Action, GamepadAxis, GamepadButton, GamepadState, JoystickId};
+
+use super::KeyState;
+
+pub const GAMEPAD_BUTTON_COUNT: usize = 15;
+pub const GAMEPAD_AXIS_COUNT: usize = 6;
+pub const MAX_GAMEPADS: usize = 16;
+
+pub const GAMEPAD_BUTTONS: [GamepadButton; GAMEPAD_BUTTON_COUNT] = [
+    GamepadButton::ButtonA, GamepadButton::ButtonB, GamepadButton::ButtonX, GamepadButton::ButtonY,
+    GamepadButton::ButtonLeftBumper, GamepadButton::ButtonRightBumper, GamepadButton::ButtonBack, GamepadButton::ButtonStart,
+    GamepadButton::ButtonGuide, GamepadButton::ButtonLeftThumb, GamepadButton::ButtonRightThumb,
+    GamepadButton::ButtonDpadUp, GamepadButton::ButtonDpadRight, GamepadButton::ButtonDpadDown, GamepadButton::ButtonDpadLeft
+];
+
+pub const GAMEPAD_AXES: [GamepadAxis; GAMEPAD_AXIS_COUNT] = [
+    GamepadAxis::AxisLeftX, GamepadAxis::AxisLeftY, GamepadAxis::AxisRightX, GamepadAxis::AxisRightY,
+    GamepadAxis::AxisLeftTrigger, GamepadAxis::AxisRightTrigger
+];
+
+/// Raw state of a gamepad as polled from glfw. Can be built by hand to feed synthetic input.
+/// Axes are in glfw's range of -1 to 1, which for the triggers means they rest at -1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GamepadSnapshot {
+    pub buttons: [bool; GAMEPAD_BUTTON_COUNT],
+    pub axes: [f32; GAMEPAD_AXIS_COUNT]
+}
+
+impl Default for GamepadSnapshot {
+    fn default() -> Self {
+        let mut axes = [0.0; GAMEPAD_AXIS_COUNT];
+        axes[GamepadAxis::AxisLeftTrigger as usize] = -1.0;
+        axes[GamepadAxis::AxisRightTrigger as usize] = -1.0;
+
+        GamepadSnapshot { buttons: [false; GAMEPAD_BUTTON_COUNT], axes }
+    }
+}
+
+impl From<GamepadState> for GamepadSnapshot {
+    fn from(state: GamepadState) -> Self {
+        GamepadSnapshot {
+            buttons: GAMEPAD_BUTTONS.map(|button| state.get_button_state(button) != Action::Release),
+            axes: GAMEPAD_AXES.map(|axis| state.get_axis(axis))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GamepadEvent {
+    Connected(JoystickId),
+    Disconnected(JoystickId)
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(in crate::engine::input) struct Gamepad {
+    pub(in crate::engine::input) buttons: [KeyState; GAMEPAD_BUTTON_COUNT],
+    /// Triggers are moved to 0..1 so they rest at 0 like every other axis.
+    pub(in crate::engine::input) axes: [f32; GAMEPAD_AXIS_COUNT],
+    // As they were at the end of the previous frame
+    pub(in crate::engine::input) previous_axes: [f32; GAMEPAD_AXIS_COUNT],
+    // Disconnected gamepads stick around until the end of the frame, so their buttons can read as released
+    pub(in crate::engine::input) connected: bool
+}
+
+impl Gamepad {
+    pub(in crate::engine::input) fn new() -> Gamepad {
+        Gamepad { buttons: [KeyState::default(); GAMEPAD_BUTTON_COUNT], axes: [0.0; GAMEPAD_AXIS_COUNT], previous_axes: [0.0; GAMEPAD_AXIS_COUNT], connected: true }
+    }
+
+    // Press and release flags are only ever set here, clearing them is left to the end of the frame
+    pub(in crate::engine::input) fn apply(&mut self, snapshot: &GamepadSnapshot) {
+        for (state, down) in self.buttons.iter_mut().zip(snapshot.buttons) {
+            if down && !state.is_down {
+                state.press = true;
+            } else if !down && state.is_down {
+                state.release = true;
+            }
+
+            state.is_down = down;
+        }
+
+        self.axes = snapshot.axes;
+        for trigger in [GamepadAxis::AxisLeftTrigger, GamepadAxis::AxisRightTrigger] {
+            self.axes[trigger as usize] = (self.axes[trigger as usize] + 1.0) / 2.0;
+        }
+    }
+
+    pub(in crate::engine::input) fn disconnect(&mut self) {
+        for state in self.buttons.iter_mut() {
+            state.release = state.is_down;
+            state.is_down = false;
+        }
+
+        self.axes = [0.0; GAMEPAD_AXIS_COUNT];
+        self.connected = false;
+    }
+}
+
+/// Values inside the deadzone read as 0, the rest is rescaled so the output still covers the full range.
+pub fn apply_deadzone(value: f32, deadzone: f32) -> f32 {
+    if value.abs() <= deadzone {
+        0.0
+    } else {
+        value.signum() * (value.abs() - deadzone) / (1.0 - deadzone)
+    }
+}
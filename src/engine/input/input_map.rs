@@ -0,0 +1,448 @@
+use std::{collections::HashMap, fmt::Display, fs::File, io::{BufRead, BufReader, Write}, path::Path};
+
+use glfw::{GamepadAxis, GamepadButton, JoystickId, Key, MouseButton};
+
+use crate::engine::errors::{InputError, Result};
+
+use super::{Input, KeyState, GAMEPAD_AXES, GAMEPAD_AXIS_COUNT, GAMEPAD_BUTTONS, MAX_GAMEPADS};
+
+/// A single physical input that bindings are made of.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InputSource {
+    Key(Key),
+    MouseButton(MouseButton),
+    ScrollX,
+    ScrollY,
+    /// Reads from every connected gamepad.
+    GamepadButton(GamepadButton),
+    GamepadAxis(GamepadAxis)
+}
+
+/// One contribution to an axis. `Pair` reads as 1 when only the positive source is down and -1 for the negative one.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AxisBinding {
+    Source(InputSource),
+    Pair { positive: InputSource, negative: InputSource }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Binding {
+    Action(Vec<InputSource>),
+    Axis(Vec<AxisBinding>),
+    Axis2 { x: Vec<AxisBinding>, y: Vec<AxisBinding> }
+}
+
+/// Named actions and axes, shared by the frame and fixed tick `Input`.
+///
+/// Config files have one binding per line, sources are separated by `,` and axis pairs are written as `positive/negative`:
+/// ```text
+/// action jump = key:Space, mouse:Button1
+/// axis zoom = scroll:y
+/// axis2 move = key:D/key:A ; key:W/key:S
+/// deadzone AxisLeftX = 0.2
+/// ```
+#[derive(Debug, Clone)]
+pub struct InputMap {
+    bindings: HashMap<String, Binding>,
+    deadzones: [f32; GAMEPAD_AXIS_COUNT]
+}
+
+const DEFAULT_DEADZONES: [f32; GAMEPAD_AXIS_COUNT] = [0.15, 0.15, 0.15, 0.15, 0.0, 0.0];
+
+pub(in crate::engine::input) const KEYS: [Key; 120] = [Key::Space, Key::Apostrophe, Key::Comma, Key::Minus, Key::Period, Key::Slash, Key::Num0, Key::Num1, Key::Num2, Key::Num3, Key::Num4, Key::Num5, Key::Num6, Key::Num7, Key::Num8, Key::Num9, Key::Semicolon, Key::Equal, Key::A, Key::B, Key::C, Key::D, Key::E, Key::F, Key::G, Key::H, Key::I, Key::J, Key::K, Key::L, Key::M, Key::N, Key::O, Key::P, Key::Q, Key::R, Key::S, Key::T, Key::U, Key::V, Key::W, Key::X, Key::Y, Key::Z, Key::LeftBracket, Key::Backslash, Key::RightBracket, Key::GraveAccent, Key::World1, Key::World2, Key::Escape, Key::Enter, Key::Tab, Key::Backspace, Key::Insert, Key::Delete, Key::Right, Key::Left, Key::Down, Key::Up, Key::PageUp, Key::PageDown, Key::Home, Key::End, Key::CapsLock, Key::ScrollLock, Key::NumLock, Key::PrintScreen, Key::Pause, Key::F1, Key::F2, Key::F3, Key::F4, Key::F5, Key::F6, Key::F7, Key::F8, Key::F9, Key::F10, Key::F11, Key::F12, Key::F13, Key::F14, Key::F15, Key::F16, Key::F17, Key::F18, Key::F19, Key::F20, Key::F21, Key::F22, Key::F23, Key::F24, Key::F25, Key::Kp0, Key::Kp1, Key::Kp2, Key::Kp3, Key::Kp4, Key::Kp5, Key::Kp6, Key::Kp7, Key::Kp8, Key::Kp9, Key::KpDecimal, Key::KpDivide, Key::KpMultiply, Key::KpSubtract, Key::KpAdd, Key::KpEnter, Key::KpEqual, Key::LeftShift, Key::LeftControl, Key::LeftAlt, Key::LeftSuper, Key::RightShift, Key::RightControl, Key::RightAlt, Key::RightSuper, Key::Menu];
+
+impl InputSource {
+    pub fn state(&self, input: &Input) -> KeyState {
+        match self {
+            InputSource::Key(key) => input.get_key_state(*key),
+            InputSource::MouseButton(button) => input.get_mouse_button_state(*button as u32),
+            InputSource::GamepadButton(button) => input.connected_gamepads().into_iter().fold(KeyState::default(), |state, id| {
+                let pad = input.get_gamepad_button_state(id, *button);
+                KeyState { is_down: state.is_down || pad.is_down, press: state.press || pad.press, release: state.release || pad.release, repeat: false }
+            }),
+            // Scrolling and axes have no duration, they are down while they aren't 0
+            InputSource::ScrollX | InputSource::ScrollY | InputSource::GamepadAxis(_) => {
+                let is_down = self.value(input) != 0.0;
+                let was_down = self.previous_value(input) != 0.0;
+                KeyState { is_down, press: is_down && !was_down, release: was_down && !is_down, repeat: false }
+            }
+        }
+    }
+
+    pub fn value(&self, input: &Input) -> f32 {
+        match self {
+            InputSource::ScrollX => input.get_scroll_x() as f32,
+            InputSource::ScrollY => input.get_scroll_y() as f32,
+            // The strongest input wins if several gamepads are connected
+            InputSource::GamepadAxis(axis) => strongest(input.connected_gamepads().into_iter().map(|id| input.get_gamepad_axis(id, *axis))),
+            _ => if self.state(input).is_down { 1.0 } else { 0.0 }
+        }
+    }
+
+    // Value at the end of the previous frame, only used for sources without press and release events
+    fn previous_value(&self, input: &Input) -> f32 {
+        match self {
+            InputSource::ScrollX => input.previous_scroll().0 as f32,
+            InputSource::ScrollY => input.previous_scroll().1 as f32,
+            InputSource::GamepadAxis(axis) => strongest((0..MAX_GAMEPADS as i32).filter_map(JoystickId::from_i32).map(|id| input.previous_gamepad_axis(id, *axis))),
+            _ => 0.0
+        }
+    }
+
+    fn parse(src: &str) -> std::result::Result<InputSource, String> {
+        let (kind, name) = src.trim().split_once(':').ok_or_else(|| format!("Expected kind:name, got \"{}\"", src.trim()))?;
+
+        match kind {
+            "key" => KEYS.iter().find(|key| format!("{:?}", key) == name).map(|key| InputSource::Key(*key)).ok_or_else(|| format!("Unknown key \"{name}\"")),
+            "mouse" => {
+                let button = match name {
+                    "Left" => Some(MouseButton::Button1),
+                    "Right" => Some(MouseButton::Button2),
+                    "Middle" => Some(MouseButton::Button3),
+                    _ => name.strip_prefix("Button").and_then(|n| n.parse::<i32>().ok()).and_then(|n| MouseButton::from_i32(n - 1))
+                };
+
+                button.map(InputSource::MouseButton).ok_or_else(|| format!("Unknown mouse button \"{name}\""))
+            },
+            "pad" => GAMEPAD_BUTTONS.iter().find(|button| format!("{:?}", button) == name).map(|button| InputSource::GamepadButton(*button)).ok_or_else(|| format!("Unknown gamepad button \"{name}\"")),
+            "padaxis" => parse_gamepad_axis(name).map(InputSource::GamepadAxis),
+            "scroll" => match name {
+                "x" => Ok(InputSource::ScrollX),
+                "y" => Ok(InputSource::ScrollY),
+                _ => Err(format!("Unknown scroll axis \"{name}\""))
+            },
+            _ => Err(format!("Unknown input kind \"{kind}\""))
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Key(key) => write!(f, "key:{:?}", key),
+            InputSource::MouseButton(button) => write!(f, "mouse:{:?}", button),
+            InputSource::ScrollX => write!(f, "scroll:x"),
+            InputSource::ScrollY => write!(f, "scroll:y"),
+            InputSource::GamepadButton(button) => write!(f, "pad:{:?}", button),
+            InputSource::GamepadAxis(axis) => write!(f, "padaxis:{:?}", axis)
+        }
+    }
+}
+
+impl AxisBinding {
+    pub fn value(&self, input: &Input) -> f32 {
+        match self {
+            AxisBinding::Source(source) => source.value(input),
+            AxisBinding::Pair { positive, negative } => positive.value(input) - negative.value(input)
+        }
+    }
+
+    fn parse(src: &str) -> std::result::Result<AxisBinding, String> {
+        match src.split_once('/') {
+            Some((positive, negative)) => Ok(AxisBinding::Pair { positive: InputSource::parse(positive)?, negative: InputSource::parse(negative)? }),
+            None => Ok(AxisBinding::Source(InputSource::parse(src)?))
+        }
+    }
+}
+
+impl Display for AxisBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AxisBinding::Source(source) => write!(f, "{source}"),
+            AxisBinding::Pair { positive, negative } => write!(f, "{positive}/{negative}")
+        }
+    }
+}
+
+// Bindings add up, but held keys and sticks together still can't go past a full deflection
+fn sum(bindings: &[AxisBinding], input: &Input) -> f32 {
+    bindings.iter().map(|binding| binding.value(input)).sum::<f32>().clamp(-1.0, 1.0)
+}
+
+fn strongest<I: Iterator<Item = f32>>(values: I) -> f32 {
+    values.fold(0.0, |a, b| if b.abs() > a.abs() { b } else { a })
+}
+
+fn parse_gamepad_axis(name: &str) -> std::result::Result<GamepadAxis, String> {
+    GAMEPAD_AXES.iter().find(|axis| format!("{:?}", axis) == name).copied().ok_or_else(|| format!("Unknown gamepad axis \"{name}\""))
+}
+
+fn parse_list<T, F: Fn(&str) -> std::result::Result<T, String>>(src: &str, f: F) -> std::result::Result<Vec<T>, String> {
+    src.split(',').filter(|s| !s.trim().is_empty()).map(|s| f(s.trim())).collect()
+}
+
+fn join<T: Display>(items: &[T]) -> String {
+    items.iter().map(|item| item.to_string()).collect::<Vec<_>>().join(", ")
+}
+
+impl Default for InputMap {
+    fn default() -> Self {
+        InputMap { bindings: HashMap::new(), deadzones: DEFAULT_DEADZONES }
+    }
+}
+
+impl InputMap {
+    pub fn new() -> InputMap {
+        InputMap::default()
+    }
+
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<InputMap> {
+        Self::parse(BufReader::new(File::open(path)?))
+    }
+
+    pub fn parse<R: BufRead>(reader: R) -> Result<InputMap> {
+        let mut map = InputMap::new();
+
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = |msg| InputError::BindingParseError { line: i + 1, msg };
+
+            if let Some(deadzone) = line.strip_prefix("deadzone ") {
+                let (axis, value) = deadzone.split_once('=').ok_or_else(|| invalid("Missing '='".to_owned()))?;
+                let axis = parse_gamepad_axis(axis.trim()).map_err(invalid)?;
+                let value = value.trim();
+                let deadzone = value.parse().ok().filter(|d| (0.0..1.0).contains(d)).ok_or_else(|| invalid(format!("Invalid deadzone \"{value}\", expected a number in [0, 1)")))?;
+                map.set_deadzone(axis, deadzone);
+                continue;
+            }
+
+            let (name, binding) = Self::parse_line(line).map_err(invalid)?;
+            map.bind(name, binding);
+        }
+
+        Ok(map)
+    }
+
+    fn parse_line(line: &str) -> std::result::Result<(String, Binding), String> {
+        let (head, sources) = line.split_once('=').ok_or("Missing '='")?;
+        let (kind, name) = head.trim().split_once(char::is_whitespace).ok_or("Missing binding name")?;
+        let name = name.trim().to_owned();
+
+        let binding = match kind {
+            "action" => Binding::Action(parse_list(sources, InputSource::parse)?),
+            "axis" => Binding::Axis(parse_list(sources, AxisBinding::parse)?),
+            "axis2" => {
+                let (x, y) = sources.split_once(';').ok_or("axis2 needs x and y bindings separated by ';'")?;
+                Binding::Axis2 { x: parse_list(x, AxisBinding::parse)?, y: parse_list(y, AxisBinding::parse)? }
+            },
+            _ => Err(format!("Unknown binding kind \"{kind}\""))?
+        };
+
+        Ok((name, binding))
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.write(File::create(path)?)
+    }
+
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<()> {
+        // Sorted so saved files don't change order between runs
+        let mut names: Vec<&String> = self.bindings.keys().collect();
+        names.sort();
+
+        for name in names {
+            match &self.bindings[name] {
+                Binding::Action(sources) => writeln!(writer, "action {name} = {}", join(sources))?,
+                Binding::Axis(bindings) => writeln!(writer, "axis {name} = {}", join(bindings))?,
+                Binding::Axis2 { x, y } => writeln!(writer, "axis2 {name} = {} ; {}", join(x), join(y))?
+            }
+        }
+
+        for axis in GAMEPAD_AXES {
+            writeln!(writer, "deadzone {:?} = {}", axis, self.deadzone(axis))?;
+        }
+
+        Ok(())
+    }
+
+    pub fn deadzone(&self, axis: GamepadAxis) -> f32 {
+        self.deadzones[axis as usize]
+    }
+
+    pub fn set_deadzone(&mut self, axis: GamepadAxis, deadzone: f32) {
+        assert!((0.0..1.0).contains(&deadzone), "Deadzone must be in [0, 1)!");
+
+        self.deadzones[axis as usize] = deadzone;
+    }
+
+    /// Adds or replaces a binding.
+    pub fn bind<S: Into<String>>(&mut self, name: S, binding: Binding) {
+        self.bindings.insert(name.into(), binding);
+    }
+
+    pub fn unbind(&mut self, name: &str) -> Option<Binding> {
+        self.bindings.remove(name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Binding> {
+        self.bindings.get(name)
+    }
+
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut Binding> {
+        self.bindings.get_mut(name)
+    }
+
+    pub fn iter(&self) -> std::collections::hash_map::Iter<'_, String, Binding> {
+        self.bindings.iter()
+    }
+
+    fn find(&self, name: &str) -> Result<&Binding> {
+        Ok(self.bindings.get(name).ok_or_else(|| InputError::UnknownBindingError { name: name.to_owned() })?)
+    }
+
+    pub(in crate::engine::input) fn action(&self, input: &Input, name: &str) -> Result<KeyState> {
+        let Binding::Action(sources) = self.find(name)? else {
+            Err(InputError::BindingTypeError { name: name.to_owned(), expected: "an action" })?
+        };
+
+        let states: Vec<KeyState> = sources.iter().map(|source| source.state(input)).collect();
+        let held = states.iter().any(|state| state.is_down && !state.press);
+        let is_down = states.iter().any(|state| state.is_down);
+
+        // Pressing a second source while the first is still held doesn't count as another press
+        Ok(KeyState {
+            is_down,
+            press: !held && states.iter().any(|state| state.press),
+            release: !is_down && states.iter().any(|state| state.release),
+            repeat: states.iter().any(|state| state.repeat)
+        })
+    }
+
+    pub(in crate::engine::input) fn axis(&self, input: &Input, name: &str) -> Result<f32> {
+        let Binding::Axis(bindings) = self.find(name)? else {
+            Err(InputError::BindingTypeError { name: name.to_owned(), expected: "an axis" })?
+        };
+
+        Ok(sum(bindings, input))
+    }
+
+    pub(in crate::engine::input) fn axis2(&self, input: &Input, name: &str) -> Result<(f32, f32)> {
+        let Binding::Axis2 { x, y } = self.find(name)? else {
+            Err(InputError::BindingTypeError { name: name.to_owned(), expected: "a 2D axis" })?
+        };
+
+        Ok((sum(x, input), sum(y, input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gl_types::vec2;
+    use glfw::{GamepadAxis, GamepadButton, JoystickId, Key, MouseButton};
+
+    use crate::engine::input::{AxisBinding, Binding, GamepadSnapshot, Input, InputMap, InputSource};
+
+    const CONFIG: &str = "# test bindings
+action jump = key:Space, mouse:Left, pad:ButtonA
+axis zoom = scroll:y
+axis turn = padaxis:AxisRightX
+deadzone AxisRightX = 0.25
+axis2 move = key:D/key:A ; key:W/key:S, key:Up/key:Down
+";
+
+    #[test]
+    pub fn input_map_round_trip() {
+        let map = InputMap::parse(CONFIG.as_bytes()).unwrap();
+
+        assert_eq!(map.get("jump"), Some(&Binding::Action(vec![InputSource::Key(Key::Space), InputSource::MouseButton(MouseButton::Button1), InputSource::GamepadButton(GamepadButton::ButtonA)])));
+        assert_eq!(map.deadzone(GamepadAxis::AxisRightX), 0.25);
+        assert_eq!(map.get("zoom"), Some(&Binding::Axis(vec![AxisBinding::Source(InputSource::ScrollY)])));
+
+        let mut saved = Vec::new();
+        map.write(&mut saved).unwrap();
+        let reloaded = InputMap::parse(saved.as_slice()).unwrap();
+        for (name, binding) in map.iter() {
+            assert_eq!(reloaded.get(name), Some(binding));
+        }
+        assert_eq!(reloaded.deadzone(GamepadAxis::AxisRightX), 0.25);
+
+        assert!(InputMap::load("input_bindings.cfg").unwrap().get("move_camera").is_some());
+        assert!(InputMap::parse("action jump = key:Nope".as_bytes()).is_err());
+        assert!(InputMap::parse("axis2 move = key:D/key:A".as_bytes()).is_err());
+        assert!(InputMap::parse("deadzone AxisLeftX = 1.5".as_bytes()).is_err());
+        assert!(InputMap::parse("deadzone AxisLeftX = NaN".as_bytes()).is_err());
+    }
+
+    #[test]
+    pub fn input_map_queries() {
+        let mut input = Input::new();
+        input.set_input_map(InputMap::parse(CONFIG.as_bytes()).unwrap());
+
+        input.modify_key_state(Key::Space).is_down = true;
+        input.modify_key_state(Key::Space).press = true;
+        assert!(input.action("jump").unwrap().press);
+
+        // Pressing while another source is already held is not a new press
+        input.modify_mouse_button_state(MouseButton::Button1 as u32).is_down = true;
+        assert!(!input.action("jump").unwrap().press);
+
+        // Still held by the mouse button after space is released
+        *input.modify_key_state(Key::Space) = Default::default();
+        input.modify_key_state(Key::Space).release = true;
+        let jump = input.action("jump").unwrap();
+        assert!(jump.is_down && !jump.press && !jump.release);
+
+        input.modify_key_state(Key::D).is_down = true;
+        input.modify_key_state(Key::Down).is_down = true;
+        input.add_scroll_delta(0.0, -2.0);
+        assert!(input.axis2("move").unwrap() == vec2!(1.0, -1.0));
+        assert_eq!(input.axis("zoom").unwrap(), -1.0);
+
+        // Rebinding is visible to every input sharing the map
+        let fixed = Input::with_input_map(&input);
+        fixed.input_map_mut().bind("zoom", Binding::Axis(vec![AxisBinding::Pair { positive: InputSource::Key(Key::E), negative: InputSource::Key(Key::Q) }]));
+        input.modify_key_state(Key::Q).is_down = true;
+        assert_eq!(input.axis("zoom").unwrap(), -1.0);
+        assert_eq!(fixed.axis("zoom").unwrap(), 0.0);
+
+        let mut snapshot = GamepadSnapshot::default();
+        snapshot.axes[GamepadAxis::AxisRightX as usize] = -0.625;
+        input.update_gamepad(JoystickId::Joystick1, Some(snapshot));
+        snapshot.axes[GamepadAxis::AxisRightX as usize] = 0.4375;
+        input.update_gamepad(JoystickId::Joystick3, Some(snapshot));
+        assert_eq!(input.axis("turn").unwrap(), -0.5);
+
+        assert!(input.axis("jump").is_err());
+        assert!(input.action("missing").is_err());
+    }
+
+    #[test]
+    pub fn axis_sources() {
+        let mut input = Input::new();
+
+        // Only the first frame of scrolling is a press
+        input.add_scroll_delta(0.0, 1.0);
+        assert!(InputSource::ScrollY.state(&input).press);
+        input.clear_frame_state();
+        input.add_scroll_delta(0.0, 1.0);
+        let scroll = InputSource::ScrollY.state(&input);
+        assert!(scroll.is_down && !scroll.press);
+        input.clear_frame_state();
+        let scroll = InputSource::ScrollY.state(&input);
+        assert!(!scroll.is_down && scroll.release);
+
+        let stick = InputSource::GamepadAxis(GamepadAxis::AxisLeftX);
+        let mut snapshot = GamepadSnapshot::default();
+        snapshot.axes[GamepadAxis::AxisLeftX as usize] = 1.0;
+        input.update_gamepad(JoystickId::Joystick1, Some(snapshot));
+        assert!(stick.state(&input).press);
+        input.clear_frame_state();
+        input.update_gamepad(JoystickId::Joystick1, Some(snapshot));
+        assert!(!stick.state(&input).press);
+        input.clear_frame_state();
+        input.update_gamepad(JoystickId::Joystick1, None);
+        assert!(stick.state(&input).release);
+
+        // Several bindings pushing the same way don't add up past 1
+        input.input_map_mut().bind("push", Binding::Axis(vec![AxisBinding::Source(InputSource::Key(Key::D)), AxisBinding::Source(InputSource::Key(Key::Right))]));
+        input.modify_key_state(Key::D).is_down = true;
+        input.modify_key_state(Key::Right).is_down = true;
+        assert_eq!(input.axis("push").unwrap(), 1.0);
+    }
+}
@@ -0,0 +1,9 @@
+mod state;
+mod input_map;
+mod gamepad;
+mod recording;
+
+pub use state::*;
+pub use input_map::*;
+pub use gamepad::*;
+pub use recording::*;
\ No newline at end of file
@@ -0,0 +1,241 @@
+use std::{fs::File, io::{BufRead, BufReader, BufWriter, Write}, path::Path};
+
+use glfw::{Action, JoystickId, Key, Modifiers, MouseButton, WindowEvent};
+
+use crate::engine::{errors::{InputError, Result}, text_format::{read_lines, Fields}};
+
+use super::{input_map::KEYS, GamepadSnapshot, GAMEPAD_AXIS_COUNT, GAMEPAD_BUTTON_COUNT, MAX_GAMEPADS};
+
+const RECORDING_HEADER: &str = "# input recording v1";
+
+/// Everything `Input` received during a single frame.
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputFrame {
+    pub frame: u64,
+    pub fixed_tick: u64,
+    /// Unscaled frame time, playback uses it instead of the real one
+    pub delta_time: f64,
+    pub events: Vec<WindowEvent>,
+    /// Only slots that changed since the previous frame
+    pub gamepads: Vec<(JoystickId, Option<GamepadSnapshot>)>
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InputRecording {
+    pub frames: Vec<InputFrame>
+}
+
+/// Whether `Input` does anything with an event, other events are not recorded.
+pub fn is_input_event(event: &WindowEvent) -> bool {
+    matches!(event,
+        WindowEvent::Key(..) | WindowEvent::MouseButton(..) | WindowEvent::Scroll(..) | WindowEvent::CursorPos(..) |
+        WindowEvent::CursorEnter(..) | WindowEvent::Size(..) | WindowEvent::Char(..)
+    )
+}
+
+fn encode_action(action: Action) -> &'static str {
+    match action {
+        Action::Press => "Press",
+        Action::Release => "Release",
+        Action::Repeat => "Repeat"
+    }
+}
+
+fn decode_action(s: &str) -> std::result::Result<Action, String> {
+    match s {
+        "Press" => Ok(Action::Press),
+        "Release" => Ok(Action::Release),
+        "Repeat" => Ok(Action::Repeat),
+        _ => Err(format!("Unknown action \"{s}\""))
+    }
+}
+
+fn encode_event(event: &WindowEvent) -> Option<String> {
+    Some(match event {
+        WindowEvent::Key(key, scancode, action, modifiers) => format!("key\t{:?}\t{}\t{}\t{}", key, scancode, encode_action(*action), modifiers.bits()),
+        WindowEvent::MouseButton(button, action, modifiers) => format!("mouse\t{}\t{}\t{}", *button as i32, encode_action(*action), modifiers.bits()),
+        WindowEvent::Scroll(x, y) => format!("scroll\t{:?}\t{:?}", x, y),
+        WindowEvent::CursorPos(x, y) => format!("cursor\t{:?}\t{:?}", x, y),
+        WindowEvent::CursorEnter(entered) => format!("enter\t{}", entered),
+        WindowEvent::Size(width, height) => format!("size\t{}\t{}", width, height),
+        WindowEvent::Char(c) => format!("char\t{}", *c as u32),
+        _ => return None
+    })
+}
+
+fn encode_gamepad(id: JoystickId, snapshot: &Option<GamepadSnapshot>) -> String {
+    match snapshot {
+        Some(snapshot) => {
+            let buttons = snapshot.buttons.iter().enumerate().fold(0u32, |bits, (i, down)| bits | ((*down as u32) << i));
+            let axes: Vec<String> = snapshot.axes.iter().map(|axis| format!("{:?}", axis)).collect();
+            format!("pad\t{}\t{:x}\t{}", id as i32, buttons, axes.join(","))
+        },
+        None => format!("pad\t{}\tnone", id as i32)
+    }
+}
+
+enum Line {
+    Frame(InputFrame),
+    Event(WindowEvent),
+    Gamepad(JoystickId, Option<GamepadSnapshot>)
+}
+
+fn decode_line(mut fields: Fields) -> std::result::Result<Line, String> {
+    let kind = fields.next_raw("entry")?;
+
+    let line = match kind {
+        "frame" => Line::Frame(InputFrame { frame: fields.next("frame")?, fixed_tick: fields.next("fixed tick")?, delta_time: fields.next("delta time")?, events: Vec::new(), gamepads: Vec::new() }),
+        "key" => {
+            let name = fields.next_raw("key")?;
+            let key = if name == "Unknown" {
+                Key::Unknown
+            } else {
+                *KEYS.iter().find(|key| format!("{:?}", key) == name).ok_or_else(|| format!("Unknown key \"{name}\""))?
+            };
+
+            let scancode = fields.next("scancode")?;
+            let action = decode_action(fields.next_raw("action")?)?;
+            Line::Event(WindowEvent::Key(key, scancode, action, Modifiers::from_bits_truncate(fields.next("modifiers")?)))
+        },
+        "mouse" => {
+            let button = MouseButton::from_i32(fields.next("mouse button")?).ok_or("Invalid mouse button")?;
+            let action = decode_action(fields.next_raw("action")?)?;
+            Line::Event(WindowEvent::MouseButton(button, action, Modifiers::from_bits_truncate(fields.next("modifiers")?)))
+        },
+        "scroll" => Line::Event(WindowEvent::Scroll(fields.next("x offset")?, fields.next("y offset")?)),
+        "cursor" => Line::Event(WindowEvent::CursorPos(fields.next("x position")?, fields.next("y position")?)),
+        "enter" => Line::Event(WindowEvent::CursorEnter(fields.next("entered flag")?)),
+        "size" => Line::Event(WindowEvent::Size(fields.next("width")?, fields.next("height")?)),
+        "char" => Line::Event(WindowEvent::Char(char::from_u32(fields.next("character")?).ok_or("Invalid character")?)),
+        "pad" => {
+            let id = JoystickId::from_i32(fields.next("joystick id")?).ok_or("Invalid joystick id")?;
+
+            match fields.next_raw("buttons")? {
+                "none" => Line::Gamepad(id, None),
+                buttons => {
+                    let buttons = u32::from_str_radix(buttons, 16).map_err(|_| "Invalid buttons")?;
+                    let axes: Vec<f32> = fields.next_raw("axes")?.split(',').map(|axis| axis.parse().map_err(|_| format!("Invalid axis \"{axis}\""))).collect::<std::result::Result<_, _>>()?;
+
+                    let mut snapshot = GamepadSnapshot::default();
+                    for i in 0..GAMEPAD_BUTTON_COUNT {
+                        snapshot.buttons[i] = buttons & (1 << i) != 0;
+                    }
+                    snapshot.axes = axes.try_into().map_err(|_| format!("Expected {GAMEPAD_AXIS_COUNT} axes"))?;
+
+                    Line::Gamepad(id, Some(snapshot))
+                }
+            }
+        },
+        _ => Err(format!("Unknown entry \"{kind}\""))?
+    };
+    fields.finish()?;
+
+    Ok(line)
+}
+
+impl InputRecording {
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<InputRecording> {
+        Self::parse(BufReader::new(File::open(path)?))
+    }
+
+    pub fn parse<R: BufRead>(reader: R) -> Result<InputRecording> {
+        let mut frames: Vec<InputFrame> = Vec::new();
+
+        read_lines(reader, RECORDING_HEADER, |line, msg| InputError::RecordingParseError { line, msg }, |fields| {
+            match decode_line(fields)? {
+                Line::Frame(frame) => frames.push(frame),
+                Line::Event(event) => frames.last_mut().ok_or("Event before the first frame")?.events.push(event),
+                Line::Gamepad(id, snapshot) => frames.last_mut().ok_or("Gamepad before the first frame")?.gamepads.push((id, snapshot))
+            }
+
+            Ok(())
+        })?;
+
+        Ok(InputRecording { frames })
+    }
+
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "{}", RECORDING_HEADER)?;
+
+        for frame in &self.frames {
+            write_frame(&mut writer, frame)?;
+        }
+
+        writer.flush()?;
+
+        Ok(())
+    }
+}
+
+fn write_frame<W: Write>(writer: &mut W, frame: &InputFrame) -> Result<()> {
+    writeln!(writer, "frame\t{}\t{}\t{:?}", frame.frame, frame.fixed_tick, frame.delta_time)?;
+
+    for line in frame.events.iter().filter_map(encode_event) {
+        writeln!(writer, "{}", line)?;
+    }
+
+    for (id, snapshot) in &frame.gamepads {
+        writeln!(writer, "{}", encode_gamepad(*id, snapshot))?;
+    }
+
+    Ok(())
+}
+
+pub(in crate::engine) struct InputRecorder {
+    writer: BufWriter<File>,
+    gamepads: [Option<GamepadSnapshot>; MAX_GAMEPADS]
+}
+
+impl InputRecorder {
+    pub(in crate::engine) fn create<P: AsRef<Path>>(path: P) -> Result<InputRecorder> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writeln!(writer, "{}", RECORDING_HEADER)?;
+
+        Ok(InputRecorder { writer, gamepads: [None; MAX_GAMEPADS] })
+    }
+
+    pub(in crate::engine) fn record(&mut self, frame: u64, fixed_tick: u64, delta_time: f64, events: &[WindowEvent], gamepads: &[(JoystickId, Option<GamepadSnapshot>)]) -> Result<()> {
+        let events = events.iter().filter(|event| is_input_event(event)).cloned().collect();
+
+        // Gamepads are polled every frame, only changes are worth storing
+        let gamepads = gamepads.iter().filter(|(id, snapshot)| {
+            let previous = &mut self.gamepads[*id as usize];
+            let changed = previous != snapshot;
+            *previous = *snapshot;
+            changed
+        }).cloned().collect();
+
+        write_frame(&mut self.writer, &InputFrame { frame, fixed_tick, delta_time, events, gamepads })
+    }
+
+    pub(in crate::engine) fn finish(mut self) -> Result<()> {
+        self.writer.flush()?;
+
+        Ok(())
+    }
+}
+
+pub(in crate::engine) struct InputPlayback {
+    frames: std::vec::IntoIter<InputFrame>,
+    gamepads: [Option<GamepadSnapshot>; MAX_GAMEPADS]
+}
+
+impl InputPlayback {
+    pub(in crate::engine) fn new(recording: InputRecording) -> InputPlayback {
+        InputPlayback { frames: recording.frames.into_iter(), gamepads: [None; MAX_GAMEPADS] }
+    }
+
+    /// Returns the next frame with the state of every gamepad slot filled in, like a live poll would.
+    pub(in crate::engine) fn next_frame(&mut self) -> Option<InputFrame> {
+        let mut frame = self.frames.next()?;
+
+        for (id, snapshot) in frame.gamepads.iter() {
+            self.gamepads[*id as usize] = *snapshot;
+        }
+
+        frame.gamepads = (0..MAX_GAMEPADS as i32).filter_map(JoystickId::from_i32).map(|id| (id, self.gamepads[id as usize])).collect();
+
+        Some(frame)
+    }
+}
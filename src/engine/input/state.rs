@@ -0,0 +1,522 @@
+use std::{cell::{Ref, RefCell, RefMut}, path::Path, rc::Rc};
+
+use gl_types::{vec2, vectors::Vec2};
+use glfw::{Action, GamepadAxis, GamepadButton, JoystickId, Key, Modifiers, MouseButton, Scancode, WindowEvent};
+
+use crate::engine::errors::Result;
+
+use super::{apply_deadzone, Gamepad, GamepadEvent, GamepadSnapshot, InputMap, MAX_GAMEPADS};
+
+const KEY_COUNT: usize = 348;
+// GLFW reports up to 8 mouse buttons
+const MOUSE_BUTTON_COUNT: usize = MouseButton::Button8 as usize + 1;
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct KeyState {
+    pub is_down: bool,
+    pub press: bool,
+    pub release: bool,
+    /// Set when the OS sent a key repeat for a held key
+    pub repeat: bool
+}
+
+/// A key event as it arrived, in order. Includes repeats and the modifiers held at the time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyEvent {
+    pub key: Key,
+    pub scancode: Scancode,
+    pub action: Action,
+    pub modifiers: Modifiers
+}
+
+pub struct Input {
+    keys: Box<[KeyState]>,
+    mouse_buttons: Box<[KeyState]>,
+    scroll_x: f64,
+    scroll_y: f64,
+    // Scrolled at the end of the previous frame, input maps use it to tell when scrolling starts and stops
+    previous_scroll: (f64, f64),
+    cursor: Cursor,
+    gamepads: [Option<Gamepad>; MAX_GAMEPADS],
+    gamepad_events: Vec<GamepadEvent>,
+    key_events: Vec<KeyEvent>,
+    chars: Vec<char>,
+    modifiers: Modifiers,
+    input_map: Rc<RefCell<InputMap>>
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Cursor {
+    x: f64,
+    y: f64,
+    delta_x: f64,
+    delta_y: f64,
+    // No delta for the first position, it would jump from 0, 0
+    has_position: bool,
+    in_window: bool,
+    entered: bool,
+    left: bool,
+    window_width: u32,
+    window_height: u32
+}
+
+impl Input {
+    pub fn new() -> Input {
+        let keys = Box::new([KeyState::default(); KEY_COUNT]);
+        let mouse_buttons = Box::new([KeyState::default(); MOUSE_BUTTON_COUNT]);
+        Input { keys, mouse_buttons, scroll_x: 0.0, scroll_y: 0.0, previous_scroll: (0.0, 0.0), cursor: Cursor::default(), gamepads: [None; MAX_GAMEPADS], gamepad_events: Vec::new(), key_events: Vec::new(), chars: Vec::new(), modifiers: Modifiers::empty(), input_map: Rc::new(RefCell::new(InputMap::new())) }
+    }
+
+    /// Creates an input with no state that shares the bindings of `other`.
+    pub fn with_input_map(other: &Input) -> Input {
+        Input { input_map: other.input_map.clone(), ..Input::new() }
+    }
+
+    pub fn input_map(&self) -> Ref<'_, InputMap> {
+        self.input_map.borrow()
+    }
+
+    // Bindings are shared, so rebinding through any input changes all of them
+    pub fn input_map_mut(&self) -> RefMut<'_, InputMap> {
+        self.input_map.borrow_mut()
+    }
+
+    pub fn set_input_map(&self, input_map: InputMap) {
+        *self.input_map.borrow_mut() = input_map;
+    }
+
+    pub fn load_input_map<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        self.set_input_map(InputMap::load(path)?);
+
+        Ok(())
+    }
+
+    pub fn action(&self, name: &str) -> Result<KeyState> {
+        self.input_map.borrow().action(self, name)
+    }
+
+    pub fn axis(&self, name: &str) -> Result<f32> {
+        self.input_map.borrow().axis(self, name)
+    }
+
+    pub fn axis2(&self, name: &str) -> Result<Vec2> {
+        let (x, y) = self.input_map.borrow().axis2(self, name)?;
+
+        Ok(vec2!(x, y))
+    }
+
+    pub fn get_mouse_button_state(&self, button: u32) -> KeyState {
+        if button as usize >= self.mouse_buttons.len() {
+            panic!("Invalid mouse button.");
+        }
+
+        self.mouse_buttons[button as usize]
+    }
+
+    pub fn modify_mouse_button_state(&mut self, button: u32) -> &mut KeyState {
+        if button as usize >= self.mouse_buttons.len() {
+            panic!("Invalid mouse button.");
+        }
+
+        &mut self.mouse_buttons[button as usize]
+    }
+
+    pub fn modify_all_mouse_button_states<F: FnMut(&mut KeyState)>(&mut self, mut f: F) {
+        for state in self.mouse_buttons.iter_mut() {
+            f(state)
+        }
+    }
+
+    pub fn get_key_state(&self, key: Key) -> KeyState {
+        match  key {
+            Key::Unknown => panic!("Unknown key"),
+            _ => self.keys[key as usize]
+        }
+    }
+
+    pub fn modify_key_state(&mut self, key: Key) -> &mut KeyState {
+        match  key {
+            Key::Unknown => panic!("Unknown key"),
+            _ => &mut self.keys[key as usize]
+        }
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, KeyState> {
+        self.keys.iter()
+    }
+
+    pub fn modify_all_key_states<F: FnMut(&mut KeyState)>(&mut self, mut f: F) {
+        for state in self.keys.iter_mut() {
+            f(state)
+        }
+    }
+
+    pub fn get_scroll_x(&self) -> f64 {
+        self.scroll_x
+    }
+
+    pub fn get_scroll_y(&self) -> f64 {
+        self.scroll_y
+    }
+
+    pub fn set_scroll_delta(&mut self, x: f64, y: f64) {
+        self.scroll_x = x;
+        self.scroll_y = y;
+    }
+
+    pub fn add_scroll_delta(&mut self, x: f64, y: f64) {
+        self.scroll_x += x;
+        self.scroll_y += y;
+    }
+
+    /// Cursor position in window coordinates, in pixels from the top left corner.
+    pub fn cursor_position(&self) -> Vec2 {
+        vec2!(self.cursor.x, self.cursor.y)
+    }
+
+    /// Cursor position divided by the window size, `0, 0` is the top left corner and `1, 1` the bottom right.
+    pub fn cursor_position_normalized(&self) -> Vec2 {
+        let width = self.cursor.window_width.max(1) as f64;
+        let height = self.cursor.window_height.max(1) as f64;
+
+        vec2!(self.cursor.x / width, self.cursor.y / height)
+    }
+
+    /// How far the cursor moved since the last frame (or fixed tick for the fixed input).
+    pub fn cursor_delta(&self) -> Vec2 {
+        vec2!(self.cursor.delta_x, self.cursor.delta_y)
+    }
+
+    pub fn is_cursor_in_window(&self) -> bool {
+        self.cursor.in_window
+    }
+
+    pub fn cursor_entered(&self) -> bool {
+        self.cursor.entered
+    }
+
+    pub fn cursor_left(&self) -> bool {
+        self.cursor.left
+    }
+
+    pub fn window_size(&self) -> (u32, u32) {
+        (self.cursor.window_width, self.cursor.window_height)
+    }
+
+    pub fn set_window_size(&mut self, width: u32, height: u32) {
+        self.cursor.window_width = width;
+        self.cursor.window_height = height;
+    }
+
+    pub fn set_cursor_position(&mut self, x: f64, y: f64) {
+        if self.cursor.has_position {
+            self.cursor.delta_x += x - self.cursor.x;
+            self.cursor.delta_y += y - self.cursor.y;
+        }
+
+        self.cursor.x = x;
+        self.cursor.y = y;
+        self.cursor.has_position = true;
+    }
+
+    pub fn set_cursor_in_window(&mut self, in_window: bool) {
+        if in_window != self.cursor.in_window {
+            if in_window {
+                self.cursor.entered = true;
+            } else {
+                self.cursor.left = true;
+            }
+        }
+
+        self.cursor.in_window = in_window;
+    }
+
+    /// Typed characters since the last frame, in order. Unlike key states this follows the keyboard layout and IME.
+    pub fn chars(&self) -> &[char] {
+        &self.chars
+    }
+
+    pub fn text(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    pub fn key_events(&self) -> &[KeyEvent] {
+        &self.key_events
+    }
+
+    /// Modifiers held during the most recent key event.
+    pub fn modifiers(&self) -> Modifiers {
+        self.modifiers
+    }
+
+    /// Feeds the polled state of a joystick slot, `None` if nothing is connected there.
+    pub fn update_gamepad(&mut self, id: JoystickId, snapshot: Option<GamepadSnapshot>) {
+        let slot = &mut self.gamepads[id as usize];
+
+        match (slot.as_mut(), snapshot) {
+            (Some(gamepad), Some(snapshot)) => {
+                if !gamepad.connected {
+                    gamepad.connected = true;
+                    self.gamepad_events.push(GamepadEvent::Connected(id));
+                }
+
+                gamepad.apply(&snapshot);
+            },
+            (None, Some(snapshot)) => {
+                let mut gamepad = Gamepad::new();
+                gamepad.apply(&snapshot);
+                *slot = Some(gamepad);
+                self.gamepad_events.push(GamepadEvent::Connected(id));
+            },
+            (Some(gamepad), None) => {
+                if gamepad.connected {
+                    gamepad.disconnect();
+                    self.gamepad_events.push(GamepadEvent::Disconnected(id));
+                }
+            },
+            (None, None) => ()
+        }
+    }
+
+    pub fn is_gamepad_connected(&self, id: JoystickId) -> bool {
+        self.gamepads[id as usize].is_some_and(|gamepad| gamepad.connected)
+    }
+
+    pub fn connected_gamepads(&self) -> Vec<JoystickId> {
+        (0..MAX_GAMEPADS as i32).filter_map(JoystickId::from_i32).filter(|id| self.is_gamepad_connected(*id)).collect()
+    }
+
+    /// Connects and disconnects since the last frame (or fixed tick for the fixed input).
+    pub fn gamepad_events(&self) -> &[GamepadEvent] {
+        &self.gamepad_events
+    }
+
+    /// Disconnected gamepads read as released, buttons held when it happened get a release that frame.
+    pub fn get_gamepad_button_state(&self, id: JoystickId, button: GamepadButton) -> KeyState {
+        self.gamepads[id as usize].map_or(KeyState::default(), |gamepad| gamepad.buttons[button as usize])
+    }
+
+    /// Axis value with the deadzone from the input map applied.
+    pub fn get_gamepad_axis(&self, id: JoystickId, axis: GamepadAxis) -> f32 {
+        apply_deadzone(self.get_gamepad_axis_raw(id, axis), self.input_map.borrow().deadzone(axis))
+    }
+
+    /// Axis value without the deadzone. Sticks go from -1 to 1, triggers from 0 at rest to 1.
+    pub fn get_gamepad_axis_raw(&self, id: JoystickId, axis: GamepadAxis) -> f32 {
+        self.gamepads[id as usize].map_or(0.0, |gamepad| gamepad.axes[axis as usize])
+    }
+
+    pub(in crate::engine::input) fn previous_scroll(&self) -> (f64, f64) {
+        self.previous_scroll
+    }
+
+    // Includes gamepads that were disconnected this frame
+    pub(in crate::engine::input) fn previous_gamepad_axis(&self, id: JoystickId, axis: GamepadAxis) -> f32 {
+        apply_deadzone(self.gamepads[id as usize].map_or(0.0, |gamepad| gamepad.previous_axes[axis as usize]), self.input_map.borrow().deadzone(axis))
+    }
+
+    pub(in crate::engine) fn handle_event(&mut self, event: &WindowEvent) {
+        match *event {
+            WindowEvent::Key(key, scancode, action, modifiers) => {
+                self.key_events.push(KeyEvent { key, scancode, action, modifiers });
+                self.modifiers = modifiers;
+
+                // Keys glfw doesn't know only show up as events
+                if key == Key::Unknown {
+                    return;
+                }
+
+                let key_state = self.modify_key_state(key);
+                match action {
+                    Action::Press => {
+                        key_state.press = true;
+                        key_state.is_down = true;
+                    },
+                    Action::Release => {
+                        key_state.release = true;
+                        key_state.is_down = false;
+                    },
+                    Action::Repeat => key_state.repeat = true
+                }
+            },
+            WindowEvent::Char(c) => self.chars.push(c),
+            WindowEvent::MouseButton(button, Action::Press, _) => {
+                let button_state = self.modify_mouse_button_state(button as u32);
+                button_state.press = true;
+                button_state.is_down = true;
+            },
+            WindowEvent::MouseButton(button, Action::Release, _) => {
+                let button_state = self.modify_mouse_button_state(button as u32);
+                button_state.release = true;
+                button_state.is_down = false;
+            },
+            WindowEvent::Scroll(x, y) => self.add_scroll_delta(x, y),
+            WindowEvent::CursorPos(x, y) => self.set_cursor_position(x, y),
+            WindowEvent::CursorEnter(entered) => self.set_cursor_in_window(entered),
+            WindowEvent::Size(width, height) => self.set_window_size(width.max(0) as u32, height.max(0) as u32),
+            _ => ()
+        }
+    }
+
+    // Clears everything that only lasts for a single frame or fixed tick
+    pub(in crate::engine) fn clear_frame_state(&mut self) {
+        self.modify_all_key_states(|key| {
+            key.press = false;
+            key.release = false;
+            key.repeat = false;
+        });
+        self.modify_all_mouse_button_states(|button| {
+            button.press = false;
+            button.release = false;
+        });
+        self.previous_scroll = (self.scroll_x, self.scroll_y);
+        self.set_scroll_delta(0.0, 0.0);
+
+        self.cursor.delta_x = 0.0;
+        self.cursor.delta_y = 0.0;
+        self.cursor.entered = false;
+        self.cursor.left = false;
+
+        for slot in self.gamepads.iter_mut() {
+            if matches!(slot, Some(gamepad) if !gamepad.connected) {
+                *slot = None;
+            }
+        }
+        for gamepad in self.gamepads.iter_mut().flatten() {
+            for button in gamepad.buttons.iter_mut() {
+                button.press = false;
+                button.release = false;
+            }
+            gamepad.previous_axes = gamepad.axes;
+        }
+        self.gamepad_events.clear();
+        self.key_events.clear();
+        self.chars.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gl_types::vec2;
+    use glfw::{Action, GamepadAxis, GamepadButton, JoystickId, Key, Modifiers, MouseButton, WindowEvent};
+
+    use crate::engine::input::{GamepadEvent, GamepadSnapshot};
+
+    use super::Input;
+
+    #[test]
+    pub fn cursor_events() {
+        let mut input = Input::new();
+        input.set_window_size(200, 100);
+
+        input.handle_event(&WindowEvent::CursorEnter(true));
+        input.handle_event(&WindowEvent::CursorPos(50.0, 50.0));
+        assert!(input.cursor_entered() && input.is_cursor_in_window());
+        assert!(input.cursor_delta() == vec2!(0.0, 0.0));
+
+        input.handle_event(&WindowEvent::CursorPos(60.0, 45.0));
+        input.handle_event(&WindowEvent::CursorPos(70.0, 40.0));
+        assert!(input.cursor_delta() == vec2!(20.0, -10.0));
+        assert!(input.cursor_position() == vec2!(70.0, 40.0));
+        assert!(input.cursor_position_normalized() == vec2!(0.35, 0.4));
+
+        input.handle_event(&WindowEvent::Key(Key::W, 0, Action::Press, Modifiers::empty()));
+        input.clear_frame_state();
+        assert!(input.cursor_delta() == vec2!(0.0, 0.0));
+        assert!(!input.cursor_entered());
+        assert!(input.get_key_state(Key::W).is_down && !input.get_key_state(Key::W).press);
+
+        input.handle_event(&WindowEvent::CursorEnter(false));
+        assert!(input.cursor_left() && !input.is_cursor_in_window());
+    }
+
+    #[test]
+    pub fn mouse_buttons() {
+        let mut input = Input::new();
+
+        input.handle_event(&WindowEvent::MouseButton(MouseButton::Button8, Action::Press, Modifiers::empty()));
+        assert!(input.get_mouse_button_state(MouseButton::Button8 as u32).press);
+
+        input.clear_frame_state();
+        input.handle_event(&WindowEvent::MouseButton(MouseButton::Button8, Action::Release, Modifiers::empty()));
+        let state = input.get_mouse_button_state(MouseButton::Button8 as u32);
+        assert!(state.release && !state.is_down);
+    }
+
+    #[test]
+    pub fn gamepad_states() {
+        let mut input = Input::new();
+        let id = JoystickId::Joystick2;
+
+        let mut snapshot = GamepadSnapshot::default();
+        snapshot.buttons[GamepadButton::ButtonA as usize] = true;
+        snapshot.axes[GamepadAxis::AxisLeftX as usize] = 0.1;
+        snapshot.axes[GamepadAxis::AxisLeftY as usize] = -0.75;
+        input.input_map_mut().set_deadzone(GamepadAxis::AxisLeftY, 0.5);
+        input.update_gamepad(id, Some(snapshot));
+
+        assert_eq!(input.gamepad_events(), [GamepadEvent::Connected(id)]);
+        assert_eq!(input.connected_gamepads(), [id]);
+        assert!(input.get_gamepad_button_state(id, GamepadButton::ButtonA).press);
+        assert_eq!(input.get_gamepad_axis(id, GamepadAxis::AxisLeftX), 0.0);
+        assert_eq!(input.get_gamepad_axis(id, GamepadAxis::AxisLeftY), -0.5);
+        assert_eq!(input.get_gamepad_axis_raw(id, GamepadAxis::AxisLeftX), 0.1);
+
+        input.clear_frame_state();
+        input.update_gamepad(id, Some(snapshot));
+        let a = input.get_gamepad_button_state(id, GamepadButton::ButtonA);
+        assert!(a.is_down && !a.press);
+        assert!(input.gamepad_events().is_empty());
+
+        snapshot.buttons[GamepadButton::ButtonA as usize] = false;
+        input.update_gamepad(id, Some(snapshot));
+        assert!(input.get_gamepad_button_state(id, GamepadButton::ButtonA).release);
+
+        // Idle triggers read as 0, not as held
+        assert_eq!(input.get_gamepad_axis(id, GamepadAxis::AxisLeftTrigger), 0.0);
+        snapshot.axes[GamepadAxis::AxisRightTrigger as usize] = 1.0;
+        input.update_gamepad(id, Some(snapshot));
+        assert_eq!(input.get_gamepad_axis(id, GamepadAxis::AxisRightTrigger), 1.0);
+
+        // Buttons held while disconnecting are released
+        snapshot.buttons[GamepadButton::ButtonB as usize] = true;
+        input.update_gamepad(id, Some(snapshot));
+        input.clear_frame_state();
+        input.update_gamepad(id, None);
+        assert_eq!(input.gamepad_events(), [GamepadEvent::Disconnected(id)]);
+        assert!(!input.is_gamepad_connected(id));
+        assert!(input.connected_gamepads().is_empty());
+        let b = input.get_gamepad_button_state(id, GamepadButton::ButtonB);
+        assert!(!b.is_down && b.release);
+        assert!(!input.get_gamepad_button_state(id, GamepadButton::ButtonA).release);
+        assert_eq!(input.get_gamepad_axis(id, GamepadAxis::AxisRightTrigger), 0.0);
+
+        input.clear_frame_state();
+        assert!(!input.get_gamepad_button_state(id, GamepadButton::ButtonB).release);
+    }
+
+    #[test]
+    pub fn text_and_key_events() {
+        let mut input = Input::new();
+
+        input.handle_event(&WindowEvent::Key(Key::LeftShift, 50, Action::Press, Modifiers::Shift));
+        input.handle_event(&WindowEvent::Key(Key::H, 43, Action::Press, Modifiers::Shift));
+        input.handle_event(&WindowEvent::Char('H'));
+        input.handle_event(&WindowEvent::Key(Key::H, 43, Action::Repeat, Modifiers::Shift));
+        input.handle_event(&WindowEvent::Char('H'));
+        input.handle_event(&WindowEvent::Char('é'));
+        input.handle_event(&WindowEvent::Key(Key::Unknown, 200, Action::Press, Modifiers::Shift | Modifiers::Alt));
+
+        assert_eq!(input.text(), "HHé");
+        assert_eq!(input.key_events().len(), 4);
+        assert_eq!(input.key_events()[2].action, Action::Repeat);
+        assert_eq!(input.key_events()[3].scancode, 200);
+        assert_eq!(input.modifiers(), Modifiers::Shift | Modifiers::Alt);
+        let h = input.get_key_state(Key::H);
+        assert!(h.is_down && h.press && h.repeat);
+
+        input.clear_frame_state();
+        assert!(input.chars().is_empty() && input.key_events().is_empty());
+        assert!(!input.get_key_state(Key::H).repeat);
+    }
+}
@@ -6,5 +6,14 @@ pub mod input;
 pub mod resources;
 
 mod engine;
+mod clock;
+mod time;
+mod schedule;
+mod events;
+mod text_format;
 
-pub use engine::*;
\ No newline at end of file
+pub use engine::*;
+pub use clock::*;
+pub use time::*;
+pub use schedule::*;
+pub use events::*;
\ No newline at end of file
@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use crate::engine::{errors::{Result, ScheduleError}, Engine};
+
+/// Points in the frame where systems run. Each frame runs PreUpdate, Update, PostUpdate, then every FixedUpdate tick and finally PreRender.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Stage {
+    PreUpdate,
+    /// Runs after the components' update.
+    Update,
+    PostUpdate,
+    /// Runs after the components' fixed_update, once per fixed tick.
+    FixedUpdate,
+    PreRender
+}
+
+pub type SystemFn = dyn FnMut(&mut Engine, f32) -> Result<()>;
+
+/// A named function run once per stage. Names are unique within a stage and are what `before` and `after` refer to.
+pub struct System {
+    name: &'static str,
+    before: Vec<&'static str>,
+    after: Vec<&'static str>,
+    // None while the system is running
+    run: Option<Box<SystemFn>>
+}
+
+impl System {
+    pub fn new<F: FnMut(&mut Engine, f32) -> Result<()> + 'static>(name: &'static str, run: F) -> System {
+        System { name, before: Vec::new(), after: Vec::new(), run: Some(Box::new(run)) }
+    }
+
+    /// Constraints on systems that aren't in the stage are ignored.
+    pub fn before(mut self, system: &'static str) -> System {
+        self.before.push(system);
+        self
+    }
+
+    pub fn after(mut self, system: &'static str) -> System {
+        self.after.push(system);
+        self
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+#[derive(Default)]
+struct StageSystems {
+    // Kept in insertion order, which breaks ties when sorting
+    systems: Vec<System>,
+    order: Vec<&'static str>
+}
+
+pub(in crate::engine) struct Schedule {
+    stages: HashMap<Stage, StageSystems>
+}
+
+impl Schedule {
+    pub(in crate::engine) fn new() -> Schedule {
+        Schedule { stages: HashMap::new() }
+    }
+
+    pub(in crate::engine) fn add(&mut self, stage: Stage, system: System) -> std::result::Result<(), ScheduleError> {
+        let systems = self.stages.entry(stage).or_default();
+
+        if systems.systems.iter().any(|other| other.name == system.name) {
+            return Err(ScheduleError::DuplicateSystemError { name: system.name });
+        }
+
+        systems.systems.push(system);
+
+        match sort(&systems.systems) {
+            Ok(order) => {
+                systems.order = order;
+                Ok(())
+            },
+            Err(cycle) => {
+                systems.systems.pop();
+                Err(ScheduleError::CycleError { stage, systems: cycle })
+            }
+        }
+    }
+
+    pub(in crate::engine) fn remove(&mut self, stage: Stage, name: &str) -> std::result::Result<(), ScheduleError> {
+        let systems = self.stages.entry(stage).or_default();
+        let Some(index) = systems.systems.iter().position(|system| system.name == name) else {
+            return Err(ScheduleError::UnknownSystemError { name: name.to_owned() });
+        };
+
+        // Sorted again so the order only depends on what's left, dropping a node can't introduce a cycle
+        systems.systems.remove(index);
+        systems.order = sort(&systems.systems).unwrap_or_default();
+
+        Ok(())
+    }
+
+    pub(in crate::engine) fn order(&self, stage: Stage) -> Vec<&'static str> {
+        self.stages.get(&stage).map(|systems| systems.order.clone()).unwrap_or_default()
+    }
+
+    pub(in crate::engine) fn take(&mut self, stage: Stage, name: &str) -> Option<Box<SystemFn>> {
+        self.find(stage, name)?.run.take()
+    }
+
+    // Systems removed while running are dropped, ones replaced by a system of the same name stay replaced
+    pub(in crate::engine) fn put_back(&mut self, stage: Stage, name: &str, run: Box<SystemFn>) {
+        if let Some(system) = self.find(stage, name) {
+            system.run.get_or_insert(run);
+        }
+    }
+
+    fn find(&mut self, stage: Stage, name: &str) -> Option<&mut System> {
+        self.stages.get_mut(&stage)?.systems.iter_mut().find(|system| system.name == name)
+    }
+}
+
+// Topological sort that keeps insertion order wherever the constraints allow it. Returns the systems on a cycle if there is one.
+fn sort(systems: &[System]) -> std::result::Result<Vec<&'static str>, Vec<&'static str>> {
+    let index_of = |name: &str| systems.iter().position(|system| system.name == name);
+    let mut edges = vec![Vec::new(); systems.len()];
+    let mut incoming = vec![0; systems.len()];
+
+    for (i, system) in systems.iter().enumerate() {
+        for j in system.before.iter().filter_map(|name| index_of(name)) {
+            edges[i].push(j);
+            incoming[j] += 1;
+        }
+
+        for j in system.after.iter().filter_map(|name| index_of(name)) {
+            edges[j].push(i);
+            incoming[i] += 1;
+        }
+    }
+
+    let mut done = vec![false; systems.len()];
+    let mut order = Vec::with_capacity(systems.len());
+
+    while let Some(i) = (0..systems.len()).find(|&i| !done[i] && incoming[i] == 0) {
+        done[i] = true;
+        order.push(systems[i].name);
+
+        for &j in &edges[i] {
+            incoming[j] -= 1;
+        }
+    }
+
+    if order.len() == systems.len() {
+        Ok(order)
+    } else {
+        // Everything after a cycle is left over as well, only report the systems that lead back to themselves
+        let on_cycle = |start: usize| {
+            let mut seen = vec![false; systems.len()];
+            let mut stack = edges[start].clone();
+
+            while let Some(i) = stack.pop() {
+                if i == start {
+                    return true;
+                }
+
+                if !std::mem::replace(&mut seen[i], true) {
+                    stack.extend(&edges[i]);
+                }
+            }
+
+            false
+        };
+
+        Err((0..systems.len()).filter(|&i| !done[i] && on_cycle(i)).map(|i| systems[i].name).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use crate::engine::{errors::{Error, ScheduleError}, Engine, Stage, System};
+
+    fn logger(log: &Rc<RefCell<Vec<&'static str>>>, name: &'static str) -> System {
+        let log = log.clone();
+
+        System::new(name, move |_, _| {
+            log.borrow_mut().push(name);
+            Ok(())
+        })
+    }
+
+    #[test]
+    pub fn system_order() {
+        let mut engine = Engine::create_headless().unwrap();
+        let log = Rc::new(RefCell::new(Vec::new()));
+
+        engine.add_system(Stage::PreRender, logger(&log, "render")).unwrap();
+        engine.add_system(Stage::Update, logger(&log, "c")).unwrap();
+        engine.add_system(Stage::Update, logger(&log, "a").after("b")).unwrap();
+        engine.add_system(Stage::Update, logger(&log, "b").after("c")).unwrap();
+        engine.add_system(Stage::Update, logger(&log, "d").before("c")).unwrap();
+        engine.add_system(Stage::PostUpdate, logger(&log, "post")).unwrap();
+        engine.add_system(Stage::PreUpdate, logger(&log, "pre").before("missing")).unwrap();
+
+        engine.step(0.0).unwrap();
+        assert_eq!(*log.borrow(), ["pre", "d", "c", "b", "a", "post", "render"]);
+
+        // The cycle is rejected and the stage keeps running as before. Only c -> b -> e -> c is reported, not d before it or a after it
+        match engine.add_system(Stage::Update, logger(&log, "e").after("b").before("c")) {
+            Err(Error::ScheduleError { source: ScheduleError::CycleError { stage, systems }, .. }) => {
+                assert_eq!(stage, Stage::Update);
+                assert_eq!(systems, ["c", "b", "e"]);
+            },
+            _ => panic!("Expected a cycle error")
+        }
+        assert!(matches!(engine.add_system(Stage::Update, logger(&log, "a")), Err(Error::ScheduleError { source: ScheduleError::DuplicateSystemError { .. }, .. })));
+
+        engine.remove_system(Stage::Update, "c").unwrap();
+        assert!(engine.remove_system(Stage::Update, "c").is_err());
+
+        log.borrow_mut().clear();
+        engine.step(0.0).unwrap();
+        assert_eq!(*log.borrow(), ["pre", "b", "a", "d", "post", "render"]);
+    }
+
+    #[test]
+    pub fn system_errors() {
+        let mut engine = Engine::create_headless().unwrap();
+        let runs = Rc::new(RefCell::new(0));
+        let counter = runs.clone();
+
+        engine.add_system(Stage::Update, System::new("failing", move |_, _| {
+            *counter.borrow_mut() += 1;
+            Err("Failing system")?
+        })).unwrap();
+
+        engine.step(0.0).unwrap();
+        engine.step(0.0).unwrap();
+        assert_eq!(*runs.borrow(), 2);
+
+        engine.set_error_policy(crate::engine::errors::ErrorPolicy::Abort);
+        match engine.step(0.0) {
+            Err(Error::SystemError { system, stage, .. }) => {
+                assert_eq!(system, "failing");
+                assert_eq!(stage, Stage::Update);
+            },
+            _ => panic!("Expected a system error")
+        }
+    }
+}
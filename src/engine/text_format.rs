@@ -0,0 +1,91 @@
+use std::{io::BufRead, str::FromStr};
+
+use crate::engine::errors::{Error, Result};
+
+// Scenes, input recordings and gl traces share one plain text layout:
+//
+//   # <format> v1
+//   <field> <tab> <field> ...
+//
+// Blank lines and lines starting with '#' are skipped. Fields that can hold arbitrary text escape \\ \t \n and \r.
+
+/// Reads every entry after checking the header, errors are reported with their 1-based line number.
+pub(in crate::engine) fn read_lines<R, E, F>(reader: R, header: &str, error: fn(usize, String) -> E, mut read: F) -> Result<()>
+where R: BufRead, E: Into<Error>, F: FnMut(Fields) -> std::result::Result<(), String> {
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+
+        if i == 0 && line != header {
+            Err(error(1, format!("Expected \"{header}\"")).into())?
+        }
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        read(Fields { parts: line.split('\t') }).map_err(|msg| error(i + 1, msg).into())?;
+    }
+
+    Ok(())
+}
+
+/// The tab separated fields of a single line, read front to back.
+pub(in crate::engine) struct Fields<'a> {
+    parts: std::str::Split<'a, char>
+}
+
+impl<'a> Fields<'a> {
+    /// The next field as written.
+    pub(in crate::engine) fn next_raw(&mut self, what: &str) -> std::result::Result<&'a str, String> {
+        self.parts.next().ok_or_else(|| format!("Missing {what}"))
+    }
+
+    /// The next field with its escapes resolved.
+    pub(in crate::engine) fn next_string(&mut self, what: &str) -> std::result::Result<String, String> {
+        unescape(self.next_raw(what)?)
+    }
+
+    pub(in crate::engine) fn next<T: FromStr>(&mut self, what: &str) -> std::result::Result<T, String> {
+        let s = self.next_raw(what)?;
+        s.parse().map_err(|_| format!("Invalid {what} \"{s}\""))
+    }
+
+    /// Everything left on the line, as written.
+    pub(in crate::engine) fn rest(self) -> impl Iterator<Item = &'a str> {
+        self.parts
+    }
+
+    /// Fails if anything is left on the line.
+    pub(in crate::engine) fn finish(mut self) -> std::result::Result<(), String> {
+        match self.parts.next() {
+            Some(field) => Err(format!("Unexpected field \"{field}\"")),
+            None => Ok(())
+        }
+    }
+}
+
+pub(in crate::engine) fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+pub(in crate::engine) fn unescape(s: &str) -> std::result::Result<String, String> {
+    let mut result = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('\\') => result.push('\\'),
+            Some('t') => result.push('\t'),
+            Some('n') => result.push('\n'),
+            Some('r') => result.push('\r'),
+            _ => Err(format!("Invalid escape in \"{s}\""))?
+        }
+    }
+
+    Ok(result)
+}
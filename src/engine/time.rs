@@ -0,0 +1,113 @@
+/// Frame timing of the engine. Scaled values are affected by the time scale and pausing, unscaled ones are not.
+pub struct Time {
+    scale: f64,
+    paused: bool,
+    delta: f64,
+    unscaled_delta: f64,
+    elapsed: f64,
+    unscaled_elapsed: f64,
+    frame_count: u64,
+    fixed_tick_count: u64
+}
+
+impl Time {
+    pub(in crate::engine) fn new() -> Time {
+        Time {
+            scale: 1.0,
+            paused: false,
+            delta: 0.0,
+            unscaled_delta: 0.0,
+            elapsed: 0.0,
+            unscaled_elapsed: 0.0,
+            frame_count: 0,
+            fixed_tick_count: 0
+        }
+    }
+
+    // Called once at the start of every frame, returns the scaled delta
+    pub(in crate::engine) fn advance(&mut self, unscaled_delta: f64) -> f64 {
+        self.unscaled_delta = unscaled_delta;
+        self.delta = if self.paused { 0.0 } else { unscaled_delta * self.scale };
+
+        self.unscaled_elapsed += self.unscaled_delta;
+        self.elapsed += self.delta;
+        self.frame_count += 1;
+
+        self.delta
+    }
+
+    pub(in crate::engine) fn advance_fixed(&mut self) {
+        self.fixed_tick_count += 1;
+    }
+
+    pub fn scale(&self) -> f64 {
+        self.scale
+    }
+
+    pub fn set_scale(&mut self, scale: f64) {
+        assert!(scale >= 0.0, "Time scale cannot be negative!");
+
+        self.scale = scale;
+    }
+
+    pub fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    pub fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn delta(&self) -> f64 {
+        self.delta
+    }
+
+    pub fn unscaled_delta(&self) -> f64 {
+        self.unscaled_delta
+    }
+
+    pub fn elapsed(&self) -> f64 {
+        self.elapsed
+    }
+
+    pub fn unscaled_elapsed(&self) -> f64 {
+        self.unscaled_elapsed
+    }
+
+    pub fn frame_count(&self) -> u64 {
+        self.frame_count
+    }
+
+    pub fn fixed_tick_count(&self) -> u64 {
+        self.fixed_tick_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Time;
+
+    #[test]
+    pub fn time_scale_and_pause() {
+        let mut time = Time::new();
+
+        assert_eq!(time.advance(0.5), 0.5);
+
+        time.set_scale(0.5);
+        assert_eq!(time.advance(0.5), 0.25);
+        assert_eq!(time.unscaled_delta(), 0.5);
+
+        time.pause();
+        assert_eq!(time.advance(0.5), 0.0);
+        time.resume();
+        assert_eq!(time.advance(1.0), 0.5);
+
+        assert_eq!(time.elapsed(), 1.25);
+        assert_eq!(time.unscaled_elapsed(), 2.5);
+        assert_eq!(time.frame_count(), 4);
+    }
+}
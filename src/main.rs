@@ -4,13 +4,12 @@ mod engine;
 
 use std::{cell::RefCell, rc::Rc};
 
-use engine::{errors::{Error, Result}, game_object::{component::Component, ObjectID}, Engine};
+use engine::{errors::{Error, Result}, game_object::{component::{components::Transform, Component}, ObjectID}, Engine, EventReader, WindowResized};
 use gl46::GL_BACK;
 use gl_types::{geometric::normalize, vec2, vec3};
-use glfw::Key;
 use regex::Regex;
 
-use crate::engine::{game_object::ComponentID, graphics::{Camera, Projection, gl_enums::{DepthFunction, EnableCap}, sprite_renderer::components::{Sprite, SpriteSheet}, terrain::Terrain}};
+use crate::engine::{game_object::ComponentID, input::InputRecording, graphics::{Camera, GLReplayer, GLTrace, Graphics, Projection, gl_enums::{DepthFunction, EnableCap}, sprite_renderer::components::{Sprite, SpriteSheet}, terrain::Terrain}};
 
 
 #[derive(Clone, Default)]
@@ -23,12 +22,12 @@ pub struct FPSCounter {
 
 impl Component for FPSCounter {
     fn update(&mut self, engine: &mut Engine, _: ObjectID, _: f32) -> Result<()> {
-        if engine.input.get_key_state(Key::Escape).press {
+        if engine.input.action("quit")?.press {
             engine.gfx.set_should_close(true);
         }
         
         self.count += 1;
-        let current_tick = engine.gfx.get_glfw_time() as f32;
+        let current_tick = engine.time.unscaled_elapsed() as f32;
 
         let delta = current_tick - self.last_update;
 
@@ -46,7 +45,7 @@ impl Component for FPSCounter {
     fn fixed_update(&mut self, engine: &mut Engine, _: ObjectID, _: f32) -> Result<()> {
 
         self.fixed_count += 1;
-        let current_tick = engine.gfx.get_glfw_time() as f32;
+        let current_tick = engine.time.unscaled_elapsed() as f32;
 
         let delta = current_tick - self.last_fixed_update;
 
@@ -66,7 +65,8 @@ pub struct Renderer {
     camera: Rc<RefCell<Camera>>,
     camera_size: f32,
     sprite1: Option<ComponentID>,
-    sprite2: Option<ComponentID>
+    sprite2: Option<ComponentID>,
+    resized: Option<EventReader<WindowResized>>
 }
 
 impl Component for Renderer {
@@ -79,14 +79,15 @@ impl Component for Renderer {
         engine.gfx.glClearDepth(0.0);
         engine.gfx.glCullFace(GL_BACK);
 
-        let sprite1 = engine.world.find_child(engine.world.get_root(), "Sprite 1")?.unwrap();
-        let sprite2 = engine.world.find_child(engine.world.get_root(), "Sprite 2")?.unwrap();
+        let sprite1 = engine.world.find_path(engine.world.get_root(), "Sprite 1")?.ok_or("Sprite 1 not found")?;
+        let sprite2 = engine.world.find_path(engine.world.get_root(), "Sprite 2")?.ok_or("Sprite 2 not found")?;
 
-        let sprite1 = engine.world.get_component::<Sprite>(sprite1)?;
-        let sprite2 = engine.world.get_component::<Sprite>(sprite2)?;
+        let sprite1 = engine.world.get_component::<Transform>(sprite1)?;
+        let sprite2 = engine.world.get_component::<Transform>(sprite2)?;
 
         self.sprite1 = Some(sprite1);
         self.sprite2 = Some(sprite2);
+        self.resized = Some(engine.events.reader());
 
         Ok(())
     }
@@ -94,57 +95,30 @@ impl Component for Renderer {
     fn update(&mut self, engine: &mut Engine, _: ObjectID, delta_time: f32) -> Result<()> {
         let mut camera = self.camera.borrow_mut();
         let speed = 10.0;
-        if engine.input.get_key_state(Key::W).is_down {
-            let pos = camera.position();
-            camera.set_position(pos + normalize(vec3!(1, 0, 1)) * delta_time * speed);
-        }
 
-        if engine.input.get_key_state(Key::A).is_down {
-            let pos = camera.position();
-            camera.set_position(pos + normalize(vec3!(-1, 0, 1)) * delta_time * speed);
-        }
-        if engine.input.get_key_state(Key::S).is_down {
-            let pos = camera.position();
-            camera.set_position(pos + normalize(vec3!(-1, 0, -1)) * delta_time * speed);
-        }
-        if engine.input.get_key_state(Key::D).is_down {
-            let pos = camera.position();
-            camera.set_position(pos + normalize(vec3!(1, 0, -1)) * delta_time * speed);
-        }
-        if engine.input.get_key_state(Key::Space).is_down {
-            let pos = camera.position();
-            camera.set_position(pos + vec3!(0, 1, 0) * delta_time * speed);
-        }
-        if engine.input.get_key_state(Key::LeftControl).is_down {
-            let pos = camera.position();
-            camera.set_position(pos + vec3!(0, -1, 0) * delta_time * speed);
-        }
+        let movement = engine.input.axis2("move_camera")?;
+        let vertical = engine.input.axis("camera_vertical")?;
+        let pos = camera.position();
+        camera.set_position(pos + (normalize(vec3!(1, 0, 1)) * movement.y() + normalize(vec3!(1, 0, -1)) * movement.x() + vec3!(0, vertical, 0)) * delta_time * speed);
 
-        let mut sprite = engine.world.borrow_component_mut::<Sprite>(self.sprite2.unwrap())?;
-        if engine.input.get_key_state(Key::Up).is_down {
-            sprite.data.position += vec3!(0, 0, 1) * delta_time * speed;
-        }
+        let mut transform = engine.world.borrow_component_mut::<Transform>(self.sprite2.unwrap())?;
+        let movement = engine.input.axis2("move_sprite")?;
+        let vertical = engine.input.axis("sprite_vertical")?;
+        let position = transform.position();
+        transform.set_position(position + vec3!(movement.x(), vertical, movement.y()) * delta_time * speed);
 
-        if engine.input.get_key_state(Key::Left).is_down {
-            sprite.data.position += vec3!(-1, 0, 0) * delta_time * speed;
-        }
-        if engine.input.get_key_state(Key::Down).is_down {
-            sprite.data.position += vec3!(0, 0, -1) * delta_time * speed;
-        }
-        if engine.input.get_key_state(Key::Right).is_down {
-            sprite.data.position += vec3!(1, 0, 0) * delta_time * speed;
-        }
-        if engine.input.get_key_state(Key::RightShift).is_down {
-            sprite.data.position += vec3!(0, 1, 0) * delta_time * speed;
-        }
-        if engine.input.get_key_state(Key::RightControl).is_down {
-            sprite.data.position += vec3!(0, -1, 0) * delta_time * speed;
-        }
+        self.camera_size -= engine.input.axis("zoom")?;
 
-        self.camera_size -= engine.input.get_scroll_y() as f32;
+        let resized = self.resized.as_mut().unwrap().read(&engine.events).last();
 
         match camera.projection_mut() {
-            Projection::Orthographic { width, .. } => *width = self.camera_size,
+            Projection::Orthographic { width, aspect, .. } => {
+                *width = self.camera_size;
+
+                if let Some(size) = resized.filter(|size| size.height > 0) {
+                    *aspect = size.width as f32 / size.height as f32;
+                }
+            },
             _ => ()
         }
 
@@ -152,8 +126,16 @@ impl Component for Renderer {
     }
 }
 
-fn start_game() -> Result<()> {
+fn start_game(record_input: Option<&str>, play_input: Option<&str>) -> Result<()> {
     let mut engine = Engine::create_window("Test Window", 1280, 720, engine::WindowMode::Windowed)?;
+    engine.input.load_input_map("input_bindings.cfg")?;
+
+    if let Some(path) = record_input {
+        engine.start_input_recording(path)?;
+    }
+    if let Some(path) = play_input {
+        engine.play_input_recording(InputRecording::load(path)?);
+    }
 
     let a = engine.world.create_game_object("a", engine.world.get_root())?;
 
@@ -162,16 +144,16 @@ fn start_game() -> Result<()> {
     sprite_sheet.add_sprite(512, 512, 1024, 1024);
     engine.world.add_component(a, sprite_sheet)?;
     
-    let sprite1 = engine.world.create_game_object("Sprite 1", engine.world.get_root())?;
-    let sprite2 = engine.world.create_game_object("Sprite 2", engine.world.get_root())?;
+    let root = engine.world.get_root();
+    let sprite1 = engine.world.create_game_object("Sprite 1", root)?;
 
-    let mut sprite_component1 = Sprite::new("sprite_sheet.png", 0);
-    sprite_component1.data.anchor = vec2!(0.5, 0);
-    let mut sprite_component2 = Sprite::new("sprite_sheet.png", 1);
-    sprite_component2.data.anchor = vec2!(0.5, 0);
+    let mut sprite_component = Sprite::new("sprite_sheet.png", 0);
+    sprite_component.anchor = vec2!(0.5, 0);
+    engine.world.add_component(sprite1, sprite_component.clone())?;
 
-    engine.world.add_component(sprite1, sprite_component1)?;
-    engine.world.add_component(sprite2, sprite_component2)?;
+    sprite_component.sprite_index = 1;
+    let sprite_prefab = engine.world.create_prefab(sprite1)?;
+    sprite_prefab.instance().name("Sprite 2").set("", sprite_component).spawn(&mut engine.world, root)?;
     
     let camera = Rc::new(RefCell::new(Camera::new(
         Projection::Orthographic {
@@ -190,7 +172,7 @@ fn start_game() -> Result<()> {
     let terrain = Terrain::new("height_map.png", "ground.png");
     engine.world.add_component(a, terrain)?;
 
-    let renderer = Renderer { camera_size: 10.0, camera, sprite1: None, sprite2: None  };
+    let renderer = Renderer { camera_size: 10.0, camera, sprite1: None, sprite2: None, resized: None };
 
     engine.world.add_component(a, FPSCounter::default())?;
     engine.world.add_component(a, renderer)?;
@@ -200,8 +182,60 @@ fn start_game() -> Result<()> {
     Ok(())
 }
 
+// Replays a trace recorded with GL_TRACE, one frame per swap
+fn replay_trace(path: &str, headless: bool) -> Result<()> {
+    let trace = GLTrace::load(path)?;
+    println!("Replaying {} calls over {} frames", trace.calls.len(), trace.frame_count());
+
+    let mut gfx = if headless {
+        Graphics::init_headless()?
+    } else {
+        Graphics::init("Trace Replay", 1280, 720, engine::WindowMode::Windowed)?
+    };
+
+    let mut replayer = GLReplayer::new(trace);
+    while !gfx.should_close() && replayer.replay_frame(&gfx)? {
+        gfx.swap_buffers();
+        gfx.poll_events();
+    }
+
+    for (name, count) in replayer.skipped() {
+        eprintln!("Skipped {} ({} calls)", name, count);
+    }
+
+    if let Some(gl) = gfx.software_context() {
+        println!("{} draw calls", gl.draw_calls().len());
+        return Ok(());
+    }
+
+    // Keep the last frame up until the window is closed
+    while !gfx.should_close() {
+        gfx.__get_glfw_mut().wait_events();
+    }
+
+    Ok(())
+}
+
 fn main() {
-    match start_game() {
+    let args: Vec<String> = std::env::args().collect();
+
+    let result = match args.get(1).map(String::as_str) {
+        Some("--replay") => match args.get(2) {
+            Some(path) => replay_trace(path, args.iter().any(|arg| arg == "--headless")),
+            None => Err("Usage: opengl_engine --replay <trace file> [--headless]".into())
+        },
+        Some("--record-input") => match args.get(2) {
+            Some(path) => start_game(Some(path), None),
+            None => Err("Usage: opengl_engine --record-input <file>".into())
+        },
+        Some("--play-input") => match args.get(2) {
+            Some(path) => start_game(None, Some(path)),
+            None => Err("Usage: opengl_engine --play-input <file>".into())
+        },
+        _ => start_game(None, None)
+    };
+
+    match result {
         Ok(_) => {},
         Err(err) => { eprint!("{}", clean_backtrace(&err, "opengl_engine")); }
     }